@@ -0,0 +1,72 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use text_components::{fmt::TextBuilder, resolving::NoResolutor, testutil};
+
+fn text(c: &mut Criterion) {
+    let (wide, deep) = (testutil::wide_tree(1000), testutil::deep_tree(100));
+    c.bench_function("TextBuilder wide", |b| {
+        b.iter(|| black_box(&wide).build(&NoResolutor, TextBuilder))
+    });
+    c.bench_function("TextBuilder deep", |b| {
+        b.iter(|| black_box(&deep).build(&NoResolutor, TextBuilder))
+    });
+}
+
+#[cfg(feature = "pretty")]
+fn pretty(c: &mut Criterion) {
+    use text_components::fmt::PrettyTextBuilder;
+    let (wide, deep) = (testutil::wide_tree(1000), testutil::deep_tree(100));
+    c.bench_function("PrettyTextBuilder wide", |b| {
+        b.iter(|| black_box(&wide).build(&NoResolutor, PrettyTextBuilder::default()))
+    });
+    c.bench_function("PrettyTextBuilder deep", |b| {
+        b.iter(|| black_box(&deep).build(&NoResolutor, PrettyTextBuilder::default()))
+    });
+}
+#[cfg(not(feature = "pretty"))]
+fn pretty(_c: &mut Criterion) {}
+
+#[cfg(feature = "nbt")]
+fn nbt(c: &mut Criterion) {
+    use text_components::nbt::{NbtBuilder, ToSNBT};
+    let wide = testutil::wide_tree(1000);
+    c.bench_function("NbtBuilder to_snbt wide", |b| {
+        b.iter(|| {
+            black_box(&wide)
+                .build(&NoResolutor, NbtBuilder::new())
+                .to_snbt()
+        })
+    });
+}
+#[cfg(not(feature = "nbt"))]
+fn nbt(_c: &mut Criterion) {}
+
+fn format(c: &mut Criterion) {
+    use text_components::format::{Color, Format};
+    let parent = Format::new()
+        .color(Color::Gold)
+        .font("uniform")
+        .bold(true)
+        .shadow_color(0xFF, 0x10, 0x20, 0x30);
+    let child = Format::new().italic(true);
+    c.bench_function("Format mix", |b| {
+        b.iter(|| black_box(&child).mix(black_box(&parent)))
+    });
+    c.bench_function("Format mix_in_place", |b| {
+        b.iter(|| {
+            let mut format = black_box(&child).clone();
+            format.mix_in_place(black_box(&parent));
+            format
+        })
+    });
+}
+
+fn resolve(c: &mut Criterion) {
+    let resolvables = testutil::resolvable_tree(1000);
+    c.bench_function("resolve resolvables", |b| {
+        b.iter(|| black_box(&resolvables).resolve(&NoResolutor))
+    });
+}
+
+criterion_group!(build, text, pretty, nbt, format, resolve);
+criterion_main!(build);
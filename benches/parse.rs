@@ -0,0 +1,23 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use text_components::{TextComponent, testutil};
+
+fn from_snbt(c: &mut Criterion) {
+    c.bench_function("from_snbt example", |b| {
+        b.iter(|| TextComponent::from_snbt(black_box(testutil::EXAMPLE_SNBT)))
+    });
+}
+
+#[cfg(feature = "nbt")]
+fn from_nbt(c: &mut Criterion) {
+    use text_components::{nbt::NbtBuilder, resolving::NoResolutor};
+    let nbt = testutil::wide_tree(600).build(&NoResolutor, NbtBuilder::new());
+    c.bench_function("from_nbt 1k nodes", |b| {
+        b.iter(|| TextComponent::from_nbt(black_box(&nbt)))
+    });
+}
+#[cfg(not(feature = "nbt"))]
+fn from_nbt(_c: &mut Criterion) {}
+
+criterion_group!(parse, from_snbt, from_nbt);
+criterion_main!(parse);
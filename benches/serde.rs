@@ -0,0 +1,27 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use text_components::{TextComponent, testutil};
+
+fn serialize(c: &mut Criterion) {
+    let (wide, deep) = (testutil::wide_tree(1000), testutil::deep_tree(100));
+    c.bench_function("serialize wide", |b| {
+        b.iter(|| serde_json::to_string(black_box(&wide)))
+    });
+    c.bench_function("serialize deep", |b| {
+        b.iter(|| serde_json::to_string(black_box(&deep)))
+    });
+}
+
+fn deserialize(c: &mut Criterion) {
+    let wide = serde_json::to_string(&testutil::wide_tree(1000)).unwrap();
+    let deep = serde_json::to_string(&testutil::deep_tree(100)).unwrap();
+    c.bench_function("deserialize wide", |b| {
+        b.iter(|| TextComponent::from_json(black_box(&wide)))
+    });
+    c.bench_function("deserialize deep", |b| {
+        b.iter(|| TextComponent::from_json(black_box(&deep)))
+    });
+}
+
+criterion_group!(serde, serialize, deserialize);
+criterion_main!(serde);
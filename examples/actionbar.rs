@@ -0,0 +1,131 @@
+//! Builds an actionbar of 15 components 20 times per second for 100 players, creating
+//! the tree from scratch every tick and reusing the allocations of the last one.
+//! Run with `cargo run --release --example actionbar --features pool,serde`, adding `nbt`
+//! to also write it as network NBT into a reused buffer.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+use text_components::{
+    Modifier, TextComponent,
+    format::Color,
+    interactivity::{HoverEvent, Interactivity},
+    pool::ComponentPool,
+    resolving::StaticScores,
+};
+
+struct Counting;
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+const TICKS: usize = 20 * 60;
+const PLAYERS: usize = 100;
+const STATS: [(&str, &str, Color); 4] = [
+    ("Health", "health", Color::Red),
+    ("Mana", "mana", Color::Blue),
+    ("Kills", "kills", Color::Gold),
+    ("Deaths", "deaths", Color::Gray),
+];
+
+/// `Health: 20 | Mana: 13 | ...` with a hover on every stat.
+fn fresh(tick: usize) -> TextComponent {
+    let mut bar = TextComponent::new();
+    for (i, (name, objective, color)) in STATS.into_iter().enumerate() {
+        if i > 0 {
+            bar = bar.add_child(" | ".color(Color::DarkGray));
+        }
+        bar = bar
+            .add_child(
+                format!("{name}: ")
+                    .color(color)
+                    .hover_event(HoverEvent::show_text(format!("Your {objective}"))),
+            )
+            .add_child(TextComponent::scoreboard("@s", objective));
+    }
+    bar.add_child(format!(" #{tick}"))
+}
+
+/// Same as [fresh] taking the boxes and vectors from the pool.
+fn pooled(pool: &mut ComponentPool, tick: usize) -> TextComponent {
+    let mut children = pool.children();
+    for (i, (name, objective, color)) in STATS.into_iter().enumerate() {
+        if i > 0 {
+            children.push(" | ".color(Color::DarkGray));
+        }
+        let mut label = TextComponent::plain(format!("{name}: ")).color(color);
+        label.interactions = Interactivity {
+            hover: Some(HoverEvent::ShowText {
+                value: pool.boxed(format!("Your {objective}").into()),
+            }),
+            ..Interactivity::new()
+        };
+        children.push(label);
+        children.push(TextComponent::scoreboard("@s", objective));
+    }
+    children.push(format!(" #{tick}").into());
+    TextComponent {
+        children,
+        ..TextComponent::new()
+    }
+}
+
+fn measure(name: &str, mut tick: impl FnMut(usize) -> usize) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let mut bytes = 0;
+    for i in 0..TICKS {
+        for _ in 0..PLAYERS {
+            bytes += tick(i);
+        }
+    }
+    let elapsed = start.elapsed();
+    let builds = TICKS * PLAYERS;
+    println!(
+        "{name}: {:?} per build, {} allocations per build ({bytes} bytes written)",
+        elapsed / builds as u32,
+        (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / builds,
+    );
+}
+
+fn main() {
+    let scores = STATS
+        .iter()
+        .fold(StaticScores::new(), |scores, (_, objective, _)| {
+            scores.score("@s", *objective, 20)
+        });
+
+    measure("fresh", |tick| fresh(tick).to_json(&scores).unwrap().len());
+
+    let mut pool = ComponentPool::new();
+    measure("pooled", |tick| {
+        let bar = pooled(&mut pool, tick);
+        let length = bar.to_json(&scores).unwrap().len();
+        pool.recycle(bar);
+        length
+    });
+
+    #[cfg(feature = "nbt")]
+    {
+        let mut buffer = Vec::new();
+        measure("pooled NBT", |tick| {
+            let bar = pooled(&mut pool, tick);
+            buffer.clear();
+            text_components::nbt::NbtBuilder::new().write_into(&scores, &bar, &mut buffer);
+            pool.recycle(bar);
+            buffer.len()
+        });
+    }
+}
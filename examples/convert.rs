@@ -0,0 +1,475 @@
+//! Converts a component read from stdin between the formats of the crate.
+//! The input is read as JSON or SNBT, whichever parses, otherwise as MiniMessage if it has
+//! tags and as legacy text with `§` or `&` codes if it doesn't.\
+//! Only the color and decoration tags of MiniMessage are read, and `reset`.
+//! ```text
+//! echo '{text:"Hi",color:"red"}' | cargo run --example convert --features serde -- --to json
+//! echo '&cHi &lthere' | cargo run --example convert --features serde -- --to html
+//! cargo run --example convert --features serde -- --to plain --lang en_us.json < message.json
+//! ```
+use std::{
+    borrow::Cow,
+    env,
+    fmt::Write as _,
+    io::{Read, stdin},
+    iter::Peekable,
+    process::ExitCode,
+    str::Chars,
+};
+use text_components::{
+    Modifier, TextComponent,
+    content::{Content, Object, Resolvable},
+    fmt::{ComponentVisitor, VisitorTarget},
+    format::{Color, Format},
+    interactivity::{ClickEvent, Interactivity},
+    resolving::{NoResolutor, TextResolutor},
+    translation::TranslationTable,
+};
+
+const USAGE: &str =
+    "Usage: convert [--to json|snbt|minimessage|legacy|plain|ansi|html] [--lang <file>]";
+
+/// Translates with a vanilla lang file, anything else resolves like [NoResolutor].
+struct Lang(TranslationTable);
+impl TextResolutor for Lang {
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        NoResolutor.resolve_content(resolvable)
+    }
+
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, data: &text_components::custom::CustomData) -> Option<TextComponent> {
+        NoResolutor.resolve_custom(data)
+    }
+
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>> {
+        self.0.get(key).map(|text| Cow::Borrowed(&**text))
+    }
+}
+
+/// The legacy code of a named color, like `c` for red.
+const LEGACY_COLORS: [(char, Color); 16] = [
+    ('0', Color::Black),
+    ('1', Color::DarkBlue),
+    ('2', Color::DarkGreen),
+    ('3', Color::DarkAqua),
+    ('4', Color::DarkRed),
+    ('5', Color::DarkPurple),
+    ('6', Color::Gold),
+    ('7', Color::Gray),
+    ('8', Color::DarkGray),
+    ('9', Color::Blue),
+    ('a', Color::Green),
+    ('b', Color::Aqua),
+    ('c', Color::Red),
+    ('d', Color::LightPurple),
+    ('e', Color::Yellow),
+    ('f', Color::White),
+];
+
+/// The text shown for what's left unresolved, like keybinds without a binding.
+fn unresolved_text(content: &Content) -> String {
+    TextComponent {
+        content: content.clone(),
+        ..TextComponent::new()
+    }
+    .to_plain(&NoResolutor)
+}
+
+/// Writes `§` codes before each run whose format changed, hex colors as `§x§r§r§g§g§b§b`.
+#[derive(Clone, Default)]
+struct Legacy {
+    text: String,
+    format: Option<Format>,
+}
+impl Legacy {
+    fn push(&mut self, text: &str, format: &Format) {
+        if text.is_empty() {
+            return;
+        }
+        if self.format.as_ref() != Some(format) {
+            if self.format.as_ref().is_some_and(|format| !format.is_none()) {
+                self.text.push_str("§r");
+            }
+            match format.color {
+                Some(Color::Rgb(r, g, b)) => {
+                    self.text.push_str("§x");
+                    for digit in format!("{r:02x}{g:02x}{b:02x}").chars() {
+                        self.text.push('§');
+                        self.text.push(digit);
+                    }
+                }
+                Some(color) => {
+                    if let Some((code, _)) = LEGACY_COLORS.iter().find(|(_, c)| *c == color) {
+                        self.text.push('§');
+                        self.text.push(*code);
+                    }
+                }
+                None => {}
+            }
+            for (set, code) in [
+                (format.obfuscated, 'k'),
+                (format.bold, 'l'),
+                (format.strikethrough, 'm'),
+                (format.underlined, 'n'),
+                (format.italic, 'o'),
+            ] {
+                if set == Some(true) {
+                    self.text.push('§');
+                    self.text.push(code);
+                }
+            }
+            self.format = Some(format.clone());
+        }
+        self.text.push_str(text);
+    }
+}
+impl ComponentVisitor for Legacy {
+    type Output = String;
+    fn visit_text(&mut self, text: &str, format: &Format, _: &Interactivity) {
+        self.push(text, format);
+    }
+    fn visit_object(&mut self, object: &Object, format: &Format, _: &Interactivity) {
+        self.push(&unresolved_text(&Content::Object(object.clone())), format);
+    }
+    fn visit_unresolved(&mut self, content: &Content, format: &Format, _: &Interactivity) {
+        self.push(&unresolved_text(content), format);
+    }
+    fn finish(self) -> String {
+        self.text
+    }
+}
+
+/// Writes each run as a `<span>` styled inline, inside a `<a>` when it opens a link.
+/// Adjacent runs with the same style and link share their tags.
+#[derive(Clone, Default)]
+struct Html {
+    /// The style, link and text of each run.
+    runs: Vec<(String, Option<String>, String)>,
+}
+impl Html {
+    fn push(&mut self, text: &str, format: &Format, interactions: &Interactivity) {
+        if text.is_empty() {
+            return;
+        }
+        let mut style = String::new();
+        if let Some(color) = format.color {
+            let (r, g, b) = color.as_rgb();
+            let _ = write!(style, "color:#{r:02x}{g:02x}{b:02x};");
+        }
+        for (set, css) in [
+            (format.bold, "font-weight:bold;"),
+            (format.italic, "font-style:italic;"),
+        ] {
+            if set == Some(true) {
+                style.push_str(css);
+            }
+        }
+        let decorations: Vec<_> = [
+            (format.underlined, "underline"),
+            (format.strikethrough, "line-through"),
+        ]
+        .into_iter()
+        .filter(|(set, _)| *set == Some(true))
+        .map(|(_, decoration)| decoration)
+        .collect();
+        if !decorations.is_empty() {
+            let _ = write!(style, "text-decoration:{};", decorations.join(" "));
+        }
+        let url = match &interactions.click {
+            Some(ClickEvent::OpenUrl { url }) => Some(url.to_string()),
+            _ => None,
+        };
+        match self.runs.last_mut() {
+            Some((last_style, last_url, last)) if *last_style == style && *last_url == url => {
+                last.push_str(text)
+            }
+            _ => self.runs.push((style, url, text.to_string())),
+        }
+    }
+}
+impl ComponentVisitor for Html {
+    type Output = String;
+    fn visit_text(&mut self, text: &str, format: &Format, interactions: &Interactivity) {
+        self.push(text, format, interactions);
+    }
+    fn visit_object(&mut self, object: &Object, format: &Format, interactions: &Interactivity) {
+        let text = unresolved_text(&Content::Object(object.clone()));
+        self.push(&text, format, interactions);
+    }
+    fn visit_unresolved(
+        &mut self,
+        content: &Content,
+        format: &Format,
+        interactions: &Interactivity,
+    ) {
+        self.push(&unresolved_text(content), format, interactions);
+    }
+    fn finish(self) -> String {
+        let mut html = String::new();
+        for (style, url, text) in self.runs {
+            if let Some(url) = &url {
+                let _ = write!(html, "<a href=\"{}\">", escape_html(url));
+            }
+            match style.is_empty() {
+                true => html.push_str(&escape_html(&text)),
+                false => {
+                    let _ = write!(
+                        html,
+                        "<span style=\"{style}\">{}</span>",
+                        escape_html(&text)
+                    );
+                }
+            }
+            if url.is_some() {
+                html.push_str("</a>");
+            }
+        }
+        html
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\n' => escaped.push_str("<br>"),
+            char => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+/// Reads legacy text, with `§` codes or `&` ones if there's no `§`. Each code starts a new
+/// child, colors and `§r` clearing the styles before them.
+fn parse_legacy(input: &str) -> TextComponent {
+    let marker = match input.contains('§') {
+        true => '§',
+        false => '&',
+    };
+    let mut children = vec![];
+    let mut format = Format::new();
+    let mut text = String::new();
+    let mut chars = input.chars().peekable();
+    while let Some(char) = chars.next() {
+        let code = match chars.peek() {
+            Some(code) if char == marker => code.to_ascii_lowercase(),
+            _ => {
+                text.push(char);
+                continue;
+            }
+        };
+        let next = match code {
+            'k' => format.clone().obfuscated(true),
+            'l' => format.clone().bold(true),
+            'm' => format.clone().strikethrough(true),
+            'n' => format.clone().underlined(true),
+            'o' => format.clone().italic(true),
+            'r' => Format::new(),
+            'x' => match hex_color(&mut chars, marker) {
+                Some(color) => Format::new().color(color),
+                None => {
+                    text.push(char);
+                    continue;
+                }
+            },
+            code => match LEGACY_COLORS.iter().find(|(c, _)| *c == code) {
+                Some((_, color)) => Format::new().color(*color),
+                None => {
+                    text.push(char);
+                    continue;
+                }
+            },
+        };
+        // The hex digits are already read
+        if code != 'x' {
+            chars.next();
+        }
+        let previous = std::mem::replace(&mut format, next);
+        flush(&mut children, &mut text, previous);
+    }
+    flush(&mut children, &mut text, format);
+    match children.len() {
+        1 => children.remove(0),
+        _ => TextComponent::new().add_children(children),
+    }
+}
+
+/// Reads the `x§r§r§g§g§b§b` after a `§`, leaving `chars` untouched if it's malformed.
+fn hex_color(chars: &mut Peekable<Chars>, marker: char) -> Option<Color> {
+    let mut rest = chars.clone();
+    rest.next();
+    let mut hex = String::new();
+    for _ in 0..6 {
+        match (rest.next(), rest.next()) {
+            (Some(m), Some(digit)) if m == marker && digit.is_ascii_hexdigit() => hex.push(digit),
+            _ => return None,
+        }
+    }
+    let [_, r, g, b] = u32::from_str_radix(&hex, 16).ok()?.to_be_bytes();
+    *chars = rest;
+    Some(Color::Rgb(r, g, b))
+}
+
+fn flush(children: &mut Vec<TextComponent>, text: &mut String, format: Format) {
+    if !text.is_empty() {
+        children.push(TextComponent::plain(std::mem::take(text)).style(format));
+    }
+}
+
+/// Whether the input has a tag like `<red>`, `<#ff0044>` or `</bold>`.
+fn looks_like_minimessage(input: &str) -> bool {
+    input.split('<').skip(1).any(|rest| {
+        let Some((tag, _)) = rest.split_once('>') else {
+            return false;
+        };
+        let name = tag.strip_prefix('/').unwrap_or(tag);
+        name.starts_with(|char: char| char.is_ascii_lowercase() || char == '#' || char == '!')
+            && name
+                .chars()
+                .all(|char| char.is_ascii_alphanumeric() || "_:#!'\"./-".contains(char))
+    })
+}
+
+/// Reads the color and decoration tags of MiniMessage, a closing tag closes the last one
+/// opened.
+fn parse_minimessage(input: &str) -> Result<TextComponent, String> {
+    let mut children = vec![];
+    let mut formats = vec![Format::new()];
+    let mut text = String::new();
+    let mut chars = input.chars();
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => text.extend(chars.next()),
+            '<' => {
+                let rest = chars.as_str();
+                let Some((tag, after)) = rest.split_once('>') else {
+                    text.push(char);
+                    continue;
+                };
+                let current = formats.last().cloned().unwrap_or_default();
+                flush(&mut children, &mut text, current.clone());
+                chars = after.chars();
+                if tag.starts_with('/') {
+                    if formats.len() > 1 {
+                        formats.pop();
+                    }
+                    continue;
+                }
+                if tag == "reset" {
+                    formats.truncate(1);
+                    continue;
+                }
+                let (name, value) = match tag.strip_prefix('!') {
+                    Some(name) => (name, false),
+                    None => (tag, true),
+                };
+                let format = match name {
+                    "bold" | "b" => current.bold(value),
+                    "italic" | "i" | "em" => current.italic(value),
+                    "underlined" | "u" => current.underlined(value),
+                    "strikethrough" | "st" => current.strikethrough(value),
+                    "obfuscated" | "obf" => current.obfuscated(value),
+                    _ => {
+                        let color = tag.strip_prefix("color:").unwrap_or(tag);
+                        let color = match color.starts_with('#') {
+                            true => Color::from_hex(color),
+                            false => Color::from_name(color),
+                        };
+                        match color {
+                            Some(color) => current.color(color),
+                            None => return Err(format!("Unsupported MiniMessage tag <{tag}>")),
+                        }
+                    }
+                };
+                formats.push(format);
+            }
+            char => text.push(char),
+        }
+    }
+    flush(&mut children, &mut text, formats.pop().unwrap_or_default());
+    Ok(match children.len() {
+        1 => children.remove(0),
+        _ => TextComponent::new().add_children(children),
+    })
+}
+
+fn read(input: &str) -> Result<TextComponent, String> {
+    match TextComponent::from_embedded_str(input) {
+        Ok(component) => Ok(component),
+        Err(_) if looks_like_minimessage(input) => parse_minimessage(input),
+        Err(err) if input.starts_with(['{', '[']) => {
+            Err(format!("Not a JSON or SNBT component: {err}"))
+        }
+        Err(_) => Ok(parse_legacy(input)),
+    }
+}
+
+fn convert(component: &TextComponent, to: &str, lang: &Lang) -> Result<String, String> {
+    match to {
+        "json" => component.to_json(lang).map_err(|err| err.to_string()),
+        #[cfg(feature = "nbt")]
+        "snbt" => {
+            use text_components::nbt::{NbtBuilder, ToSNBT};
+            component
+                .build(lang, NbtBuilder::new())
+                .try_to_snbt()
+                .map_err(|err| err.to_string())
+        }
+        "minimessage" => Ok(component.resolve(lang).to_minimessage()),
+        "legacy" => Ok(component
+            .resolve(lang)
+            .build(lang, VisitorTarget(Legacy::default()))),
+        "plain" => Ok(component.to_plain(lang)),
+        "ansi" => {
+            colored::control::set_override(true);
+            Ok(component.to_pretty(lang).to_string())
+        }
+        "html" => Ok(component
+            .resolve(lang)
+            .build(lang, VisitorTarget(Html::default()))),
+        #[cfg(not(feature = "nbt"))]
+        "snbt" => Err(String::from("Writing SNBT needs the nbt feature")),
+        _ => Err(format!("Unknown format {to}\n{USAGE}")),
+    }
+}
+
+fn run() -> Result<String, String> {
+    let mut to = String::from("json");
+    let mut lang = Lang(TranslationTable::new());
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--to" => to = args.next().ok_or(USAGE)?,
+            "--lang" => {
+                let path = args.next().ok_or(USAGE)?;
+                let file =
+                    std::fs::read_to_string(&path).map_err(|err| format!("{path}: {err}"))?;
+                lang.0 = serde_json::from_str(&file).map_err(|err| format!("{path}: {err}"))?;
+            }
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    let mut input = String::new();
+    stdin()
+        .read_to_string(&mut input)
+        .map_err(|err| err.to_string())?;
+    let component = read(input.trim())?;
+    convert(&component, &to, &lang)
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
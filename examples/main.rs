@@ -1,38 +1,40 @@
 #[cfg(feature = "custom")]
 use chrono::Utc;
-#[cfg(feature = "serde")]
-use serde::Serialize;
 #[cfg(feature = "nbt")]
 use simdnbt::{
     ToNbtTag,
     owned::{BaseNbt, Nbt, NbtCompound, NbtTag},
 };
+use std::{borrow::Cow, sync::LazyLock};
 #[cfg(feature = "custom")]
 use text_components::custom::{CustomContent, CustomData, CustomRegistry, Payload};
 #[cfg(feature = "nbt")]
-use text_components::nbt::{NbtBuilder, ToSNBT};
+use text_components::nbt::ToSNBT;
 use text_components::{
     Modifier, TextComponent,
     content::{NbtSource, ObjectPlayer, Resolvable},
     fmt::set_display_resolutor,
     format::Color,
     interactivity::{ClickEvent, HoverEvent},
-    resolving::TextResolutor,
+    resolving::{StaticScores, TextResolutor},
     translation::{TranslatedMessage, Translation},
 };
 use uuid::Uuid;
 
+static SCORES: LazyLock<StaticScores> =
+    LazyLock::new(|| StaticScores::new().score("MrMelther", "objective", 5));
+
 struct EmptyResolutor;
 impl TextResolutor for EmptyResolutor {
-    fn translate(&self, key: &str) -> Option<String> {
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>> {
         match key {
-            "content" => Some(String::from(
+            "content" => Some(Cow::Borrowed(
                 "This is a test TextComponent!\n Color: %s\n Bold: %s\n Italic: %s\n Underline: %s\n Strikethrough: %s\n Obfuscated: %s\n Shadow Color: %s\n Translation: %s\n Link: %s\n(All the green text is translated with arguments checked at compile time!)",
             )),
-            "translated" => Some(String::from(
+            "translated" => Some(Cow::Borrowed(
                 "This text is Translated! (Without compile time check!)",
             )),
-            "resoluble" => Some(String::from(
+            "resoluble" => Some(Cow::Borrowed(
                 "\n\nResolubles:\n Object: %s\n Scoreboard: %s\n Entity: %s\n Nbt: %s",
             )),
             _ => None,
@@ -40,7 +42,7 @@ impl TextResolutor for EmptyResolutor {
     }
     fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
         match resolvable {
-            Resolvable::Scoreboard { .. } => TextComponent::plain("5"),
+            Resolvable::Scoreboard { .. } => SCORES.resolve_content(resolvable),
             Resolvable::Entity { .. } => TextComponent::plain("MrMelther")
                 .insertion("MrMelther")
                 .click_event(ClickEvent::suggest_command("/msg MrMelther "))
@@ -101,7 +103,7 @@ impl CustomContent for TimeContent {
 
     fn as_data(&self) -> CustomData {
         CustomData {
-            id: std::borrow::Cow::Borrowed("time"),
+            id: Cow::Borrowed("time"),
             payload: Payload::Empty,
         }
     }
@@ -113,6 +115,7 @@ impl CustomContent for TimeContent {
 
 fn main() {
     set_display_resolutor(&EmptyResolutor);
+    #[cfg_attr(not(feature = "custom"), allow(unused_mut))]
     let mut resolubles = RESOLUBLE
         .message([
             ObjectPlayer::name("MrMelther").reset(),
@@ -149,17 +152,14 @@ fn main() {
 
     println!("\nDebug:\n{:?}", component);
     #[cfg(feature = "serde")]
-    {
-        let mut vec = vec![];
-        let _ = component
-            .resolve(&EmptyResolutor)
-            .serialize(&mut serde_json::Serializer::new(&mut vec));
-        println!("\nSerde (json):\n{}", String::from_utf8(vec).unwrap());
-    }
+    println!(
+        "\nSerde (json):\n{}",
+        component.to_json(&EmptyResolutor).unwrap()
+    );
     #[cfg(feature = "nbt")]
     println!(
-        "\nNBT (SNBT):\ntellraw @a {}",
-        component.build(&EmptyResolutor, NbtBuilder).to_snbt()
+        "\nNBT (SNBT):\n{}",
+        component.to_tellraw(&EmptyResolutor, "@a").unwrap()
     );
     println!("\nText:\n{}", component);
     println!("\nPretty Text:\n{:p}", component);
@@ -3,7 +3,7 @@ use text_components::{
     Modifier, TextComponent,
     format::Color,
     interactivity::{ClickEvent, HoverEvent},
-    nbt::{NbtBuilder, ToSNBT},
+    nbt::NbtBuilder,
     resolving::NoResolutor,
 };
 
@@ -19,11 +19,8 @@ fn main() -> Result<(), String> {
             ("string".into(), NbtTag::String("This is a text".into())),
         ]),
     ));
-    let component = TextComponent::nbt_display(nbt);
-    println!(
-        "tellraw @p {}",
-        component.build(&NoResolutor, NbtBuilder).to_snbt()
-    );
+    let component = TextComponent::nbt_display(&nbt);
+    println!("{}", component.to_tellraw(&NoResolutor, "@p").unwrap());
     println!("{:p}", component);
 
     let nbt = "Holly molly I can get TextComponents from NBTs!"
@@ -36,7 +33,7 @@ fn main() -> Result<(), String> {
                 "/tell \"Guys, I'm very happy!\"",
             )),
         ])
-        .build(&NoResolutor, NbtBuilder);
+        .build(&NoResolutor, NbtBuilder::new());
     println!("{:?}", nbt);
     let component =
         TextComponent::from_nbt(&nbt).ok_or(String::from("Cannot recompose the TextComponent!"))?;
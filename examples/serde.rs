@@ -1,11 +1,13 @@
-use text_components::{Modifier, TextComponent, format::Color, translation::TranslatedMessage};
+use text_components::{
+    Modifier, TextComponent, format::Color, resolving::NoResolutor, translation::TranslatedMessage,
+};
 
 fn main() {
     let component: TextComponent = TranslatedMessage::new("key", None)
         .color(Color::Blue)
         .bold(true);
-    println!("{}", serde_json::to_string_pretty(&component).unwrap());
-    let component: TextComponent = serde_json::from_str(
+    println!("{}", component.to_json(&NoResolutor).unwrap());
+    let component = TextComponent::from_json(
         "{
             \"text\": \"This is a Serde test\",
             \"color\": \"blue\",
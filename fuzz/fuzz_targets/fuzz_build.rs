@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use text_components::{
+    TextComponent,
+    nbt::{NbtBuilder, ToSNBT},
+    resolving::NoResolutor,
+};
+
+// None of the builders may panic, whatever the component holds.
+fuzz_target!(|component: TextComponent| {
+    let _ = component.to_plain(&NoResolutor);
+    let _ = component.to_pretty(&NoResolutor).to_string();
+    let _ = component.to_minimessage();
+    let _ = component.build(&NoResolutor, NbtBuilder::new()).try_to_snbt();
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use simdnbt::owned::read_tag;
+use text_components::TextComponent;
+
+fuzz_target!(|data: &[u8]| {
+    let mut data = std::io::Cursor::new(data);
+    if let Ok(tag) = read_tag(&mut data) {
+        let _ = TextComponent::from_nbt(&tag);
+    }
+});
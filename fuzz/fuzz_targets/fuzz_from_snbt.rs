@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use text_components::TextComponent;
+
+fuzz_target!(|data: &str| {
+    let _ = TextComponent::from_snbt(data);
+});
@@ -0,0 +1,24 @@
+#![no_main]
+
+use libfuzzer_sys::{Corpus, fuzz_target};
+use text_components::{
+    TextComponent,
+    nbt::{NbtBuilder, ToSNBT},
+    resolving::NoResolutor,
+};
+
+// Whatever the NBT builder emits has to be accepted by the SNBT parser,
+// and building the parsed component again must give back the same SNBT.
+fuzz_target!(|component: TextComponent| -> Corpus {
+    // NaN and infinite floats of unknown fields can't be written
+    let Ok(snbt) = component.build(&NoResolutor, NbtBuilder::new()).try_to_snbt() else {
+        return Corpus::Reject;
+    };
+    let parsed = TextComponent::from_snbt(&snbt)
+        .unwrap_or_else(|err| panic!("{err}, while parsing: {snbt}"));
+    assert_eq!(
+        Ok(snbt),
+        parsed.build(&NoResolutor, NbtBuilder::new()).try_to_snbt()
+    );
+    Corpus::Keep
+});
@@ -0,0 +1,275 @@
+//! [Arbitrary] implementations used to fuzz the parsers and builders.
+//!
+//! Recursive types share a depth counter so generated trees stay shallow
+//! enough to be built and parsed back without blowing the stack.
+#[cfg(feature = "custom")]
+use crate::custom::{CustomData, Payload};
+use crate::{
+    TextComponent,
+    content::{
+        Content, NbtSource, Object, ObjectPlayer, PlayerProperties, Resolvable, normalize_keybind,
+    },
+    dialog::DialogRef,
+    format::{Color, Format, namespaced_font},
+    interactivity::{ClickEvent, HoverEvent, Interactivity},
+    translation::TranslatedMessage,
+};
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+use std::{borrow::Cow, cell::Cell};
+use uuid::Uuid;
+
+const MAX_DEPTH: u32 = 8;
+
+thread_local! {
+    static DEPTH: Cell<u32> = const { Cell::new(0) };
+}
+
+fn cow(u: &mut Unstructured<'_>) -> Result<Cow<'static, str>> {
+    Ok(Cow::Owned(String::arbitrary(u)?))
+}
+
+fn option_cow(u: &mut Unstructured<'_>) -> Result<Option<Cow<'static, str>>> {
+    Ok(if u.arbitrary()? { Some(cow(u)?) } else { None })
+}
+
+/// Generates a nested component, or a plain one once [MAX_DEPTH] is reached.
+fn nested(u: &mut Unstructured<'_>) -> Result<TextComponent> {
+    if DEPTH.get() >= MAX_DEPTH {
+        return Ok(TextComponent::plain(cow(u)?));
+    }
+    DEPTH.set(DEPTH.get() + 1);
+    let component = TextComponent::arbitrary(u);
+    DEPTH.set(DEPTH.get() - 1);
+    component
+}
+
+fn nested_vec(u: &mut Unstructured<'_>) -> Result<Vec<TextComponent>> {
+    let mut components = vec![];
+    if DEPTH.get() >= MAX_DEPTH {
+        return Ok(components);
+    }
+    for _ in 0..u.int_in_range(0..=4u8)? {
+        components.push(nested(u)?);
+    }
+    Ok(components)
+}
+
+impl<'a> Arbitrary<'a> for TextComponent {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(TextComponent {
+            content: Content::arbitrary(u)?,
+            children: nested_vec(u)?,
+            format: Format::arbitrary(u)?,
+            interactions: Interactivity::arbitrary(u)?,
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Content {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=5u8)? {
+            0 => Content::Text { text: cow(u)? },
+            1 => Content::Translate(TranslatedMessage::arbitrary(u)?),
+            2 => Content::Keybind {
+                keybind: normalize_keybind(cow(u)?),
+            },
+            #[cfg(feature = "custom")]
+            3 => Content::Custom(CustomData::arbitrary(u)?),
+            4 => Content::Object(Object::arbitrary(u)?),
+            5 => Content::Resolvable(Resolvable::arbitrary(u)?),
+            _ => Content::Text { text: cow(u)? },
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for TranslatedMessage {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(TranslatedMessage {
+            key: cow(u)?.into(),
+            fallback: option_cow(u)?,
+            args: if u.arbitrary()? {
+                Some(nested_vec(u)?.into_boxed_slice())
+            } else {
+                None
+            },
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Object {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(if u.arbitrary()? {
+            Object::Atlas {
+                atlas: option_cow(u)?,
+                sprite: cow(u)?,
+            }
+        } else {
+            Object::Player {
+                player: ObjectPlayer::arbitrary(u)?,
+                hat: u.arbitrary()?,
+            }
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ObjectPlayer {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut properties = vec![];
+        for _ in 0..u.int_in_range(0..=2u8)? {
+            properties.push(PlayerProperties::arbitrary(u)?);
+        }
+        Ok(ObjectPlayer {
+            name: option_cow(u)?,
+            id: u.arbitrary()?,
+            texture: option_cow(u)?,
+            properties,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for PlayerProperties {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(PlayerProperties {
+            name: cow(u)?,
+            value: cow(u)?,
+            signature: option_cow(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Resolvable {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=2u8)? {
+            0 => Resolvable::Scoreboard {
+                selector: cow(u)?,
+                objective: cow(u)?,
+            },
+            1 => Resolvable::Entity {
+                selector: cow(u)?,
+                separator: Box::new(nested(u)?),
+            },
+            _ => Resolvable::NBT {
+                path: cow(u)?,
+                interpret: u.arbitrary()?,
+                separator: Box::new(nested(u)?),
+                source: NbtSource::arbitrary(u)?,
+            },
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for NbtSource {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=2u8)? {
+            0 => NbtSource::Entity(cow(u)?),
+            1 => NbtSource::Block(cow(u)?),
+            _ => NbtSource::Storage(cow(u)?),
+        })
+    }
+}
+
+#[cfg(feature = "custom")]
+impl<'a> Arbitrary<'a> for CustomData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(CustomData {
+            id: cow(u)?,
+            payload: Payload::Empty,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Format {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Format {
+            color: u.arbitrary()?,
+            font: option_cow(u)?.map(|font| namespaced_font(font.into())),
+            bold: u.arbitrary()?,
+            italic: u.arbitrary()?,
+            underlined: u.arbitrary()?,
+            strikethrough: u.arbitrary()?,
+            obfuscated: u.arbitrary()?,
+            shadow_color: u.arbitrary::<Option<u32>>()?.map(i64::from),
+            no_inherit: false,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Color {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=16u8)? {
+            0 => Color::Aqua,
+            1 => Color::Black,
+            2 => Color::Blue,
+            3 => Color::DarkAqua,
+            4 => Color::DarkBlue,
+            5 => Color::DarkGray,
+            6 => Color::DarkGreen,
+            7 => Color::DarkPurple,
+            8 => Color::DarkRed,
+            9 => Color::Gold,
+            10 => Color::Gray,
+            11 => Color::Green,
+            12 => Color::LightPurple,
+            13 => Color::Red,
+            14 => Color::White,
+            15 => Color::Yellow,
+            _ => Color::Rgb(u.arbitrary()?, u.arbitrary()?, u.arbitrary()?),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Interactivity {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Interactivity {
+            insertion: option_cow(u)?.map(Into::into),
+            click: u.arbitrary()?,
+            hover: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ClickEvent {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=6u8)? {
+            0 => ClickEvent::OpenUrl { url: cow(u)? },
+            1 => ClickEvent::RunCommand { command: cow(u)? },
+            2 => ClickEvent::SuggestCommand { command: cow(u)? },
+            3 => ClickEvent::ChangePage {
+                page: u.arbitrary()?,
+            },
+            4 => ClickEvent::CopyToClipboard { value: cow(u)? },
+            #[cfg(feature = "custom")]
+            5 => ClickEvent::Custom(CustomData::arbitrary(u)?),
+            _ => ClickEvent::ShowDialog {
+                dialog: DialogRef::Id(cow(u)?),
+            },
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for HoverEvent {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=2u8)? {
+            0 => HoverEvent::ShowText {
+                value: Box::new(nested(u)?),
+            },
+            1 => HoverEvent::ShowItem {
+                id: cow(u)?,
+                count: u.arbitrary()?,
+                components: option_cow(u)?,
+            },
+            _ => HoverEvent::ShowEntity {
+                name: if u.arbitrary()? {
+                    Some(Box::new(nested(u)?))
+                } else {
+                    None
+                },
+                id: cow(u)?,
+                uuid: Uuid::from_bytes(u.arbitrary()?),
+            },
+        })
+    }
+}
@@ -0,0 +1,245 @@
+//! Click events that run Rust closures when the server receives them back.
+//! ```no_run
+//! # use text_components::{Modifier, callback::CallbackRegistry};
+//! let callbacks = CallbackRegistry::new();
+//! let component = "Click me".click_event(callbacks.register(|ctx| println!("Clicked {}", ctx.id)));
+//! # let command = String::new();
+//! // Once the client runs "/tc_callback <uuid>" or sends the custom click:
+//! callbacks.handle(&command)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+#[cfg(feature = "custom")]
+use crate::custom::{CustomData, Payload};
+use crate::interactivity::ClickEvent;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use uuid::Uuid;
+
+type Callback = Arc<dyn Fn(&CallbackContext) + Send + Sync>;
+
+/// Information about the callback being run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallbackContext {
+    pub id: Uuid,
+    /// How many times the callback was run, this time included.
+    pub uses: u32,
+    /// How many more times the callback can be run, [None] if unlimited.
+    pub remaining: Option<u32>,
+}
+
+/// Limits of a registered callback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CallbackOptions {
+    /// Time after registering when the callback stops working.
+    pub expires_after: Option<Duration>,
+    /// Times the callback can be run before it's removed, with `Some(0)` it's never run.
+    pub max_uses: Option<u32>,
+}
+impl CallbackOptions {
+    /// A callback that can only be run once.
+    pub fn once() -> Self {
+        CallbackOptions {
+            max_uses: Some(1),
+            ..Default::default()
+        }
+    }
+    /// A callback that stops working after `duration`.
+    pub fn expires_after(duration: Duration) -> Self {
+        CallbackOptions {
+            expires_after: Some(duration),
+            ..Default::default()
+        }
+    }
+}
+
+/// How the [ClickEvent]s given by a [CallbackRegistry] reach the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallbackStyle {
+    /// A [run_command](ClickEvent::RunCommand) of the prefix followed by the uuid.
+    Command(Cow<'static, str>),
+    /// A [custom](ClickEvent::Custom) click event whose id is the prefix followed by the uuid.
+    #[cfg(feature = "custom")]
+    Custom(Cow<'static, str>),
+}
+impl CallbackStyle {
+    fn prefix(&self) -> &str {
+        match self {
+            CallbackStyle::Command(prefix) => prefix,
+            #[cfg(feature = "custom")]
+            CallbackStyle::Custom(prefix) => prefix,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallbackError {
+    /// The id isn't a uuid, with or without the registry prefix.
+    InvalidId(String),
+    /// There is no callback with this id, it may have been used up.
+    Unknown(Uuid),
+    /// The callback expired and was removed.
+    Expired(Uuid),
+    /// The callback has no uses left and was removed.
+    UsedUp(Uuid),
+}
+impl Display for CallbackError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CallbackError::InvalidId(id) => write!(f, "\"{}\" is not a callback id.", id),
+            CallbackError::Unknown(id) => write!(f, "There is no callback with the id {}.", id),
+            CallbackError::Expired(id) => write!(f, "The callback {} expired.", id),
+            CallbackError::UsedUp(id) => write!(f, "The callback {} has no uses left.", id),
+        }
+    }
+}
+impl std::error::Error for CallbackError {}
+
+struct Entry {
+    callback: Callback,
+    expires: Option<Instant>,
+    remaining: Option<u32>,
+    uses: u32,
+}
+impl Entry {
+    fn expired(&self, now: Instant) -> bool {
+        self.expires.is_some_and(|expires| now >= expires)
+    }
+}
+
+/// Stores closures behind generated ids and hands out the [ClickEvent]s that trigger them.
+pub struct CallbackRegistry {
+    style: CallbackStyle,
+    callbacks: Mutex<HashMap<Uuid, Entry>>,
+}
+
+impl Default for CallbackRegistry {
+    fn default() -> Self {
+        CallbackRegistry::new()
+    }
+}
+
+impl CallbackRegistry {
+    /// A registry giving `/tc_callback <uuid>` commands.
+    pub fn new() -> Self {
+        CallbackRegistry::with_style(CallbackStyle::Command(Cow::Borrowed("/tc_callback ")))
+    }
+    pub fn with_style(style: CallbackStyle) -> Self {
+        CallbackRegistry {
+            style,
+            callbacks: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Registers a callback that can be run forever.
+    pub fn register(
+        &self,
+        callback: impl Fn(&CallbackContext) + Send + Sync + 'static,
+    ) -> ClickEvent {
+        self.register_with(CallbackOptions::default(), callback)
+    }
+
+    /// Registers a callback with the given limits.
+    pub fn register_with(
+        &self,
+        options: CallbackOptions,
+        callback: impl Fn(&CallbackContext) + Send + Sync + 'static,
+    ) -> ClickEvent {
+        let id = Uuid::new_v4();
+        let now = Instant::now();
+        let mut callbacks = self.callbacks.lock().unwrap();
+        callbacks.retain(|_, entry| !entry.expired(now));
+        callbacks.insert(
+            id,
+            Entry {
+                callback: Arc::new(callback),
+                expires: options.expires_after.map(|duration| now + duration),
+                remaining: options.max_uses,
+                uses: 0,
+            },
+        );
+        let id = format!("{}{}", self.style.prefix(), id);
+        match self.style {
+            CallbackStyle::Command(_) => ClickEvent::RunCommand { command: id.into() },
+            #[cfg(feature = "custom")]
+            CallbackStyle::Custom(_) => ClickEvent::Custom(CustomData {
+                id: id.into(),
+                payload: Payload::Empty,
+            }),
+        }
+    }
+
+    /// Runs the callback behind `id`, which can be the whole command or custom id,
+    /// with or without the leading `/`, or only the uuid.
+    pub fn handle(&self, id: &str) -> Result<CallbackContext, CallbackError> {
+        let uuid = self.parse_id(id)?;
+        let now = Instant::now();
+        let (callback, context) = {
+            let mut callbacks = self.callbacks.lock().unwrap();
+            callbacks.retain(|key, entry| *key == uuid || !entry.expired(now));
+            let Some(entry) = callbacks.get_mut(&uuid) else {
+                return Err(CallbackError::Unknown(uuid));
+            };
+            if entry.expired(now) {
+                callbacks.remove(&uuid);
+                return Err(CallbackError::Expired(uuid));
+            }
+            if entry.remaining == Some(0) {
+                // Only reachable when registered with no uses
+                callbacks.remove(&uuid);
+                return Err(CallbackError::UsedUp(uuid));
+            }
+            entry.uses += 1;
+            entry.remaining = entry.remaining.map(|remaining| remaining - 1);
+            let context = CallbackContext {
+                id: uuid,
+                uses: entry.uses,
+                remaining: entry.remaining,
+            };
+            let callback = entry.callback.clone();
+            if entry.remaining == Some(0) {
+                callbacks.remove(&uuid);
+            }
+            (callback, context)
+        };
+        // Run without the lock so callbacks can register or handle others
+        callback(&context);
+        Ok(context)
+    }
+
+    /// Removes a callback before it's used up or expired, returns if it existed.
+    pub fn remove(&self, id: Uuid) -> bool {
+        self.callbacks.lock().unwrap().remove(&id).is_some()
+    }
+
+    /// Removes every expired callback.
+    pub fn purge(&self) {
+        let now = Instant::now();
+        self.callbacks
+            .lock()
+            .unwrap()
+            .retain(|_, entry| !entry.expired(now));
+    }
+
+    /// Amount of callbacks stored, expired ones that weren't purged yet included.
+    pub fn len(&self) -> usize {
+        self.callbacks.lock().unwrap().len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn parse_id(&self, id: &str) -> Result<Uuid, CallbackError> {
+        let prefix = self.style.prefix();
+        let trimmed = id.trim();
+        let uuid = trimmed
+            .strip_prefix(prefix)
+            .or_else(|| trimmed.strip_prefix(prefix.trim_start_matches('/')))
+            .unwrap_or(trimmed);
+        Uuid::parse_str(uuid.trim()).map_err(|_| CallbackError::InvalidId(id.to_string()))
+    }
+}
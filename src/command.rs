@@ -0,0 +1,223 @@
+//! The commands showing components to players, written as in datapack functions
+//! (without the leading `/`) with the components as SNBT.
+//! ```
+//! # #[cfg(feature = "nbt")]
+//! # fn main() -> Result<(), text_components::command::CommandError> {
+//! # use text_components::{Modifier, format::Color, resolving::NoResolutor};
+//! // tellraw @a {text:"Hello",color:"red"}
+//! "Hello".color(Color::Red).to_tellraw(&NoResolutor, "@a")?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "nbt"))]
+//! # fn main() {}
+//! ```
+use std::fmt::{self, Display, Formatter};
+use uuid::Uuid;
+
+/// Why a target can't be used in a command, see [check_target].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TargetError {
+    /// There's no target at all.
+    Empty,
+    /// A selector with a variable other than `@p`, `@a`, `@r`, `@s`, `@e` and `@n`.
+    UnknownSelector(String),
+    /// The `[..]` arguments of a selector aren't closed or have text after them.
+    MalformedArguments(String),
+    /// Not a selector, a player name or a UUID.
+    InvalidName(String),
+}
+impl Display for TargetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TargetError::Empty => write!(f, "The target is empty."),
+            TargetError::UnknownSelector(selector) => {
+                write!(f, "The selector \"{selector}\" doesn't exist.")
+            }
+            TargetError::MalformedArguments(selector) => {
+                write!(
+                    f,
+                    "The arguments of the selector \"{selector}\" are malformed."
+                )
+            }
+            TargetError::InvalidName(name) => {
+                write!(f, "\"{name}\" is not a selector, a player name or a UUID.")
+            }
+        }
+    }
+}
+impl std::error::Error for TargetError {}
+
+/// Why a command can't be written.
+#[cfg(feature = "nbt")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandError {
+    Target(TargetError),
+    /// The component has a NaN or infinite float, only possible in unknown NBT fields.
+    Snbt(crate::nbt::SnbtWriteError),
+}
+#[cfg(feature = "nbt")]
+impl Display for CommandError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::Target(error) => error.fmt(f),
+            CommandError::Snbt(error) => error.fmt(f),
+        }
+    }
+}
+#[cfg(feature = "nbt")]
+impl std::error::Error for CommandError {}
+#[cfg(feature = "nbt")]
+impl From<TargetError> for CommandError {
+    fn from(error: TargetError) -> Self {
+        CommandError::Target(error)
+    }
+}
+#[cfg(feature = "nbt")]
+impl From<crate::nbt::SnbtWriteError> for CommandError {
+    fn from(error: crate::nbt::SnbtWriteError) -> Self {
+        CommandError::Snbt(error)
+    }
+}
+
+/// Checks that the target looks like something the game accepts: a selector like
+/// `@a[tag=admin]`, a player name or a UUID.\
+/// The selector arguments are only checked to be closed, not to be known by the game.
+pub fn check_target(target: &str) -> Result<(), TargetError> {
+    if target.is_empty() {
+        return Err(TargetError::Empty);
+    }
+    if let Some(selector) = target.strip_prefix('@') {
+        let mut chars = selector.chars();
+        if !chars
+            .next()
+            .is_some_and(|variable| "parsen".contains(variable))
+        {
+            return Err(TargetError::UnknownSelector(target.to_string()));
+        }
+        let arguments = chars.as_str();
+        if !arguments.is_empty() && !closed_arguments(arguments) {
+            return Err(TargetError::MalformedArguments(target.to_string()));
+        }
+        return Ok(());
+    }
+    let is_name = target.len() <= 16
+        && target
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '_');
+    if is_name || (target.contains('-') && Uuid::try_parse(target).is_ok()) {
+        Ok(())
+    } else {
+        Err(TargetError::InvalidName(target.to_string()))
+    }
+}
+
+/// Whether the text is a `[..]` ending where it's closed, with nested brackets
+/// closed by their matching one and quoted strings.
+fn closed_arguments(arguments: &str) -> bool {
+    if !arguments.starts_with('[') {
+        return false;
+    }
+    let mut brackets = Vec::new();
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, char) in arguments.char_indices() {
+        if let Some(open) = quote {
+            match char {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                _ if char == open => quote = None,
+                _ => (),
+            }
+            continue;
+        }
+        match char {
+            '"' | '\'' => quote = Some(char),
+            '[' => brackets.push(']'),
+            '{' => brackets.push('}'),
+            ']' | '}' => {
+                if brackets.pop() != Some(char) {
+                    return false;
+                }
+                if brackets.is_empty() {
+                    return i + 1 == arguments.len();
+                }
+            }
+            _ => (),
+        }
+    }
+    false
+}
+
+#[cfg(feature = "nbt")]
+mod nbt {
+    use super::{CommandError, check_target};
+    use crate::{
+        TextComponent,
+        containers::Book,
+        nbt::{NbtBuilder, SnbtWriteError, ToSNBT},
+        resolving::TextResolutor,
+    };
+
+    impl TextComponent {
+        /// `tellraw <target> <component>`, sending the component to the chat of the targets.
+        pub fn to_tellraw<R: TextResolutor + ?Sized>(
+            &self,
+            resolutor: &R,
+            target: &str,
+        ) -> Result<String, CommandError> {
+            check_target(target)?;
+            Ok(format!(
+                "tellraw {target} {}",
+                self.to_command_snbt(resolutor)?
+            ))
+        }
+
+        /// `title <target> title <component>`, showing the component in the middle of the screen.
+        pub fn to_title<R: TextResolutor + ?Sized>(
+            &self,
+            resolutor: &R,
+            target: &str,
+        ) -> Result<String, CommandError> {
+            check_target(target)?;
+            Ok(format!(
+                "title {target} title {}",
+                self.to_command_snbt(resolutor)?
+            ))
+        }
+
+        /// `title <target> actionbar <component>`, showing the component above the hotbar.
+        pub fn to_actionbar<R: TextResolutor + ?Sized>(
+            &self,
+            resolutor: &R,
+            target: &str,
+        ) -> Result<String, CommandError> {
+            check_target(target)?;
+            Ok(format!(
+                "title {target} actionbar {}",
+                self.to_command_snbt(resolutor)?
+            ))
+        }
+
+        fn to_command_snbt<R: TextResolutor + ?Sized>(
+            &self,
+            resolutor: &R,
+        ) -> Result<String, SnbtWriteError> {
+            self.build(resolutor, NbtBuilder::new()).try_to_snbt()
+        }
+    }
+
+    impl Book {
+        /// `give <target> written_book[written_book_content={..}]`, giving the book to the targets.
+        pub fn to_give_book<R: TextResolutor + ?Sized>(
+            &self,
+            resolutor: &R,
+            target: &str,
+        ) -> Result<String, CommandError> {
+            check_target(target)?;
+            Ok(format!(
+                "give {target} minecraft:written_book[written_book_content={}]",
+                self.to_nbt(resolutor).try_to_snbt()?
+            ))
+        }
+    }
+}
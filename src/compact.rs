@@ -0,0 +1,743 @@
+//! A serde representation of [TextComponent] for non self-describing formats.
+//!
+//! The derived implementation of [TextComponent] targets vanilla's JSON shape,
+//! which relies on `#[serde(flatten)]`, untagged and internally tagged enums and
+//! skipped fields, none of which work on formats like bincode or postcard.\
+//! [TextComponentCompact] mirrors the component tree without any of those, and
+//! this module can be used directly on a field.\
+//! Nothing is lost on the way, the inline translations, `no_inherit` and the unknown fields
+//! are carried too (unknown values as their JSON text or binary NBT).
+//! ```
+//! # use serde::{Deserialize, Serialize};
+//! # use text_components::TextComponent;
+//! #[derive(Serialize, Deserialize)]
+//! struct Cached {
+//!     #[serde(with = "text_components::compact")]
+//!     component: TextComponent,
+//! }
+//! ```
+#[cfg(feature = "custom")]
+use crate::custom::{CustomData, Payload};
+#[cfg(feature = "unknown-fields")]
+use crate::unknown::{UnknownFields, UnknownValue};
+use crate::{
+    TextComponent,
+    content::{Content, NbtSource, Object, ObjectPlayer, PlayerProperties, Resolvable},
+    dialog::{AfterAction, Dialog, DialogAction, DialogBody, DialogButton, DialogKind, DialogRef},
+    format::{Color, Format},
+    interactivity::{ClickEvent, HoverEvent, Interactivity},
+    intern::SharedStr,
+    translation::{TranslatedMessage, TranslationTable},
+};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{borrow::Cow, sync::Arc};
+use uuid::Uuid;
+
+/// Serializes a [TextComponent] through its [TextComponentCompact] form.
+pub fn serialize<S: Serializer>(
+    component: &TextComponent,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    TextComponentCompact::from(component).serialize(serializer)
+}
+
+/// Deserializes a [TextComponent] from its [TextComponentCompact] form.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TextComponent, D::Error> {
+    TextComponentCompact::deserialize(deserializer).map(Into::into)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextComponentCompact {
+    pub content: ContentCompact,
+    pub children: Vec<TextComponentCompact>,
+    pub format: FormatCompact,
+    pub interactions: InteractivityCompact,
+    pub inline_translations: Option<TranslationTable>,
+    #[cfg(feature = "unknown-fields")]
+    pub unknown_fields: Option<Vec<(String, UnknownValueCompact)>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ContentCompact {
+    Text(Cow<'static, str>),
+    Translate {
+        key: SharedStr,
+        fallback: Option<Cow<'static, str>>,
+        args: Option<Vec<TextComponentCompact>>,
+    },
+    Keybind(Cow<'static, str>),
+    #[cfg(feature = "custom")]
+    Custom(CustomCompact),
+    Atlas {
+        atlas: Option<Cow<'static, str>>,
+        sprite: Cow<'static, str>,
+    },
+    Player {
+        name: Option<Cow<'static, str>>,
+        id: Option<[i32; 4]>,
+        texture: Option<Cow<'static, str>>,
+        properties: Vec<PlayerPropertiesCompact>,
+        hat: bool,
+    },
+    Scoreboard {
+        selector: Cow<'static, str>,
+        objective: Cow<'static, str>,
+    },
+    Entity {
+        selector: Cow<'static, str>,
+        separator: Box<TextComponentCompact>,
+    },
+    Nbt {
+        path: Cow<'static, str>,
+        interpret: Option<bool>,
+        separator: Box<TextComponentCompact>,
+        source: NbtSource,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerPropertiesCompact {
+    pub name: Cow<'static, str>,
+    pub value: Cow<'static, str>,
+    pub signature: Option<Cow<'static, str>>,
+}
+
+#[cfg(feature = "custom")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomCompact {
+    pub id: Cow<'static, str>,
+    pub payload: Payload,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FormatCompact {
+    pub color: Option<Color>,
+    pub font: Option<SharedStr>,
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    pub underlined: Option<bool>,
+    pub strikethrough: Option<bool>,
+    pub obfuscated: Option<bool>,
+    pub shadow_color: Option<i64>,
+    pub no_inherit: bool,
+}
+
+/// An [UnknownValue], written as its JSON text or its binary NBT.
+#[cfg(feature = "unknown-fields")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "UnknownValueRepr", into = "UnknownValueRepr")]
+pub struct UnknownValueCompact(pub UnknownValue);
+
+#[cfg(feature = "unknown-fields")]
+#[derive(Serialize, Deserialize)]
+enum UnknownValueRepr {
+    Json(String),
+    #[cfg(feature = "nbt")]
+    Nbt(Vec<u8>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InteractivityCompact {
+    pub insertion: Option<SharedStr>,
+    pub click: Option<ClickEventCompact>,
+    pub hover: Option<HoverEventCompact>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ClickEventCompact {
+    OpenUrl(Cow<'static, str>),
+    RunCommand(Cow<'static, str>),
+    SuggestCommand(Cow<'static, str>),
+    ChangePage(i32),
+    CopyToClipboard(Cow<'static, str>),
+    ShowDialog(Cow<'static, str>),
+    #[cfg(feature = "custom")]
+    Custom(CustomCompact),
+    ShowInlineDialog(Box<DialogCompact>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum HoverEventCompact {
+    ShowText(Box<TextComponentCompact>),
+    ShowItem {
+        id: Cow<'static, str>,
+        count: Option<i32>,
+        components: Option<Cow<'static, str>>,
+    },
+    ShowEntity {
+        name: Option<Box<TextComponentCompact>>,
+        id: Cow<'static, str>,
+        uuid: Uuid,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DialogCompact {
+    pub kind: DialogKindCompact,
+    pub title: TextComponentCompact,
+    pub external_title: Option<TextComponentCompact>,
+    pub body: Vec<DialogBodyCompact>,
+    pub can_close_with_escape: bool,
+    pub pause: bool,
+    pub after_action: AfterAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DialogKindCompact {
+    Notice {
+        action: Option<DialogButtonCompact>,
+    },
+    Confirmation {
+        yes: Box<DialogButtonCompact>,
+        no: Box<DialogButtonCompact>,
+    },
+    MultiAction {
+        actions: Vec<DialogButtonCompact>,
+        columns: Option<u32>,
+        exit_action: Option<DialogButtonCompact>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DialogBodyCompact {
+    PlainMessage {
+        contents: TextComponentCompact,
+        width: Option<u32>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DialogButtonCompact {
+    pub label: TextComponentCompact,
+    pub tooltip: Option<TextComponentCompact>,
+    pub width: Option<u32>,
+    pub action: Option<DialogActionCompact>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DialogActionCompact {
+    OpenUrl(Cow<'static, str>),
+    RunCommand(Cow<'static, str>),
+    SuggestCommand(Cow<'static, str>),
+    CopyToClipboard(Cow<'static, str>),
+}
+
+impl From<&TextComponent> for TextComponentCompact {
+    fn from(value: &TextComponent) -> Self {
+        TextComponentCompact {
+            content: (&value.content).into(),
+            children: value.children.iter().map(Into::into).collect(),
+            format: (&value.format).into(),
+            interactions: (&value.interactions).into(),
+            inline_translations: value.inline_translations.as_deref().cloned(),
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: value.unknown_fields.as_ref().map(|fields| {
+                fields
+                    .0
+                    .iter()
+                    .map(|(key, value)| (key.clone(), UnknownValueCompact(value.clone())))
+                    .collect()
+            }),
+        }
+    }
+}
+impl From<TextComponent> for TextComponentCompact {
+    fn from(value: TextComponent) -> Self {
+        (&value).into()
+    }
+}
+impl From<TextComponentCompact> for TextComponent {
+    fn from(value: TextComponentCompact) -> Self {
+        TextComponent {
+            content: value.content.into(),
+            children: value.children.into_iter().map(Into::into).collect(),
+            format: value.format.into(),
+            interactions: value.interactions.into(),
+            inline_translations: value.inline_translations.map(Arc::new),
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: value.unknown_fields.map(|fields| {
+                Box::new(UnknownFields(
+                    fields
+                        .into_iter()
+                        .map(|(key, value)| (key, value.0))
+                        .collect(),
+                ))
+            }),
+        }
+    }
+}
+
+impl From<&Content> for ContentCompact {
+    fn from(value: &Content) -> Self {
+        match value {
+            Content::Text { text } => ContentCompact::Text(text.clone()),
+            Content::Translate(message) => ContentCompact::Translate {
+                key: message.key.clone(),
+                fallback: message.fallback.clone(),
+                args: message
+                    .args
+                    .as_ref()
+                    .map(|args| args.iter().map(Into::into).collect()),
+            },
+            Content::Keybind { keybind } => ContentCompact::Keybind(keybind.clone()),
+            #[cfg(feature = "custom")]
+            Content::Custom(data) => ContentCompact::Custom(data.into()),
+            Content::Object(Object::Atlas { atlas, sprite }) => ContentCompact::Atlas {
+                atlas: atlas.clone(),
+                sprite: sprite.clone(),
+            },
+            Content::Object(Object::Player { player, hat }) => ContentCompact::Player {
+                name: player.name.clone(),
+                id: player.id,
+                texture: player.texture.clone(),
+                properties: player.properties.iter().map(Into::into).collect(),
+                hat: *hat,
+            },
+            Content::Resolvable(Resolvable::Scoreboard {
+                selector,
+                objective,
+            }) => ContentCompact::Scoreboard {
+                selector: selector.clone(),
+                objective: objective.clone(),
+            },
+            Content::Resolvable(Resolvable::Entity {
+                selector,
+                separator,
+            }) => ContentCompact::Entity {
+                selector: selector.clone(),
+                separator: Box::new((&**separator).into()),
+            },
+            Content::Resolvable(Resolvable::NBT {
+                path,
+                interpret,
+                separator,
+                source,
+            }) => ContentCompact::Nbt {
+                path: path.clone(),
+                interpret: *interpret,
+                separator: Box::new((&**separator).into()),
+                source: source.clone(),
+            },
+        }
+    }
+}
+impl From<ContentCompact> for Content {
+    fn from(value: ContentCompact) -> Self {
+        match value {
+            ContentCompact::Text(text) => Content::Text { text },
+            ContentCompact::Translate {
+                key,
+                fallback,
+                args,
+            } => Content::Translate(TranslatedMessage {
+                key,
+                fallback,
+                args: args.map(|args| {
+                    args.into_iter()
+                        .map(Into::into)
+                        .collect::<Vec<TextComponent>>()
+                        .into_boxed_slice()
+                }),
+            }),
+            ContentCompact::Keybind(keybind) => Content::Keybind { keybind },
+            #[cfg(feature = "custom")]
+            ContentCompact::Custom(data) => Content::Custom(data.into()),
+            ContentCompact::Atlas { atlas, sprite } => {
+                Content::Object(Object::Atlas { atlas, sprite })
+            }
+            ContentCompact::Player {
+                name,
+                id,
+                texture,
+                properties,
+                hat,
+            } => Content::Object(Object::Player {
+                player: ObjectPlayer {
+                    name,
+                    id,
+                    texture,
+                    properties: properties.into_iter().map(Into::into).collect(),
+                },
+                hat,
+            }),
+            ContentCompact::Scoreboard {
+                selector,
+                objective,
+            } => Content::Resolvable(Resolvable::Scoreboard {
+                selector,
+                objective,
+            }),
+            ContentCompact::Entity {
+                selector,
+                separator,
+            } => Content::Resolvable(Resolvable::Entity {
+                selector,
+                separator: Box::new((*separator).into()),
+            }),
+            ContentCompact::Nbt {
+                path,
+                interpret,
+                separator,
+                source,
+            } => Content::Resolvable(Resolvable::NBT {
+                path,
+                interpret,
+                separator: Box::new((*separator).into()),
+                source,
+            }),
+        }
+    }
+}
+
+impl From<&PlayerProperties> for PlayerPropertiesCompact {
+    fn from(value: &PlayerProperties) -> Self {
+        PlayerPropertiesCompact {
+            name: value.name.clone(),
+            value: value.value.clone(),
+            signature: value.signature.clone(),
+        }
+    }
+}
+impl From<PlayerPropertiesCompact> for PlayerProperties {
+    fn from(value: PlayerPropertiesCompact) -> Self {
+        PlayerProperties {
+            name: value.name,
+            value: value.value,
+            signature: value.signature,
+        }
+    }
+}
+
+#[cfg(feature = "custom")]
+impl From<&CustomData> for CustomCompact {
+    fn from(value: &CustomData) -> Self {
+        CustomCompact {
+            id: value.id.clone(),
+            payload: value.payload.clone(),
+        }
+    }
+}
+#[cfg(feature = "custom")]
+impl From<CustomCompact> for CustomData {
+    fn from(value: CustomCompact) -> Self {
+        CustomData {
+            id: value.id,
+            payload: value.payload,
+        }
+    }
+}
+
+impl From<&Format> for FormatCompact {
+    fn from(value: &Format) -> Self {
+        FormatCompact {
+            color: value.color,
+            font: value.font.clone(),
+            bold: value.bold,
+            italic: value.italic,
+            underlined: value.underlined,
+            strikethrough: value.strikethrough,
+            obfuscated: value.obfuscated,
+            shadow_color: value.shadow_color,
+            no_inherit: value.no_inherit,
+        }
+    }
+}
+impl From<FormatCompact> for Format {
+    fn from(value: FormatCompact) -> Self {
+        Format {
+            color: value.color,
+            font: value.font,
+            bold: value.bold,
+            italic: value.italic,
+            underlined: value.underlined,
+            strikethrough: value.strikethrough,
+            obfuscated: value.obfuscated,
+            shadow_color: value.shadow_color,
+            no_inherit: value.no_inherit,
+        }
+    }
+}
+
+#[cfg(feature = "unknown-fields")]
+impl From<UnknownValueCompact> for UnknownValueRepr {
+    fn from(value: UnknownValueCompact) -> Self {
+        match value.0 {
+            UnknownValue::Json(json) => UnknownValueRepr::Json(json.to_string()),
+            #[cfg(feature = "nbt")]
+            UnknownValue::Nbt(tag) => {
+                let mut bytes = vec![];
+                tag.write(&mut bytes);
+                UnknownValueRepr::Nbt(bytes)
+            }
+        }
+    }
+}
+#[cfg(feature = "unknown-fields")]
+impl TryFrom<UnknownValueRepr> for UnknownValueCompact {
+    type Error = String;
+
+    fn try_from(value: UnknownValueRepr) -> Result<Self, String> {
+        Ok(UnknownValueCompact(match value {
+            UnknownValueRepr::Json(json) => {
+                UnknownValue::Json(serde_json::from_str(&json).map_err(|err| err.to_string())?)
+            }
+            #[cfg(feature = "nbt")]
+            UnknownValueRepr::Nbt(bytes) => UnknownValue::Nbt(
+                simdnbt::owned::read_tag(&mut std::io::Cursor::new(&bytes[..]))
+                    .map_err(|err| format!("{err:?}"))?,
+            ),
+        }))
+    }
+}
+
+impl From<&Interactivity> for InteractivityCompact {
+    fn from(value: &Interactivity) -> Self {
+        InteractivityCompact {
+            insertion: value.insertion.clone(),
+            click: value.click.as_ref().map(Into::into),
+            hover: value.hover.as_ref().map(Into::into),
+        }
+    }
+}
+impl From<InteractivityCompact> for Interactivity {
+    fn from(value: InteractivityCompact) -> Self {
+        Interactivity {
+            insertion: value.insertion,
+            click: value.click.map(Into::into),
+            hover: value.hover.map(Into::into),
+        }
+    }
+}
+
+impl From<&ClickEvent> for ClickEventCompact {
+    fn from(value: &ClickEvent) -> Self {
+        match value {
+            ClickEvent::OpenUrl { url } => ClickEventCompact::OpenUrl(url.clone()),
+            ClickEvent::RunCommand { command } => ClickEventCompact::RunCommand(command.clone()),
+            ClickEvent::SuggestCommand { command } => {
+                ClickEventCompact::SuggestCommand(command.clone())
+            }
+            ClickEvent::ChangePage { page } => ClickEventCompact::ChangePage(*page),
+            ClickEvent::CopyToClipboard { value } => {
+                ClickEventCompact::CopyToClipboard(value.clone())
+            }
+            ClickEvent::ShowDialog {
+                dialog: DialogRef::Id(id),
+            } => ClickEventCompact::ShowDialog(id.clone()),
+            ClickEvent::ShowDialog {
+                dialog: DialogRef::Inline(dialog),
+            } => ClickEventCompact::ShowInlineDialog(Box::new((&**dialog).into())),
+            #[cfg(feature = "custom")]
+            ClickEvent::Custom(data) => ClickEventCompact::Custom(data.into()),
+        }
+    }
+}
+impl From<ClickEventCompact> for ClickEvent {
+    fn from(value: ClickEventCompact) -> Self {
+        match value {
+            ClickEventCompact::OpenUrl(url) => ClickEvent::OpenUrl { url },
+            ClickEventCompact::RunCommand(command) => ClickEvent::RunCommand { command },
+            ClickEventCompact::SuggestCommand(command) => ClickEvent::SuggestCommand { command },
+            ClickEventCompact::ChangePage(page) => ClickEvent::ChangePage { page },
+            ClickEventCompact::CopyToClipboard(value) => ClickEvent::CopyToClipboard { value },
+            ClickEventCompact::ShowDialog(id) => ClickEvent::ShowDialog {
+                dialog: DialogRef::Id(id),
+            },
+            ClickEventCompact::ShowInlineDialog(dialog) => ClickEvent::ShowDialog {
+                dialog: DialogRef::Inline(Box::new((*dialog).into())),
+            },
+            #[cfg(feature = "custom")]
+            ClickEventCompact::Custom(data) => ClickEvent::Custom(data.into()),
+        }
+    }
+}
+
+impl From<&Dialog> for DialogCompact {
+    fn from(value: &Dialog) -> Self {
+        DialogCompact {
+            kind: (&value.kind).into(),
+            title: (&value.title).into(),
+            external_title: value.external_title.as_ref().map(Into::into),
+            body: value.body.iter().map(Into::into).collect(),
+            can_close_with_escape: value.can_close_with_escape,
+            pause: value.pause,
+            after_action: value.after_action,
+        }
+    }
+}
+impl From<DialogCompact> for Dialog {
+    fn from(value: DialogCompact) -> Self {
+        Dialog {
+            kind: value.kind.into(),
+            title: value.title.into(),
+            external_title: value.external_title.map(Into::into),
+            body: value.body.into_iter().map(Into::into).collect(),
+            can_close_with_escape: value.can_close_with_escape,
+            pause: value.pause,
+            after_action: value.after_action,
+        }
+    }
+}
+
+impl From<&DialogKind> for DialogKindCompact {
+    fn from(value: &DialogKind) -> Self {
+        match value {
+            DialogKind::Notice { action } => DialogKindCompact::Notice {
+                action: action.as_ref().map(Into::into),
+            },
+            DialogKind::Confirmation { yes, no } => DialogKindCompact::Confirmation {
+                yes: Box::new((&**yes).into()),
+                no: Box::new((&**no).into()),
+            },
+            DialogKind::MultiAction {
+                actions,
+                columns,
+                exit_action,
+            } => DialogKindCompact::MultiAction {
+                actions: actions.iter().map(Into::into).collect(),
+                columns: *columns,
+                exit_action: exit_action.as_ref().map(Into::into),
+            },
+        }
+    }
+}
+impl From<DialogKindCompact> for DialogKind {
+    fn from(value: DialogKindCompact) -> Self {
+        match value {
+            DialogKindCompact::Notice { action } => DialogKind::Notice {
+                action: action.map(Into::into),
+            },
+            DialogKindCompact::Confirmation { yes, no } => DialogKind::Confirmation {
+                yes: Box::new((*yes).into()),
+                no: Box::new((*no).into()),
+            },
+            DialogKindCompact::MultiAction {
+                actions,
+                columns,
+                exit_action,
+            } => DialogKind::MultiAction {
+                actions: actions.into_iter().map(Into::into).collect(),
+                columns,
+                exit_action: exit_action.map(Into::into),
+            },
+        }
+    }
+}
+
+impl From<&DialogBody> for DialogBodyCompact {
+    fn from(value: &DialogBody) -> Self {
+        match value {
+            DialogBody::PlainMessage { contents, width } => DialogBodyCompact::PlainMessage {
+                contents: contents.into(),
+                width: *width,
+            },
+        }
+    }
+}
+impl From<DialogBodyCompact> for DialogBody {
+    fn from(value: DialogBodyCompact) -> Self {
+        match value {
+            DialogBodyCompact::PlainMessage { contents, width } => DialogBody::PlainMessage {
+                contents: contents.into(),
+                width,
+            },
+        }
+    }
+}
+
+impl From<&DialogButton> for DialogButtonCompact {
+    fn from(value: &DialogButton) -> Self {
+        DialogButtonCompact {
+            label: (&value.label).into(),
+            tooltip: value.tooltip.as_ref().map(Into::into),
+            width: value.width,
+            action: value.action.as_ref().map(Into::into),
+        }
+    }
+}
+impl From<DialogButtonCompact> for DialogButton {
+    fn from(value: DialogButtonCompact) -> Self {
+        DialogButton {
+            label: value.label.into(),
+            tooltip: value.tooltip.map(Into::into),
+            width: value.width,
+            action: value.action.map(Into::into),
+        }
+    }
+}
+
+impl From<&DialogAction> for DialogActionCompact {
+    fn from(value: &DialogAction) -> Self {
+        match value {
+            DialogAction::OpenUrl { url } => DialogActionCompact::OpenUrl(url.clone()),
+            DialogAction::RunCommand { command } => {
+                DialogActionCompact::RunCommand(command.clone())
+            }
+            DialogAction::SuggestCommand { command } => {
+                DialogActionCompact::SuggestCommand(command.clone())
+            }
+            DialogAction::CopyToClipboard { value } => {
+                DialogActionCompact::CopyToClipboard(value.clone())
+            }
+        }
+    }
+}
+impl From<DialogActionCompact> for DialogAction {
+    fn from(value: DialogActionCompact) -> Self {
+        match value {
+            DialogActionCompact::OpenUrl(url) => DialogAction::OpenUrl { url },
+            DialogActionCompact::RunCommand(command) => DialogAction::RunCommand { command },
+            DialogActionCompact::SuggestCommand(command) => {
+                DialogAction::SuggestCommand { command }
+            }
+            DialogActionCompact::CopyToClipboard(value) => DialogAction::CopyToClipboard { value },
+        }
+    }
+}
+
+impl From<&HoverEvent> for HoverEventCompact {
+    fn from(value: &HoverEvent) -> Self {
+        match value {
+            HoverEvent::ShowText { value } => {
+                HoverEventCompact::ShowText(Box::new((&**value).into()))
+            }
+            HoverEvent::ShowItem {
+                id,
+                count,
+                components,
+            } => HoverEventCompact::ShowItem {
+                id: id.clone(),
+                count: *count,
+                components: components.clone(),
+            },
+            HoverEvent::ShowEntity { name, id, uuid } => HoverEventCompact::ShowEntity {
+                name: name.as_ref().map(|name| Box::new((&**name).into())),
+                id: id.clone(),
+                uuid: *uuid,
+            },
+        }
+    }
+}
+impl From<HoverEventCompact> for HoverEvent {
+    fn from(value: HoverEventCompact) -> Self {
+        match value {
+            HoverEventCompact::ShowText(value) => HoverEvent::ShowText {
+                value: Box::new((*value).into()),
+            },
+            HoverEventCompact::ShowItem {
+                id,
+                count,
+                components,
+            } => HoverEvent::ShowItem {
+                id,
+                count,
+                components,
+            },
+            HoverEventCompact::ShowEntity { name, id, uuid } => HoverEvent::ShowEntity {
+                name: name.map(|name| Box::new((*name).into())),
+                id,
+                uuid,
+            },
+        }
+    }
+}
@@ -0,0 +1,458 @@
+//! Written books and signs, with the shapes the game stores them in.
+//! ```
+//! # #[cfg(feature = "nbt")]
+//! # fn main() {
+//! # use text_components::{TextComponent, containers::{Book, PageLayout}, resolving::NoResolutor};
+//! # let long_component = TextComponent::plain("Once upon a time...");
+//! let book = Book::paginated("Story", "Notch", &long_component, &NoResolutor, PageLayout::default());
+//! // {title:"Story",author:"Notch",pages:[{text:"..."},...]}
+//! let tag = book.to_nbt(&NoResolutor);
+//! # }
+//! # #[cfg(not(feature = "nbt"))]
+//! # fn main() {}
+//! ```
+#![deny(clippy::unwrap_used, clippy::indexing_slicing)]
+use crate::{
+    TextComponent, content::Content, format::Format, interactivity::Interactivity,
+    resolving::TextResolutor,
+};
+use std::borrow::Cow;
+
+/// A written book, stored as the `written_book_content` item component.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Book {
+    pub title: Cow<'static, str>,
+    pub author: Cow<'static, str>,
+    pub pages: Vec<TextComponent>,
+}
+
+impl Book {
+    pub fn new<T: Into<Cow<'static, str>>, A: Into<Cow<'static, str>>>(
+        title: T,
+        author: A,
+        pages: Vec<TextComponent>,
+    ) -> Self {
+        Book {
+            title: title.into(),
+            author: author.into(),
+            pages,
+        }
+    }
+
+    /// Creates a book splitting `component` into [pages](paginate).
+    pub fn paginated<T, A, R>(
+        title: T,
+        author: A,
+        component: &TextComponent,
+        resolutor: &R,
+        layout: PageLayout,
+    ) -> Self
+    where
+        T: Into<Cow<'static, str>>,
+        A: Into<Cow<'static, str>>,
+        R: TextResolutor + ?Sized,
+    {
+        Book::new(title, author, paginate(component, resolutor, layout))
+    }
+
+    /// The pages as the stringified JSON books used before 1.20.5.
+    #[cfg(feature = "serde")]
+    pub fn legacy_pages<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+    ) -> serde_json::Result<Vec<String>> {
+        self.pages
+            .iter()
+            .map(|page| page.to_json(resolutor))
+            .collect()
+    }
+}
+
+/// The text in one side of a sign.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct SignText {
+    #[cfg_attr(feature = "serde", serde(rename = "messages"))]
+    pub lines: [TextComponent; 4],
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub color: DyeColor,
+    #[cfg_attr(feature = "serde", serde(rename = "has_glowing_text", default))]
+    pub glowing: bool,
+}
+
+impl SignText {
+    /// Creates a black sign text, missing lines are left empty.
+    pub fn new<T: Into<TextComponent>>(lines: impl IntoIterator<Item = T>) -> Self {
+        let mut sign = SignText::default();
+        for (line, text) in sign.lines.iter_mut().zip(lines) {
+            *line = text.into();
+        }
+        sign
+    }
+    pub fn color(mut self, color: DyeColor) -> Self {
+        self.color = color;
+        self
+    }
+    pub fn glowing(mut self, glowing: bool) -> Self {
+        self.glowing = glowing;
+        self
+    }
+}
+
+/// The dye colors a sign can be painted with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DyeColor {
+    White,
+    Orange,
+    Magenta,
+    LightBlue,
+    Yellow,
+    Lime,
+    Pink,
+    Gray,
+    LightGray,
+    Cyan,
+    Purple,
+    Blue,
+    Brown,
+    Green,
+    Red,
+    #[default]
+    Black,
+}
+
+impl DyeColor {
+    pub const ALL: [DyeColor; 16] = [
+        DyeColor::White,
+        DyeColor::Orange,
+        DyeColor::Magenta,
+        DyeColor::LightBlue,
+        DyeColor::Yellow,
+        DyeColor::Lime,
+        DyeColor::Pink,
+        DyeColor::Gray,
+        DyeColor::LightGray,
+        DyeColor::Cyan,
+        DyeColor::Purple,
+        DyeColor::Blue,
+        DyeColor::Brown,
+        DyeColor::Green,
+        DyeColor::Red,
+        DyeColor::Black,
+    ];
+
+    /// The id the game uses for this color.
+    pub fn name(&self) -> &'static str {
+        match self {
+            DyeColor::White => "white",
+            DyeColor::Orange => "orange",
+            DyeColor::Magenta => "magenta",
+            DyeColor::LightBlue => "light_blue",
+            DyeColor::Yellow => "yellow",
+            DyeColor::Lime => "lime",
+            DyeColor::Pink => "pink",
+            DyeColor::Gray => "gray",
+            DyeColor::LightGray => "light_gray",
+            DyeColor::Cyan => "cyan",
+            DyeColor::Purple => "purple",
+            DyeColor::Blue => "blue",
+            DyeColor::Brown => "brown",
+            DyeColor::Green => "green",
+            DyeColor::Red => "red",
+            DyeColor::Black => "black",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        DyeColor::ALL.into_iter().find(|color| color.name() == name)
+    }
+}
+
+/// The size of a page when [paginating](paginate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PageLayout {
+    /// Characters per line, the game measures lines in pixels
+    /// so this is an approximation for the default font.
+    pub width: usize,
+    /// Lines per page.
+    pub lines: usize,
+}
+impl Default for PageLayout {
+    /// The 14 lines of a book page, about 19 characters wide.
+    fn default() -> Self {
+        PageLayout {
+            width: 19,
+            lines: 14,
+        }
+    }
+}
+
+/// Splits a component into pages of at most `layout.lines` lines of `layout.width` characters,
+/// wrapping between words when possible.\
+/// The component is resolved first and its tree flattened, every piece of text keeps its
+/// effective format and interactions. Contents other than text are never split.
+pub fn paginate<R: TextResolutor + ?Sized>(
+    component: &TextComponent,
+    resolutor: &R,
+    layout: PageLayout,
+) -> Vec<TextComponent> {
+    let mut pieces = vec![];
+    flatten(
+        &component.resolve(resolutor),
+        &Format::new(),
+        &Interactivity::new(),
+        &mut pieces,
+    );
+    let mut pages = Paginator {
+        layout: PageLayout {
+            width: layout.width.max(1),
+            lines: layout.lines.max(1),
+        },
+        pages: vec![],
+        page: vec![],
+        line_len: 0,
+        line: 0,
+    };
+    for piece in pieces {
+        match &piece.content {
+            Content::Text { text } => pages.push_text(&piece, text),
+            _ => {
+                let len = piece.to_plain(resolutor).chars().count();
+                if pages.line_len > 0 && pages.line_len + len > pages.layout.width {
+                    pages.new_line(&piece);
+                }
+                pages.line_len += len;
+                pages.page.push(piece);
+            }
+        }
+    }
+    if !pages.page.is_empty() || pages.pages.is_empty() {
+        pages.finish_page();
+    }
+    pages.pages
+}
+
+fn flatten(
+    component: &TextComponent,
+    format: &Format,
+    interactions: &Interactivity,
+    pieces: &mut Vec<TextComponent>,
+) {
+    let format = component.format.mix(format);
+    let mut inherited = interactions.clone();
+    component.interactions.mix(&mut inherited);
+    pieces.push(TextComponent {
+        content: component.content.clone(),
+        children: vec![],
+        format: format.clone(),
+        interactions: inherited.clone(),
+        inline_translations: None,
+        #[cfg(feature = "unknown-fields")]
+        unknown_fields: None,
+    });
+    for child in &component.children {
+        flatten(child, &format, &inherited, pieces);
+    }
+}
+
+struct Paginator {
+    layout: PageLayout,
+    pages: Vec<TextComponent>,
+    page: Vec<TextComponent>,
+    line_len: usize,
+    line: usize,
+}
+
+impl Paginator {
+    fn push_text(&mut self, style: &TextComponent, text: &str) {
+        let mut rest = text;
+        while let Some(char) = rest.chars().next() {
+            if char == '\n' {
+                self.new_line(style);
+                rest = &rest[1..];
+                continue;
+            }
+            if char.is_whitespace() {
+                if self.line_len >= self.layout.width {
+                    self.new_line(style);
+                } else {
+                    self.append(style, &rest[..char.len_utf8()]);
+                }
+                rest = &rest[char.len_utf8()..];
+                continue;
+            }
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            let mut word = &rest[..end];
+            rest = &rest[end..];
+            if self.line_len > 0 && self.line_len + word.chars().count() > self.layout.width {
+                self.new_line(style);
+            }
+            // Words longer than a line are split wherever they reach the end
+            while let Some((split, _)) = word.char_indices().nth(self.layout.width - self.line_len)
+            {
+                self.append(style, &word[..split]);
+                self.new_line(style);
+                word = &word[split..];
+            }
+            self.append(style, word);
+        }
+    }
+
+    fn append(&mut self, style: &TextComponent, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        self.line_len += text.chars().count();
+        if let Some(last) = self.page.last_mut()
+            && let Content::Text { text: last_text } = &mut last.content
+            && last.format == style.format
+            && last.interactions == style.interactions
+        {
+            last_text.to_mut().push_str(text);
+            return;
+        }
+        self.page.push(TextComponent {
+            content: Content::Text {
+                text: Cow::Owned(text.to_string()),
+            },
+            children: vec![],
+            format: style.format.clone(),
+            interactions: style.interactions.clone(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
+        });
+    }
+
+    fn new_line(&mut self, style: &TextComponent) {
+        self.line += 1;
+        self.line_len = 0;
+        if self.line >= self.layout.lines {
+            self.finish_page();
+        } else {
+            self.append(style, "\n");
+            self.line_len = 0;
+        }
+    }
+
+    fn finish_page(&mut self) {
+        let mut children = std::mem::take(&mut self.page);
+        // Trailing whitespace would only push the next page's text further
+        if let Some(last) = children.last_mut()
+            && let Content::Text { text } = &mut last.content
+        {
+            let trimmed = text.trim_end().len();
+            text.to_mut().truncate(trimmed);
+        }
+        children
+            .retain(|child| !matches!(&child.content, Content::Text { text } if text.is_empty()));
+        self.pages.push(match <[_; 1]>::try_from(children) {
+            Ok([page]) => page,
+            Err(children) => TextComponent {
+                children,
+                ..TextComponent::new()
+            },
+        });
+        self.line = 0;
+        self.line_len = 0;
+    }
+}
+
+#[cfg(feature = "nbt")]
+mod nbt {
+    use super::{Book, DyeColor, SignText};
+    use crate::{TextComponent, nbt::NbtBuilder, resolving::TextResolutor};
+    use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+
+    impl Book {
+        /// Builds the `written_book_content` component:
+        /// `{title:"..",author:"..",pages:[<component>,..]}`
+        pub fn to_nbt<R: TextResolutor + ?Sized>(&self, resolutor: &R) -> NbtTag {
+            let pages = self
+                .pages
+                .iter()
+                .filter_map(|page| page.build(resolutor, NbtBuilder::new()).into_compound())
+                .collect();
+            NbtTag::Compound(NbtCompound::from_values(vec![
+                (
+                    "title".into(),
+                    NbtTag::String(self.title.to_string().into()),
+                ),
+                (
+                    "author".into(),
+                    NbtTag::String(self.author.to_string().into()),
+                ),
+                ("pages".into(), NbtTag::List(NbtList::Compound(pages))),
+            ]))
+        }
+
+        /// Reads a `written_book_content` component, accepting filterable
+        /// `{raw:..}` titles and pages as well as plain string pages.
+        pub fn from_nbt(tag: &NbtTag) -> Option<Self> {
+            let NbtTag::Compound(compound) = tag else {
+                return None;
+            };
+            let title = match raw(compound.get("title")?) {
+                NbtTag::String(title) => title.to_string(),
+                _ => return None,
+            };
+            let NbtTag::String(author) = compound.get("author")? else {
+                return None;
+            };
+            let mut pages = vec![];
+            if let Some(NbtTag::List(list)) = compound.get("pages") {
+                for page in list.as_nbt_tags() {
+                    pages.push(TextComponent::from_nbt(raw(&page)).unwrap_or_default());
+                }
+            }
+            Some(Book::new(title, author.to_string(), pages))
+        }
+    }
+
+    impl SignText {
+        /// Builds one side of a sign: `{messages:[..],color:"black",has_glowing_text:0b}`
+        pub fn to_nbt<R: TextResolutor + ?Sized>(&self, resolutor: &R) -> NbtTag {
+            let messages = self
+                .lines
+                .iter()
+                .filter_map(|line| line.build(resolutor, NbtBuilder::new()).into_compound())
+                .collect();
+            NbtTag::Compound(NbtCompound::from_values(vec![
+                ("messages".into(), NbtTag::List(NbtList::Compound(messages))),
+                ("color".into(), NbtTag::String(self.color.name().into())),
+                ("has_glowing_text".into(), NbtTag::Byte(self.glowing as i8)),
+            ]))
+        }
+
+        pub fn from_nbt(tag: &NbtTag) -> Option<Self> {
+            let NbtTag::Compound(compound) = tag else {
+                return None;
+            };
+            let NbtTag::List(messages) = compound.get("messages")? else {
+                return None;
+            };
+            let mut sign = SignText::new(
+                messages
+                    .as_nbt_tags()
+                    .iter()
+                    .map(|line| TextComponent::from_nbt(line).unwrap_or_default()),
+            );
+            if let Some(NbtTag::String(color)) = compound.get("color") {
+                sign.color = DyeColor::from_name(&color.to_string())?;
+            }
+            if let Some(NbtTag::Byte(glowing)) = compound.get("has_glowing_text") {
+                sign.glowing = *glowing != 0;
+            }
+            Some(sign)
+        }
+    }
+
+    fn raw(tag: &NbtTag) -> &NbtTag {
+        match tag {
+            NbtTag::Compound(compound) => compound.get("raw").unwrap_or(tag),
+            _ => tag,
+        }
+    }
+}
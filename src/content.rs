@@ -3,7 +3,10 @@ use crate::custom::CustomData;
 use crate::{
     TextComponent, format::Format, interactivity::Interactivity, translation::TranslatedMessage,
 };
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    fmt::{self, Display, Formatter, Write},
+};
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
@@ -14,6 +17,10 @@ pub enum Content {
     },
     Translate(TranslatedMessage),
     Keybind {
+        #[cfg_attr(
+            feature = "serde",
+            serde(deserialize_with = "crate::serde::deserialize_keybind")
+        )]
         keybind: Cow<'static, str>,
     },
     /// #### Needs [resolution](TextComponent::resolve)
@@ -24,6 +31,245 @@ pub enum Content {
     Resolvable(Resolvable),
 }
 
+/// Keybinds are written with their `key.` prefix (`key.jump`), some data packs left
+/// it out and vanilla accepted them anyways, so keybinds without a `.` get it added.
+pub(crate) fn normalize_keybind(keybind: Cow<'static, str>) -> Cow<'static, str> {
+    if keybind.contains('.') {
+        return keybind;
+    }
+    Cow::Owned(format!("key.{keybind}"))
+}
+
+impl Content {
+    /// The text of a [Content::Text].
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Content::Text { text } => Some(text),
+            _ => None,
+        }
+    }
+    pub fn as_translation(&self) -> Option<&TranslatedMessage> {
+        match self {
+            Content::Translate(message) => Some(message),
+            _ => None,
+        }
+    }
+    pub fn as_translation_mut(&mut self) -> Option<&mut TranslatedMessage> {
+        match self {
+            Content::Translate(message) => Some(message),
+            _ => None,
+        }
+    }
+    /// The keybind of a [Content::Keybind], like `key.jump`.
+    pub fn as_keybind(&self) -> Option<&str> {
+        match self {
+            Content::Keybind { keybind } => Some(keybind),
+            _ => None,
+        }
+    }
+    pub fn as_object(&self) -> Option<&Object> {
+        match self {
+            Content::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+    pub fn as_resolvable(&self) -> Option<&Resolvable> {
+        match self {
+            Content::Resolvable(resolvable) => Some(resolvable),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "custom")]
+    pub fn as_custom(&self) -> Option<&CustomData> {
+        match self {
+            Content::Custom(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    pub fn is_text(&self) -> bool {
+        matches!(self, Content::Text { .. })
+    }
+    pub fn is_translation(&self) -> bool {
+        matches!(self, Content::Translate(_))
+    }
+    pub fn is_keybind(&self) -> bool {
+        matches!(self, Content::Keybind { .. })
+    }
+    pub fn is_object(&self) -> bool {
+        matches!(self, Content::Object(_))
+    }
+    /// Whether this content needs [resolution](TextComponent::resolve).
+    pub fn is_resolvable(&self) -> bool {
+        matches!(self, Content::Resolvable(_))
+    }
+    #[cfg(feature = "custom")]
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Content::Custom(_))
+    }
+    /// The name of the kind of content, resolvables are named after their own kind.
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Content::Text { .. } => "text",
+            Content::Translate(_) => "translate",
+            Content::Keybind { .. } => "keybind",
+            #[cfg(feature = "custom")]
+            Content::Custom(_) => "custom",
+            Content::Object(_) => "object",
+            Content::Resolvable(Resolvable::Scoreboard { .. }) => "score",
+            Content::Resolvable(Resolvable::Entity { .. }) => "selector",
+            Content::Resolvable(Resolvable::NBT { .. }) => "nbt",
+        }
+    }
+    /// Writes the content like [describe](TextComponent::describe), as `text("Hi")` or
+    /// `translate(key, 2 args)`.
+    pub(crate) fn write_summary(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        match self {
+            Content::Text { text } => write!(f, "text({text:?})"),
+            Content::Translate(message) => write!(f, "{message}"),
+            Content::Keybind { keybind } => write!(f, "keybind({keybind})"),
+            #[cfg(feature = "custom")]
+            Content::Custom(data) => write!(f, "custom({})", data.id),
+            Content::Object(Object::Atlas { atlas, sprite }) => match atlas {
+                Some(atlas) => write!(f, "object({atlas} {sprite})"),
+                None => write!(f, "object({sprite})"),
+            },
+            Content::Object(Object::Player { player, .. }) => match &player.name {
+                Some(name) => write!(f, "object(player {name})"),
+                None => write!(f, "object(player)"),
+            },
+            Content::Resolvable(resolvable) => write!(f, "{resolvable}"),
+        }
+    }
+}
+
+/// Shortcuts to the [Content] of a component.
+/// ## Example
+/// ```
+/// # use text_components::{Modifier, format::Color};
+/// let mut component = "Hello".color(Color::Red);
+/// assert_eq!(component.as_text(), Some("Hello"));
+/// assert!(!component.is_translation());
+/// component.set_text("Bye"); // Still red
+/// ```
+impl TextComponent {
+    pub fn as_text(&self) -> Option<&str> {
+        self.content.as_text()
+    }
+    pub fn as_translation(&self) -> Option<&TranslatedMessage> {
+        self.content.as_translation()
+    }
+    pub fn as_translation_mut(&mut self) -> Option<&mut TranslatedMessage> {
+        self.content.as_translation_mut()
+    }
+    pub fn as_keybind(&self) -> Option<&str> {
+        self.content.as_keybind()
+    }
+    pub fn as_object(&self) -> Option<&Object> {
+        self.content.as_object()
+    }
+    pub fn as_resolvable(&self) -> Option<&Resolvable> {
+        self.content.as_resolvable()
+    }
+    #[cfg(feature = "custom")]
+    pub fn as_custom(&self) -> Option<&CustomData> {
+        self.content.as_custom()
+    }
+
+    pub fn is_text(&self) -> bool {
+        self.content.is_text()
+    }
+    pub fn is_translation(&self) -> bool {
+        self.content.is_translation()
+    }
+    pub fn is_keybind(&self) -> bool {
+        self.content.is_keybind()
+    }
+    pub fn is_object(&self) -> bool {
+        self.content.is_object()
+    }
+    pub fn is_resolvable(&self) -> bool {
+        self.content.is_resolvable()
+    }
+    #[cfg(feature = "custom")]
+    pub fn is_custom(&self) -> bool {
+        self.content.is_custom()
+    }
+    /// Whether this component is only a text, without format, interactions, children or
+    /// unknown keys, so it can be written as a bare string.
+    pub fn is_plain(&self) -> bool {
+        #[cfg(feature = "unknown-fields")]
+        if self.unknown_fields.is_some() {
+            return false;
+        }
+        self.is_text()
+            && self.format.is_none()
+            && self.interactions.is_none()
+            && self.children.is_empty()
+    }
+
+    /// Replaces the content of this component with a text, keeping its format,
+    /// interactions and children.
+    pub fn set_text<T: Into<Cow<'static, str>>>(&mut self, text: T) {
+        self.content = Content::Text { text: text.into() };
+    }
+
+    /// Turns this component into a plain text one in place, the children are cleared
+    /// keeping the capacity of their vector so it can be filled again without allocating.
+    pub fn clear_to_text<T: Into<Cow<'static, str>>>(&mut self, text: T) {
+        self.set_text(text);
+        self.format = Format::new();
+        self.interactions = Interactivity::new();
+        self.children.clear();
+        self.inline_translations = None;
+        #[cfg(feature = "unknown-fields")]
+        {
+            self.unknown_fields = None;
+        }
+    }
+
+    /// The children of this component, to refill them in place, like after
+    /// [clear_to_text](TextComponent::clear_to_text), keeping the capacity of the vector.
+    pub fn children_vec_mut(&mut self) -> &mut Vec<TextComponent> {
+        &mut self.children
+    }
+
+    /// A one line summary of the content and children of this component, for logs.
+    /// ## Example
+    /// ```
+    /// # use text_components::{Modifier, TextComponent};
+    /// # let component = "Hello".add_children(vec![
+    /// #     TextComponent::translated_key("greeting"),
+    /// #     TextComponent::scoreboard("@p", "kills"),
+    /// #     TextComponent::plain("!"),
+    /// # ]);
+    /// // text("Hello") + 3 children [translate, score, text]
+    /// component.describe();
+    /// ```
+    pub fn describe(&self) -> String {
+        let mut summary = String::new();
+        // Writing to a String never fails
+        let _ = self.write_description(&mut summary);
+        summary
+    }
+
+    fn write_description(&self, f: &mut String) -> fmt::Result {
+        self.content.write_summary(f)?;
+        match self.children.len() {
+            0 => return Ok(()),
+            1 => write!(f, " + 1 child [")?,
+            len => write!(f, " + {len} children [")?,
+        }
+        for (i, child) in self.children.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(child.content.kind())?;
+        }
+        f.write_str("]")
+    }
+}
+
 impl From<String> for Content {
     fn from(value: String) -> Self {
         Content::Text {
@@ -32,28 +278,83 @@ impl From<String> for Content {
     }
 }
 
+/// The texture atlas the sprite of an [Object::Atlas] is taken from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Atlas {
+    /// Leaves the atlas out, the game then uses [Atlas::BLOCKS].
+    #[default]
+    Default,
+    Custom(Cow<'static, str>),
+}
+impl Atlas {
+    pub const BLOCKS: Atlas = Atlas::Custom(Cow::Borrowed("minecraft:blocks"));
+    pub const ITEMS: Atlas = Atlas::Custom(Cow::Borrowed("minecraft:items"));
+    pub const GUI: Atlas = Atlas::Custom(Cow::Borrowed("minecraft:gui"));
+
+    /// The id written in the `atlas` key, [None] for [Atlas::Default].
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Atlas::Default => None,
+            Atlas::Custom(id) => Some(id),
+        }
+    }
+    pub fn into_id(self) -> Option<Cow<'static, str>> {
+        match self {
+            Atlas::Default => None,
+            Atlas::Custom(id) => Some(id),
+        }
+    }
+    pub fn is_valid(&self) -> bool {
+        self.id().is_none_or(is_resource_location)
+    }
+}
+impl From<&'static str> for Atlas {
+    fn from(value: &'static str) -> Self {
+        Atlas::Custom(Cow::Borrowed(value))
+    }
+}
+impl From<String> for Atlas {
+    fn from(value: String) -> Self {
+        Atlas::Custom(Cow::Owned(value))
+    }
+}
+impl From<Option<Cow<'static, str>>> for Atlas {
+    fn from(value: Option<Cow<'static, str>>) -> Self {
+        value.map_or(Atlas::Default, Atlas::Custom)
+    }
+}
+
+/// Whether `value` is a valid `namespace:path` resource location, the namespace being optional.
+pub fn is_resource_location(value: &str) -> bool {
+    let (namespace, path) = value.split_once(':').unwrap_or(("minecraft", value));
+    !namespace.is_empty()
+        && !path.is_empty()
+        && namespace
+            .chars()
+            .all(|char| matches!(char, 'a'..='z' | '0'..='9' | '_' | '-' | '.'))
+        && path
+            .chars()
+            .all(|char| matches!(char, 'a'..='z' | '0'..='9' | '_' | '-' | '.' | '/'))
+}
+
+/// Written with its `object` discriminator (`"atlas"` or `"player"`) by every format,
+/// when reading it the kind is taken from the `sprite` or `player` keys if it's missing.\
+/// The `player` of a head may be read from just the name of the player, the `hat` is
+/// only written when it's hidden.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub enum Object {
     Atlas {
-        #[cfg_attr(
-            feature = "serde",
-            serde(skip_serializing_if = "Option::is_none", default)
-        )]
         atlas: Option<Cow<'static, str>>,
         sprite: Cow<'static, str>,
     },
     Player {
         player: ObjectPlayer,
-        #[cfg_attr(
-            feature = "serde",
-            serde(skip_serializing_if = "Clone::clone", default)
-        )]
         hat: bool,
     },
 }
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
 pub struct ObjectPlayer {
     #[cfg_attr(
         feature = "serde",
@@ -77,31 +378,44 @@ pub struct ObjectPlayer {
     pub properties: Vec<PlayerProperties>,
 }
 impl ObjectPlayer {
+    /// A [ObjectPlayer] with nothing set, which [is_empty](ObjectPlayer::is_empty).\
+    /// Same as [Default], usable in constants.
+    /// ## Example
+    /// ```
+    /// # use text_components::content::ObjectPlayer;
+    /// let player = ObjectPlayer {
+    ///     name: Some("Steve".into()),
+    ///     texture: Some("entity/player/wide/steve".into()),
+    ///     ..ObjectPlayer::empty()
+    /// };
+    /// ```
+    pub const fn empty() -> Self {
+        ObjectPlayer {
+            name: None,
+            id: None,
+            texture: None,
+            properties: Vec::new(),
+        }
+    }
     /// Creates a [ObjectPlayer] from a player's name.
     pub fn name<T: Into<Cow<'static, str>>>(name: T) -> Self {
         ObjectPlayer {
             name: Some(name.into()),
-            id: None,
-            texture: None,
-            properties: vec![],
+            ..ObjectPlayer::empty()
         }
     }
     /// Creates a [ObjectPlayer] from the id of a player.
     pub fn id(id: [i32; 4]) -> Self {
         ObjectPlayer {
-            name: None,
             id: Some(id),
-            texture: None,
-            properties: vec![],
+            ..ObjectPlayer::empty()
         }
     }
     /// Creates a [ObjectPlayer] from the path to a texture of a resource pack.
     pub fn texture<T: Into<Cow<'static, str>>>(path: T) -> Self {
         ObjectPlayer {
-            name: None,
-            id: None,
             texture: Some(path.into()),
-            properties: vec![],
+            ..ObjectPlayer::empty()
         }
     }
     /// Creates a [ObjectPlayer] from a player's skin properties.
@@ -112,16 +426,15 @@ impl ObjectPlayer {
         signature: Option<R>,
     ) -> Self {
         ObjectPlayer {
-            name: None,
-            id: None,
-            texture: None,
             properties: vec![PlayerProperties {
-                name: Cow::Borrowed("textures"),
                 value: value.into(),
                 signature: signature.map(Into::into),
+                ..PlayerProperties::default()
             }],
+            ..ObjectPlayer::empty()
         }
     }
+    /// Whether nothing is set.
     pub fn is_empty(&self) -> bool {
         self.name.is_none()
             && self.id.is_none()
@@ -132,20 +445,57 @@ impl ObjectPlayer {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
 pub struct PlayerProperties {
     pub name: Cow<'static, str>,
     pub value: Cow<'static, str>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none", default)
+    )]
     pub signature: Option<Cow<'static, str>>,
 }
 
+/// A `textures` property, the only one the game reads, with an empty value and no
+/// signature.
+/// ## Example
+/// ```
+/// # use text_components::content::PlayerProperties;
+/// # const TEXTURES_BASE64: &str = "e3RleHR1cmVzOnt9fQ==";
+/// let skin = PlayerProperties {
+///     value: TEXTURES_BASE64.into(),
+///     ..Default::default()
+/// };
+/// ```
+impl Default for PlayerProperties {
+    fn default() -> Self {
+        PlayerProperties {
+            name: Cow::Borrowed("textures"),
+            value: Cow::Borrowed(""),
+            signature: None,
+        }
+    }
+}
+
+/// Contents the server replaces with what they point at.\
+/// A compound with the keys of more than one is read as the first of them in the order the
+/// game tries them: `score`, then `selector`, then `nbt`. The other keys are skipped, so
+/// `{"selector":"@a","nbt":"Health","entity":"@p"}` is an [Entity](Resolvable::Entity).\
+/// SNBT reads the `type` key the game writes before that, JSON parsing doesn't.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub enum Resolvable {
     /// The selector must only accept 1 target
     /// #### Needs [resolution](TextComponent::resolve)
-    #[cfg_attr(feature = "serde", serde(rename = "score"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            untagged,
+            serialize_with = "crate::serde::serialize_score",
+            deserialize_with = "crate::serde::deserialize_score_owned"
+        )
+    )]
     Scoreboard {
-        #[cfg_attr(feature = "serde", serde(rename = "name"))]
         selector: Cow<'static, str>,
         objective: Cow<'static, str>,
     },
@@ -153,7 +503,13 @@ pub enum Resolvable {
     #[cfg_attr(feature = "serde", serde(untagged))]
     Entity {
         selector: Cow<'static, str>,
-        #[cfg_attr(feature = "serde", serde(default = "Resolvable::entity_separator"))]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                default = "Resolvable::entity_separator",
+                deserialize_with = "crate::serde::deserialize_separator"
+            )
+        )]
         separator: Box<TextComponent>,
     },
     /// #### Needs [resolution](TextComponent::resolve)
@@ -165,10 +521,20 @@ pub enum Resolvable {
         // replaced with the one inside the nbt selected if possible
         #[cfg_attr(
             feature = "serde",
-            serde(skip_serializing_if = "Option::is_none", default)
+            serde(
+                skip_serializing_if = "Option::is_none",
+                deserialize_with = "crate::serde::option_bool_lenient",
+                default
+            )
         )]
         interpret: Option<bool>,
-        #[cfg_attr(feature = "serde", serde(default = "Resolvable::nbt_separator"))]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                default = "Resolvable::nbt_separator",
+                deserialize_with = "crate::serde::deserialize_separator"
+            )
+        )]
         separator: Box<TextComponent>,
         #[cfg_attr(feature = "serde", serde(flatten, default = "NbtSource::Entity"))]
         source: NbtSource,
@@ -195,6 +561,47 @@ impl Resolvable {
             ..Default::default()
         })
     }
+    /// The separator put between the values this resolvable resolves to,
+    /// scores have none so they use the [entity one](Resolvable::entity_separator).
+    pub fn separator(&self) -> Cow<'_, TextComponent> {
+        match self {
+            Resolvable::Entity { separator, .. } | Resolvable::NBT { separator, .. } => {
+                Cow::Borrowed(separator)
+            }
+            Resolvable::Scoreboard { .. } => Cow::Owned(*Resolvable::entity_separator()),
+        }
+    }
+    /// Joins the components this resolvable resolved to with its [separator](Resolvable::separator),
+    /// like the game does when a selector matches many entities.
+    /// ## Example
+    /// ```
+    /// # use text_components::content::Resolvable;
+    /// # let resolvable = Resolvable::Entity {
+    /// #     selector: "@a".into(),
+    /// #     separator: Resolvable::entity_separator(),
+    /// # };
+    /// // Results in "Steve, Alex" with a gray comma
+    /// resolvable.join_resolved(vec!["Steve".into(), "Alex".into()]);
+    /// ```
+    pub fn join_resolved(&self, items: Vec<TextComponent>) -> TextComponent {
+        let mut items = items.into_iter();
+        let Some(first) = items.next() else {
+            return TextComponent::new();
+        };
+        let Some(second) = items.next() else {
+            return first;
+        };
+        let separator = self.separator();
+        let mut children = vec![first, separator.clone().into_owned(), second];
+        for item in items {
+            children.push(separator.clone().into_owned());
+            children.push(item);
+        }
+        TextComponent {
+            children,
+            ..TextComponent::new()
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -205,6 +612,31 @@ pub enum NbtSource {
     Block(Cow<'static, str>),
     Storage(Cow<'static, str>),
 }
+/// Written as the kind and its value, like `score(@s, kills)` or `selector(@e[type=pig])`.
+impl Display for Resolvable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Resolvable::Scoreboard {
+                selector,
+                objective,
+            } => write!(f, "score({selector}, {objective})"),
+            Resolvable::Entity { selector, .. } => write!(f, "selector({selector})"),
+            Resolvable::NBT {
+                path,
+                interpret,
+                source,
+                ..
+            } => {
+                write!(f, "nbt({source} {path}")?;
+                if *interpret == Some(true) {
+                    f.write_str(" interpret")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
 impl NbtSource {
     /// Creates a [NbtSource] from a entity selector.
     pub fn entity<T: Into<Cow<'static, str>>>(selector: T) -> Self {
@@ -220,6 +652,17 @@ impl NbtSource {
     }
 }
 
+/// Written as the kind and its target, like `entity @s` or `block 0 64 0`.
+impl Display for NbtSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NbtSource::Entity(selector) => write!(f, "entity {selector}"),
+            NbtSource::Block(position) => write!(f, "block {position}"),
+            NbtSource::Storage(identifier) => write!(f, "storage {identifier}"),
+        }
+    }
+}
+
 impl From<Content> for TextComponent {
     fn from(value: Content) -> Self {
         TextComponent {
@@ -227,6 +670,9 @@ impl From<Content> for TextComponent {
             children: Vec::new(),
             format: Format::new(),
             interactions: Interactivity::new(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
         }
     }
 }
@@ -237,6 +683,9 @@ impl From<Object> for TextComponent {
             children: Vec::new(),
             format: Format::new(),
             interactions: Interactivity::new(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
         }
     }
 }
@@ -250,6 +699,9 @@ impl From<ObjectPlayer> for TextComponent {
             children: Vec::new(),
             format: Format::new(),
             interactions: Interactivity::new(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
         }
     }
 }
@@ -260,6 +712,9 @@ impl From<Resolvable> for TextComponent {
             children: Vec::new(),
             format: Format::new(),
             interactions: Interactivity::new(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
         }
     }
 }
@@ -0,0 +1,465 @@
+//! Dialogs shown by [ShowDialog](crate::interactivity::ClickEvent::ShowDialog) click events,
+//! following the 1.21.6 `minecraft:dialog` format.
+//! ```
+//! # use text_components::{
+//! #     Modifier,
+//! #     dialog::{Dialog, DialogButton},
+//! #     interactivity::ClickEvent,
+//! # };
+//! let dialog = Dialog::notice("Rules")
+//!     .body("Be nice to each other.")
+//!     .button(DialogButton::new("Got it"));
+//! "Read the rules".click_event(ClickEvent::show_dialog(dialog));
+//! ```
+//! Only plain message bodies and static button actions are modelled,
+//! dialogs with inputs or item bodies have to be registered and referenced by id.
+use crate::TextComponent;
+use std::borrow::Cow;
+
+/// What a [ShowDialog](crate::interactivity::ClickEvent::ShowDialog) click event opens.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum DialogRef {
+    /// The id of a dialog in the registry, like `minecraft:server_links`.
+    Id(Cow<'static, str>),
+    /// A dialog defined in the click event itself.
+    Inline(Box<Dialog>),
+}
+
+impl From<&'static str> for DialogRef {
+    fn from(value: &'static str) -> Self {
+        DialogRef::Id(Cow::Borrowed(value))
+    }
+}
+impl From<String> for DialogRef {
+    fn from(value: String) -> Self {
+        DialogRef::Id(Cow::Owned(value))
+    }
+}
+impl From<Cow<'static, str>> for DialogRef {
+    fn from(value: Cow<'static, str>) -> Self {
+        DialogRef::Id(value)
+    }
+}
+impl From<Dialog> for DialogRef {
+    fn from(value: Dialog) -> Self {
+        DialogRef::Inline(Box::new(value))
+    }
+}
+
+/// A dialog definition.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+pub struct Dialog {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub kind: DialogKind,
+    pub title: TextComponent,
+    /// Name of the button leading to this dialog from other ones, the title if [None].
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none", default)
+    )]
+    pub external_title: Option<TextComponent>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Vec::is_empty", default)
+    )]
+    pub body: Vec<DialogBody>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            skip_serializing_if = "is_true",
+            default = "crate::serde::default_true"
+        )
+    )]
+    pub can_close_with_escape: bool,
+    /// Whether the game is paused while the dialog is open in singleplayer.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            skip_serializing_if = "is_true",
+            default = "crate::serde::default_true"
+        )
+    )]
+    pub pause: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "AfterAction::is_default", default)
+    )]
+    pub after_action: AfterAction,
+}
+
+#[cfg(feature = "serde")]
+fn is_true(value: &bool) -> bool {
+    *value
+}
+
+impl Dialog {
+    fn new<T: Into<TextComponent>>(kind: DialogKind, title: T) -> Self {
+        Dialog {
+            kind,
+            title: title.into(),
+            external_title: None,
+            body: vec![],
+            can_close_with_escape: true,
+            pause: true,
+            after_action: AfterAction::Close,
+        }
+    }
+    /// A dialog with a single button, which only closes it unless [given](Dialog::button) one.
+    pub fn notice<T: Into<TextComponent>>(title: T) -> Self {
+        Dialog::new(DialogKind::Notice { action: None }, title)
+    }
+    /// A dialog with a yes and a no button.
+    pub fn confirmation<T: Into<TextComponent>>(
+        title: T,
+        yes: DialogButton,
+        no: DialogButton,
+    ) -> Self {
+        Dialog::new(
+            DialogKind::Confirmation {
+                yes: Box::new(yes),
+                no: Box::new(no),
+            },
+            title,
+        )
+    }
+    /// A dialog with a grid of buttons, [added](Dialog::button) one by one.
+    pub fn multi_action<T: Into<TextComponent>>(title: T) -> Self {
+        Dialog::new(
+            DialogKind::MultiAction {
+                actions: vec![],
+                columns: None,
+                exit_action: None,
+            },
+            title,
+        )
+    }
+
+    /// Adds a paragraph of text to the body.
+    pub fn body<T: Into<TextComponent>>(mut self, contents: T) -> Self {
+        self.body.push(DialogBody::PlainMessage {
+            contents: contents.into(),
+            width: None,
+        });
+        self
+    }
+    /// Sets the button of a notice or adds one to a multi action dialog,
+    /// does nothing on confirmations.
+    pub fn button(mut self, button: DialogButton) -> Self {
+        match &mut self.kind {
+            DialogKind::Notice { action } => *action = Some(button),
+            DialogKind::MultiAction { actions, .. } => actions.push(button),
+            DialogKind::Confirmation { .. } => (),
+        }
+        self
+    }
+    pub fn external_title<T: Into<TextComponent>>(mut self, title: T) -> Self {
+        self.external_title = Some(title.into());
+        self
+    }
+    pub fn can_close_with_escape(mut self, value: bool) -> Self {
+        self.can_close_with_escape = value;
+        self
+    }
+    pub fn pause(mut self, value: bool) -> Self {
+        self.pause = value;
+        self
+    }
+    pub fn after_action(mut self, action: AfterAction) -> Self {
+        self.after_action = action;
+        self
+    }
+}
+
+/// The type of a [Dialog] and the buttons that come with it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
+pub enum DialogKind {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "minecraft:notice", alias = "notice")
+    )]
+    Notice {
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Option::is_none", default)
+        )]
+        action: Option<DialogButton>,
+    },
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "minecraft:confirmation", alias = "confirmation")
+    )]
+    Confirmation {
+        yes: Box<DialogButton>,
+        no: Box<DialogButton>,
+    },
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "minecraft:multi_action", alias = "multi_action")
+    )]
+    MultiAction {
+        actions: Vec<DialogButton>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Option::is_none", default)
+        )]
+        columns: Option<u32>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Option::is_none", default)
+        )]
+        exit_action: Option<DialogButton>,
+    },
+}
+impl DialogKind {
+    /// The id written in the `type` field.
+    pub fn id(&self) -> &'static str {
+        match self {
+            DialogKind::Notice { .. } => "minecraft:notice",
+            DialogKind::Confirmation { .. } => "minecraft:confirmation",
+            DialogKind::MultiAction { .. } => "minecraft:multi_action",
+        }
+    }
+}
+
+/// An element of the body of a [Dialog].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
+pub enum DialogBody {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "minecraft:plain_message", alias = "plain_message")
+    )]
+    PlainMessage {
+        contents: TextComponent,
+        /// Width of the text in pixels, the game uses 200 if [None].
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Option::is_none", default)
+        )]
+        width: Option<u32>,
+    },
+}
+
+/// A button of a [Dialog].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+pub struct DialogButton {
+    pub label: TextComponent,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none", default)
+    )]
+    pub tooltip: Option<TextComponent>,
+    /// Width of the button in pixels, the game uses 150 if [None].
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none", default)
+    )]
+    pub width: Option<u32>,
+    /// What the button does besides closing the dialog.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none", default)
+    )]
+    pub action: Option<DialogAction>,
+}
+impl DialogButton {
+    /// A button that only closes the dialog.
+    pub fn new<T: Into<TextComponent>>(label: T) -> Self {
+        DialogButton {
+            label: label.into(),
+            tooltip: None,
+            width: None,
+            action: None,
+        }
+    }
+    pub fn tooltip<T: Into<TextComponent>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = Some(width);
+        self
+    }
+    pub fn action(mut self, action: DialogAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+}
+
+/// What a [DialogButton] does when pressed, the same actions click events have.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum DialogAction {
+    OpenUrl { url: Cow<'static, str> },
+    RunCommand { command: Cow<'static, str> },
+    SuggestCommand { command: Cow<'static, str> },
+    CopyToClipboard { value: Cow<'static, str> },
+}
+
+/// What happens to the [Dialog] after one of its buttons is pressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum AfterAction {
+    #[default]
+    Close,
+    /// Keeps the dialog open.
+    None,
+    /// Replaces the dialog with a waiting screen until the server shows another one.
+    WaitForResponse,
+}
+impl AfterAction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AfterAction::Close => "close",
+            AfterAction::None => "none",
+            AfterAction::WaitForResponse => "wait_for_response",
+        }
+    }
+    #[cfg(feature = "serde")]
+    fn is_default(&self) -> bool {
+        *self == AfterAction::Close
+    }
+}
+
+#[cfg(feature = "nbt")]
+mod nbt {
+    use super::{Dialog, DialogAction, DialogBody, DialogButton, DialogKind, DialogRef};
+    use crate::{TextComponent, nbt::NbtBuilder, resolving::TextResolutor};
+    use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+
+    type Values = Vec<(simdnbt::Mutf8String, NbtTag)>;
+
+    fn component<R: TextResolutor + ?Sized>(component: &TextComponent, resolutor: &R) -> NbtTag {
+        component.build(resolutor, NbtBuilder::new())
+    }
+
+    fn string(value: &str) -> NbtTag {
+        NbtTag::String(value.into())
+    }
+
+    impl DialogRef {
+        /// The id as a string or the [inline dialog](Dialog::to_nbt).
+        pub fn to_nbt<R: TextResolutor + ?Sized>(&self, resolutor: &R) -> NbtTag {
+            match self {
+                DialogRef::Id(id) => string(id),
+                DialogRef::Inline(dialog) => dialog.to_nbt(resolutor),
+            }
+        }
+    }
+
+    impl Dialog {
+        /// Builds the dialog as the game stores it in the `minecraft:dialog` registry.
+        pub fn to_nbt<R: TextResolutor + ?Sized>(&self, resolutor: &R) -> NbtTag {
+            let mut values: Values = vec![("type".into(), string(self.kind.id()))];
+            values.push(("title".into(), component(&self.title, resolutor)));
+            if let Some(title) = &self.external_title {
+                values.push(("external_title".into(), component(title, resolutor)));
+            }
+            if !self.body.is_empty() {
+                let body = self
+                    .body
+                    .iter()
+                    .map(|body| match body {
+                        DialogBody::PlainMessage { contents, width } => {
+                            let mut values: Values = vec![
+                                ("type".into(), string("minecraft:plain_message")),
+                                ("contents".into(), component(contents, resolutor)),
+                            ];
+                            if let Some(width) = width {
+                                values.push(("width".into(), NbtTag::Int(*width as i32)));
+                            }
+                            NbtCompound::from_values(values)
+                        }
+                    })
+                    .collect();
+                values.push(("body".into(), NbtTag::List(NbtList::Compound(body))));
+            }
+            if !self.can_close_with_escape {
+                values.push(("can_close_with_escape".into(), NbtTag::Byte(0)));
+            }
+            if !self.pause {
+                values.push(("pause".into(), NbtTag::Byte(0)));
+            }
+            if self.after_action != Default::default() {
+                values.push(("after_action".into(), string(self.after_action.name())));
+            }
+            match &self.kind {
+                DialogKind::Notice { action } => {
+                    if let Some(action) = action {
+                        values.push(("action".into(), action.to_nbt(resolutor)));
+                    }
+                }
+                DialogKind::Confirmation { yes, no } => {
+                    values.push(("yes".into(), yes.to_nbt(resolutor)));
+                    values.push(("no".into(), no.to_nbt(resolutor)));
+                }
+                DialogKind::MultiAction {
+                    actions,
+                    columns,
+                    exit_action,
+                } => {
+                    let actions = actions
+                        .iter()
+                        .filter_map(|action| action.to_nbt(resolutor).into_compound())
+                        .collect();
+                    values.push(("actions".into(), NbtTag::List(NbtList::Compound(actions))));
+                    if let Some(columns) = columns {
+                        values.push(("columns".into(), NbtTag::Int(*columns as i32)));
+                    }
+                    if let Some(exit_action) = exit_action {
+                        values.push(("exit_action".into(), exit_action.to_nbt(resolutor)));
+                    }
+                }
+            }
+            NbtTag::Compound(NbtCompound::from_values(values))
+        }
+    }
+
+    impl DialogButton {
+        fn to_nbt<R: TextResolutor + ?Sized>(&self, resolutor: &R) -> NbtTag {
+            let mut values: Values = vec![("label".into(), component(&self.label, resolutor))];
+            if let Some(tooltip) = &self.tooltip {
+                values.push(("tooltip".into(), component(tooltip, resolutor)));
+            }
+            if let Some(width) = self.width {
+                values.push(("width".into(), NbtTag::Int(width as i32)));
+            }
+            if let Some(action) = &self.action {
+                let (kind, key, value) = match action {
+                    DialogAction::OpenUrl { url } => ("open_url", "url", url),
+                    DialogAction::RunCommand { command } => ("run_command", "command", command),
+                    DialogAction::SuggestCommand { command } => {
+                        ("suggest_command", "command", command)
+                    }
+                    DialogAction::CopyToClipboard { value } => {
+                        ("copy_to_clipboard", "value", value)
+                    }
+                };
+                values.push((
+                    "action".into(),
+                    NbtTag::Compound(NbtCompound::from_values(vec![
+                        ("type".into(), string(kind)),
+                        (key.into(), string(value)),
+                    ])),
+                ));
+            }
+            NbtTag::Compound(NbtCompound::from_values(values))
+        }
+    }
+}
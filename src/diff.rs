@@ -0,0 +1,177 @@
+//! Differences between two components, to send only what changed to clients supporting
+//! partial updates.
+//! ```
+//! # use text_components::{Modifier, TextComponent};
+//! # let mut old = "Score: ".add_children(vec![TextComponent::new(), "".add_child("41")]);
+//! # let new = "Score: ".add_children(vec![TextComponent::new(), "".add_child("42")]);
+//! let diff = old.diff(&new);
+//! // [SetText([1, 0], "42")]
+//! old.apply_diff(&diff);
+//! assert_eq!(old, new);
+//! ```
+use crate::{TextComponent, content::Content, format::Format, interactivity::Interactivity};
+use std::borrow::Cow;
+
+/// An edit of the component found following the `path`, the indexes of the children to
+/// go through starting from the root (an empty path is the root itself).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DiffOp {
+    /// Replaces the whole component, children included.
+    Replace(Vec<usize>, TextComponent),
+    SetText(Vec<usize>, Cow<'static, str>),
+    SetFormat(Vec<usize>, Format),
+    SetInteractions(Vec<usize>, Interactivity),
+    InsertChild(Vec<usize>, usize, TextComponent),
+    RemoveChild(Vec<usize>, usize),
+}
+
+/// The edits turning a component into another, made by [TextComponent::diff].\
+/// They must be applied in order, the paths of an edit take the previous ones into account.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct ComponentDiff {
+    pub ops: Vec<DiffOp>,
+}
+
+impl ComponentDiff {
+    /// Whether both components were already equal.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+}
+
+impl TextComponent {
+    /// Finds the edits needed to turn this component into `other`, only descending into
+    /// the parts that changed so a single text change deep in the tree is a single edit.\
+    /// Children kept at the start or the end are left untouched when some are inserted or
+    /// removed in between.
+    pub fn diff(&self, other: &TextComponent) -> ComponentDiff {
+        let mut diff = ComponentDiff::default();
+        diff_into(self, other, &mut vec![], &mut diff.ops);
+        diff
+    }
+
+    /// Applies the edits of a [ComponentDiff], so `a.apply_diff(&a.diff(&b))` makes `a`
+    /// equal to `b`.\
+    /// Edits with a path not found in this component are skipped.
+    pub fn apply_diff(&mut self, diff: &ComponentDiff) {
+        for op in &diff.ops {
+            let (path, index) = match op {
+                DiffOp::Replace(path, _)
+                | DiffOp::SetText(path, _)
+                | DiffOp::SetFormat(path, _)
+                | DiffOp::SetInteractions(path, _) => (path, None),
+                DiffOp::InsertChild(path, index, _) | DiffOp::RemoveChild(path, index) => {
+                    (path, Some(*index))
+                }
+            };
+            let Some(target) = self.at_path(path) else {
+                continue;
+            };
+            match op {
+                DiffOp::Replace(_, component) => *target = component.clone(),
+                DiffOp::SetText(_, text) => target.content = Content::Text { text: text.clone() },
+                DiffOp::SetFormat(_, format) => target.format = format.clone(),
+                DiffOp::SetInteractions(_, interactions) => {
+                    target.interactions = interactions.clone()
+                }
+                DiffOp::InsertChild(_, _, child) => {
+                    if let Some(index) = index
+                        && index <= target.children.len()
+                    {
+                        target.children.insert(index, child.clone());
+                    }
+                }
+                DiffOp::RemoveChild(..) => {
+                    if let Some(index) = index
+                        && index < target.children.len()
+                    {
+                        target.children.remove(index);
+                    }
+                }
+            }
+        }
+    }
+
+    fn at_path(&mut self, path: &[usize]) -> Option<&mut TextComponent> {
+        let mut component = self;
+        for &index in path {
+            component = component.children.get_mut(index)?;
+        }
+        Some(component)
+    }
+}
+
+fn diff_into(
+    old: &TextComponent,
+    new: &TextComponent,
+    path: &mut Vec<usize>,
+    ops: &mut Vec<DiffOp>,
+) {
+    if old == new {
+        return;
+    }
+    // No edit carries these, so the whole component is replaced
+    #[cfg(feature = "unknown-fields")]
+    let unknown_changed = old.unknown_fields != new.unknown_fields;
+    #[cfg(not(feature = "unknown-fields"))]
+    let unknown_changed = false;
+    if unknown_changed || old.inline_translations != new.inline_translations {
+        ops.push(DiffOp::Replace(path.clone(), new.clone()));
+        return;
+    }
+    match (&old.content, &new.content) {
+        (Content::Text { text: old_text }, Content::Text { text: new_text }) => {
+            if old_text != new_text {
+                ops.push(DiffOp::SetText(path.clone(), new_text.clone()));
+            }
+        }
+        (old_content, new_content) => {
+            if old_content != new_content {
+                ops.push(DiffOp::Replace(path.clone(), new.clone()));
+                return;
+            }
+        }
+    }
+    if old.format != new.format {
+        ops.push(DiffOp::SetFormat(path.clone(), new.format.clone()));
+    }
+    if old.interactions != new.interactions {
+        ops.push(DiffOp::SetInteractions(
+            path.clone(),
+            new.interactions.clone(),
+        ));
+    }
+
+    let (old_children, new_children) = (&old.children, &new.children);
+    let prefix = old_children
+        .iter()
+        .zip(new_children)
+        .take_while(|(old, new)| old == new)
+        .count();
+    let max_suffix = old_children.len().min(new_children.len()) - prefix;
+    let suffix = old_children
+        .iter()
+        .rev()
+        .zip(new_children.iter().rev())
+        .take(max_suffix)
+        .take_while(|(old, new)| old == new)
+        .count();
+    let old_middle = &old_children[prefix..old_children.len() - suffix];
+    let new_middle = &new_children[prefix..new_children.len() - suffix];
+
+    let paired = old_middle.len().min(new_middle.len());
+    for (i, (old_child, new_child)) in old_middle.iter().zip(new_middle).enumerate() {
+        path.push(prefix + i);
+        diff_into(old_child, new_child, path, ops);
+        path.pop();
+    }
+    for _ in paired..old_middle.len() {
+        ops.push(DiffOp::RemoveChild(path.clone(), prefix + paired));
+    }
+    for (i, child) in new_middle.iter().enumerate().skip(paired) {
+        ops.push(DiffOp::InsertChild(path.clone(), prefix + i, child.clone()));
+    }
+}
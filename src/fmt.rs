@@ -1,68 +1,48 @@
+#![deny(clippy::unwrap_used, clippy::indexing_slicing)]
 use crate::{
     TextComponent,
-    content::{Content, Object},
+    content::{Content, Object, Resolvable},
     format::{Color, Format},
-    interactivity::{ClickEvent, Interactivity},
-    resolving::{BuildTarget, NoResolutor, TextResolutor},
+    interactivity::{HoverEvent, Interactivity},
+    resolving::{
+        BuildTarget, NoResolutor, PlaceholderConfig, TextResolutor, TranslationSegment,
+        TranslationText, keybind_text,
+    },
 };
-use colored::{ColoredString, Colorize};
-use rand::random_range;
 use std::{
     borrow::Cow,
-    fmt::{self, Debug, Display, Formatter, Pointer},
+    fmt::{self, Debug, Display, Formatter},
+    sync::OnceLock,
 };
-use supports_hyperlinks::supports_hyperlinks;
 
-const OBFUSCATION_CHARS: [char; 822] = [
-    '!', '"', '#', '$', '%', '&', '\'', '(', ')', '*', '+', ',', '-', '.', '/', '0', '1', '2', '3',
-    '4', '5', '6', '7', '8', '9', ':', ';', '<', '=', '>', '?', '@', 'A', 'B', 'C', 'D', 'E', 'F',
-    'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y',
-    'Z', '[', '\\', ']', '^', '_', '`', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l',
-    'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '{', '|', '}', '~', '¡',
-    '¢', '£', '¤', '¥', '¦', '§', '¨', '©', 'ª', '«', '¬', '®', '¯', '°', '±', '²', '³', '´', 'µ',
-    '¶', '·', '¸', '¹', 'º', '»', '¼', '½', '¾', '¿', 'À', 'Á', 'Â', 'Ã', 'Ä', 'Å', 'Æ', 'Ç', 'È',
-    'É', 'Ê', 'Ë', 'Ì', 'Í', 'Î', 'Ï', 'Ð', 'Ñ', 'Ò', 'Ó', 'Ô', 'Õ', 'Ö', '×', 'Ø', 'Ù', 'Ú', 'Û',
-    'Ü', 'Ý', 'Þ', 'ß', 'à', 'á', 'â', 'ã', 'ä', 'å', 'æ', 'ç', 'è', 'é', 'ê', 'ë', 'ì', 'í', 'î',
-    'ï', 'ð', 'ñ', 'ò', 'ó', 'ô', 'õ', 'ö', '÷', 'ø', 'ù', 'ú', 'û', 'ü', 'ý', 'þ', 'ÿ', 'Ā', 'ā',
-    'Ă', 'ă', 'Ą', 'ą', 'Ć', 'ć', 'Ĉ', 'ĉ', 'Ċ', 'ċ', 'Č', 'č', 'Ď', 'ď', 'Đ', 'đ', 'Ē', 'ē', 'Ĕ',
-    'ĕ', 'Ė', 'ė', 'Ę', 'ę', 'Ě', 'ě', 'Ĝ', 'ĝ', 'Ğ', 'ğ', 'Ġ', 'ġ', 'Ģ', 'ģ', 'Ĥ', 'ĥ', 'Ħ', 'ħ',
-    'Ĩ', 'ĩ', 'Ī', 'ī', 'Ĭ', 'ĭ', 'Į', 'į', 'İ', 'ı', 'Ĳ', 'ĳ', 'Ĵ', 'ĵ', 'Ķ', 'ķ', 'ĸ', 'Ĺ', 'ĺ',
-    'Ļ', 'ļ', 'Ľ', 'ľ', 'Ŀ', 'ŀ', 'Ł', 'ł', 'Ń', 'ń', 'Ņ', 'ņ', 'Ň', 'ň', 'ŉ', 'Ŋ', 'ŋ', 'Ō', 'ō',
-    'Ŏ', 'ŏ', 'Ő', 'ő', 'Œ', 'œ', 'Ŕ', 'ŕ', 'Ŗ', 'ŗ', 'Ř', 'ř', 'Ś', 'ś', 'Ŝ', 'ŝ', 'Ş', 'ş', 'Š',
-    'š', 'Ţ', 'ţ', 'Ť', 'ť', 'Ŧ', 'ŧ', 'Ũ', 'ũ', 'Ū', 'ū', 'Ŭ', 'ŭ', 'Ů', 'ů', 'Ű', 'ű', 'Ų', 'ų',
-    'Ŵ', 'ŵ', 'Ŷ', 'ŷ', 'Ÿ', 'Ź', 'ź', 'Ż', 'ż', 'Ž', 'ž', 'ſ', 'ƀ', 'Ɓ', 'Ƃ', 'ƃ', 'Ƅ', 'ƅ', 'Ɔ',
-    'Ƈ', 'ƈ', 'Ɖ', 'Ɗ', 'Ƌ', 'ƌ', 'ƍ', 'Ǝ', 'Ə', 'Ɛ', 'Ƒ', 'ƒ', 'Ɠ', 'Ɣ', 'ƕ', 'Ɩ', 'Ɨ', 'Ƙ', 'ƙ',
-    'ƚ', 'ƛ', 'Ɯ', 'Ɲ', 'ƞ', 'Ɵ', 'Ơ', 'ơ', 'Ƣ', 'ƣ', 'Ƥ', 'ƥ', 'Ʀ', 'Ƨ', 'ƨ', 'Ʃ', 'ƪ', 'ƫ', 'Ƭ',
-    'ƭ', 'Ʈ', 'Ư', 'ư', 'Ʊ', 'Ʋ', 'Ƴ', 'ƴ', 'Ƶ', 'ƶ', 'Ʒ', 'Ƹ', 'ƹ', 'ƺ', 'ƻ', 'Ƽ', 'ƽ', 'ƾ', 'ƿ',
-    'ǀ', 'ǁ', 'ǂ', 'ǃ', 'Ǆ', 'ǅ', 'ǆ', 'Ǉ', 'ǈ', 'ǉ', 'Ǌ', 'ǋ', 'ǌ', 'Ǎ', 'ǎ', 'Ǐ', 'ǐ', 'Ǒ', 'ǒ',
-    'Ǔ', 'ǔ', 'Ǖ', 'ǖ', 'Ǘ', 'ǘ', 'Ǚ', 'ǚ', 'Ǜ', 'ǜ', 'ǝ', 'Ǟ', 'ǟ', 'Ǡ', 'ǡ', 'Ǣ', 'ǣ', 'Ǥ', 'ǥ',
-    'Ǧ', 'ǧ', 'Ǩ', 'ǩ', 'Ǫ', 'ǫ', 'Ǭ', 'ǭ', 'Ǯ', 'ǯ', 'ǰ', 'Ǳ', 'ǲ', 'ǳ', 'Ǵ', 'ǵ', 'Ƕ', 'Ƿ', 'Ǹ',
-    'ǹ', 'Ǻ', 'ǻ', 'Ǽ', 'ǽ', 'Ǿ', 'ǿ', 'Ȁ', 'ȁ', 'Ȃ', 'ȃ', 'Ȅ', 'ȅ', 'Ȇ', 'ȇ', 'Ȉ', 'ȉ', 'Ȋ', 'ȋ',
-    'Ȍ', 'ȍ', 'Ȏ', 'ȏ', 'Ȑ', 'ȑ', 'Ȓ', 'ȓ', 'Ȕ', 'ȕ', 'Ȗ', 'ȗ', 'Ș', 'ș', 'Ț', 'ț', 'Ȝ', 'ȝ', 'Ȟ',
-    'ȟ', 'Ƞ', 'ȡ', 'Ȣ', 'ȣ', 'Ȥ', 'ȥ', 'Ȧ', 'ȧ', 'Ȩ', 'ȩ', 'Ȫ', 'ȫ', 'Ȭ', 'ȭ', 'Ȯ', 'ȯ', 'Ȱ', 'ȱ',
-    'Ȳ', 'ȳ', 'ȴ', 'ȵ', 'ȶ', 'ȷ', 'ȸ', 'ȹ', 'Ⱥ', 'Ȼ', 'ȼ', 'Ƚ', 'Ⱦ', 'ȿ', 'ɀ', 'Ɂ', 'ɂ', 'Ƀ', 'Ʉ',
-    'Ʌ', 'Ɇ', 'ɇ', 'Ɉ', 'ɉ', 'Ɋ', 'ɋ', 'Ɍ', 'ɍ', 'Ɏ', 'ɏ', 'ɐ', 'ɑ', 'ɒ', 'ɓ', 'ɔ', 'ɕ', 'ɖ', 'ɗ',
-    'ɘ', 'ə', 'ɚ', 'ɛ', 'ɜ', 'ɝ', 'ɞ', 'ɟ', 'ɠ', 'ɡ', 'ɢ', 'ɣ', 'ɤ', 'ɥ', 'ɦ', 'ɧ', 'ɨ', 'ɩ', 'ɪ',
-    'ɫ', 'ɬ', 'ɭ', 'ɮ', 'ɯ', 'ɰ', 'ɱ', 'ɲ', 'ɳ', 'ɴ', 'ɵ', 'ɶ', 'ɷ', 'ɸ', 'ɹ', 'ɺ', 'ɻ', 'ɼ', 'ɽ',
-    'ɾ', 'ɿ', 'ʀ', 'ʁ', 'ʂ', 'ʃ', 'ʄ', 'ʅ', 'ʆ', 'ʇ', 'ʈ', 'ʉ', 'ʊ', 'ʋ', 'ʌ', 'ʍ', 'ʎ', 'ʏ', 'ʐ',
-    'ʑ', 'ʒ', 'ʓ', 'ʔ', 'ʕ', 'ʖ', 'ʗ', 'ʘ', 'ʙ', 'ʚ', 'ʛ', 'ʜ', 'ʝ', 'ʞ', 'ʟ', 'ʠ', 'ʡ', 'ʢ', 'ʣ',
-    'ʤ', 'ʥ', 'ʦ', 'ʧ', 'ʨ', 'ʩ', 'ʪ', 'ʫ', 'ʬ', 'ʭ', 'ʮ', 'ʯ', 'Ά', '·', 'Έ', 'Ή', 'Ί', '΋', 'Ό',
-    '΍', 'Ύ', 'Ώ', 'ΐ', 'Α', 'Β', 'Γ', 'Δ', 'Ε', 'Ζ', 'Η', 'Θ', 'Ι', 'Κ', 'Λ', 'Μ', 'Ν', 'Ξ', 'Ο',
-    'Π', 'Ρ', '΢', 'Σ', 'Τ', 'Υ', 'Φ', 'Χ', 'Ψ', 'Ω', 'Ϊ', 'Ϋ', 'ά', 'έ', 'ή', 'ί', 'ΰ', 'α', 'β',
-    'γ', 'δ', 'ε', 'ζ', 'η', 'θ', 'ι', 'κ', 'λ', 'μ', 'ν', 'ξ', 'ο', 'π', 'ρ', 'ς', 'σ', 'τ', 'υ',
-    'φ', 'χ', 'ψ', 'ω', 'ϊ', 'ϋ', 'ό', 'ύ', 'ώ', 'Ϗ', 'ϐ', 'ϑ', 'ϒ', 'ϓ', 'ϔ', 'ϕ', 'ϖ', 'ϗ', 'Ϙ',
-    'ϙ', 'Ϛ', 'ϛ', 'Ϝ', 'ϝ', 'Ϟ', 'ϟ', 'Ϡ', 'ϡ', 'Ϣ', 'ϣ', 'Ϥ', 'ϥ', 'Ϧ', 'ϧ', 'Ϩ', 'ϩ', 'Ϫ', 'ϫ',
-    'Ϭ', 'ϭ', 'Ϯ', 'ϯ', 'ϰ', 'ϱ', 'ϲ', 'ϳ', 'ϴ', 'ϵ', '϶', 'Ϸ', 'ϸ', 'Ϲ', 'Ϻ', 'ϻ', 'ϼ', 'Ͻ', 'Ͼ',
-    'Ͽ', 'Ѐ', 'Ё', 'Ђ', 'Ѓ', 'Є', 'Ѕ', 'І', 'Ї', 'Ј', 'Љ', 'Њ', 'Ћ', 'Ќ', 'Ѝ', 'Ў', 'Џ', 'А', 'Б',
-    'В', 'Г', 'Д', 'Е', 'Ж', 'З', 'И', 'Й', 'К', 'Л', 'М', 'Н', 'О', 'П', 'Р', 'С', 'Т', 'У', 'Ф',
-    'Х', 'Ц', 'Ч', 'Ш', 'Щ', 'Ъ', 'Ы', 'Ь', 'Э', 'Ю', 'Я', 'а', 'б', 'в', 'г', 'д', 'е', 'ж', 'з',
-    'и', 'й', 'к', 'л', 'м', 'н', 'о', 'п', 'р', 'с', 'т', 'у', 'ф', 'х', 'ц', 'ч', 'ш', 'щ', 'ъ',
-    'ы', 'ь', 'э', 'ю', 'я',
-];
+mod minimessage;
+#[cfg(feature = "pretty")]
+pub mod obfuscation;
+#[cfg(feature = "pretty")]
+mod pretty;
+#[cfg(feature = "pretty")]
+pub use pretty::{PrettyOptions, PrettyTextBuilder};
+mod visitor;
+pub use visitor::{ComponentVisitor, VisitorTarget};
 
 pub struct TextBuilder;
 impl TextBuilder {
-    fn stringify_content<R: TextResolutor + ?Sized, S: BuildTarget>(
+    /// Builds the content of a component (without its children) as text, translating it
+    /// through the resolutor and building its arguments with the given target.\
+    /// The result goes through [decorate_interactions](BuildTarget::decorate_interactions).
+    pub fn stringify_content<R: TextResolutor + ?Sized, S: BuildTarget>(
+        target: &S,
+        resolutor: &R,
+        component: &TextComponent,
+    ) -> S::Result
+    where
+        S::Result: From<String> + ToString + Display,
+    {
+        let text = Self::content_text(target, resolutor, component);
+        target.decorate_interactions(text, &component.interactions)
+    }
+
+    fn content_text<R: TextResolutor + ?Sized, S: BuildTarget>(
         target: &S,
         resolutor: &R,
         component: &TextComponent,
@@ -73,56 +53,66 @@ impl TextBuilder {
         match &component.content {
             Content::Text { text } => text.to_string().into(),
             Content::Translate(message) => {
-                let translated = match resolutor.translate(&message.key) {
-                    Some(t) => t,
-                    None => match &message.fallback {
-                        Some(f) => return f.to_string().into(),
-                        None => return format!("[Translation: {}]", message.key).into(),
-                    },
-                };
-                let parts = resolutor.split_translation(translated);
-                let mut built_parts = vec![];
-                for (part, pos) in parts {
-                    let component_part = TextComponent {
-                        content: part.into(),
+                // Every part is built with the format of the translation, the placeholder included
+                let literal = |text: Cow<'_, str>| {
+                    let part = TextComponent {
+                        content: text.into_owned().into(),
                         format: component.format.clone(),
                         ..TextComponent::new()
                     };
-                    built_parts.push(
-                        target
-                            .build_component(resolutor, &component_part)
-                            .to_string(),
-                    );
-                    if pos != 0
-                        && let Some(args) = &message.args
-                        && pos <= args.len()
-                        && let Some(arg) = args.get(pos - 1)
-                    {
-                        let arg_part = TextComponent {
-                            content: arg.content.clone(),
-                            children: arg.children.clone(),
-                            format: arg.format.mix(&component.format),
-                            interactions: arg.interactions.clone(),
-                        };
-                        built_parts.push(target.build_component(resolutor, &arg_part).to_string());
+                    target.build_component(resolutor, &part).to_string()
+                };
+                let translated = match resolutor
+                    .placeholders()
+                    .translation_text(resolutor, message)
+                {
+                    TranslationText::Translated(translated) => translated,
+                    TranslationText::Literal(text) => return literal(text).into(),
+                };
+                let mut built = String::new();
+                for segment in resolutor.translation_segments(&translated) {
+                    match segment {
+                        TranslationSegment::Literal(part) => built.push_str(&literal(part)),
+                        TranslationSegment::Argument(index) => {
+                            if let Some(arg) =
+                                message.args.as_ref().and_then(|args| args.get(index))
+                            {
+                                let mut arg_part = arg.clone();
+                                arg_part.format.mix_in_place(&component.format);
+                                built.push_str(
+                                    &target.build_component(resolutor, &arg_part).to_string(),
+                                );
+                            }
+                        }
                     }
                 }
-                built_parts.concat().into()
-            }
-            Content::Keybind { keybind } => format!("[Keybind: {}]", keybind).into(),
-            Content::Object(Object::Atlas { sprite, .. }) => format!("[Object: {}]", sprite).into(),
-            Content::Object(Object::Player { player, .. }) => {
-                if let Some(name) = &player.name {
-                    return format!("[Head: {}]", name).into();
-                }
-                if let Some(id) = &player.id {
-                    return format!("[Head: {:?}]", id).into();
-                }
-                String::from("[Head]").into()
+                built.into()
             }
-            Content::Resolvable(_) => String::from("[Resolvable]").into(), // Just in case ;)
+            Content::Keybind { keybind } => match keybind_text(resolutor, keybind) {
+                Some(text) => text.into_owned().into(),
+                None => Self::placeholder(resolutor.placeholders(), &component.content).into(),
+            },
+            content => Self::placeholder(resolutor.placeholders(), content).into(),
+        }
+    }
+
+    fn object_placeholder(placeholders: &PlaceholderConfig, object: &Object) -> String {
+        match object {
+            Object::Atlas { sprite, .. } => format!("[Object: {}]", sprite),
+            Object::Player { player, .. } => placeholders.head_placeholder(player),
+        }
+    }
+
+    /// Text shown for contents that can't be turned into text.
+    fn placeholder(placeholders: &PlaceholderConfig, content: &Content) -> String {
+        match content {
+            Content::Keybind { keybind } => format!("[Keybind: {}]", keybind),
+            Content::Object(object) => Self::object_placeholder(placeholders, object),
+            Content::Resolvable(_) => String::from("[Resolvable]"), // Just in case ;)
             #[cfg(feature = "custom")]
-            Content::Custom { .. } => String::from("[Custom]").into(),
+            Content::Custom { .. } => String::from("[Custom]"),
+            Content::Text { text } => text.to_string(),
+            Content::Translate(message) => placeholders.translation_placeholder(message),
         }
     }
 }
@@ -133,107 +123,130 @@ impl BuildTarget for TextBuilder {
         resolutor: &R,
         component: &TextComponent,
     ) -> String {
-        Self::stringify_content(self, resolutor, component)
-            + &component
-                .children
-                .iter()
-                .map(|child| self.build_component(resolutor, child))
-                .collect::<Vec<String>>()
-                .concat()
+        let visitor = PlainVisitor {
+            text: String::new(),
+            placeholders: resolutor.placeholders(),
+        };
+        VisitorTarget(visitor).build_component(resolutor, component)
     }
 }
 
-pub struct PrettyTextBuilder;
-impl BuildTarget for PrettyTextBuilder {
-    type Result = ColoredString;
-    fn build_component<R: TextResolutor + ?Sized>(
-        &self,
-        resolutor: &R,
-        component: &TextComponent,
-    ) -> ColoredString {
-        let mut final_text = TextBuilder::stringify_content(self, resolutor, component);
+/// How the plain [TextBuilder] writes [obfuscated](Format::obfuscated) text, set in the
+/// [PlaceholderConfig].\
+/// Plain text usually ends in logs or webhooks, where revealing text meant to be hidden
+/// (like the answer of a quiz) may not be wanted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ObfuscatedText {
+    /// Written as it is.
+    #[default]
+    Reveal,
+    /// Every visible character is replaced with this one, the whitespace is kept.
+    Mask(char),
+    /// Left out.
+    Skip,
+}
 
-        if let Content::Translate(_) = component.content {
-            return format!(
-                "{}{}",
-                final_text,
-                component
-                    .children
-                    .iter()
-                    .map(|child| {
-                        let child = TextComponent {
-                            content: child.content.clone(),
-                            children: child.children.clone(),
-                            format: child.format.mix(&component.format),
-                            interactions: child.interactions.clone(),
-                        };
-                        self.build_component(resolutor, &child).to_string()
-                    })
-                    .collect::<Vec<String>>()
-                    .concat()
-            )
-            .into();
+#[derive(Clone)]
+struct PlainVisitor<'a> {
+    text: String,
+    placeholders: &'a PlaceholderConfig,
+}
+impl PlainVisitor<'_> {
+    fn push(&mut self, text: &str, format: &Format) {
+        match (format.obfuscated, self.placeholders.obfuscated) {
+            (Some(true), ObfuscatedText::Mask(mask)) => {
+                self.text
+                    .extend(text.chars().map(|char| match char.is_whitespace() {
+                        true => char,
+                        false => mask,
+                    }))
+            }
+            (Some(true), ObfuscatedText::Skip) => (),
+            _ => self.text.push_str(text),
         }
+    }
+}
+impl ComponentVisitor for PlainVisitor<'_> {
+    type Output = String;
+    fn visit_text(&mut self, text: &str, format: &Format, _: &Interactivity) {
+        self.push(text, format);
+    }
+    fn visit_object(&mut self, object: &Object, format: &Format, _: &Interactivity) {
+        self.push(
+            &TextBuilder::object_placeholder(self.placeholders, object),
+            format,
+        );
+    }
+    fn visit_unresolved(&mut self, content: &Content, format: &Format, _: &Interactivity) {
+        self.push(
+            &TextBuilder::placeholder(self.placeholders, content),
+            format,
+        );
+    }
+    fn finish(self) -> String {
+        self.text
+    }
+}
 
-        if let Some(true) = component.format.obfuscated {
-            let obfuscated = final_text
-                .chars()
-                .map(|char| {
-                    if !char.is_whitespace() && !char.is_control() {
-                        return OBFUSCATION_CHARS[random_range(0..822)];
-                    }
-                    char
-                })
-                .collect::<String>();
-            final_text = ColoredString::from(obfuscated);
-        }
-        if let Some(color) = &component.format.color {
-            final_text = color.colorize_text(final_text.to_string());
-        }
-        if let Some(true) = component.format.bold {
-            final_text = final_text.bold();
-        }
-        if let Some(true) = component.format.italic {
-            final_text = final_text.italic();
-        }
-        if let Some(true) = component.format.underlined {
-            final_text = final_text.underline();
-        }
-        if let Some(true) = component.format.strikethrough {
-            final_text = final_text.strikethrough();
-        }
-        if let Some(color) = component.format.shadow_color {
-            final_text = final_text.on_truecolor(
-                ((color >> 16) & 0xFF) as u8,
-                ((color >> 8) & 0xFF) as u8,
-                (color & 0xFF) as u8,
-            );
+/// A piece of text with the format and interactions it ends up with after inheritance,
+/// given by [flatten_runs](TextComponent::flatten_runs).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextRun {
+    pub text: String,
+    pub format: Format,
+    pub interactions: Interactivity,
+}
+
+#[derive(Clone)]
+struct RunsVisitor<'a> {
+    runs: Vec<TextRun>,
+    placeholders: &'a PlaceholderConfig,
+}
+impl RunsVisitor<'_> {
+    fn push(&mut self, text: &str, format: &Format, interactions: &Interactivity) {
+        if text.is_empty() {
+            return;
         }
-        if supports_hyperlinks()
-            && let Some(ClickEvent::OpenUrl { url }) = &component.interactions.click
+        if let Some(last) = self.runs.last_mut()
+            && last.format == *format
+            && last.interactions == *interactions
         {
-            final_text = format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, final_text).into();
+            last.text.push_str(text);
+            return;
         }
-
-        format!(
-            "{}{}",
-            final_text,
-            component
-                .children
-                .iter()
-                .map(|child| {
-                    let child = TextComponent {
-                        content: child.content.clone(),
-                        children: child.children.clone(),
-                        format: child.format.mix(&component.format),
-                        interactions: child.interactions.clone(),
-                    };
-                    self.build_component(resolutor, &child).to_string()
-                })
-                .collect::<Vec<String>>()
-                .concat()
-        )
-        .into()
+        self.runs.push(TextRun {
+            text: text.to_string(),
+            format: format.clone(),
+            interactions: interactions.clone(),
+        });
+    }
+}
+impl ComponentVisitor for RunsVisitor<'_> {
+    type Output = Vec<TextRun>;
+    fn visit_text(&mut self, text: &str, format: &Format, interactions: &Interactivity) {
+        self.push(text, format, interactions);
+    }
+    fn visit_object(&mut self, object: &Object, format: &Format, interactions: &Interactivity) {
+        self.push(
+            &TextBuilder::object_placeholder(self.placeholders, object),
+            format,
+            interactions,
+        );
+    }
+    fn visit_unresolved(
+        &mut self,
+        content: &Content,
+        format: &Format,
+        interactions: &Interactivity,
+    ) {
+        self.push(
+            &TextBuilder::placeholder(self.placeholders, content),
+            format,
+            interactions,
+        );
+    }
+    fn finish(self) -> Vec<TextRun> {
+        self.runs
     }
 }
 
@@ -241,38 +254,61 @@ impl TextComponent {
     pub fn to_plain<R: TextResolutor + ?Sized>(&self, resolutor: &R) -> String {
         self.build(resolutor, TextBuilder)
     }
-    pub fn to_pretty<R: TextResolutor + ?Sized>(&self, resolutor: &R) -> ColoredString {
-        self.build(resolutor, PrettyTextBuilder)
+
+    /// Resolves this component and turns it into the runs of text shown, each one with
+    /// its fully inherited format and interactions.\
+    /// Translations are expanded, objects and keybinds become the same placeholders as in
+    /// [to_plain](TextComponent::to_plain), empty texts are left out and consecutive runs
+    /// looking the same are merged.
+    /// ## Example
+    /// ```
+    /// # use text_components::{Modifier, format::Color, resolving::NoResolutor};
+    /// // [TextRun { text: "Hello ", format: { color: Red } }, TextRun { text: "World", format: { color: Red bold } }]
+    /// "Hello ".color(Color::Red).add_child("World".bold(true)).flatten_runs(&NoResolutor);
+    /// ```
+    pub fn flatten_runs<R: TextResolutor + ?Sized>(&self, resolutor: &R) -> Vec<TextRun> {
+        let visitor = RunsVisitor {
+            runs: vec![],
+            placeholders: resolutor.placeholders(),
+        };
+        self.build(resolutor, VisitorTarget(visitor))
     }
 }
 
-static mut DISPLAY_RESOLUTOR: &dyn TextResolutor = &NoResolutor;
-static mut INITIALIZED: bool = false;
+static DISPLAY_RESOLUTOR: OnceLock<&'static (dyn TextResolutor + Sync)> = OnceLock::new();
 
-pub fn set_display_resolutor<T: TextResolutor>(resolutor: &'static T) {
-    unsafe {
-        if !INITIALIZED {
-            DISPLAY_RESOLUTOR = resolutor;
-            INITIALIZED = true;
-        }
-    }
+/// Sets the resolutor used by [Display] and `{:p}`, only the first call has an effect.
+pub fn set_display_resolutor<T: TextResolutor + Sync>(resolutor: &'static T) {
+    let _ = DISPLAY_RESOLUTOR.set(resolutor);
 }
 
-impl Display for TextComponent {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", unsafe { self.to_plain(DISPLAY_RESOLUTOR) })
+/// The resolutor [set](set_display_resolutor) for [Display], [NoResolutor] until then.
+pub(crate) fn display_resolutor() -> &'static dyn TextResolutor {
+    match DISPLAY_RESOLUTOR.get() {
+        Some(resolutor) => *resolutor,
+        None => &NoResolutor,
     }
 }
 
-/// Clearly a Pointer, not 'p' because of pretty, OF COURSE
-impl Pointer for TextComponent {
+impl Display for TextComponent {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", unsafe { self.to_pretty(DISPLAY_RESOLUTOR) })
+        write!(f, "{}", self.to_plain(display_resolutor()))
     }
 }
 
 impl Debug for TextComponent {
+    /// `{:#?}` writes the tree as an outline, a line per component with its content, format
+    /// and events, and its hover texts, arguments and children indented below it.
+    /// ```text
+    /// translate(greeting, 1 arg) { color: Gold } hover_event: show_text
+    ///   hover_event: text("Hi")
+    ///   with[0]: selector(@s)
+    ///   text("!")
+    /// ```
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return self.write_outline(f, 0, format_args!(""));
+        }
         let mut debug = f.debug_struct("TextComponent");
         debug.field("content", &self.content);
         if !self.format.is_none() {
@@ -284,10 +320,96 @@ impl Debug for TextComponent {
         if !self.children.is_empty() {
             debug.field("children", &self.children);
         }
+        if let Some(table) = &self.inline_translations {
+            debug.field("inline_translations", table);
+        }
         debug.finish()
     }
 }
 
+impl TextComponent {
+    /// Writes the line of the component at the depth and the ones below it, straight to the
+    /// formatter so deep trees aren't copied at each level.
+    fn write_outline(
+        &self,
+        f: &mut Formatter<'_>,
+        depth: usize,
+        label: fmt::Arguments<'_>,
+    ) -> fmt::Result {
+        write!(f, "{:indent$}{label}", "", indent = depth * 2)?;
+        self.content.write_summary(f)?;
+        if !self.format.is_none() {
+            write!(f, " {:?}", self.format)?;
+        }
+        let Interactivity {
+            insertion,
+            click,
+            hover,
+        } = &self.interactions;
+        if let Some(insertion) = insertion {
+            write!(f, " insertion: {insertion:?}")?;
+        }
+        if let Some(click) = click {
+            write!(f, " click_event: {click}")?;
+        }
+        match hover {
+            Some(HoverEvent::ShowText { .. }) => f.write_str(" hover_event: show_text")?,
+            Some(HoverEvent::ShowEntity { id, uuid, .. }) => {
+                write!(f, " hover_event: show_entity({id} {uuid})")?
+            }
+            Some(hover) => write!(f, " hover_event: {hover}")?,
+            None => (),
+        }
+
+        let depth = depth + 1;
+        match hover {
+            Some(HoverEvent::ShowText { value }) => {
+                f.write_str("\n")?;
+                value.write_outline(f, depth, format_args!("hover_event: "))?;
+            }
+            Some(HoverEvent::ShowEntity {
+                name: Some(name), ..
+            }) => {
+                f.write_str("\n")?;
+                name.write_outline(f, depth, format_args!("name: "))?;
+            }
+            _ => (),
+        }
+        match &self.content {
+            Content::Translate(message) => {
+                for (i, arg) in message
+                    .args
+                    .as_deref()
+                    .unwrap_or_default()
+                    .iter()
+                    .enumerate()
+                {
+                    f.write_str("\n")?;
+                    arg.write_outline(f, depth, format_args!("with[{i}]: "))?;
+                }
+            }
+            Content::Resolvable(Resolvable::Entity { separator, .. })
+                if *separator != Resolvable::entity_separator() =>
+            {
+                f.write_str("\n")?;
+                separator.write_outline(f, depth, format_args!("separator: "))?;
+            }
+            Content::Resolvable(Resolvable::NBT { separator, .. })
+                if *separator != Resolvable::nbt_separator() =>
+            {
+                f.write_str("\n")?;
+                separator.write_outline(f, depth, format_args!("separator: "))?;
+            }
+            _ => (),
+        }
+        for child in &self.children {
+            f.write_str("\n")?;
+            child.write_outline(f, depth, format_args!(""))?;
+        }
+        Ok(())
+    }
+}
+
 impl Debug for Content {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
@@ -303,72 +425,93 @@ impl Debug for Content {
 }
 
 impl Debug for Format {
+    /// `{ color: Gold, bold, shadow_color: #ff000000 }` with the values set to `true`, or
+    /// `{ RESET }` for a [reset](Format::reset) format, `{:#?}` puts each value in a line.\
+    /// A [no_inherit](Format::no_inherit) format starts with `NO_INHERIT`.
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        if let Some(Color::White) = self.color
-            && let Some(Cow::Borrowed("minecraft:default")) = self.font
-            && let Some(false) = self.bold
-            && let Some(false) = self.italic
-            && let Some(false) = self.underlined
-            && let Some(false) = self.strikethrough
-            && let Some(false) = self.obfuscated
-            && let None = self.shadow_color
-        {
+        if *self == Format::new().reset() {
             return write!(f, "{{ RESET }}");
         }
 
-        let mut items = vec![];
-        if let Some(color) = &self.color {
-            items.push(match color {
-                crate::format::Color::Aqua => " color: Aqua".to_string(),
-                crate::format::Color::Black => " color: Black".to_string(),
-                crate::format::Color::Blue => " color: Blue".to_string(),
-                crate::format::Color::DarkAqua => " color: Dark Aqua".to_string(),
-                crate::format::Color::DarkBlue => " color: Dark Blue".to_string(),
-                crate::format::Color::DarkGray => " color: Dark Gray".to_string(),
-                crate::format::Color::DarkGreen => " color: Dark Green".to_string(),
-                crate::format::Color::DarkPurple => " color: Dark Purple".to_string(),
-                crate::format::Color::DarkRed => " color: Dark Red".to_string(),
-                crate::format::Color::Gold => " color: Gold".to_string(),
-                crate::format::Color::Gray => " color: Gray".to_string(),
-                crate::format::Color::Green => " color: Green".to_string(),
-                crate::format::Color::LightPurple => " color: Light Purple".to_string(),
-                crate::format::Color::Red => " color: Red".to_string(),
-                crate::format::Color::White => " color: White".to_string(),
-                crate::format::Color::Yellow => " color: Yellow".to_string(),
-                crate::format::Color::Rgb(r, g, b) => format!(" color: [{r}, {g}, {b}]"),
-            });
+        let mut items = DebugItems { f, empty: true };
+        if self.no_inherit {
+            items.item(format_args!("NO_INHERIT"))?;
+        }
+        match &self.color {
+            Some(Color::Rgb(r, g, b)) => {
+                items.item(format_args!("color: #{r:02x}{g:02x}{b:02x}"))?
+            }
+            Some(color) => items.item(format_args!("color: {}", color_debug_name(color)))?,
+            None => (),
         }
         if let Some(font) = &self.font
             && font != "minecraft:default"
         {
-            items.push(format!(" font: \"{font}\""));
-        }
-        if let Some(true) = self.bold {
-            items.push(" bold".to_string());
-        }
-        if let Some(true) = self.italic {
-            items.push(" italic".to_string());
-        }
-        if let Some(true) = self.underlined {
-            items.push(" underlined".to_string());
+            items.item(format_args!("font: {:?}", font.as_str()))?;
         }
-        if let Some(true) = self.strikethrough {
-            items.push(" strikethrough".to_string());
-        }
-        if let Some(true) = self.obfuscated {
-            items.push(" obfuscated".to_string());
+        for (name, value) in [
+            ("bold", self.bold),
+            ("italic", self.italic),
+            ("underlined", self.underlined),
+            ("strikethrough", self.strikethrough),
+            ("obfuscated", self.obfuscated),
+        ] {
+            if let Some(true) = value {
+                items.item(format_args!("{name}"))?;
+            }
         }
         if let Some(color) = self.shadow_color {
-            items.push(format!(
-                " [{}, {}, {}, {}]",
-                (color >> 16) & 255,
-                (color >> 8) & 255,
-                color & 255,
-                (color >> 24) & 255
-            ));
+            items.item(format_args!("shadow_color: #{:08x}", color as u32))?;
+        }
+        items.finish()
+    }
+}
+
+fn color_debug_name(color: &Color) -> &'static str {
+    match color {
+        Color::Aqua => "Aqua",
+        Color::Black => "Black",
+        Color::Blue => "Blue",
+        Color::DarkAqua => "Dark Aqua",
+        Color::DarkBlue => "Dark Blue",
+        Color::DarkGray => "Dark Gray",
+        Color::DarkGreen => "Dark Green",
+        Color::DarkPurple => "Dark Purple",
+        Color::DarkRed => "Dark Red",
+        Color::Gold => "Gold",
+        Color::Gray => "Gray",
+        Color::Green => "Green",
+        Color::LightPurple => "Light Purple",
+        Color::Red => "Red",
+        Color::White => "White",
+        Color::Yellow => "Yellow",
+        Color::Rgb(..) => "Rgb",
+    }
+}
+
+/// Writes `{ a, b }`, or one item per line with `{:#?}`.
+struct DebugItems<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    empty: bool,
+}
+impl DebugItems<'_, '_> {
+    fn item(&mut self, item: fmt::Arguments<'_>) -> fmt::Result {
+        match (self.f.alternate(), self.empty) {
+            (true, true) => write!(self.f, "{{\n    {item},")?,
+            (true, false) => write!(self.f, "\n    {item},")?,
+            (false, true) => write!(self.f, "{{ {item}")?,
+            (false, false) => write!(self.f, ", {item}")?,
         }
+        self.empty = false;
+        Ok(())
+    }
 
-        write!(f, "{{{} }}", items.join(","))
+    fn finish(self) -> fmt::Result {
+        match (self.f.alternate(), self.empty) {
+            (_, true) => write!(self.f, "{{ }}"),
+            (true, false) => write!(self.f, "\n}}"),
+            (false, false) => write!(self.f, " }}"),
+        }
     }
 }
 
@@ -0,0 +1,275 @@
+#![deny(clippy::unwrap_used, clippy::indexing_slicing)]
+use super::TextBuilder;
+use crate::{
+    TextComponent,
+    content::{Content, NbtSource, Resolvable},
+    dialog::DialogRef,
+    format::Format,
+    interactivity::{ClickEvent, HoverEvent, Interactivity},
+    resolving::NoResolutor,
+};
+
+impl TextComponent {
+    /// Writes this component as a [MiniMessage](https://docs.advntr.dev/minimessage/format.html) string.
+    ///
+    /// Tags are only opened where the effective style changes from the parent's,
+    /// and closed in reverse order, literal `<` and `\` are escaped with a `\`.\
+    /// Objects and custom contents have no tag, they are written as the escaped
+    /// plain text [TextBuilder] gives them (`[Head: Notch]`, `[Object: sprite]`, `[Custom]`),
+    /// and the item components of a `show_item` hover are left out.\
+    /// MiniMessage only references dialogs by id, so the `click` tag of a `show_dialog` click
+    /// event with an inline dialog is left out, the text isn't clickable.
+    /// ## Example
+    /// ```
+    /// # use text_components::{Modifier, format::Color};
+    /// // Results in "<red>Hello <bold>World</bold></red>"
+    /// "Hello ".color(Color::Red).add_child("World".bold(true)).to_minimessage();
+    /// ```
+    pub fn to_minimessage(&self) -> String {
+        let mut out = String::new();
+        write_component(self, &Format::new(), &Interactivity::new(), &mut out);
+        out
+    }
+}
+
+fn write_component(
+    component: &TextComponent,
+    parent: &Format,
+    inherited: &Interactivity,
+    out: &mut String,
+) {
+    let mut closes = vec![];
+    let mut open = |out: &mut String, tag: String, close: &str| {
+        out.push('<');
+        out.push_str(&tag);
+        out.push('>');
+        closes.push(close.to_string());
+    };
+
+    let format = &component.format;
+    if let Some(color) = &format.color
+        && parent.color.as_ref() != Some(color)
+    {
+        let color = color.to_string();
+        open(out, color.clone(), &color);
+    }
+    if let Some(font) = &format.font
+        && parent.font.as_ref() != Some(font)
+    {
+        open(out, format!("font:{}", key(font)), "font");
+    }
+    let decorations = [
+        ("bold", format.bold, parent.bold),
+        ("italic", format.italic, parent.italic),
+        ("underlined", format.underlined, parent.underlined),
+        ("strikethrough", format.strikethrough, parent.strikethrough),
+        ("obfuscated", format.obfuscated, parent.obfuscated),
+    ];
+    for (name, value, parent) in decorations {
+        match value {
+            Some(true) if parent != Some(true) => open(out, name.to_string(), name),
+            Some(false) if parent == Some(true) => {
+                let name = format!("!{name}");
+                open(out, name.clone(), &name)
+            }
+            _ => (),
+        }
+    }
+    if let Some(shadow) = format.shadow_color
+        && parent.shadow_color != Some(shadow)
+    {
+        let shadow = format!(
+            "shadow:#{:02x}{:02x}{:02x}{:02x}",
+            (shadow >> 16) & 0xFF,
+            (shadow >> 8) & 0xFF,
+            shadow & 0xFF,
+            (shadow >> 24) & 0xFF
+        );
+        open(out, shadow, "shadow");
+    }
+
+    let interactions = &component.interactions;
+    if let Some(insertion) = &interactions.insertion
+        && inherited.insertion.as_ref() != Some(insertion)
+    {
+        open(out, format!("insert:{}", argument(insertion)), "insert");
+    }
+    if let Some(click) = &interactions.click
+        && inherited.click.as_ref() != Some(click)
+        && let Some(arguments) = click_arguments(click)
+    {
+        open(out, format!("click:{arguments}"), "click");
+    }
+    if let Some(hover) = &interactions.hover
+        && inherited.hover.as_ref() != Some(hover)
+    {
+        open(out, format!("hover:{}", hover_arguments(hover)), "hover");
+    }
+
+    let format = format.mix(parent);
+    let mut interactions = inherited.clone();
+    component.interactions.mix(&mut interactions);
+
+    write_content(component, &format, &interactions, out);
+    for child in &component.children {
+        write_component(child, &format, &interactions, out);
+    }
+    for close in closes.iter().rev() {
+        out.push_str("</");
+        out.push_str(close);
+        out.push('>');
+    }
+}
+
+fn write_content(
+    component: &TextComponent,
+    format: &Format,
+    interactions: &Interactivity,
+    out: &mut String,
+) {
+    // Arguments and separators are rendered with the style of the component holding them
+    let nested = |component: &TextComponent| {
+        let mut out = String::new();
+        write_component(component, format, interactions, &mut out);
+        argument(&out)
+    };
+    match &component.content {
+        Content::Text { text } => escape(text, out),
+        Content::Translate(message) => {
+            match &message.fallback {
+                Some(fallback) => out.push_str(&format!(
+                    "<lang_or:{}:{}",
+                    argument(&message.key),
+                    argument(fallback)
+                )),
+                None => out.push_str(&format!("<lang:{}", argument(&message.key))),
+            }
+            for arg in message.args.iter().flatten() {
+                out.push(':');
+                out.push_str(&nested(arg));
+            }
+            out.push('>');
+        }
+        Content::Keybind { keybind } => out.push_str(&format!("<key:{}>", key(keybind))),
+        Content::Resolvable(Resolvable::Scoreboard {
+            selector,
+            objective,
+        }) => out.push_str(&format!(
+            "<score:{}:{}>",
+            argument(selector),
+            argument(objective)
+        )),
+        Content::Resolvable(Resolvable::Entity {
+            selector,
+            separator,
+        }) => {
+            out.push_str(&format!("<selector:{}", argument(selector)));
+            if *separator != Resolvable::entity_separator() {
+                out.push(':');
+                out.push_str(&nested(separator));
+            }
+            out.push('>');
+        }
+        Content::Resolvable(Resolvable::NBT {
+            path,
+            interpret,
+            separator,
+            source,
+        }) => {
+            let (kind, id) = match source {
+                NbtSource::Block(id) => ("block", id),
+                NbtSource::Entity(id) => ("entity", id),
+                NbtSource::Storage(id) => ("storage", id),
+            };
+            out.push_str(&format!("<nbt:{kind}:{}:{}", argument(id), argument(path)));
+            let interpret = *interpret == Some(true);
+            if interpret || *separator != Resolvable::nbt_separator() {
+                out.push(':');
+                out.push_str(&nested(separator));
+            }
+            if interpret {
+                out.push_str(":interpret");
+            }
+            out.push('>');
+        }
+        _ => {
+            let content = TextComponent {
+                content: component.content.clone(),
+                ..TextComponent::new()
+            };
+            escape(&content.build(&NoResolutor, TextBuilder), out);
+        }
+    }
+}
+
+/// [None] for the inline dialogs MiniMessage can't reference.
+fn click_arguments(click: &ClickEvent) -> Option<String> {
+    let (action, value) = match click {
+        ClickEvent::OpenUrl { url } => ("open_url", url.to_string()),
+        ClickEvent::RunCommand { command } => ("run_command", command.to_string()),
+        ClickEvent::SuggestCommand { command } => ("suggest_command", command.to_string()),
+        ClickEvent::ChangePage { page } => ("change_page", page.to_string()),
+        ClickEvent::CopyToClipboard { value } => ("copy_to_clipboard", value.to_string()),
+        ClickEvent::ShowDialog { dialog } => match dialog {
+            DialogRef::Id(id) => ("show_dialog", id.to_string()),
+            DialogRef::Inline(_) => return None,
+        },
+        #[cfg(feature = "custom")]
+        ClickEvent::Custom(data) => ("custom", data.id.to_string()),
+    };
+    Some(format!("{action}:{}", argument(&value)))
+}
+
+fn hover_arguments(hover: &HoverEvent) -> String {
+    match hover {
+        HoverEvent::ShowText { value } => {
+            format!("show_text:{}", argument(&value.to_minimessage()))
+        }
+        HoverEvent::ShowItem { id, count, .. } => match count {
+            Some(count) => format!("show_item:{}:{count}", argument(id)),
+            None => format!("show_item:{}", argument(id)),
+        },
+        HoverEvent::ShowEntity { name, id, uuid } => match name {
+            Some(name) => format!(
+                "show_entity:{}:{uuid}:{}",
+                argument(id),
+                argument(&name.to_minimessage())
+            ),
+            None => format!("show_entity:{}:{uuid}", argument(id)),
+        },
+    }
+}
+
+/// Resource locations are left as they are, the tags read their namespace back.
+fn key(value: &str) -> String {
+    if !value.is_empty()
+        && value
+            .chars()
+            .all(|char| matches!(char, 'a'..='z' | '0'..='9' | '_' | '-' | '.' | '/' | ':'))
+        && value.matches(':').count() <= 1
+    {
+        return value.to_string();
+    }
+    argument(value)
+}
+
+/// Quotes a tag argument when it has characters that would end or split it.
+fn argument(value: &str) -> String {
+    if !value.is_empty()
+        && !value
+            .chars()
+            .any(|char| matches!(char, ':' | '\'' | '"' | '<' | '>' | '\\') || char.is_whitespace())
+    {
+        return value.to_string();
+    }
+    format!("'{}'", value.replace('\'', "\\'"))
+}
+
+fn escape(text: &str, out: &mut String) {
+    for char in text.chars() {
+        if char == '<' || char == '\\' {
+            out.push('\\');
+        }
+        out.push(char);
+    }
+}
@@ -0,0 +1,212 @@
+#![deny(clippy::unwrap_used, clippy::indexing_slicing)]
+use super::{
+    TextBuilder, display_resolutor,
+    obfuscation::{Charset, obfuscate_with},
+};
+use crate::{
+    TextComponent,
+    content::Content,
+    interactivity::{ClickEvent, HoverEvent},
+    resolving::{BuildTarget, TextResolutor},
+};
+use colored::{ColoredString, Colorize};
+use std::{
+    cell::RefCell,
+    fmt::{self, Display, Formatter, Pointer},
+};
+use supports_hyperlinks::supports_hyperlinks;
+
+/// What the [PrettyTextBuilder] shows besides the formatted text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrettyOptions {
+    /// Marks the components with a click or hover event with a number, like `[1]`, and
+    /// lists their events after the text, like `[1] run_command: /spawn`.\
+    /// Hover texts are listed dimmed.
+    pub footnotes: bool,
+    /// The characters obfuscated text is replaced with, [Ascii](Charset::Ascii) for
+    /// terminals whose fonts lack the rest.
+    pub charset: Charset,
+}
+impl PrettyOptions {
+    /// Lists the click and hover events as footnotes.
+    pub fn footnotes() -> Self {
+        PrettyOptions {
+            footnotes: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// Builds the text with its format as terminal colors and styles.
+/// ## Example
+/// ```
+/// # use text_components::{
+/// #     Modifier,
+/// #     fmt::{PrettyOptions, PrettyTextBuilder},
+/// #     interactivity::ClickEvent,
+/// #     resolving::NoResolutor,
+/// # };
+/// // "Spawn[1]\n[1] run_command: /spawn"
+/// "Spawn"
+///     .click_event(ClickEvent::run_command("/spawn"))
+///     .build(&NoResolutor, PrettyTextBuilder(PrettyOptions::footnotes()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrettyTextBuilder(pub PrettyOptions);
+impl BuildTarget for PrettyTextBuilder {
+    type Result = ColoredString;
+    fn build_component<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+        component: &TextComponent,
+    ) -> ColoredString {
+        let charset = self.0.charset;
+        if !self.0.footnotes {
+            return PrettyWalk {
+                footnotes: None,
+                charset,
+            }
+            .build_component(resolutor, component);
+        }
+        let footnotes = RefCell::new(vec![]);
+        let text = PrettyWalk {
+            footnotes: Some(&footnotes),
+            charset,
+        }
+        .build_component(resolutor, component);
+        let mut text = text.to_string();
+        for (i, footnote) in footnotes.into_inner().into_iter().enumerate() {
+            text.push_str(&format!("\n[{}] {footnote}", i + 1));
+        }
+        text.into()
+    }
+}
+
+/// The walk of a [PrettyTextBuilder], collecting the footnotes if it's listing them.
+struct PrettyWalk<'a> {
+    footnotes: Option<&'a RefCell<Vec<String>>>,
+    charset: Charset,
+}
+impl PrettyWalk<'_> {
+    /// Adds the footnotes of the events set in the component, giving its markers.
+    fn note<R: TextResolutor + ?Sized>(&self, resolutor: &R, component: &TextComponent) -> String {
+        let Some(footnotes) = self.footnotes else {
+            return String::new();
+        };
+        let mut markers = String::new();
+        let mut add = |footnote: String| {
+            let mut footnotes = footnotes.borrow_mut();
+            footnotes.push(footnote);
+            markers.push_str(&format!("[{}]", footnotes.len()));
+        };
+        if let Some(click) = &component.interactions.click {
+            add(event_footnote(click));
+        }
+        match &component.interactions.hover {
+            Some(HoverEvent::ShowText { value }) => add(format!(
+                "show_text: {}",
+                TextBuilder.build_component(resolutor, value).dimmed()
+            )),
+            Some(hover) => add(event_footnote(hover)),
+            None => (),
+        }
+        markers
+    }
+}
+
+/// `run_command: /spawn` for the `run_command(/spawn)` the event displays as.
+fn event_footnote(event: &impl Display) -> String {
+    let event = event.to_string();
+    match event.split_once('(') {
+        Some((action, value)) => format!("{action}: {}", value.strip_suffix(')').unwrap_or(value)),
+        None => event,
+    }
+}
+
+impl BuildTarget for PrettyWalk<'_> {
+    type Result = ColoredString;
+    fn build_component<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+        component: &TextComponent,
+    ) -> ColoredString {
+        let markers = self.note(resolutor, component);
+        let mut final_text = TextBuilder::stringify_content(self, resolutor, component);
+
+        if let Content::Translate(_) = component.content {
+            return format!(
+                "{}{}{}",
+                final_text,
+                markers,
+                component
+                    .children
+                    .iter()
+                    .map(|child| {
+                        let mut child = child.clone();
+                        child.format.mix_in_place(&component.format);
+                        self.build_component(resolutor, &child).to_string()
+                    })
+                    .collect::<Vec<String>>()
+                    .concat()
+            )
+            .into();
+        }
+
+        if let Some(true) = component.format.obfuscated {
+            final_text = obfuscate_with(&final_text, self.charset, &mut rand::rng()).into();
+        }
+        if let Some(color) = &component.format.color {
+            final_text = color.colorize_text(final_text.to_string());
+        }
+        if let Some(true) = component.format.bold {
+            final_text = final_text.bold();
+        }
+        if let Some(true) = component.format.italic {
+            final_text = final_text.italic();
+        }
+        if let Some(true) = component.format.underlined {
+            final_text = final_text.underline();
+        }
+        if let Some(true) = component.format.strikethrough {
+            final_text = final_text.strikethrough();
+        }
+        if let Some((r, g, b, _)) = component.format.shadow_color_rgba() {
+            final_text = final_text.on_truecolor(r, g, b);
+        }
+        if supports_hyperlinks()
+            && let Some(ClickEvent::OpenUrl { url }) = &component.interactions.click
+        {
+            final_text = format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, final_text).into();
+        }
+
+        format!(
+            "{}{}{}",
+            final_text,
+            markers,
+            component
+                .children
+                .iter()
+                .map(|child| {
+                    let mut child = child.clone();
+                    child.format.mix_in_place(&component.format);
+                    self.build_component(resolutor, &child).to_string()
+                })
+                .collect::<Vec<String>>()
+                .concat()
+        )
+        .into()
+    }
+}
+
+impl TextComponent {
+    pub fn to_pretty<R: TextResolutor + ?Sized>(&self, resolutor: &R) -> ColoredString {
+        self.build(resolutor, PrettyTextBuilder::default())
+    }
+}
+
+/// Clearly a Pointer, not 'p' because of pretty, OF COURSE
+impl Pointer for TextComponent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_pretty(display_resolutor()))
+    }
+}
@@ -0,0 +1,147 @@
+#![deny(clippy::unwrap_used, clippy::indexing_slicing)]
+use crate::{
+    TextComponent,
+    content::{Content, Object},
+    format::Format,
+    interactivity::Interactivity,
+    resolving::{BuildTarget, TextResolutor, TranslationSegment, TranslationText, keybind_text},
+};
+use std::borrow::Cow;
+
+/// Leaf logic of a build target, the traversal is done by [VisitorTarget].\
+/// Every visit gets the effective format and interactions of the content, inherited from
+/// its parents, translations are split through the resolutor and their arguments are
+/// visited in place.
+/// ## Example
+/// ```
+/// # use text_components::{
+/// #     Modifier, TextComponent,
+/// #     content::{Content, Object},
+/// #     fmt::{ComponentVisitor, VisitorTarget},
+/// #     format::Format,
+/// #     interactivity::Interactivity,
+/// #     resolving::NoResolutor,
+/// # };
+/// #[derive(Clone, Default)]
+/// struct Length(usize);
+/// impl ComponentVisitor for Length {
+///     type Output = usize;
+///     fn visit_text(&mut self, text: &str, _: &Format, _: &Interactivity) {
+///         self.0 += text.chars().count();
+///     }
+///     fn visit_object(&mut self, _: &Object, _: &Format, _: &Interactivity) {
+///         self.0 += 1;
+///     }
+///     fn visit_unresolved(&mut self, _: &Content, _: &Format, _: &Interactivity) {}
+///     fn finish(self) -> usize {
+///         self.0
+///     }
+/// }
+/// let component = "Hello".add_child(" world".bold(true));
+/// let length = component.build(&NoResolutor, VisitorTarget(Length::default()));
+/// assert_eq!(length, 11);
+/// ```
+pub trait ComponentVisitor {
+    type Output;
+    fn visit_text(&mut self, text: &str, format: &Format, interactions: &Interactivity);
+    fn visit_object(&mut self, object: &Object, format: &Format, interactions: &Interactivity);
+    /// Called for contents left unresolved, like resolvables, custom data or keybinds the
+    /// resolutor has no text for.
+    fn visit_unresolved(
+        &mut self,
+        content: &Content,
+        format: &Format,
+        interactions: &Interactivity,
+    );
+    fn enter_children(&mut self) {}
+    fn exit_children(&mut self) {}
+    fn finish(self) -> Self::Output;
+}
+
+/// Drives a [ComponentVisitor] through the component depth-first, a clone of the visitor
+/// is used for every build.
+#[derive(Debug, Clone, Default)]
+pub struct VisitorTarget<V: ComponentVisitor + Clone>(pub V);
+
+impl<V: ComponentVisitor + Clone> BuildTarget for VisitorTarget<V> {
+    type Result = V::Output;
+    fn build_component<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+        component: &TextComponent,
+    ) -> V::Output {
+        let mut visitor = self.0.clone();
+        walk(
+            &mut visitor,
+            resolutor,
+            component,
+            &Format::new(),
+            &Interactivity::new(),
+        );
+        visitor.finish()
+    }
+}
+
+fn walk<V: ComponentVisitor, R: TextResolutor + ?Sized>(
+    visitor: &mut V,
+    resolutor: &R,
+    component: &TextComponent,
+    parent_format: &Format,
+    parent_interactions: &Interactivity,
+) {
+    // Most components only set a few values, the parent ones are kept when they set none
+    let format = match component.format.is_none() {
+        true => Cow::Borrowed(parent_format),
+        false => Cow::Owned(component.format.mix(parent_format)),
+    };
+    let interactions = match component.interactions.is_none() {
+        true => Cow::Borrowed(parent_interactions),
+        false => {
+            let mut interactions = parent_interactions.clone();
+            component.interactions.mix(&mut interactions);
+            Cow::Owned(interactions)
+        }
+    };
+
+    match &component.content {
+        Content::Text { text } => visitor.visit_text(text, &format, &interactions),
+        Content::Translate(message) => {
+            match resolutor
+                .placeholders()
+                .translation_text(resolutor, message)
+            {
+                TranslationText::Translated(translated) => {
+                    for segment in resolutor.translation_segments(&translated) {
+                        match segment {
+                            TranslationSegment::Literal(part) => {
+                                visitor.visit_text(&part, &format, &interactions)
+                            }
+                            TranslationSegment::Argument(index) => {
+                                if let Some(arg) =
+                                    message.args.as_ref().and_then(|args| args.get(index))
+                                {
+                                    walk(visitor, resolutor, arg, &format, &interactions);
+                                }
+                            }
+                        }
+                    }
+                }
+                TranslationText::Literal(text) => visitor.visit_text(&text, &format, &interactions),
+            }
+        }
+        Content::Object(object) => visitor.visit_object(object, &format, &interactions),
+        Content::Keybind { keybind } => match keybind_text(resolutor, keybind) {
+            Some(text) => visitor.visit_text(&text, &format, &interactions),
+            None => visitor.visit_unresolved(&component.content, &format, &interactions),
+        },
+        content => visitor.visit_unresolved(content, &format, &interactions),
+    }
+
+    if !component.children.is_empty() {
+        visitor.enter_children();
+        for child in &component.children {
+            walk(visitor, resolutor, child, &format, &interactions);
+        }
+        visitor.exit_children();
+    }
+}
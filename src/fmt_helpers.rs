@@ -0,0 +1,247 @@
+//! Components for the numbers, durations and times every scoreboard and chat shows.
+//! ```
+//! # use std::time::{Duration, SystemTime};
+//! # use text_components::{
+//! #     TextComponent,
+//! #     fmt_helpers::{DurationStyle, NumberFormat},
+//! # };
+//! # let last_seen = SystemTime::now() - Duration::from_secs(200);
+//! // "1,234,567"
+//! TextComponent::number(1_234_567, NumberFormat::new());
+//! // "2h15m"
+//! TextComponent::duration(Duration::from_secs(8100), DurationStyle::Compact);
+//! // "3 minutes ago", translatable with the "time.ago" and "time.minutes" keys
+//! TextComponent::relative_time(last_seen);
+//! ```
+use crate::{TextComponent, translation::TranslatedMessage};
+use std::{
+    borrow::Cow,
+    time::{Duration, SystemTime},
+};
+
+/// How [TextComponent::number] writes a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumberFormat {
+    /// Put between every 3 digits of the integer part, [None] to leave them together.
+    pub thousands_separator: Option<char>,
+    pub decimal_separator: char,
+    /// Amount of decimals written, rounding the rest.
+    pub decimals: usize,
+    /// Whether to shorten thousands, millions and billions with a `k`, `M` or `B` suffix.
+    pub compact: bool,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat::new()
+    }
+}
+
+impl NumberFormat {
+    /// Separates thousands with commas and writes no decimals.
+    pub const fn new() -> Self {
+        NumberFormat {
+            thousands_separator: Some(','),
+            decimal_separator: '.',
+            decimals: 0,
+            compact: false,
+        }
+    }
+    pub const fn thousands_separator(mut self, separator: Option<char>) -> Self {
+        self.thousands_separator = separator;
+        self
+    }
+    pub const fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+    pub const fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+    pub const fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Writes the number as text.
+    pub fn format(&self, n: f64) -> String {
+        if n.is_nan() {
+            return String::from("NaN");
+        }
+        if n.is_infinite() {
+            return String::from(if n < 0. { "-∞" } else { "∞" });
+        }
+        let mut value = n.abs();
+        let mut suffix = "";
+        if self.compact {
+            let units = [("", 1.), ("k", 1e3), ("M", 1e6), ("B", 1e9)];
+            let mut unit = units
+                .iter()
+                .rposition(|(_, size)| value >= *size)
+                .unwrap_or(0);
+            // Checked after rounding so 999,999 becomes 1M and not 1000k
+            while unit < units.len() - 1 && round(value / units[unit].1, self.decimals) >= 1e3 {
+                unit += 1;
+            }
+            suffix = units[unit].0;
+            value /= units[unit].1;
+        }
+
+        let digits = format!("{:.*}", self.decimals, value);
+        let (integer, decimals) = digits.split_at(digits.find('.').unwrap_or(digits.len()));
+        let mut text = String::new();
+        if n < 0. && digits.bytes().any(|b| b.is_ascii_digit() && b != b'0') {
+            text.push('-');
+        }
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0
+                && (integer.len() - i) % 3 == 0
+                && let Some(separator) = self.thousands_separator
+            {
+                text.push(separator);
+            }
+            text.push(digit);
+        }
+        if let Some(decimals) = decimals.strip_prefix('.') {
+            text.push(self.decimal_separator);
+            text.push_str(decimals);
+        }
+        text.push_str(suffix);
+        text
+    }
+}
+
+fn round(n: f64, decimals: usize) -> f64 {
+    let scale = 10f64.powi(decimals as i32);
+    (n * scale).round() / scale
+}
+
+/// How [TextComponent::duration] writes a duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DurationStyle {
+    /// `2h15m`, not translated.
+    #[default]
+    Compact,
+    /// `2 hours 15 minutes`, translated with the `time.hours` and `time.hour` like keys.
+    Verbose,
+}
+
+const UNITS: [(&str, &str, u64); 4] = [
+    ("d", "day", 86400),
+    ("h", "hour", 3600),
+    ("m", "minute", 60),
+    ("s", "second", 1),
+];
+
+/// Splits seconds into the amount of each unit, skipping the empty ones.
+fn split_units(mut seconds: u64) -> Vec<(usize, u64)> {
+    let mut amounts = vec![];
+    for (i, (_, _, size)) in UNITS.iter().enumerate() {
+        if seconds >= *size {
+            amounts.push((i, seconds / size));
+            seconds %= size;
+        }
+    }
+    amounts
+}
+
+fn unit_name(unit: usize, amount: u64) -> Cow<'static, str> {
+    let (_, name, _) = UNITS[unit];
+    match amount {
+        1 => Cow::Borrowed(name),
+        _ => Cow::Owned(format!("{name}s")),
+    }
+}
+
+/// A translated amount of a unit, like `3 minutes` with the `time.minutes` key.
+fn unit_component(unit: usize, amount: u64) -> TextComponent {
+    let name = unit_name(unit, amount);
+    TranslatedMessage {
+        key: format!("time.{name}").into(),
+        fallback: Some(format!("{amount} {name}").into()),
+        args: Some(Box::new([TextComponent::plain(amount.to_string())])),
+    }
+    .component()
+}
+
+impl TextComponent {
+    /// Creates a [TextComponent] with a number written following a [NumberFormat].
+    /// ## Example
+    /// ```
+    /// # use text_components::{TextComponent, fmt_helpers::NumberFormat};
+    /// // Results in "1.2M"
+    /// TextComponent::number(1_234_567, NumberFormat::new().decimals(1).compact(true));
+    /// ```
+    pub fn number<N: Into<f64>>(n: N, format: NumberFormat) -> TextComponent {
+        TextComponent::plain(format.format(n.into()))
+    }
+
+    /// Creates a [TextComponent] with a duration down to the seconds, empty units are left out.
+    /// ## Example
+    /// ```
+    /// # use std::time::Duration;
+    /// # use text_components::{TextComponent, fmt_helpers::DurationStyle};
+    /// // Results in "2h15m"
+    /// TextComponent::duration(Duration::from_secs(8100), DurationStyle::Compact);
+    /// // Results in "2 hours 15 minutes" if not translated
+    /// TextComponent::duration(Duration::from_secs(8100), DurationStyle::Verbose);
+    /// ```
+    pub fn duration(duration: Duration, style: DurationStyle) -> TextComponent {
+        let amounts = split_units(duration.as_secs());
+        match style {
+            DurationStyle::Compact if amounts.is_empty() => TextComponent::plain("0s"),
+            DurationStyle::Compact => TextComponent::plain(
+                amounts
+                    .iter()
+                    .map(|(unit, amount)| format!("{amount}{}", UNITS[*unit].0))
+                    .collect::<String>(),
+            ),
+            DurationStyle::Verbose if amounts.is_empty() => unit_component(3, 0),
+            DurationStyle::Verbose => {
+                let mut component = TextComponent::new();
+                for (i, (unit, amount)) in amounts.into_iter().enumerate() {
+                    if i > 0 {
+                        component.children.push(TextComponent::const_plain(" "));
+                    }
+                    component.children.push(unit_component(unit, amount));
+                }
+                component
+            }
+        }
+    }
+
+    /// Creates a [TextComponent] describing how long ago (or how far in the future) a time is,
+    /// see [relative_time_from](TextComponent::relative_time_from).
+    pub fn relative_time(time: SystemTime) -> TextComponent {
+        TextComponent::relative_time_from(time, SystemTime::now())
+    }
+
+    /// Creates a [TextComponent] describing the time from `now` in its biggest unit,
+    /// translated with the `time.ago`, `time.in` and `time.now` keys.
+    /// ## Example
+    /// ```
+    /// # use std::time::{Duration, SystemTime};
+    /// # use text_components::TextComponent;
+    /// # let now = SystemTime::now();
+    /// // Results in "3 minutes ago" if not translated
+    /// TextComponent::relative_time_from(now - Duration::from_secs(200), now);
+    /// ```
+    pub fn relative_time_from(time: SystemTime, now: SystemTime) -> TextComponent {
+        let (seconds, past) = match now.duration_since(time) {
+            Ok(elapsed) => (elapsed.as_secs(), true),
+            Err(error) => (error.duration().as_secs(), false),
+        };
+        let Some((unit, amount)) = split_units(seconds).into_iter().find(|(unit, _)| *unit < 3)
+        else {
+            return TranslatedMessage::new("time.now", None).component_fallback("just now");
+        };
+        let name = unit_name(unit, amount);
+        let (key, fallback) = match past {
+            true => ("time.ago", format!("{amount} {name} ago")),
+            false => ("time.in", format!("in {amount} {name}")),
+        };
+        TranslatedMessage::new(key, Some(Box::new([unit_component(unit, amount)])))
+            .component_fallback(fallback)
+    }
+}
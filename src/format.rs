@@ -1,5 +1,14 @@
+#![deny(clippy::unwrap_used, clippy::indexing_slicing)]
+use crate::{
+    TextComponent,
+    content::{Content, Resolvable},
+    interactivity::HoverEvent,
+    intern::SharedStr,
+    translation::TranslatedMessage,
+};
+#[cfg(feature = "pretty")]
 use colored::{ColoredString, Colorize};
-use std::{borrow::Cow, fmt::Display};
+use std::fmt::Display;
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
@@ -13,37 +22,69 @@ pub struct Format {
         feature = "serde",
         serde(skip_serializing_if = "Option::is_none", default)
     )]
-    pub font: Option<Cow<'static, str>>,
+    pub font: Option<SharedStr>,
     #[cfg_attr(
         feature = "serde",
-        serde(skip_serializing_if = "Option::is_none", default)
+        serde(
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::option_bool_lenient",
+            default
+        )
     )]
     pub bold: Option<bool>,
     #[cfg_attr(
         feature = "serde",
-        serde(skip_serializing_if = "Option::is_none", default)
+        serde(
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::option_bool_lenient",
+            default
+        )
     )]
     pub italic: Option<bool>,
     #[cfg_attr(
         feature = "serde",
-        serde(skip_serializing_if = "Option::is_none", default)
+        serde(
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::option_bool_lenient",
+            default
+        )
     )]
     pub underlined: Option<bool>,
     #[cfg_attr(
         feature = "serde",
-        serde(skip_serializing_if = "Option::is_none", default)
+        serde(
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::option_bool_lenient",
+            default
+        )
     )]
     pub strikethrough: Option<bool>,
     #[cfg_attr(
         feature = "serde",
-        serde(skip_serializing_if = "Option::is_none", default)
+        serde(
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::option_bool_lenient",
+            default
+        )
     )]
     pub obfuscated: Option<bool>,
     #[cfg_attr(
         feature = "serde",
-        serde(skip_serializing_if = "Option::is_none", default)
+        serde(
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "crate::serde::serialize_shadow_color",
+            deserialize_with = "crate::serde::option_shadow_color",
+            default
+        )
     )]
     pub shadow_color: Option<i64>,
+    /// Set by [no_inherit](Format::no_inherit), the values this format leaves unset are the
+    /// defaults instead of the ones of the parent.\
+    /// It's never serialized, [resolving](TextComponent::resolve) writes the defaults that
+    /// differ from the parent ones in its place, see
+    /// [expand_no_inherit](TextComponent::expand_no_inherit).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub no_inherit: bool,
 }
 
 impl Default for Format {
@@ -62,6 +103,55 @@ impl Format {
             strikethrough: None,
             obfuscated: None,
             shadow_color: None,
+            no_inherit: false,
+        }
+    }
+    /// A [Format] taking nothing from the parent, see [no_inherit](Format::no_inherit).
+    pub const fn reset_marker() -> Self {
+        Self::new().no_inherit()
+    }
+    /// Creates a [Format] with every value at once, usable in constants where `font` can't
+    /// be set with [font](Format::font).\
+    /// The font is kept as written, adding `minecraft:` would need an allocation a const fn
+    /// can't make, so it needs its namespace. [style!](crate::style) adds it at compile time.
+    /// ## Example
+    /// ```
+    /// # use text_components::format::{Color, Format};
+    /// const ERROR: Format = Format::with(
+    ///     Some(Color::Red),
+    ///     Some("minecraft:uniform"),
+    ///     Some(true),
+    ///     None,
+    ///     None,
+    ///     None,
+    ///     None,
+    ///     None,
+    /// );
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub const fn with(
+        color: Option<Color>,
+        font: Option<&'static str>,
+        bold: Option<bool>,
+        italic: Option<bool>,
+        underlined: Option<bool>,
+        strikethrough: Option<bool>,
+        obfuscated: Option<bool>,
+        shadow_color: Option<i64>,
+    ) -> Self {
+        Self {
+            color,
+            font: match font {
+                Some(font) => Some(SharedStr::from_static(font)),
+                None => None,
+            },
+            bold,
+            italic,
+            underlined,
+            strikethrough,
+            obfuscated,
+            shadow_color,
+            no_inherit: false,
         }
     }
     pub fn is_none(&self) -> bool {
@@ -73,8 +163,9 @@ impl Format {
             && self.strikethrough.is_none()
             && self.obfuscated.is_none()
             && self.shadow_color.is_none()
+            && !self.no_inherit
     }
-    pub fn color(mut self, color: Color) -> Self {
+    pub const fn color(mut self, color: Color) -> Self {
         self.color = Some(color);
         self
     }
@@ -84,40 +175,119 @@ impl Format {
         }
         self
     }
-    pub fn font<F: Into<Cow<'static, str>>>(mut self, font: F) -> Self {
-        self.font = Some(font.into());
+    /// Sets the font, a bare name like `uniform` is taken as `minecraft:uniform`.\
+    /// Use [try_font](Format::try_font) to check the id, fonts of resource packs need their
+    /// own namespace.
+    pub fn font<F: Into<SharedStr>>(mut self, font: F) -> Self {
+        self.font = Some(namespaced_font(font.into()));
         self
     }
-    pub fn bold(mut self, value: bool) -> Self {
+    /// Same as [font](Format::font), failing if the font isn't a valid resource location.
+    /// ## Example
+    /// ```
+    /// # use text_components::format::Format;
+    /// // Ok, "minecraft:uniform"
+    /// Format::new().try_font("uniform");
+    /// // Err, fonts are lowercase
+    /// Format::new().try_font("example:Fancy");
+    /// ```
+    pub fn try_font<F: Into<SharedStr>>(self, font: F) -> Result<Self, FontError> {
+        let font = font.into();
+        let (namespace, path) = font.split_once(':').unwrap_or(("minecraft", &font));
+        if path.is_empty() {
+            return Err(FontError::EmptyPath(font.to_string()));
+        }
+        let invalid = namespace
+            .chars()
+            .find(|char| !matches!(char, 'a'..='z' | '0'..='9' | '_' | '-' | '.'))
+            .or_else(|| {
+                path.chars()
+                    .find(|char| !matches!(char, 'a'..='z' | '0'..='9' | '_' | '-' | '.' | '/'))
+            });
+        match invalid {
+            Some(char) => Err(FontError::InvalidCharacter(font.to_string(), char)),
+            None => Ok(self.font(font)),
+        }
+    }
+    pub const fn bold(mut self, value: bool) -> Self {
         self.bold = Some(value);
         self
     }
-    pub fn italic(mut self, value: bool) -> Self {
+    pub const fn italic(mut self, value: bool) -> Self {
         self.italic = Some(value);
         self
     }
-    pub fn underlined(mut self, value: bool) -> Self {
+    pub const fn underlined(mut self, value: bool) -> Self {
         self.underlined = Some(value);
         self
     }
-    pub fn strikethrough(mut self, value: bool) -> Self {
+    pub const fn strikethrough(mut self, value: bool) -> Self {
         self.strikethrough = Some(value);
         self
     }
-    pub fn obfuscated(mut self, value: bool) -> Self {
+    pub const fn obfuscated(mut self, value: bool) -> Self {
         self.obfuscated = Some(value);
         self
     }
-    pub fn shadow_color(mut self, a: u8, r: u8, g: u8, b: u8) -> Self {
+    pub const fn shadow_color(mut self, a: u8, r: u8, g: u8, b: u8) -> Self {
         self.shadow_color = Some(Self::parse_shadow_color(a, r, g, b));
         self
     }
-    pub fn parse_shadow_color(a: u8, r: u8, g: u8, b: u8) -> i64 {
+    pub const fn parse_shadow_color(a: u8, r: u8, g: u8, b: u8) -> i64 {
         (((a as u32) << 24) + ((r as u32) << 16) + ((g as u32) << 8) + (b as u32)) as i64
     }
+    /// Reads a packed shadow color the way the game does, from its low 32 bits.\
+    /// The game writes it as a signed int, so `-16777216` is the same opaque black as
+    /// `4278190080`.
+    pub const fn packed_shadow_color(color: i64) -> i64 {
+        color as u32 as i64
+    }
+    /// The red, green, blue and alpha of the shadow color, [None] if it's inherited.
+    pub const fn shadow_color_rgba(&self) -> Option<(u8, u8, u8, u8)> {
+        match self.shadow_color {
+            Some(color) => Some((
+                (color >> 16) as u8,
+                (color >> 8) as u8,
+                color as u8,
+                (color >> 24) as u8,
+            )),
+            None => None,
+        }
+    }
+    /// The red, green and blue of the color, [None] if it's inherited.
+    pub const fn color_rgb(&self) -> Option<(u8, u8, u8)> {
+        match &self.color {
+            Some(color) => Some(color.as_rgb()),
+            None => None,
+        }
+    }
+    /// The red, green and blue the text is shown with under the parent, which has to be
+    /// the already [mixed](Format::mix) format of its parent.\
+    /// [None] if neither sets it, the text is white then.
+    pub const fn effective_color(&self, parent: &Format) -> Option<(u8, u8, u8)> {
+        match (&self.color, self.no_inherit) {
+            (Some(color), _) => Some(color.as_rgb()),
+            (None, true) => None,
+            (None, false) => parent.color_rgb(),
+        }
+    }
+    /// Unpacks a shadow color into the `[r, g, b, a]` float list form.
+    pub fn shadow_color_floats(color: i64) -> [f32; 4] {
+        let channel = |shift: i64| ((color >> shift) & 0xFF) as f32 / 255.0;
+        [channel(16), channel(8), channel(0), channel(24)]
+    }
+    /// Packs a `[r, g, b, a]` float list shadow color, each channel clamped to `0.0..=1.0`.
+    pub fn float_shadow_color(r: f32, g: f32, b: f32, a: f32) -> i64 {
+        let channel = |n: f32| (n.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Self::parse_shadow_color(channel(a), channel(r), channel(g), channel(b))
+    }
+    /// Sets every value to its default: white, `minecraft:default` and all flags `false`.\
+    /// They're explicit values, so they win over the parent in [mix](Format::mix) and are
+    /// all serialized. The shadow color is left unset and still inherited, its default
+    /// depends on the color. [no_inherit](Format::no_inherit) is the lighter alternative.
     pub fn reset(mut self) -> Self {
         self.color = Some(Color::White);
-        self.font = Some(Cow::Borrowed("minecraft:default"));
+        self.font = Some(SharedStr::from_static("minecraft:default"));
         self.bold = Some(false);
         self.italic = Some(false);
         self.underlined = Some(false);
@@ -126,55 +296,91 @@ impl Format {
         self.shadow_color = None;
         self
     }
+    /// Stops taking the unset values from the parent, they're the defaults instead,
+    /// the shadow color included. The values set are kept.\
+    /// Unlike [reset](Format::reset) nothing is serialized for it: resolving writes only the
+    /// defaults needed to hide the values the parent sets, see
+    /// [expand_no_inherit](TextComponent::expand_no_inherit).
+    /// ## Example
+    /// ```
+    /// # use text_components::{Modifier, format::Color};
+    /// // {text:"Error: ",color:"red",bold:1b,extra:[{text:"details",color:"white",bold:0b}]}
+    /// "Error: "
+    ///     .color(Color::Red)
+    ///     .bold(true)
+    ///     .add_child("details".no_inherit());
+    /// ```
+    pub const fn no_inherit(mut self) -> Self {
+        self.no_inherit = true;
+        self
+    }
+    /// The default shadow color of a text of this color, a quarter of its brightness.
+    pub const fn default_shadow_color(color: Color) -> i64 {
+        let (r, g, b) = color.as_rgb();
+        Self::parse_shadow_color(0xFF, r / 4, g / 4, b / 4)
+    }
+    /// Gives a new [Format] with the values of this one, taking the unset ones from `other`.\
+    /// Nothing is taken when this one is [no_inherit](Format::no_inherit).
     pub fn mix(&self, other: &Format) -> Format {
+        if self.no_inherit {
+            return self.clone();
+        }
         Format {
-            color: if self.color.is_some() {
-                self.color.clone()
-            } else {
-                other.color.clone()
-            },
-            font: if self.font.is_some() {
-                self.font.clone()
-            } else {
-                other.font.clone()
-            },
-            bold: if self.bold.is_some() {
-                self.bold
-            } else {
-                other.bold
-            },
-            italic: if self.italic.is_some() {
-                self.italic
-            } else {
-                other.italic
-            },
-            underlined: if self.underlined.is_some() {
-                self.underlined
-            } else {
-                other.underlined
-            },
-            strikethrough: if self.strikethrough.is_some() {
-                self.strikethrough
-            } else {
-                other.strikethrough
-            },
-            obfuscated: if self.obfuscated.is_some() {
-                self.obfuscated
-            } else {
-                other.obfuscated
-            },
-            shadow_color: if self.shadow_color.is_some() {
-                self.shadow_color
-            } else {
-                other.shadow_color
-            },
+            color: self.color.or(other.color),
+            font: self.font.as_ref().or(other.font.as_ref()).cloned(),
+            bold: self.bold.or(other.bold),
+            italic: self.italic.or(other.italic),
+            underlined: self.underlined.or(other.underlined),
+            strikethrough: self.strikethrough.or(other.strikethrough),
+            obfuscated: self.obfuscated.or(other.obfuscated),
+            shadow_color: self.shadow_color.or(other.shadow_color),
+            no_inherit: false,
+        }
+    }
+    /// Same as [mix](Format::mix) without making a new [Format], only the unset values
+    /// are taken from the parent.
+    pub fn mix_in_place(&mut self, parent: &Format) {
+        if self.no_inherit {
+            return;
+        }
+        self.color = self.color.or(parent.color);
+        if self.font.is_none() {
+            self.font.clone_from(&parent.font);
+        }
+        self.bold = self.bold.or(parent.bold);
+        self.italic = self.italic.or(parent.italic);
+        self.underlined = self.underlined.or(parent.underlined);
+        self.strikethrough = self.strikethrough.or(parent.strikethrough);
+        self.obfuscated = self.obfuscated.or(parent.obfuscated);
+        self.shadow_color = self.shadow_color.or(parent.shadow_color);
+    }
+    /// Same as [mix](Format::mix) for two formats of the same component, `other` is kept
+    /// even if this one is [no_inherit](Format::no_inherit), which stays set.
+    pub(crate) fn overlay(&self, other: &Format) -> Format {
+        let mut format = Format {
+            no_inherit: false,
+            ..self.clone()
         }
+        .mix(other);
+        format.no_inherit = self.no_inherit || other.no_inherit;
+        format
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The form shadow colors are written in, both are always accepted when parsing.\
+/// It's chosen for each call, with `NbtBuilder::shadow_colors` and the serde
+/// `WithShadowColors` wrapper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShadowColorFormat {
+    /// A single ARGB int, like `-16777216`.
+    #[default]
+    Packed,
+    /// A `[r, g, b, a]` list of floats between `0.0` and `1.0`.
+    Floats,
+}
+
+/// Serialized as its name (`dark_aqua`) or as a lowercase `#rrggbb` hex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Color {
     Aqua,
     Black,
@@ -195,22 +401,80 @@ pub enum Color {
     Rgb(u8, u8, u8),
 }
 impl Color {
+    /// The red, green and blue the game shows this color with, named colors from the
+    /// vanilla palette.
+    pub const fn as_rgb(&self) -> (u8, u8, u8) {
+        match *self {
+            Color::Black => (0x00, 0x00, 0x00),
+            Color::DarkBlue => (0x00, 0x00, 0xAA),
+            Color::DarkGreen => (0x00, 0xAA, 0x00),
+            Color::DarkAqua => (0x00, 0xAA, 0xAA),
+            Color::DarkRed => (0xAA, 0x00, 0x00),
+            Color::DarkPurple => (0xAA, 0x00, 0xAA),
+            Color::Gold => (0xFF, 0xAA, 0x00),
+            Color::Gray => (0xAA, 0xAA, 0xAA),
+            Color::DarkGray => (0x55, 0x55, 0x55),
+            Color::Blue => (0x55, 0x55, 0xFF),
+            Color::Green => (0x55, 0xFF, 0x55),
+            Color::Aqua => (0x55, 0xFF, 0xFF),
+            Color::Red => (0xFF, 0x55, 0x55),
+            Color::LightPurple => (0xFF, 0x55, 0xFF),
+            Color::Yellow => (0xFF, 0xFF, 0x55),
+            Color::White => (0xFF, 0xFF, 0xFF),
+            Color::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+    /// Same as [Color::Rgb], usable in constants unlike [from_hex](Color::from_hex).
+    pub const fn hex(r: u8, g: u8, b: u8) -> Color {
+        Color::Rgb(r, g, b)
+    }
+    /// Reads a named color or a hex color like [parse_hex](Color::parse_hex), usable in
+    /// constants where what isn't a color fails to compile, see [style!](crate::style).
+    /// ## Panics
+    /// When `color` isn't a color name, `#rrggbb` or `#rgb`.
+    pub const fn const_parse(color: &str) -> Color {
+        let mut names: &[(&str, Color)] = &NAMED_COLORS;
+        while let [(name, named), rest @ ..] = names {
+            if const_eq(name.as_bytes(), color.as_bytes()) {
+                return *named;
+            }
+            names = rest;
+        }
+        let digits = match color.as_bytes() {
+            [b'#', digits @ ..] => digits,
+            digits => digits,
+        };
+        match hex_rgb(digits) {
+            Some(color) => color,
+            None => panic!("Colors are a name, #rrggbb or #rgb"),
+        }
+    }
+    /// Gives the named color, like `dark_aqua`.
+    pub fn from_name(name: &str) -> Option<Color> {
+        NAMED_COLORS
+            .iter()
+            .find(|(named, _)| *named == name)
+            .map(|(_, color)| *color)
+    }
+    /// Reads a hex color like [parse_hex](Color::parse_hex), giving [None] if it isn't one.
     pub fn from_hex(color: &str) -> Option<Color> {
-        if color.starts_with('#')
-            && color.chars().count() == 7
-            && color[1..].find(|a: char| !a.is_ascii_hexdigit()).is_none()
-        {
-            let color = color.strip_prefix('#').unwrap();
-            let (r, color) = color.split_at(2);
-            let (g, b) = color.split_at(2);
-            return Some(Color::Rgb(
-                u8::from_str_radix(r, 16).unwrap(),
-                u8::from_str_radix(g, 16).unwrap(),
-                u8::from_str_radix(b, 16).unwrap(),
-            ));
+        Color::parse_hex(color).ok()
+    }
+    /// Reads a `#rrggbb` hex color in any case, also taking the `#rgb` shorthand (each
+    /// digit doubled, `#f0f` is `#ff00ff`) and either of them without the `#`.\
+    /// Colors are always written back as lowercase `#rrggbb`.
+    pub fn parse_hex(color: &str) -> Result<Color, HexColorError> {
+        let digits = color.strip_prefix('#').unwrap_or(color);
+        if let Some(digit) = digits.chars().find(|digit| !digit.is_ascii_hexdigit()) {
+            return Err(HexColorError::InvalidDigit(color.to_string(), digit));
+        }
+        match (hex_rgb(digits.as_bytes()), digits.len()) {
+            (Some(color), _) => Ok(color),
+            (None, 8) => Err(HexColorError::Argb(color.to_string())),
+            (None, _) => Err(HexColorError::Length(color.to_string())),
         }
-        None
     }
+    #[cfg(feature = "pretty")]
     pub fn colorize_text<T: Into<String>>(&self, text: T) -> ColoredString {
         match self {
             Color::Black => text.into().black(),
@@ -233,6 +497,177 @@ impl Color {
         }
     }
 }
+/// Why a text isn't a hex color, see [Color::parse_hex].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HexColorError {
+    /// A character other than the `#` and the hex digits.
+    InvalidDigit(String, char),
+    /// `#aarrggbb`, text colors have no alpha channel.
+    Argb(String),
+    /// Not 3 or 6 digits long.
+    Length(String),
+}
+const NAMED_COLORS: [(&str, Color); 16] = [
+    ("aqua", Color::Aqua),
+    ("black", Color::Black),
+    ("blue", Color::Blue),
+    ("dark_aqua", Color::DarkAqua),
+    ("dark_blue", Color::DarkBlue),
+    ("dark_gray", Color::DarkGray),
+    ("dark_green", Color::DarkGreen),
+    ("dark_purple", Color::DarkPurple),
+    ("dark_red", Color::DarkRed),
+    ("gold", Color::Gold),
+    ("gray", Color::Gray),
+    ("green", Color::Green),
+    ("light_purple", Color::LightPurple),
+    ("red", Color::Red),
+    ("white", Color::White),
+    ("yellow", Color::Yellow),
+];
+
+const fn const_eq(mut a: &[u8], mut b: &[u8]) -> bool {
+    loop {
+        match (a, b) {
+            ([], []) => return true,
+            ([x, a_rest @ ..], [y, b_rest @ ..]) if *x == *y => {
+                a = a_rest;
+                b = b_rest;
+            }
+            _ => return false,
+        }
+    }
+}
+
+/// The color of `rrggbb` or `rgb` digits, [None] for any other length.
+const fn hex_rgb(digits: &[u8]) -> Option<Color> {
+    match *digits {
+        [r, g, b] => Some(Color::Rgb(
+            hex_digit(r) * 0x11,
+            hex_digit(g) * 0x11,
+            hex_digit(b) * 0x11,
+        )),
+        [r1, r2, g1, g2, b1, b2] => Some(Color::Rgb(
+            hex_digit(r1) * 16 + hex_digit(r2),
+            hex_digit(g1) * 16 + hex_digit(g2),
+            hex_digit(b1) * 16 + hex_digit(b2),
+        )),
+        _ => None,
+    }
+}
+
+const fn hex_digit(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        b'A'..=b'F' => digit - b'A' + 10,
+        _ => panic!("Hex colors only have the digits 0-9 and a-f"),
+    }
+}
+
+/// The font given to [Format::try_font] isn't a resource location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FontError {
+    /// A character other than `a-z`, `0-9`, `_`, `-`, `.` and the `/` of the path.
+    InvalidCharacter(String, char),
+    /// Nothing after the namespace, like `minecraft:`.
+    EmptyPath(String),
+}
+
+/// Puts the fonts without a namespace in `minecraft`, like the client does.
+pub(crate) fn namespaced_font(font: SharedStr) -> SharedStr {
+    match font.contains(':') {
+        true => font,
+        false => format!("minecraft:{font}").into(),
+    }
+}
+
+// A const fn can't allocate, style! namespaces its font into an array sized
+// by namespaced_font_len instead.
+#[doc(hidden)]
+pub const fn namespaced_font_len(font: &str) -> usize {
+    match has_namespace(font.as_bytes()) {
+        true => font.len(),
+        false => "minecraft:".len() + font.len(),
+    }
+}
+#[doc(hidden)]
+pub const fn namespaced_font_bytes<const N: usize>(font: &str) -> [u8; N] {
+    let font = font.as_bytes();
+    let prefix: &[u8] = match has_namespace(font) {
+        true => b"",
+        false => b"minecraft:",
+    };
+    let (mut prefix, mut font) = (prefix, font);
+    let mut bytes = [0; N];
+    let mut rest: &mut [u8] = &mut bytes;
+    while let [byte, tail @ ..] = rest {
+        *byte = match (prefix, font) {
+            ([next, prefix_rest @ ..], _) => {
+                prefix = prefix_rest;
+                *next
+            }
+            ([], [next, font_rest @ ..]) => {
+                font = font_rest;
+                *next
+            }
+            ([], []) => panic!("N is longer than the namespaced font"),
+        };
+        rest = tail;
+    }
+    bytes
+}
+const fn has_namespace(mut font: &[u8]) -> bool {
+    while let [byte, rest @ ..] = font {
+        if *byte == b':' {
+            return true;
+        }
+        font = rest;
+    }
+    false
+}
+
+/// Sets the default when the value is unset and the parent one isn't the default.
+fn expand_field<T: Clone + PartialEq>(value: &mut Option<T>, parent: &Option<T>, default: T) {
+    if value.is_none() && parent.as_ref().is_some_and(|parent| *parent != default) {
+        *value = Some(default);
+    }
+}
+
+impl Display for FontError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FontError::InvalidCharacter(font, char) => write!(
+                f,
+                "The font \"{font}\" has '{char}', which can't be in a resource location."
+            ),
+            FontError::EmptyPath(font) => write!(f, "The font \"{font}\" has no path."),
+        }
+    }
+}
+impl std::error::Error for FontError {}
+
+impl Display for HexColorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HexColorError::InvalidDigit(color, digit) => {
+                write!(
+                    f,
+                    "The color \"{color}\" has '{digit}', which isn't a hex digit."
+                )
+            }
+            HexColorError::Argb(color) => write!(
+                f,
+                "The color \"{color}\" has an alpha channel, text colors are #rrggbb and the translucent ones go in the shadow_color."
+            ),
+            HexColorError::Length(color) => {
+                write!(f, "The color \"{color}\" isn't #rrggbb or #rgb.")
+            }
+        }
+    }
+}
+impl std::error::Error for HexColorError {}
+
 impl Display for Color {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -252,7 +687,298 @@ impl Display for Color {
             Color::Red => write!(f, "red"),
             Color::White => write!(f, "white"),
             Color::Yellow => write!(f, "yellow"),
-            Color::Rgb(r, g, b) => write!(f, "#{:02X}{:02X}{:02X}", r, g, b),
+            Color::Rgb(r, g, b) => write!(f, "#{:02x}{:02x}{:02x}", r, g, b),
         }
     }
 }
+
+/// Moving the format between parents and children, for consumers that handle
+/// inheritance differently.
+impl TextComponent {
+    /// Gives every component of the tree its full inherited format, the children, the
+    /// translation arguments and the separators, so it looks the same to consumers that
+    /// don't implement inheritance.\
+    /// Hover texts don't inherit anything and are left as they are.
+    /// ## Example
+    /// ```
+    /// # use text_components::{Modifier, format::Color};
+    /// // {text:"Hi ",color:"red",extra:[{text:"there",color:"red",bold:1b}]}
+    /// "Hi ".color(Color::Red).add_child("there".bold(true)).bake_inheritance();
+    /// ```
+    pub fn bake_inheritance(&self) -> TextComponent {
+        self.baked(&Format::new())
+    }
+
+    fn baked(&self, parent: &Format) -> TextComponent {
+        let format = self.format.mix(parent);
+        let content = match &self.content {
+            Content::Translate(message) => Content::Translate(TranslatedMessage {
+                args: message
+                    .args
+                    .as_ref()
+                    .map(|args| args.iter().map(|arg| arg.baked(&format)).collect()),
+                ..message.clone()
+            }),
+            Content::Resolvable(Resolvable::Entity {
+                selector,
+                separator,
+            }) => Content::Resolvable(Resolvable::Entity {
+                selector: selector.clone(),
+                separator: Box::new(separator.baked(&format)),
+            }),
+            Content::Resolvable(Resolvable::NBT {
+                path,
+                interpret,
+                separator,
+                source,
+            }) => Content::Resolvable(Resolvable::NBT {
+                path: path.clone(),
+                interpret: *interpret,
+                separator: Box::new(separator.baked(&format)),
+                source: source.clone(),
+            }),
+            content => content.clone(),
+        };
+        TextComponent {
+            content,
+            children: self
+                .children
+                .iter()
+                .map(|child| child.baked(&format))
+                .collect(),
+            format,
+            interactions: self.interactions.clone(),
+            inline_translations: self.inline_translations.clone(),
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: self.unknown_fields.clone(),
+        }
+    }
+
+    /// The inverse of [bake_inheritance](TextComponent::bake_inheritance), shrinking the
+    /// tree by leaving out of the children the values they'd inherit anyway.\
+    /// Values shared by every child are moved to the parent when it has no text of its
+    /// own, so the format of everything shown stays the same.
+    /// ## Example
+    /// ```
+    /// # use text_components::{Modifier, TextComponent, format::Color};
+    /// // {text:"",color:"red",extra:["Hi ",{text:"there",bold:1b}]}
+    /// TextComponent::new()
+    ///     .add_children(vec!["Hi ".color(Color::Red), "there".color(Color::Red).bold(true)])
+    ///     .factor_common_format();
+    /// ```
+    pub fn factor_common_format(&self) -> TextComponent {
+        let mut component = self.clone();
+        component.factor();
+        component
+    }
+
+    fn factor(&mut self) {
+        match &mut self.content {
+            Content::Translate(TranslatedMessage {
+                args: Some(args), ..
+            }) => args.iter_mut().for_each(TextComponent::factor),
+            Content::Resolvable(
+                Resolvable::Entity { separator, .. } | Resolvable::NBT { separator, .. },
+            ) => separator.factor(),
+            _ => (),
+        }
+        self.children.iter_mut().for_each(TextComponent::factor);
+        self.factor_field(|format| &mut format.color);
+        self.factor_field(|format| &mut format.font);
+        self.factor_field(|format| &mut format.bold);
+        self.factor_field(|format| &mut format.italic);
+        self.factor_field(|format| &mut format.underlined);
+        self.factor_field(|format| &mut format.strikethrough);
+        self.factor_field(|format| &mut format.obfuscated);
+        self.factor_field(|format| &mut format.shadow_color);
+    }
+
+    /// Children with [no_inherit](Format::no_inherit) take nothing from the parent, so they
+    /// are left as they are.
+    fn factor_field<T: Clone + PartialEq>(&mut self, field: fn(&mut Format) -> &mut Option<T>) {
+        let mut values = self
+            .children
+            .iter_mut()
+            .filter(|child| !child.format.no_inherit)
+            .map(|child| field(&mut child.format).clone());
+        let shared = values
+            .next()
+            .flatten()
+            .filter(|first| values.all(|value| value.as_ref() == Some(first)));
+        let own = field(&mut self.format);
+        // Only a parent without text of its own can take a value without looking different
+        if own.is_none() && self.content.as_text() == Some("") {
+            *own = shared;
+        }
+        let Some(own) = own.clone() else {
+            return;
+        };
+        for child in self
+            .children
+            .iter_mut()
+            .filter(|child| !child.format.no_inherit)
+        {
+            let value = field(&mut child.format);
+            if value.as_ref() == Some(&own) {
+                *value = None;
+            }
+        }
+    }
+
+    /// Replaces the [no_inherit](Format::no_inherit) of the tree with the explicit values
+    /// the game needs to show it the same, as it has no such flag. Only the defaults hiding
+    /// a value set by the parent are written.\
+    /// [Resolving](TextComponent::resolve) already does this, it's only needed to serialize
+    /// a component without resolving it.
+    /// ## Example
+    /// ```
+    /// # use text_components::{Modifier, TextComponent, format::Color};
+    /// // {text:"",color:"red",extra:[{text:"a",color:"white"}]}
+    /// TextComponent::new().color(Color::Red).add_child("a".no_inherit()).expand_no_inherit();
+    /// ```
+    pub fn expand_no_inherit(&self) -> TextComponent {
+        let mut component = self.clone();
+        component.expand(&Format::new(), false);
+        component
+    }
+
+    /// `parent` is the format the game gives to this component, `default_shadow` tells if
+    /// the shadow color should be the default one of the color instead of the inherited one.
+    pub(crate) fn expand(&mut self, parent: &Format, default_shadow: bool) {
+        let own_shadow = self.format.shadow_color.is_some();
+        let no_inherit = self.format.no_inherit;
+        if no_inherit {
+            let format = &mut self.format;
+            format.no_inherit = false;
+            expand_field(&mut format.color, &parent.color, Color::White);
+            if format.font.is_none()
+                && parent
+                    .font
+                    .as_ref()
+                    .is_some_and(|font| font != "minecraft:default")
+            {
+                format.font = Some(SharedStr::from_static("minecraft:default"));
+            }
+            expand_field(&mut format.bold, &parent.bold, false);
+            expand_field(&mut format.italic, &parent.italic, false);
+            expand_field(&mut format.underlined, &parent.underlined, false);
+            expand_field(&mut format.strikethrough, &parent.strikethrough, false);
+            expand_field(&mut format.obfuscated, &parent.obfuscated, false);
+        }
+        let format = self.format.mix(parent);
+        let default_shadow = !own_shadow && (no_inherit || default_shadow);
+        let format = match (default_shadow, format.shadow_color) {
+            (true, Some(inherited)) => {
+                let shadow = Format::default_shadow_color(format.color.unwrap_or(Color::White));
+                if inherited != shadow {
+                    self.format.shadow_color = Some(shadow);
+                }
+                Format {
+                    shadow_color: Some(shadow),
+                    ..format
+                }
+            }
+            _ => format,
+        };
+
+        match &mut self.content {
+            Content::Translate(TranslatedMessage {
+                args: Some(args), ..
+            }) => args
+                .iter_mut()
+                .for_each(|arg| arg.expand(&format, default_shadow)),
+            Content::Resolvable(
+                Resolvable::Entity { separator, .. } | Resolvable::NBT { separator, .. },
+            ) => separator.expand(&format, default_shadow),
+            _ => (),
+        }
+        for child in &mut self.children {
+            child.expand(&format, default_shadow);
+        }
+        if let Some(
+            HoverEvent::ShowText { value }
+            | HoverEvent::ShowEntity {
+                name: Some(value), ..
+            },
+        ) = &mut self.interactions.hover
+        {
+            value.expand(&Format::new(), false);
+        }
+    }
+}
+
+/// Makes a [Format] at compile time, for the constant styles of a theme.\
+/// Keys are the ones of the format, flags alone are set to `true`. Colors are a name or a
+/// hex color read by [Color::const_parse], the shadow color a packed ARGB int like
+/// `0xFF000000`, and a font without a namespace gets `minecraft:` like with
+/// [Format::font]. Unknown keys and invalid colors fail to compile.
+/// ## Example
+/// ```
+/// # use text_components::{Modifier, format::Format, style};
+/// const TITLE: Format = style!(color: "#ff0044", bold, italic: false, font: "uniform");
+/// const MUTED: Format = style!(color: "dark_gray", shadow_color: 0x80000000);
+/// "Welcome".style(TITLE);
+/// ```
+#[macro_export]
+macro_rules! style {
+    (@ [$($c:tt)*] [$($f:tt)*] [$($b:tt)*] [$($i:tt)*] [$($u:tt)*] [$($s:tt)*] [$($o:tt)*] [$($sh:tt)*]) => {
+        const {
+            $crate::format::Format::with(
+                $($c)*, $($f)*, $($b)*, $($i)*, $($u)*, $($s)*, $($o)*, $($sh)*,
+            )
+        }
+    };
+    (@ $c:tt $f:tt $b:tt $i:tt $u:tt $s:tt $o:tt $sh:tt color: $value:expr $(, $($rest:tt)*)?) => {
+        $crate::style!(@ [Some($crate::format::Color::const_parse($value))] $f $b $i $u $s $o $sh $($($rest)*)?)
+    };
+    (@ $c:tt $f:tt $b:tt $i:tt $u:tt $s:tt $o:tt $sh:tt font: $value:expr $(, $($rest:tt)*)?) => {
+        $crate::style!(@ $c [Some({
+            // Items aren't hygienic, an unlikely name keeps `$value` from naming this one
+            const __STYLE_FONT: &[u8; $crate::format::namespaced_font_len($value)] =
+                &$crate::format::namespaced_font_bytes($value);
+            match ::core::str::from_utf8(__STYLE_FONT) {
+                Ok(font) => font,
+                Err(_) => ::core::unreachable!(),
+            }
+        })] $b $i $u $s $o $sh $($($rest)*)?)
+    };
+    (@ $c:tt $f:tt $b:tt $i:tt $u:tt $s:tt $o:tt $sh:tt bold: $value:expr $(, $($rest:tt)*)?) => {
+        $crate::style!(@ $c $f [Some($value)] $i $u $s $o $sh $($($rest)*)?)
+    };
+    (@ $c:tt $f:tt $b:tt $i:tt $u:tt $s:tt $o:tt $sh:tt bold $(, $($rest:tt)*)?) => {
+        $crate::style!(@ $c $f [Some(true)] $i $u $s $o $sh $($($rest)*)?)
+    };
+    (@ $c:tt $f:tt $b:tt $i:tt $u:tt $s:tt $o:tt $sh:tt italic: $value:expr $(, $($rest:tt)*)?) => {
+        $crate::style!(@ $c $f $b [Some($value)] $u $s $o $sh $($($rest)*)?)
+    };
+    (@ $c:tt $f:tt $b:tt $i:tt $u:tt $s:tt $o:tt $sh:tt italic $(, $($rest:tt)*)?) => {
+        $crate::style!(@ $c $f $b [Some(true)] $u $s $o $sh $($($rest)*)?)
+    };
+    (@ $c:tt $f:tt $b:tt $i:tt $u:tt $s:tt $o:tt $sh:tt underlined: $value:expr $(, $($rest:tt)*)?) => {
+        $crate::style!(@ $c $f $b $i [Some($value)] $s $o $sh $($($rest)*)?)
+    };
+    (@ $c:tt $f:tt $b:tt $i:tt $u:tt $s:tt $o:tt $sh:tt underlined $(, $($rest:tt)*)?) => {
+        $crate::style!(@ $c $f $b $i [Some(true)] $s $o $sh $($($rest)*)?)
+    };
+    (@ $c:tt $f:tt $b:tt $i:tt $u:tt $s:tt $o:tt $sh:tt strikethrough: $value:expr $(, $($rest:tt)*)?) => {
+        $crate::style!(@ $c $f $b $i $u [Some($value)] $o $sh $($($rest)*)?)
+    };
+    (@ $c:tt $f:tt $b:tt $i:tt $u:tt $s:tt $o:tt $sh:tt strikethrough $(, $($rest:tt)*)?) => {
+        $crate::style!(@ $c $f $b $i $u [Some(true)] $o $sh $($($rest)*)?)
+    };
+    (@ $c:tt $f:tt $b:tt $i:tt $u:tt $s:tt $o:tt $sh:tt obfuscated: $value:expr $(, $($rest:tt)*)?) => {
+        $crate::style!(@ $c $f $b $i $u $s [Some($value)] $sh $($($rest)*)?)
+    };
+    (@ $c:tt $f:tt $b:tt $i:tt $u:tt $s:tt $o:tt $sh:tt obfuscated $(, $($rest:tt)*)?) => {
+        $crate::style!(@ $c $f $b $i $u $s [Some(true)] $sh $($($rest)*)?)
+    };
+    (@ $c:tt $f:tt $b:tt $i:tt $u:tt $s:tt $o:tt $sh:tt shadow_color: $value:expr $(, $($rest:tt)*)?) => {
+        $crate::style!(@ $c $f $b $i $u $s $o [Some($crate::format::Format::packed_shadow_color($value))] $($($rest)*)?)
+    };
+    (@ $c:tt $f:tt $b:tt $i:tt $u:tt $s:tt $o:tt $sh:tt $key:ident $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!("Unknown style key `", ::core::stringify!($key), "`"))
+    };
+    ($($key:tt)*) => {
+        $crate::style!(@ [None] [None] [None] [None] [None] [None] [None] [None] $($key)*)
+    };
+}
@@ -1,9 +1,26 @@
+//! Click and hover events of components, every kind of event has a constructor:
+//!
+//! | Event | Constructors |
+//! |---|---|
+//! | [ClickEvent::OpenUrl] | [open_url](ClickEvent::open_url), [try_open_url](ClickEvent::try_open_url), [open_url_display](ClickEvent::open_url_display) |
+//! | [ClickEvent::RunCommand] | [run_command](ClickEvent::run_command) |
+//! | [ClickEvent::SuggestCommand] | [suggest_command](ClickEvent::suggest_command) |
+//! | [ClickEvent::ChangePage] | [change_page](ClickEvent::change_page) |
+//! | [ClickEvent::CopyToClipboard] | [copy_to_clipboard](ClickEvent::copy_to_clipboard) |
+//! | [ClickEvent::ShowDialog] | [show_dialog](ClickEvent::show_dialog) |
+//! | `ClickEvent::Custom` | `ClickEvent::custom`, with the `custom` feature |
+//! | [HoverEvent::ShowText] | [show_text](HoverEvent::show_text) |
+//! | [HoverEvent::ShowItem] | [show_item](HoverEvent::show_item) |
+//! | [HoverEvent::ShowEntity] | [show_entity](HoverEvent::show_entity), [show_entity_named](HoverEvent::show_entity_named) |
 use uuid::Uuid;
 
-use crate::TextComponent;
 #[cfg(feature = "custom")]
-use crate::custom::CustomData;
-use std::borrow::Cow;
+use crate::custom::{CustomData, Payload};
+use crate::{Modifier, TextComponent, dialog::DialogRef, format::Color, intern::SharedStr};
+use std::{
+    borrow::Cow,
+    fmt::{self, Display, Formatter},
+};
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
@@ -12,7 +29,7 @@ pub struct Interactivity {
         feature = "serde",
         serde(skip_serializing_if = "Option::is_none", default)
     )]
-    pub insertion: Option<Cow<'static, str>>,
+    pub insertion: Option<SharedStr>,
     #[cfg_attr(
         feature = "serde",
         serde(
@@ -83,7 +100,7 @@ pub enum ClickEvent {
         value: Cow<'static, str>,
     },
     ShowDialog {
-        dialog: Cow<'static, str>,
+        dialog: DialogRef,
     },
     #[cfg(feature = "custom")]
     Custom(CustomData),
@@ -93,6 +110,40 @@ impl ClickEvent {
     pub fn open_url<T: Into<Cow<'static, str>>>(url: T) -> Self {
         ClickEvent::OpenUrl { url: url.into() }
     }
+    /// Same as [open_url](ClickEvent::open_url), but checks the url is one the game opens.\
+    /// The scheme must be `http` or `https` and there can't be control characters,
+    /// spaces and other characters not allowed in urls are percent-encoded.
+    /// ## Example
+    /// ```
+    /// # use text_components::interactivity::ClickEvent;
+    /// // Opens "https://example.com/a%20page"
+    /// ClickEvent::try_open_url("https://example.com/a page")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_open_url<T: AsRef<str>>(url: T) -> Result<Self, UrlError> {
+        Ok(ClickEvent::OpenUrl {
+            url: Cow::Owned(normalize_url(url.as_ref())?),
+        })
+    }
+    /// Creates a link looking component, blue and underlined, that opens `url`
+    /// when clicked and shows it when hovered.
+    /// ## Example
+    /// ```
+    /// # use text_components::interactivity::ClickEvent;
+    /// ClickEvent::open_url_display("https://minecraft.net", "the website");
+    /// ```
+    pub fn open_url_display<T: Into<Cow<'static, str>>, L: Into<TextComponent>>(
+        url: T,
+        label: L,
+    ) -> TextComponent {
+        let url = url.into();
+        label
+            .into()
+            .color(Color::Blue)
+            .underlined(true)
+            .hover_event(HoverEvent::show_text(TextComponent::plain(url.clone())))
+            .click_event(ClickEvent::OpenUrl { url })
+    }
     /// Creates a [ClickEvent] that runs a command when triggered.
     pub fn run_command<T: Into<Cow<'static, str>>>(command: T) -> Self {
         ClickEvent::RunCommand {
@@ -116,12 +167,149 @@ impl ClickEvent {
         }
     }
     /// Creates a [ClickEvent] that shows a custom dialog when triggered.
-    /// * `dialog` - Either a dialog id or a dialog definition
-    pub fn show_dialog<T: Into<Cow<'static, str>>>(dialog: T) -> Self {
+    /// * `dialog` - Either a dialog id or a [Dialog](crate::dialog::Dialog) definition
+    pub fn show_dialog<T: Into<DialogRef>>(dialog: T) -> Self {
         ClickEvent::ShowDialog {
             dialog: dialog.into(),
         }
     }
+    /// Creates a [ClickEvent] that sends `id` and the payload back to the server when triggered.
+    #[cfg(feature = "custom")]
+    pub fn custom<T: Into<Cow<'static, str>>>(id: T, payload: Payload) -> Self {
+        ClickEvent::Custom(CustomData {
+            id: id.into(),
+            payload,
+        })
+    }
+    /// Checks the event would work for players, only `open_url` events can fail,
+    /// when their url isn't one [try_open_url](ClickEvent::try_open_url) accepts as it is.
+    pub fn validate(&self) -> Result<(), UrlError> {
+        if let ClickEvent::OpenUrl { url } = self {
+            normalize_url(url)?;
+            if let Some(char) = url.trim().chars().find(|char| !is_url_char(*char)) {
+                return Err(UrlError::IllegalCharacter(char));
+            }
+        }
+        Ok(())
+    }
+    /// The kind of action this event triggers.
+    pub fn action(&self) -> ClickAction {
+        match self {
+            ClickEvent::OpenUrl { .. } => ClickAction::OpenUrl,
+            ClickEvent::RunCommand { .. } => ClickAction::RunCommand,
+            ClickEvent::SuggestCommand { .. } => ClickAction::SuggestCommand,
+            ClickEvent::ChangePage { .. } => ClickAction::ChangePage,
+            ClickEvent::CopyToClipboard { .. } => ClickAction::CopyToClipboard,
+            ClickEvent::ShowDialog { .. } => ClickAction::ShowDialog,
+            #[cfg(feature = "custom")]
+            ClickEvent::Custom(_) => ClickAction::Custom,
+        }
+    }
+}
+
+/// Written as the action and its value, like `run_command(/spawn)`.
+impl Display for ClickEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ClickEvent::OpenUrl { url } => write!(f, "open_url({url})"),
+            ClickEvent::RunCommand { command } => write!(f, "run_command({command})"),
+            ClickEvent::SuggestCommand { command } => write!(f, "suggest_command({command})"),
+            ClickEvent::ChangePage { page } => write!(f, "change_page({page})"),
+            ClickEvent::CopyToClipboard { value } => write!(f, "copy_to_clipboard({value})"),
+            ClickEvent::ShowDialog {
+                dialog: DialogRef::Id(id),
+            } => write!(f, "show_dialog({id})"),
+            ClickEvent::ShowDialog {
+                dialog: DialogRef::Inline(_),
+            } => write!(f, "show_dialog(inline)"),
+            #[cfg(feature = "custom")]
+            ClickEvent::Custom(data) => write!(f, "custom({})", data.id),
+        }
+    }
+}
+
+/// Why a url can't be opened by the game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UrlError {
+    /// The url doesn't start with `http://` or `https://`.
+    UnsupportedScheme(String),
+    /// There's nothing after the scheme.
+    MissingHost,
+    /// The url has a control character, like a new line.
+    ControlCharacter(char),
+    /// The url has a character that needs to be percent-encoded first.
+    IllegalCharacter(char),
+}
+impl Display for UrlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            UrlError::UnsupportedScheme(scheme) => {
+                write!(
+                    f,
+                    "The url scheme \"{scheme}\" can't be opened, only http and https can."
+                )
+            }
+            UrlError::MissingHost => write!(f, "The url has no host."),
+            UrlError::ControlCharacter(char) => {
+                write!(f, "The url has the control character {:?}.", char)
+            }
+            UrlError::IllegalCharacter(char) => {
+                write!(
+                    f,
+                    "The url has the character {:?}, which must be percent-encoded.",
+                    char
+                )
+            }
+        }
+    }
+}
+impl std::error::Error for UrlError {}
+
+fn is_url_char(char: char) -> bool {
+    char.is_ascii_alphanumeric() || "-._~:/?#[]@!$&'()*+,;=%".contains(char)
+}
+
+fn normalize_url(url: &str) -> Result<String, UrlError> {
+    let url = url.trim();
+    if let Some(char) = url.chars().find(|char| char.is_control()) {
+        return Err(UrlError::ControlCharacter(char));
+    }
+    let Some((scheme, rest)) = url.split_once("://") else {
+        let scheme = url.split_once(':').map(|(scheme, _)| scheme).unwrap_or("");
+        return Err(UrlError::UnsupportedScheme(scheme.to_string()));
+    };
+    let scheme = scheme.to_ascii_lowercase();
+    if scheme != "http" && scheme != "https" {
+        return Err(UrlError::UnsupportedScheme(scheme));
+    }
+    if rest.is_empty() || rest.starts_with(['/', '?', '#']) {
+        return Err(UrlError::MissingHost);
+    }
+    let mut normalized = format!("{scheme}://");
+    for char in rest.chars() {
+        if is_url_char(char) {
+            normalized.push(char);
+            continue;
+        }
+        let mut bytes = [0; 4];
+        for byte in char.encode_utf8(&mut bytes).bytes() {
+            normalized.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    Ok(normalized)
+}
+
+/// The action of a [ClickEvent] without its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClickAction {
+    OpenUrl,
+    RunCommand,
+    SuggestCommand,
+    ChangePage,
+    CopyToClipboard,
+    ShowDialog,
+    #[cfg(feature = "custom")]
+    Custom,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -129,6 +317,10 @@ impl ClickEvent {
 #[cfg_attr(feature = "serde", serde(tag = "action", rename_all = "snake_case"))]
 pub enum HoverEvent {
     ShowText {
+        #[cfg_attr(
+            feature = "serde",
+            serde(deserialize_with = "crate::serde::deserialize_hover_text")
+        )]
         value: Box<TextComponent>,
     },
     ShowItem {
@@ -191,4 +383,117 @@ impl HoverEvent {
             uuid,
         }
     }
+    /// Same as [show_entity](HoverEvent::show_entity) with a name, which is usually known.
+    /// ## Example
+    /// ```
+    /// # use text_components::{Modifier, format::Color, interactivity::HoverEvent};
+    /// # struct Pig {
+    /// #     uuid: uuid::Uuid,
+    /// # }
+    /// # let pig = Pig { uuid: uuid::Uuid::nil() };
+    /// HoverEvent::show_entity_named("minecraft:pig", pig.uuid, "Babe".color(Color::Gold));
+    /// ```
+    pub fn show_entity_named<T: Into<Cow<'static, str>>, R: Into<TextComponent>>(
+        id: T,
+        uuid: Uuid,
+        name: R,
+    ) -> Self {
+        HoverEvent::show_entity(id, uuid, Some(name))
+    }
+    /// The kind of action this event triggers.
+    pub fn action(&self) -> HoverAction {
+        match self {
+            HoverEvent::ShowText { .. } => HoverAction::ShowText,
+            HoverEvent::ShowItem { .. } => HoverAction::ShowItem,
+            HoverEvent::ShowEntity { .. } => HoverAction::ShowEntity,
+        }
+    }
+}
+
+/// Written as the action and its value, like `show_item(minecraft:diamond x3)`.
+impl Display for HoverEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            HoverEvent::ShowText { value } => write!(f, "show_text({:?})", value.to_string()),
+            HoverEvent::ShowItem {
+                id,
+                count,
+                components,
+            } => {
+                write!(f, "show_item({id}")?;
+                if let Some(count) = count {
+                    write!(f, " x{count}")?;
+                }
+                if let Some(components) = components {
+                    write!(f, " {components}")?;
+                }
+                write!(f, ")")
+            }
+            HoverEvent::ShowEntity { name, id, uuid } => {
+                write!(f, "show_entity({id} {uuid}")?;
+                if let Some(name) = name {
+                    write!(f, " {:?}", name.to_string())?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// The action of a [HoverEvent] without its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HoverAction {
+    ShowText,
+    ShowItem,
+    ShowEntity,
+}
+
+/// Changing the events anywhere in a tree, like the commands of components forwarded
+/// between servers.
+impl TextComponent {
+    /// Calls `f` with every click event of the tree: the ones of the children, translation
+    /// arguments, separators, hover texts and the names of shown entities.\
+    /// The dialogs of [show_dialog](ClickEvent::ShowDialog) events aren't entered.
+    pub fn map_click_events(&mut self, mut f: impl FnMut(&mut ClickEvent)) {
+        self.visit_click_events(&mut f);
+    }
+
+    fn visit_click_events(&mut self, f: &mut impl FnMut(&mut ClickEvent)) {
+        if let Some(click) = &mut self.interactions.click {
+            f(click);
+        }
+        self.for_each_nested_mut(|nested| nested.visit_click_events(f));
+    }
+
+    /// Same as [map_click_events](TextComponent::map_click_events) for the hover events,
+    /// the components a hover shows are visited after `f` changed it.
+    pub fn map_hover_events(&mut self, mut f: impl FnMut(&mut HoverEvent)) {
+        self.visit_hover_events(&mut f);
+    }
+
+    fn visit_hover_events(&mut self, f: &mut impl FnMut(&mut HoverEvent)) {
+        if let Some(hover) = &mut self.interactions.hover {
+            f(hover);
+        }
+        self.for_each_nested_mut(|nested| nested.visit_hover_events(f));
+    }
+
+    /// Replaces the command of every [run_command](ClickEvent::RunCommand) and
+    /// [suggest_command](ClickEvent::SuggestCommand) event of the tree with the one `f` gives.
+    /// ## Example
+    /// ```
+    /// # use text_components::{Modifier, interactivity::ClickEvent};
+    /// # let mut component = "Spawn".click_event(ClickEvent::run_command("/spawn"));
+    /// // "/spawn" runs "/server lobby:spawn"
+    /// component.rewrite_commands(|command| format!("/server lobby:{}", &command[1..]));
+    /// ```
+    pub fn rewrite_commands(&mut self, mut f: impl FnMut(&str) -> String) {
+        self.map_click_events(|click| {
+            if let ClickEvent::RunCommand { command } | ClickEvent::SuggestCommand { command } =
+                click
+            {
+                *command = Cow::Owned(f(command));
+            }
+        });
+    }
 }
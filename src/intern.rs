@@ -0,0 +1,219 @@
+//! Sharing of the strings repeated across many components (fonts, translation keys and
+//! insertions), so parsing thousands of similar components doesn't keep thousands of copies.
+//! ```
+//! # use text_components::{TextComponent, intern::ComponentInterner};
+//! # let lines = [r#"{text:"Hi"}"#, r#""Hi""#];
+//! let interner = ComponentInterner::new();
+//! let components = lines
+//!     .iter()
+//!     .map(|line| TextComponent::from_snbt_interned(line, &interner))
+//!     .collect::<Result<Vec<_>, _>>()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+use crate::{TextComponent, content::Content};
+use std::{
+    borrow::{Borrow, Cow},
+    collections::HashSet,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+    sync::{Arc, Mutex},
+};
+
+/// An immutable string that is either static, shared with an [Arc] or owned.\
+/// It behaves like a [str] and is made from the same types as a `Cow<'static, str>`.
+#[derive(Clone)]
+pub struct SharedStr(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    Static(&'static str),
+    Shared(Arc<str>),
+    Owned(String),
+}
+
+impl SharedStr {
+    pub const fn from_static(text: &'static str) -> Self {
+        SharedStr(Repr::Static(text))
+    }
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Static(text) => text,
+            Repr::Shared(text) => text,
+            Repr::Owned(text) => text,
+        }
+    }
+    /// Whether the text is shared with other [SharedStr]s.
+    pub fn is_shared(&self) -> bool {
+        matches!(self.0, Repr::Shared(_))
+    }
+}
+
+impl Default for SharedStr {
+    fn default() -> Self {
+        SharedStr::from_static("")
+    }
+}
+
+impl Deref for SharedStr {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SharedStr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for SharedStr {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for SharedStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+impl Eq for SharedStr {}
+impl PartialEq<str> for SharedStr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+impl PartialEq<&str> for SharedStr {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Hash for SharedStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl fmt::Debug for SharedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for SharedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl From<&'static str> for SharedStr {
+    fn from(value: &'static str) -> Self {
+        SharedStr::from_static(value)
+    }
+}
+impl From<String> for SharedStr {
+    fn from(value: String) -> Self {
+        SharedStr(Repr::Owned(value))
+    }
+}
+impl From<Arc<str>> for SharedStr {
+    fn from(value: Arc<str>) -> Self {
+        SharedStr(Repr::Shared(value))
+    }
+}
+impl From<Cow<'static, str>> for SharedStr {
+    fn from(value: Cow<'static, str>) -> Self {
+        match value {
+            Cow::Borrowed(text) => SharedStr::from_static(text),
+            Cow::Owned(text) => SharedStr::from(text),
+        }
+    }
+}
+impl From<SharedStr> for Cow<'static, str> {
+    fn from(value: SharedStr) -> Self {
+        match value.0 {
+            Repr::Static(text) => Cow::Borrowed(text),
+            _ => Cow::Owned(value.into()),
+        }
+    }
+}
+impl From<SharedStr> for String {
+    fn from(value: SharedStr) -> Self {
+        match value.0 {
+            Repr::Owned(text) => text,
+            _ => value.as_str().to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for SharedStr {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for SharedStr {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(SharedStr::from)
+    }
+}
+
+/// A cache of the strings given to it, every [SharedStr] it gives for the same text points
+/// to the same allocation.\
+/// Clones share the cache, the strings are freed once the interner and every component
+/// using them are dropped.
+#[derive(Debug, Clone, Default)]
+pub struct ComponentInterner {
+    strings: Arc<Mutex<HashSet<Arc<str>>>>,
+}
+
+impl ComponentInterner {
+    pub fn new() -> Self {
+        ComponentInterner::default()
+    }
+
+    /// Gives the cached copy of the text, adding it if it wasn't cached yet.
+    pub fn intern(&self, text: &str) -> SharedStr {
+        let mut strings = self.strings.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(shared) = strings.get(text) {
+            return SharedStr::from(shared.clone());
+        }
+        let shared: Arc<str> = Arc::from(text);
+        strings.insert(shared.clone());
+        SharedStr::from(shared)
+    }
+
+    /// Amount of different strings cached.
+    pub fn len(&self) -> usize {
+        self.strings.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Replaces the fonts, translation keys and insertions of the component and every
+    /// nested one with their cached copies.
+    pub fn intern_component(&self, component: &mut TextComponent) {
+        let intern = |text: &mut SharedStr| {
+            if let Repr::Owned(owned) = &text.0 {
+                *text = self.intern(owned);
+            }
+        };
+        if let Some(font) = &mut component.format.font {
+            intern(font);
+        }
+        if let Some(insertion) = &mut component.interactions.insertion {
+            intern(insertion);
+        }
+        if let Content::Translate(message) = &mut component.content {
+            intern(&mut message.key);
+        }
+        component.for_each_nested_mut(|nested| self.intern_component(nested));
+    }
+}
@@ -0,0 +1,284 @@
+//! Conversions with azalea-chat's [FormattedText].
+use super::InteropError;
+#[cfg(feature = "custom")]
+use crate::custom::{CustomData, Payload};
+use crate::{
+    TextComponent,
+    content::Content,
+    format::{Color, Format},
+    interactivity::{ClickEvent, HoverEvent, Interactivity},
+    translation::TranslatedMessage,
+};
+use azalea_chat::{
+    FormattedText,
+    base_component::BaseComponent,
+    click_event::ClickEvent as AzaleaClick,
+    hover_event::HoverEvent as AzaleaHover,
+    style::{Style, TextColor},
+    text_component::TextComponent as AzaleaText,
+    translatable_component::{PrimitiveOrComponent, TranslatableComponent},
+};
+use std::borrow::Cow;
+
+impl TryFrom<&TextComponent> for FormattedText {
+    type Error = InteropError;
+
+    fn try_from(component: &TextComponent) -> Result<Self, Self::Error> {
+        super::check_nested(component, &|component| {
+            let interactions = &component.interactions;
+            match &component.content {
+                Content::Text { .. } | Content::Translate(_) => {}
+                content => return Err(InteropError::Content(content.kind())),
+            }
+            match &interactions.click {
+                Some(ClickEvent::ShowDialog { .. }) => {
+                    return Err(InteropError::ClickEvent("show_dialog"));
+                }
+                #[cfg(feature = "custom")]
+                Some(ClickEvent::Custom(_)) => return Err(InteropError::ClickEvent("custom")),
+                _ => {}
+            }
+            match &interactions.hover {
+                Some(HoverEvent::ShowItem { .. }) => Err(InteropError::HoverEvent("show_item")),
+                Some(HoverEvent::ShowEntity { .. }) => Err(InteropError::HoverEvent("show_entity")),
+                _ => Ok(()),
+            }
+        })?;
+        Ok(to_lossy(component))
+    }
+}
+impl TryFrom<TextComponent> for FormattedText {
+    type Error = InteropError;
+
+    fn try_from(component: TextComponent) -> Result<Self, Self::Error> {
+        FormattedText::try_from(&component)
+    }
+}
+
+/// Converts the component even if azalea can't hold all of it, see the
+/// [module docs](super#azalea-chat) for what's lost.
+pub fn to_lossy(component: &TextComponent) -> FormattedText {
+    let base = BaseComponent {
+        siblings: component.children.iter().map(to_lossy).collect(),
+        style: Box::new(style(&component.format, &component.interactions)),
+    };
+    match &component.content {
+        Content::Text { text } => FormattedText::Text(AzaleaText {
+            base,
+            text: text.to_string(),
+        }),
+        Content::Translate(message) => FormattedText::Translatable(TranslatableComponent {
+            base,
+            key: message.key.to_string(),
+            fallback: message.fallback.as_deref().map(str::to_string),
+            args: message
+                .args
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .map(|arg| PrimitiveOrComponent::FormattedText(to_lossy(arg)))
+                .collect(),
+        }),
+        _ => FormattedText::Text(AzaleaText {
+            base,
+            text: super::fallback_text(component),
+        }),
+    }
+}
+
+fn style(format: &Format, interactions: &Interactivity) -> Style {
+    Style::new()
+        .color(format.color.as_ref().map(text_color))
+        .shadow_color(format.shadow_color.map(|color| color as u32))
+        .bold(format.bold)
+        .italic(format.italic)
+        .underlined(format.underlined)
+        .strikethrough(format.strikethrough)
+        .obfuscated(format.obfuscated)
+        .click_event(interactions.click.as_ref().and_then(to_click_event))
+        .hover_event(interactions.hover.as_ref().and_then(to_hover_event))
+        .insertion(interactions.insertion.as_deref().map(str::to_string))
+        .font(format.font.as_deref().map(str::to_string))
+}
+
+fn text_color(color: &Color) -> TextColor {
+    TextColor {
+        value: {
+            let (r, g, b) = color.as_rgb();
+            u32::from_be_bytes([0, r, g, b])
+        },
+        name: match color {
+            Color::Rgb(..) => None,
+            named => Some(named.to_string()),
+        },
+    }
+}
+
+fn to_click_event(event: &ClickEvent) -> Option<AzaleaClick> {
+    Some(match event {
+        ClickEvent::OpenUrl { url } => AzaleaClick::OpenUrl {
+            url: url.to_string(),
+        },
+        ClickEvent::RunCommand { command } => AzaleaClick::RunCommand {
+            command: command.to_string(),
+        },
+        ClickEvent::SuggestCommand { command } => AzaleaClick::SuggestCommand {
+            command: command.to_string(),
+        },
+        ClickEvent::ChangePage { page } => AzaleaClick::ChangePage { page: *page },
+        ClickEvent::CopyToClipboard { value } => AzaleaClick::CopyToClipboard {
+            value: value.to_string(),
+        },
+        _ => return None,
+    })
+}
+
+fn to_hover_event(event: &HoverEvent) -> Option<AzaleaHover> {
+    match event {
+        HoverEvent::ShowText { value }
+        | HoverEvent::ShowEntity {
+            name: Some(value), ..
+        } => Some(AzaleaHover::ShowText {
+            value: Box::new(to_lossy(value)),
+        }),
+        _ => None,
+    }
+}
+
+impl TryFrom<&FormattedText> for TextComponent {
+    type Error = InteropError;
+
+    fn try_from(text: &FormattedText) -> Result<Self, Self::Error> {
+        check(text)?;
+        Ok(from_lossy(text))
+    }
+}
+impl TryFrom<FormattedText> for TextComponent {
+    type Error = InteropError;
+
+    fn try_from(text: FormattedText) -> Result<Self, Self::Error> {
+        TextComponent::try_from(&text)
+    }
+}
+
+/// The first thing in the text or the texts nested in it this crate can't hold.
+fn check(text: &FormattedText) -> Result<(), InteropError> {
+    let base = text.get_base();
+    match &base.style.click_event {
+        Some(AzaleaClick::OpenFile { .. }) => return Err(InteropError::ClickEvent("open_file")),
+        Some(AzaleaClick::ShowDialog {}) => return Err(InteropError::ClickEvent("show_dialog")),
+        #[cfg(not(feature = "custom"))]
+        Some(AzaleaClick::Custom { .. }) => return Err(InteropError::ClickEvent("custom")),
+        _ => {}
+    }
+    match &base.style.hover_event {
+        Some(AzaleaHover::ShowText { value }) => check(value)?,
+        Some(AzaleaHover::ShowItem {}) => return Err(InteropError::HoverEvent("show_item")),
+        Some(AzaleaHover::ShowEntity { .. }) => {
+            return Err(InteropError::HoverEvent("show_entity"));
+        }
+        None => {}
+    }
+    if let FormattedText::Translatable(translation) = text {
+        for arg in &translation.args {
+            if let PrimitiveOrComponent::FormattedText(arg) = arg {
+                check(arg)?;
+            }
+        }
+    }
+    base.siblings.iter().try_for_each(check)
+}
+
+/// Converts the text even if this crate can't hold all of it, see the
+/// [module docs](super#azalea-chat) for what's lost.
+pub fn from_lossy(text: &FormattedText) -> TextComponent {
+    let content = match text {
+        FormattedText::Text(text) => Content::Text {
+            text: Cow::Owned(text.text.clone()),
+        },
+        FormattedText::Translatable(translation) => Content::Translate(TranslatedMessage {
+            key: translation.key.clone().into(),
+            fallback: translation.fallback.clone().map(Cow::Owned),
+            args: (!translation.args.is_empty())
+                .then(|| translation.args.iter().map(argument).collect()),
+        }),
+    };
+    let base = text.get_base();
+    let style = &base.style;
+    let mut component = TextComponent::new();
+    component.content = content;
+    component.children = base.siblings.iter().map(from_lossy).collect();
+    let format = &mut component.format;
+    format.color = style.color.as_ref().map(color);
+    format.font = style.font.clone().map(Into::into);
+    format.bold = style.bold;
+    format.italic = style.italic;
+    format.underlined = style.underlined;
+    format.strikethrough = style.strikethrough;
+    format.obfuscated = style.obfuscated;
+    format.shadow_color = style.shadow_color.map(i64::from);
+    let interactions = &mut component.interactions;
+    interactions.insertion = style.insertion.clone().map(Into::into);
+    interactions.click = style.click_event.as_ref().and_then(from_click_event);
+    interactions.hover = style.hover_event.as_ref().and_then(from_hover_event);
+    component
+}
+
+fn argument(argument: &PrimitiveOrComponent) -> TextComponent {
+    match argument {
+        PrimitiveOrComponent::Boolean(value) => TextComponent::plain(value.to_string()),
+        PrimitiveOrComponent::Short(value) => TextComponent::plain(value.to_string()),
+        PrimitiveOrComponent::Integer(value) => TextComponent::plain(value.to_string()),
+        PrimitiveOrComponent::Long(value) => TextComponent::plain(value.to_string()),
+        PrimitiveOrComponent::Float(value) => TextComponent::plain(value.to_string()),
+        PrimitiveOrComponent::Double(value) => TextComponent::plain(value.to_string()),
+        PrimitiveOrComponent::String(value) => TextComponent::plain(value.clone()),
+        PrimitiveOrComponent::FormattedText(text) => from_lossy(text),
+    }
+}
+
+fn color(color: &TextColor) -> Color {
+    color
+        .name
+        .as_deref()
+        .and_then(Color::from_name)
+        .unwrap_or_else(|| {
+            let [_, r, g, b] = color.value.to_be_bytes();
+            Color::Rgb(r, g, b)
+        })
+}
+
+fn from_click_event(event: &AzaleaClick) -> Option<ClickEvent> {
+    Some(match event {
+        AzaleaClick::OpenUrl { url } => ClickEvent::OpenUrl {
+            url: Cow::Owned(url.clone()),
+        },
+        AzaleaClick::RunCommand { command } => ClickEvent::RunCommand {
+            command: Cow::Owned(command.clone()),
+        },
+        AzaleaClick::SuggestCommand { command } => ClickEvent::SuggestCommand {
+            command: Cow::Owned(command.clone()),
+        },
+        AzaleaClick::ChangePage { page } => ClickEvent::ChangePage { page: *page },
+        AzaleaClick::CopyToClipboard { value } => ClickEvent::CopyToClipboard {
+            value: Cow::Owned(value.clone()),
+        },
+        #[cfg(feature = "custom")]
+        AzaleaClick::Custom { id, .. } => ClickEvent::Custom(CustomData {
+            id: Cow::Owned(id.clone()),
+            payload: Payload::Empty,
+        }),
+        _ => return None,
+    })
+}
+
+fn from_hover_event(event: &AzaleaHover) -> Option<HoverEvent> {
+    match event {
+        AzaleaHover::ShowText { value } | AzaleaHover::ShowEntity { name: value, .. } => {
+            Some(HoverEvent::ShowText {
+                value: Box::new(from_lossy(value)),
+            })
+        }
+        AzaleaHover::ShowItem {} => None,
+    }
+}
@@ -0,0 +1,108 @@
+//! Conversions between [TextComponent] and the component types of other crates, made
+//! field by field instead of through JSON.
+//!
+//! Enable `interop-azalea` for [azalea-chat](https://docs.rs/azalea-chat)'s `FormattedText`
+//! or `interop-valence` for [valence_text](https://docs.rs/valence_text)'s `Text`, both
+//! convert with [TryFrom] in each direction:
+//! ```
+//! # #[cfg(feature = "interop-valence")]
+//! # fn main() -> Result<(), text_components::interop::InteropError> {
+//! # use text_components::{Modifier, TextComponent};
+//! let component = "Hello".bold(true);
+//! let text = valence_text::Text::try_from(&component)?;
+//! assert_eq!(TextComponent::try_from(&text)?, component);
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "interop-valence"))]
+//! # fn main() {}
+//! ```
+//! The other crates can't hold everything a [TextComponent] can, so a component with
+//! something they'd lose, in itself or in its nested components, fails with an
+//! [InteropError]. To convert it anyway, the `to_lossy` and `from_lossy` functions of
+//! [azalea] and [valence] replace the contents they don't have with the placeholder
+//! [TextBuilder](crate::fmt::TextBuilder) shows for them, like `[Object: item/diamond]`,
+//! and drop the events and style values they don't have.
+//!
+//! Inline translations and unknown fields can't be converted to either crate.
+//! ### azalea-chat
+//! Only texts and translations are kept, keybinds and resolvables included become
+//! placeholders. `show_item` and `show_entity` hovers and `show_dialog` and `custom`
+//! click events are lost both ways, `open_file` click events when converting back, as are
+//! `custom` ones without the `custom` feature. Translation arguments that are numbers or
+//! booleans become texts.
+//! ### valence_text
+//! Written in the 1.20.1 format, objects and custom contents become placeholders, as do
+//! NBT storages that aren't resource locations. Translation fallbacks, shadow colors,
+//! fonts other than `default`, `uniform` and `alt`, the `show_dialog` and `custom` click
+//! events and the hovers whose item or entity type aren't resource locations are lost.\
+//! Going back, `open_file` click events, score `value`s, the `reset` color and
+//! `show_entity` hovers without an entity type are lost. Item tags are carried as the
+//! item's `components`.
+#[cfg(feature = "interop-azalea")]
+pub mod azalea;
+#[cfg(feature = "interop-valence")]
+pub mod valence;
+
+use crate::{TextComponent, resolving::NoResolutor};
+use std::fmt::{self, Display, Formatter};
+
+/// What the other crate can't hold, found by a strict conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InteropError {
+    /// A content, like `object`.
+    Content(&'static str),
+    /// A click event action, like `show_dialog`.
+    ClickEvent(&'static str),
+    /// A hover event action, like `show_item`.
+    HoverEvent(&'static str),
+    /// Any other value, like `shadow_color`.
+    Value(&'static str),
+}
+impl Display for InteropError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InteropError::Content(kind) => write!(f, "The {kind} content can't be converted."),
+            InteropError::ClickEvent(action) => {
+                write!(f, "The {action} click event can't be converted.")
+            }
+            InteropError::HoverEvent(action) => {
+                write!(f, "The {action} hover event can't be converted.")
+            }
+            InteropError::Value(value) => write!(f, "The {value} can't be converted."),
+        }
+    }
+}
+impl std::error::Error for InteropError {}
+
+/// Runs `check` on the component and every component nested in it, stopping at the
+/// first error.
+fn check_nested(
+    component: &TextComponent,
+    check: &impl Fn(&TextComponent) -> Result<(), InteropError>,
+) -> Result<(), InteropError> {
+    check(component)?;
+    if component.inline_translations.is_some() {
+        return Err(InteropError::Value("inline translations"));
+    }
+    #[cfg(feature = "unknown-fields")]
+    if component.unknown_fields.is_some() {
+        return Err(InteropError::Value("unknown fields"));
+    }
+    let mut result = Ok(());
+    component.for_each_nested(|nested| {
+        if result.is_ok() {
+            result = check_nested(nested, check);
+        }
+    });
+    result
+}
+
+/// The text a content the other crate can't hold is replaced with, keeping the rest of
+/// the component.
+fn fallback_text(component: &TextComponent) -> String {
+    TextComponent {
+        content: component.content.clone(),
+        ..TextComponent::new()
+    }
+    .to_plain(&NoResolutor)
+}
@@ -0,0 +1,427 @@
+//! Conversions with valence_text's [Text].
+use super::InteropError;
+use crate::{
+    TextComponent,
+    content::{Content, NbtSource, Resolvable},
+    format::Color,
+    interactivity::{ClickEvent, HoverEvent},
+    translation::TranslatedMessage,
+};
+use std::borrow::Cow;
+use valence_ident::Ident;
+use valence_text::{
+    ClickEvent as ValenceClick, Color as ValenceColor, Font, HoverEvent as ValenceHover,
+    ScoreboardValueContent, Text, TextContent, TextInner,
+    color::{NamedColor, RgbColor},
+};
+
+impl TryFrom<&TextComponent> for Text {
+    type Error = InteropError;
+
+    fn try_from(component: &TextComponent) -> Result<Self, Self::Error> {
+        super::check_nested(component, &|component| {
+            match &component.content {
+                Content::Translate(TranslatedMessage {
+                    fallback: Some(_), ..
+                }) => return Err(InteropError::Value("translation fallback")),
+                Content::Resolvable(Resolvable::NBT {
+                    source: NbtSource::Storage(storage),
+                    ..
+                }) if Ident::new(storage.clone()).is_err() => {
+                    return Err(InteropError::Value("NBT storage"));
+                }
+                content if text_content(content).is_none() => {
+                    return Err(InteropError::Content(content.kind()));
+                }
+                _ => {}
+            }
+            let format = &component.format;
+            if format.shadow_color.is_some() {
+                return Err(InteropError::Value("shadow color"));
+            }
+            if format
+                .font
+                .as_deref()
+                .is_some_and(|name| font(name).is_none())
+            {
+                return Err(InteropError::Value("font"));
+            }
+            let interactions = &component.interactions;
+            match &interactions.click {
+                Some(ClickEvent::ShowDialog { .. }) => {
+                    return Err(InteropError::ClickEvent("show_dialog"));
+                }
+                #[cfg(feature = "custom")]
+                Some(ClickEvent::Custom(_)) => return Err(InteropError::ClickEvent("custom")),
+                _ => {}
+            }
+            match &interactions.hover {
+                Some(HoverEvent::ShowItem { id, .. }) if Ident::new(id.clone()).is_err() => {
+                    Err(InteropError::Value("item id"))
+                }
+                Some(HoverEvent::ShowEntity { id, .. }) if Ident::new(id.clone()).is_err() => {
+                    Err(InteropError::Value("entity type"))
+                }
+                _ => Ok(()),
+            }
+        })?;
+        Ok(to_lossy(component))
+    }
+}
+impl TryFrom<TextComponent> for Text {
+    type Error = InteropError;
+
+    fn try_from(component: TextComponent) -> Result<Self, Self::Error> {
+        Text::try_from(&component)
+    }
+}
+
+/// Converts the component even if valence can't hold all of it, see the
+/// [module docs](super#valence_text) for what's lost.
+pub fn to_lossy(component: &TextComponent) -> Text {
+    let content = text_content(&component.content).unwrap_or_else(|| TextContent::Text {
+        text: Cow::Owned(super::fallback_text(component)),
+    });
+    let format = &component.format;
+    let interactions = &component.interactions;
+    let mut text = Text::default();
+    *text = TextInner {
+        content,
+        color: format.color.as_ref().map(valence_color),
+        font: format.font.as_deref().and_then(font),
+        bold: format.bold,
+        italic: format.italic,
+        underlined: format.underlined,
+        strikethrough: format.strikethrough,
+        obfuscated: format.obfuscated,
+        insertion: interactions
+            .insertion
+            .as_deref()
+            .map(|insertion| Cow::Owned(insertion.to_string())),
+        click_event: interactions.click.as_ref().and_then(to_click_event),
+        hover_event: interactions.hover.as_ref().and_then(to_hover_event),
+        extra: component.children.iter().map(to_lossy).collect(),
+    };
+    text
+}
+
+/// The content in valence, [None] if it has no equivalent.
+fn text_content(content: &Content) -> Option<TextContent> {
+    Some(match content {
+        Content::Text { text } => TextContent::Text { text: text.clone() },
+        Content::Translate(message) => TextContent::Translate {
+            translate: Cow::Owned(message.key.to_string()),
+            with: message
+                .args
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .map(to_lossy)
+                .collect(),
+        },
+        Content::Keybind { keybind } => TextContent::Keybind {
+            keybind: keybind.clone(),
+        },
+        Content::Resolvable(Resolvable::Scoreboard {
+            selector,
+            objective,
+        }) => TextContent::ScoreboardValue {
+            score: ScoreboardValueContent {
+                name: selector.clone(),
+                objective: objective.clone(),
+                value: None,
+            },
+        },
+        Content::Resolvable(Resolvable::Entity {
+            selector,
+            separator,
+        }) => TextContent::EntityNames {
+            selector: selector.clone(),
+            separator: Some(to_lossy(separator)),
+        },
+        Content::Resolvable(Resolvable::NBT {
+            path,
+            interpret,
+            separator,
+            source,
+        }) => {
+            let nbt = path.clone();
+            let interpret = *interpret;
+            let separator = Some(to_lossy(separator));
+            match source {
+                NbtSource::Entity(entity) => TextContent::EntityNbt {
+                    entity: entity.clone(),
+                    nbt,
+                    interpret,
+                    separator,
+                },
+                NbtSource::Block(block) => TextContent::BlockNbt {
+                    block: block.clone(),
+                    nbt,
+                    interpret,
+                    separator,
+                },
+                NbtSource::Storage(storage) => TextContent::StorageNbt {
+                    storage: Ident::new(storage.clone()).ok()?,
+                    nbt,
+                    interpret,
+                    separator,
+                },
+            }
+        }
+        _ => return None,
+    })
+}
+
+fn valence_color(color: &Color) -> ValenceColor {
+    match *color {
+        Color::Rgb(r, g, b) => ValenceColor::rgb(r, g, b),
+        ref named => NamedColor::try_from(named.to_string().as_str())
+            .map(ValenceColor::Named)
+            .unwrap_or_else(|_| {
+                let (r, g, b) = named.as_rgb();
+                ValenceColor::rgb(r, g, b)
+            }),
+    }
+}
+
+fn font(font: &str) -> Option<Font> {
+    match font {
+        "minecraft:default" => Some(Font::Default),
+        "minecraft:uniform" => Some(Font::Uniform),
+        "minecraft:alt" => Some(Font::Alt),
+        _ => None,
+    }
+}
+
+fn to_click_event(event: &ClickEvent) -> Option<ValenceClick> {
+    Some(match event {
+        ClickEvent::OpenUrl { url } => ValenceClick::OpenUrl(url.clone()),
+        ClickEvent::RunCommand { command } => ValenceClick::RunCommand(command.clone()),
+        ClickEvent::SuggestCommand { command } => ValenceClick::SuggestCommand(command.clone()),
+        ClickEvent::ChangePage { page } => ValenceClick::ChangePage(*page),
+        ClickEvent::CopyToClipboard { value } => ValenceClick::CopyToClipboard(value.clone()),
+        _ => return None,
+    })
+}
+
+fn to_hover_event(event: &HoverEvent) -> Option<ValenceHover> {
+    Some(match event {
+        HoverEvent::ShowText { value } => ValenceHover::ShowText(to_lossy(value)),
+        HoverEvent::ShowItem {
+            id,
+            count,
+            components,
+        } => ValenceHover::ShowItem {
+            id: Ident::new(id.clone()).ok()?,
+            count: *count,
+            tag: components.clone().unwrap_or_default(),
+        },
+        HoverEvent::ShowEntity { name, id, uuid } => ValenceHover::ShowEntity {
+            id: *uuid,
+            kind: Ident::new(id.clone()).ok(),
+            name: name.as_deref().map(to_lossy),
+        },
+    })
+}
+
+impl TryFrom<&Text> for TextComponent {
+    type Error = InteropError;
+
+    fn try_from(text: &Text) -> Result<Self, Self::Error> {
+        check(text)?;
+        Ok(from_lossy(text))
+    }
+}
+impl TryFrom<Text> for TextComponent {
+    type Error = InteropError;
+
+    fn try_from(text: Text) -> Result<Self, Self::Error> {
+        TextComponent::try_from(&text)
+    }
+}
+
+/// The first thing in the text or the texts nested in it this crate can't hold.
+fn check(text: &Text) -> Result<(), InteropError> {
+    if let Some(ValenceColor::Reset) = text.color {
+        return Err(InteropError::Value("reset color"));
+    }
+    if let Some(ValenceClick::OpenFile(_)) = text.click_event {
+        return Err(InteropError::ClickEvent("open_file"));
+    }
+    match &text.hover_event {
+        Some(ValenceHover::ShowText(value)) => check(value)?,
+        Some(ValenceHover::ShowEntity {
+            kind: Some(_),
+            name,
+            ..
+        }) => name.iter().try_for_each(check)?,
+        Some(ValenceHover::ShowEntity { kind: None, .. }) => {
+            return Err(InteropError::Value("entity type"));
+        }
+        _ => {}
+    }
+    match &text.content {
+        TextContent::ScoreboardValue { score } if score.value.is_some() => {
+            return Err(InteropError::Value("score value"));
+        }
+        TextContent::Translate { with, .. } => with.iter().try_for_each(check)?,
+        TextContent::EntityNames { separator, .. }
+        | TextContent::BlockNbt { separator, .. }
+        | TextContent::EntityNbt { separator, .. }
+        | TextContent::StorageNbt { separator, .. } => separator.iter().try_for_each(check)?,
+        _ => {}
+    }
+    text.extra.iter().try_for_each(check)
+}
+
+/// Converts the text even if this crate can't hold all of it, see the
+/// [module docs](super#valence_text) for what's lost.
+pub fn from_lossy(text: &Text) -> TextComponent {
+    let mut component = TextComponent::new();
+    component.content = content(&text.content);
+    component.children = text.extra.iter().map(from_lossy).collect();
+    let format = &mut component.format;
+    format.color = text.color.map(color);
+    format.font = text.font.map(|font| font_name(font).into());
+    format.bold = text.bold;
+    format.italic = text.italic;
+    format.underlined = text.underlined;
+    format.strikethrough = text.strikethrough;
+    format.obfuscated = text.obfuscated;
+    let interactions = &mut component.interactions;
+    interactions.insertion = text
+        .insertion
+        .as_deref()
+        .map(|insertion| insertion.to_string().into());
+    interactions.click = text.click_event.as_ref().and_then(from_click_event);
+    interactions.hover = text.hover_event.as_ref().and_then(from_hover_event);
+    component
+}
+
+fn content(content: &TextContent) -> Content {
+    let separator = |separator: &Option<Text>, default: fn() -> Box<TextComponent>| {
+        separator
+            .as_ref()
+            .map_or_else(default, |separator| Box::new(from_lossy(separator)))
+    };
+    let nbt = |path: &Cow<'static, str>,
+               interpret: &Option<bool>,
+               custom: &Option<Text>,
+               source: NbtSource| {
+        Content::Resolvable(Resolvable::NBT {
+            path: path.clone(),
+            interpret: *interpret,
+            separator: separator(custom, Resolvable::nbt_separator),
+            source,
+        })
+    };
+    match content {
+        TextContent::Text { text } => Content::Text { text: text.clone() },
+        TextContent::Translate { translate, with } => Content::Translate(TranslatedMessage {
+            key: translate.to_string().into(),
+            fallback: None,
+            args: (!with.is_empty()).then(|| with.iter().map(from_lossy).collect()),
+        }),
+        TextContent::ScoreboardValue { score } => Content::Resolvable(Resolvable::Scoreboard {
+            selector: score.name.clone(),
+            objective: score.objective.clone(),
+        }),
+        TextContent::EntityNames {
+            selector,
+            separator: custom,
+        } => Content::Resolvable(Resolvable::Entity {
+            selector: selector.clone(),
+            separator: separator(custom, Resolvable::entity_separator),
+        }),
+        TextContent::Keybind { keybind } => Content::Keybind {
+            keybind: keybind.clone(),
+        },
+        TextContent::BlockNbt {
+            block,
+            nbt: path,
+            interpret,
+            separator,
+        } => nbt(path, interpret, separator, NbtSource::Block(block.clone())),
+        TextContent::EntityNbt {
+            entity,
+            nbt: path,
+            interpret,
+            separator,
+        } => nbt(
+            path,
+            interpret,
+            separator,
+            NbtSource::Entity(entity.clone()),
+        ),
+        TextContent::StorageNbt {
+            storage,
+            nbt: path,
+            interpret,
+            separator,
+        } => nbt(
+            path,
+            interpret,
+            separator,
+            NbtSource::Storage(Cow::Owned(storage.as_str().to_string())),
+        ),
+    }
+}
+
+fn color(color: ValenceColor) -> Color {
+    match color {
+        ValenceColor::Reset => Color::White,
+        ValenceColor::Named(named) => Color::from_name(named.name()).unwrap_or(Color::White),
+        ValenceColor::Rgb(RgbColor { r, g, b }) => Color::Rgb(r, g, b),
+    }
+}
+
+fn font_name(font: Font) -> &'static str {
+    match font {
+        Font::Default => "minecraft:default",
+        Font::Uniform => "minecraft:uniform",
+        Font::Alt => "minecraft:alt",
+    }
+}
+
+fn from_click_event(event: &ValenceClick) -> Option<ClickEvent> {
+    Some(match event {
+        ValenceClick::OpenUrl(url) => ClickEvent::OpenUrl { url: url.clone() },
+        ValenceClick::RunCommand(command) => ClickEvent::RunCommand {
+            command: command.clone(),
+        },
+        ValenceClick::SuggestCommand(command) => ClickEvent::SuggestCommand {
+            command: command.clone(),
+        },
+        ValenceClick::ChangePage(page) => ClickEvent::ChangePage { page: *page },
+        ValenceClick::CopyToClipboard(value) => ClickEvent::CopyToClipboard {
+            value: value.clone(),
+        },
+        ValenceClick::OpenFile(_) => return None,
+    })
+}
+
+fn from_hover_event(event: &ValenceHover) -> Option<HoverEvent> {
+    match event {
+        ValenceHover::ShowText(text) => Some(HoverEvent::show_text(from_lossy(text))),
+        ValenceHover::ShowItem { id, count, tag } => Some(HoverEvent::ShowItem {
+            id: Cow::Owned(id.as_str().to_string()),
+            count: *count,
+            components: (!tag.is_empty()).then(|| tag.clone()),
+        }),
+        ValenceHover::ShowEntity {
+            id: uuid,
+            kind: Some(kind),
+            name,
+        } => Some(HoverEvent::ShowEntity {
+            name: name.as_ref().map(|name| Box::new(from_lossy(name))),
+            id: Cow::Owned(kind.as_str().to_string()),
+            uuid: *uuid,
+        }),
+        ValenceHover::ShowEntity {
+            kind: None, name, ..
+        } => name
+            .as_ref()
+            .map(|name| HoverEvent::show_text(from_lossy(name))),
+    }
+}
@@ -1,32 +1,66 @@
 #[cfg(feature = "custom")]
 use crate::custom::CustomContent;
+#[cfg(feature = "unknown-fields")]
+use crate::unknown::UnknownFields;
 use crate::{
-    content::{Content, NbtSource, Object, ObjectPlayer, Resolvable},
+    content::{Atlas, Content, NbtSource, Object, ObjectPlayer, Resolvable, is_resource_location},
     format::{Color, Format},
     interactivity::{ClickEvent, HoverEvent, Interactivity},
-    translation::TranslatedMessage,
+    intern::SharedStr,
+    translation::{TranslatedMessage, TranslationTable},
 };
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::Arc};
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 #[cfg(feature = "build")]
 pub mod build;
+#[cfg(feature = "callbacks")]
+pub mod callback;
+pub mod command;
+#[cfg(feature = "binary-serde")]
+pub mod compact;
+pub mod containers;
 pub mod content;
 #[cfg(feature = "custom")]
 pub mod custom;
+pub mod dialog;
+pub mod diff;
 pub mod fmt;
+pub mod fmt_helpers;
 pub mod format;
 pub mod interactivity;
+pub mod intern;
+#[cfg(any(feature = "interop-azalea", feature = "interop-valence"))]
+pub mod interop;
 #[cfg(feature = "nbt")]
 pub mod nbt;
 pub mod parse;
+pub mod path;
+#[cfg(feature = "pool")]
+pub mod pool;
 pub mod resolving;
+pub mod sanitize;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod stats;
+pub mod template;
+#[cfg(feature = "test-util")]
+pub mod testutil;
 pub mod translation;
+#[cfg(feature = "unknown-fields")]
+pub mod unknown;
+pub mod validate;
 
 /// A recursive rich text format with interaction capabilities.
 /// ### Styling
 /// Any type implementing [Into]<[TextComponent]> can be styled into a\
 /// TextComponent using the trait [format::Modifier] like this:
 /// ```
+/// # use text_components::{Modifier, TextComponent, format::Color, translation::Translation};
+/// # const TRANSLATION_TEST: Translation<0> = Translation("translation.test.none");
 /// // Plain text component
 /// TextComponent::plain("Plain text").color(Color::Red);
 /// // String slice
@@ -37,50 +71,78 @@ pub mod translation;
 /// ### Interactivity
 /// Text that can be inserted into the chat with Shift+Click:
 /// ```
-/// component.insert("Insert text here!");
+/// # use text_components::{Modifier, TextComponent};
+/// # let component = TextComponent::new();
+/// component.insertion("Insert text here!");
 /// ```
 /// Data that can be displayed by hovering the text:
 /// ```
+/// # use text_components::{Modifier, TextComponent, interactivity::HoverEvent};
+/// # let component = TextComponent::new();
 /// component.hover_event(HoverEvent::show_text("Click me!"));
 /// ```
 /// A event triggered when the user clicks the text:
 /// ```
+/// # use text_components::{Modifier, TextComponent, interactivity::ClickEvent};
+/// # let component = TextComponent::new();
 /// component.click_event(
 ///     ClickEvent::open_url("https://www.minecraft.net/")
 /// );
 /// ```
 /// ### Children
 /// ```
-/// component.add_child("Child 1");
-/// component.add_children(vec![
-///     "Child 2".color("#bf00ff"),
+/// # use text_components::{Modifier, TextComponent, format::Color};
+/// # let component = TextComponent::new();
+/// # const CHILD_THREE: &str = "Child 3";
+/// component.add_child("Child 1").add_children(vec![
+///     "Child 2".color(Color::Rgb(0xBF, 0x00, 0xFF)),
 ///     CHILD_THREE.italic(true),
 /// ]);
 /// ```
 /// ### Display
 /// [TextComponent] implements [Display](std::fmt::Display) for easy logging, this means that\
 /// `format!("{}", component)` will return the text component as plain text resolved by the default resolver,\
-/// if you want a pretty text `{:p}` can be used instead for this proupose.
+/// if you want a pretty text `{:p}` can be used instead for this proupose (needs the "pretty" feature, on by default).
 /// Using this methods is not recommended when the component will be sent to a player.
+/// ### Threads
+/// [TextComponent] and every type inside it are [Send] and [Sync], so prepared components
+/// can be shared in an [Arc] and built from many threads at once, each with its own resolutor.
 /// ### Building
 /// A [TextComponent] needs to be built into another format before sending it\
 /// anywhere, which requires a [TextResolutor](crate::build::TextResolutor)
 /// and a [BuildTarget](crate::build::BuildTarget):
 /// ```
+/// # use text_components::{TextComponent, fmt::TextBuilder, resolving::NoResolutor};
+/// # let (resolutor, target) = (&NoResolutor, TextBuilder);
 /// let component = TextComponent::plain("Component to build");
 /// component.build(resolutor, target);
 /// ```
 /// If the "serde" feature is enabled a [TextComponent] can be serialized with:
 /// ```
+/// # #[cfg(feature = "serde")]
+/// # fn main() -> serde_json::Result<()> {
+/// # use serde::Serialize;
+/// # use text_components::{TextComponent, resolving::NoResolutor};
+/// # let resolutor = &NoResolutor;
+/// # let serializer = &mut serde_json::Serializer::new(Vec::new());
 /// let component = TextComponent::plain("Component to build");
 /// component.resolve(resolutor).serialize(serializer);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
 /// ```
+/// The derived serde implementation targets vanilla's JSON shape, for binary formats
+/// like bincode or postcard enable the "binary-serde" feature and use [compact](crate::compact).
 /// A function can be attached to a [BuildTarget](crate::build::BuildTarget) for easy access:
 /// ```
+/// # use text_components::{TextComponent, resolving::NoResolutor};
+/// # let resolutor = &NoResolutor;
 /// let component = TextComponent::plain("Component to build");
 /// // Builds with TextBuilder a plain String
 /// component.to_plain(resolutor);
-/// // Build with RichTextBuilder a decorated String
+/// // Build with PrettyTextBuilder a decorated String ("pretty" feature)
+/// # #[cfg(feature = "pretty")]
 /// component.to_pretty(resolutor);
 /// ```
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -90,15 +152,96 @@ pub struct TextComponent {
     pub content: Content,
     #[cfg_attr(
         feature = "serde",
-        serde(skip_serializing_if = "Vec::is_empty", rename = "extra", default)
+        serde(
+            skip_serializing_if = "Vec::is_empty",
+            rename = "extra",
+            serialize_with = "crate::serde::serialize_children",
+            deserialize_with = "crate::serde::deserialize_children",
+            default
+        )
     )]
     pub children: Vec<TextComponent>,
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub format: Format,
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub interactions: Interactivity,
+    /// Translations used before the resolutor's ones, see
+    /// [with_inline_translations](TextComponent::with_inline_translations).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub inline_translations: Option<Arc<TranslationTable>>,
+    /// Keys this crate doesn't know, kept when parsing inside
+    /// [preserve_unknown](crate::unknown::preserve_unknown).
+    #[cfg(feature = "unknown-fields")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            flatten,
+            serialize_with = "crate::serde::serialize_unknown",
+            deserialize_with = "crate::serde::deserialize_unknown"
+        )
+    )]
+    pub unknown_fields: Option<Box<UnknownFields>>,
 }
 
+/// The order components are written in by [NbtBuilder](crate::nbt::NbtBuilder), and so
+/// as SNBT, and by [to_canonical_json](TextComponent::to_canonical_json): the content,
+/// the values of events and player profiles, the children and lastly the style, in the
+/// order the game's own codecs write it.
+pub const CANONICAL_KEY_ORDER: [&str; 48] = [
+    // Content
+    "type",
+    "action",
+    "id",
+    "text",
+    "translate",
+    "fallback",
+    "with",
+    "score",
+    "name",
+    "objective",
+    "selector",
+    "keybind",
+    "nbt",
+    "interpret",
+    "block",
+    "entity",
+    "storage",
+    "source",
+    "object",
+    "atlas",
+    "sprite",
+    "player",
+    "hat",
+    "separator",
+    // Events and player profiles
+    "url",
+    "command",
+    "page",
+    "value",
+    "dialog",
+    "count",
+    "components",
+    "uuid",
+    "texture",
+    "properties",
+    "signature",
+    "payload",
+    // Children
+    "extra",
+    // Style
+    "color",
+    "shadow_color",
+    "bold",
+    "italic",
+    "underlined",
+    "strikethrough",
+    "obfuscated",
+    "click_event",
+    "hover_event",
+    "insertion",
+    "font",
+];
+
 // Constructors
 impl TextComponent {
     /// Creates an empty [TextComponent], useful to make it the parent.
@@ -110,12 +253,34 @@ impl TextComponent {
             children: vec![],
             format: Format::new(),
             interactions: Interactivity::new(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
+        }
+    }
+
+    /// Creates an empty [TextComponent] with room for `capacity` children, for parents
+    /// whose amount of children is known beforehand, like the lines of a leaderboard.
+    /// ## Example
+    /// ```
+    /// # use text_components::TextComponent;
+    /// # let scores = [("Steve", 5), ("Alex", 3)];
+    /// let mut board = TextComponent::with_children_capacity(scores.len());
+    /// for (name, score) in scores {
+    ///     board.children.push(format!("{name}: {score}\n").into());
+    /// }
+    /// ```
+    pub fn with_children_capacity(capacity: usize) -> Self {
+        TextComponent {
+            children: Vec::with_capacity(capacity),
+            ..TextComponent::new()
         }
     }
 
     /// Creates a [TextComponent] of a plain text at compile time.
     /// ## Example
     /// ```
+    /// # use text_components::TextComponent;
     /// // Results in "Test Component"
     /// TextComponent::const_plain("Test Component");
     /// ```
@@ -127,17 +292,22 @@ impl TextComponent {
             children: vec![],
             format: Format::new(),
             interactions: Interactivity::new(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
         }
     }
 
     /// Creates a [TextComponent] of a plain text.
     /// ## Example
     /// ```
+    /// # use text_components::TextComponent;
     /// // Results in "Test Component"
     /// TextComponent::plain("Test Component");
     /// ```
     /// This is equivalent of doing:
     /// ```
+    /// # use text_components::TextComponent;
     /// let component: TextComponent = "Test Component".into();
     /// ```
     pub fn plain<T: Into<Cow<'static, str>>>(text: T) -> Self {
@@ -146,6 +316,9 @@ impl TextComponent {
             children: vec![],
             format: Format::new(),
             interactions: Interactivity::new(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
         }
     }
 
@@ -154,19 +327,27 @@ impl TextComponent {
     /// ## Examples
     /// #### For a translation without arguments:
     /// ```
+    /// # use text_components::{TextComponent, translation::Translation};
+    /// # const ITEM_MINECRAFT_DIAMOND_SWORD: Translation<0> = Translation("item.minecraft.diamond_sword");
     /// // Results in "Diamond Sword"
-    /// TextComponent::translated(ITEM_MINECRAFT_DIAMOMD_SWORD.msg());
+    /// TextComponent::translated(ITEM_MINECRAFT_DIAMOND_SWORD.msg());
     /// ```
     /// This is equivalent of doing:
     /// ```
-    /// let component: TextComponent = ITEM_MINECRAFT_DIAMOND_SWORD.into()
+    /// # use text_components::{TextComponent, translation::Translation};
+    /// # const ITEM_MINECRAFT_DIAMOND_SWORD: Translation<0> = Translation("item.minecraft.diamond_sword");
+    /// let component: TextComponent = (&ITEM_MINECRAFT_DIAMOND_SWORD).into();
     /// ```
     /// or
     /// ```
-    /// ITEM_MINECRAFT_DIAMOND_SWORD.msg().component()
+    /// # use text_components::translation::Translation;
+    /// # const ITEM_MINECRAFT_DIAMOND_SWORD: Translation<0> = Translation("item.minecraft.diamond_sword");
+    /// ITEM_MINECRAFT_DIAMOND_SWORD.msg().component();
     /// ```
     /// #### For a translation with 2 arguments:
     /// ```
+    /// # use text_components::{TextComponent, translation::Translation};
+    /// # const DEATH_ATTACK_INDIRECT_MAGIC: Translation<2> = Translation("death.attack.indirectMagic");
     /// // Results in "The Rust compiler was killed by you using magic".
     /// TextComponent::translated(DEATH_ATTACK_INDIRECT_MAGIC.message(["The Rust compiler", "you"]));
     /// ```
@@ -176,36 +357,96 @@ impl TextComponent {
             children: vec![],
             format: Format::new(),
             interactions: Interactivity::new(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
         }
     }
+    /// Creates a [TextComponent] translating a key only known at runtime, without arguments.\
+    /// For keys known at compile time a [Translation](crate::translation::Translation) is preferred.
+    /// ## Example
+    /// ```
+    /// # use text_components::TextComponent;
+    /// // Results in "Done"
+    /// TextComponent::translated_key(format!("gui.{}", "done"));
+    /// ```
+    pub fn translated_key<T: Into<SharedStr>>(key: T) -> Self {
+        TextComponent::translated_with(key, Vec::<TextComponent>::new())
+    }
+    /// Creates a [TextComponent] translating a key only known at runtime with the given
+    /// arguments, in order.\
+    /// For keys known at compile time a [Translation](crate::translation::Translation) is preferred,
+    /// it checks the amount of arguments.
+    /// ## Example
+    /// ```
+    /// # use text_components::TextComponent;
+    /// // Results in "Steve was slain by Alex"
+    /// TextComponent::translated_with("death.attack.player", ["Steve", "Alex"]);
+    /// ```
+    pub fn translated_with<T: Into<SharedStr>, A: Into<TextComponent>>(
+        key: T,
+        args: impl IntoIterator<Item = A>,
+    ) -> Self {
+        let args = args.into_iter().map(Into::into).collect::<Box<[_]>>();
+        TextComponent::translated(TranslatedMessage {
+            key: key.into(),
+            fallback: None,
+            args: (!args.is_empty()).then_some(args),
+        })
+    }
 
     /// Creates a [TextComponent] with an image from a resource pack in it.\
     /// * `sprite` - The path to the texture, starting from the atlas\
-    /// * `atlas` - The atlas where the texture belongs, if it's [None] will default to "minecraft:blocks"
+    /// * `atlas` - The [Atlas] where the texture belongs, [Atlas::Default] leaves it to the game (blocks)
     /// ## Example
     /// ```
+    /// # use text_components::{TextComponent, content::Atlas};
     /// // Displays the Diamond Sword sprite
-    /// TextComponent::atlas("item/diamond_sword", Some("minecraft:items"));
+    /// TextComponent::atlas("item/diamond_sword", Atlas::ITEMS);
     /// ```
-    pub fn atlas<T: Into<Cow<'static, str>>, R: Into<Cow<'static, str>>>(
-        sprite: T,
-        atlas: Option<R>,
-    ) -> Self {
+    /// The sprite isn't checked, to reject invalid ones use [try_atlas](TextComponent::try_atlas).
+    pub fn atlas<T: Into<Cow<'static, str>>, A: Into<Atlas>>(sprite: T, atlas: A) -> Self {
         TextComponent {
             content: Content::Object(Object::Atlas {
-                atlas: atlas.map(Into::into),
+                atlas: atlas.into().into_id(),
                 sprite: sprite.into(),
             }),
             children: Vec::new(),
             format: Format::new(),
             interactions: Interactivity::new(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
         }
     }
+    /// Same as [atlas](TextComponent::atlas) with [Atlas::Default].
+    /// ## Example
+    /// ```
+    /// # use text_components::TextComponent;
+    /// // Displays the Stone texture
+    /// TextComponent::sprite("block/stone");
+    /// ```
+    pub fn sprite<T: Into<Cow<'static, str>>>(sprite: T) -> Self {
+        TextComponent::atlas(sprite, Atlas::Default)
+    }
+    /// Same as [atlas](TextComponent::atlas), but returns [None] if the
+    /// sprite or the atlas aren't valid resource locations.
+    pub fn try_atlas<T: Into<Cow<'static, str>>, A: Into<Atlas>>(
+        sprite: T,
+        atlas: A,
+    ) -> Option<Self> {
+        let (sprite, atlas) = (sprite.into(), atlas.into());
+        if !is_resource_location(&sprite) || !atlas.is_valid() {
+            return None;
+        }
+        Some(TextComponent::atlas(sprite, atlas))
+    }
     /// Creates a [TextComponent] with the head of a player in it.
     /// * `player` - A [ObjectPlayer] containing the required info
     /// * `hat` - Whether to display the hat layer
     /// ## Example
     /// ```
+    /// # use text_components::{TextComponent, content::ObjectPlayer};
     /// // Displays the head of Jeb_
     /// TextComponent::player_head(ObjectPlayer::name("Jeb_"), true);
     /// ```
@@ -215,6 +456,9 @@ impl TextComponent {
             children: Vec::new(),
             format: Format::new(),
             interactions: Interactivity::new(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
         }
     }
 
@@ -224,6 +468,7 @@ impl TextComponent {
     /// * `objective` - The internal name of the scoreboard to show
     /// ## Example
     /// ```
+    /// # use text_components::TextComponent;
     /// // Displays the 'deaths' scoreboard value of the nearest player
     /// TextComponent::scoreboard("@p", "deaths");
     /// ```
@@ -240,6 +485,9 @@ impl TextComponent {
             children: Vec::new(),
             format: Format::new(),
             interactions: Interactivity::new(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
         }
     }
 
@@ -248,6 +496,7 @@ impl TextComponent {
     /// * `separator` - The component separating multiple entities. If [None] will be a grey comma
     /// ## Example
     /// ```
+    /// # use text_components::TextComponent;
     /// // Displays all the players name separated by a space
     /// TextComponent::entity("@a", Some(" ".into()));
     /// ```
@@ -264,9 +513,26 @@ impl TextComponent {
             children: Vec::new(),
             format: Format::new(),
             interactions: Interactivity::new(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
         }
     }
 
+    /// Same as [entity](TextComponent::entity) with a separator, which may be
+    /// anything turning into a component.
+    /// ```
+    /// # use text_components::TextComponent;
+    /// TextComponent::entity_sep_str("@a", " ");
+    /// ```
+    /// #### Needs [resolution](TextComponent::resolve)
+    pub fn entity_sep_str<T: Into<Cow<'static, str>>, S: Into<TextComponent>>(
+        selector: T,
+        separator: S,
+    ) -> Self {
+        TextComponent::entity(selector, Some(separator.into()))
+    }
+
     /// Creates a [TextComponent] containing the data of a Nbt tag.
     /// * `path` - The Nbt path of the tag to show
     /// * `source` - A [NbtSource] indicating where to search the nbt tag
@@ -274,6 +540,7 @@ impl TextComponent {
     /// * `separator` - The component separating multiple Nbt tags. If [None] will be a comma
     /// ## Example
     /// ```
+    /// # use text_components::{TextComponent, content::NbtSource};
     /// // Displays the nearest player health
     /// TextComponent::nbt("Health", NbtSource::entity("@p"), false, None);
     /// ```
@@ -297,9 +564,28 @@ impl TextComponent {
             children: Vec::new(),
             format: Format::new(),
             interactions: Interactivity::new(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
         }
     }
 
+    /// Same as [nbt](TextComponent::nbt) with a separator, which may be
+    /// anything turning into a component.
+    /// ```
+    /// # use text_components::{TextComponent, content::NbtSource};
+    /// TextComponent::nbt_sep_str("Inventory", NbtSource::entity("@p"), false, " | ");
+    /// ```
+    /// #### Needs [resolution](TextComponent::resolve)
+    pub fn nbt_sep_str<T: Into<Cow<'static, str>>, S: Into<TextComponent>>(
+        path: T,
+        source: NbtSource,
+        interpret: bool,
+        separator: S,
+    ) -> Self {
+        TextComponent::nbt(path, source, interpret, Some(separator.into()))
+    }
+
     #[cfg(feature = "custom")]
     pub fn custom<T: CustomContent>(content: T) -> TextComponent {
         TextComponent {
@@ -307,7 +593,29 @@ impl TextComponent {
             children: vec![],
             format: Format::new(),
             interactions: Interactivity::new(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
+        }
+    }
+}
+
+// Capacity
+impl TextComponent {
+    /// Reserves room for at least `additional` more children.
+    pub fn reserve_children(&mut self, additional: usize) {
+        self.children.reserve(additional);
+    }
+
+    /// Shrinks the children of every component in the tree to fit, and the properties
+    /// of player heads.\
+    /// Useful for components kept in caches long after being built or normalized.
+    pub fn shrink(&mut self) {
+        self.children.shrink_to_fit();
+        if let Content::Object(Object::Player { player, .. }) = &mut self.content {
+            player.properties.shrink_to_fit();
         }
+        self.for_each_nested_mut(TextComponent::shrink);
     }
 }
 
@@ -335,7 +643,7 @@ pub trait Modifier {
     /// Appends a [vec] of [Into]<[TextComponent]> as children of this component
     fn add_children<T: Into<TextComponent>>(self, children: Vec<T>) -> Self::Output;
     /// Sets the Shift+Click chat insertion string
-    fn insertion<T: Into<Cow<'static, str>>>(self, insertion: T) -> Self::Output;
+    fn insertion<T: Into<SharedStr>>(self, insertion: T) -> Self::Output;
     /// Sets the [ClickEvent] for this component
     fn click_event(self, click: ClickEvent) -> Self::Output;
     /// Sets the [HoverEvent] for this component
@@ -343,11 +651,12 @@ pub trait Modifier {
     /// Sets the [Color] of this component
     /// * If you want to use a hex code check [color_hex](TextComponent::color_hex)
     fn color(self, color: Color) -> Self::Output;
-    /// Sets the color of this component from a 6 digit hex color
+    /// Sets the color of this component from a `#rrggbb` or `#rgb` hex color, left as it
+    /// was if the color isn't valid, see [Color::parse_hex]
     /// * If you want to use a predefined color check [color](TextComponent::color)
     fn color_hex(self, color: &str) -> Self::Output;
-    /// Sets the font used to display this component
-    fn font<F: Into<Cow<'static, str>>>(self, font: F) -> Self::Output;
+    /// Sets the font used to display this component, see [Format::font]
+    fn font<F: Into<SharedStr>>(self, font: F) -> Self::Output;
     /// Makes this component **bold**
     fn bold(self, value: bool) -> Self::Output;
     /// Makes this component *italic*
@@ -360,8 +669,20 @@ pub trait Modifier {
     fn obfuscated(self, value: bool) -> Self::Output;
     /// Sets the shadow color of this component
     fn shadow_color(self, a: u8, r: u8, g: u8, b: u8) -> Self::Output;
-    /// Sets all the format of this component to the default
+    /// Sets all the format of this component to the default, see [Format::reset]
     fn reset(self) -> Self::Output;
+    /// Makes this component take nothing from its parent without serializing every value,
+    /// see [Format::no_inherit]
+    fn no_inherit(self) -> Self::Output;
+    /// Sets the values set in `format`, the others are kept.\
+    /// Meant for the styles of a theme, made with [style!].
+    /// ## Example
+    /// ```
+    /// # use text_components::{Modifier, format::Format, style};
+    /// const ERROR: Format = style!(color: "#ff0044", bold);
+    /// "Failed".style(ERROR);
+    /// ```
+    fn style(self, format: Format) -> Self::Output;
 }
 
 impl<T: Into<TextComponent> + Sized> Modifier for T {
@@ -373,13 +694,13 @@ impl<T: Into<TextComponent> + Sized> Modifier for T {
     }
     fn add_children<F: Into<TextComponent>>(self, children: Vec<F>) -> TextComponent {
         let mut component = self.into();
-        for child in children {
-            component.children.push(child.into());
-        }
+        component
+            .children
+            .extend(children.into_iter().map(Into::into));
         component
     }
 
-    fn insertion<R: Into<Cow<'static, str>>>(self, insertion: R) -> TextComponent {
+    fn insertion<R: Into<SharedStr>>(self, insertion: R) -> TextComponent {
         let mut component = self.into();
         component.interactions.insertion = Some(insertion.into());
         component
@@ -405,7 +726,7 @@ impl<T: Into<TextComponent> + Sized> Modifier for T {
         component.format = component.format.color_hex(color);
         component
     }
-    fn font<F: Into<Cow<'static, str>>>(self, font: F) -> TextComponent {
+    fn font<F: Into<SharedStr>>(self, font: F) -> TextComponent {
         let mut component = self.into();
         component.format = component.format.font(font);
         component
@@ -445,6 +766,17 @@ impl<T: Into<TextComponent> + Sized> Modifier for T {
         component.format = component.format.reset();
         component
     }
+    fn no_inherit(self) -> TextComponent {
+        let mut component = self.into();
+        component.format.no_inherit = true;
+        component
+    }
+
+    fn style(self, format: Format) -> TextComponent {
+        let mut component = self.into();
+        component.format = format.overlay(&component.format);
+        component
+    }
 }
 
 impl<'a> Modifier for &'a mut TextComponent {
@@ -455,13 +787,11 @@ impl<'a> Modifier for &'a mut TextComponent {
     }
 
     fn add_children<T: Into<TextComponent>>(self, children: Vec<T>) -> &'a mut TextComponent {
-        for child in children {
-            self.children.push(child.into());
-        }
+        self.children.extend(children.into_iter().map(Into::into));
         self
     }
 
-    fn insertion<T: Into<Cow<'static, str>>>(self, insertion: T) -> &'a mut TextComponent {
+    fn insertion<T: Into<SharedStr>>(self, insertion: T) -> &'a mut TextComponent {
         self.interactions.insertion = Some(insertion.into());
         self
     }
@@ -488,8 +818,8 @@ impl<'a> Modifier for &'a mut TextComponent {
         self
     }
 
-    fn font<F: Into<Cow<'static, str>>>(self, font: F) -> &'a mut TextComponent {
-        self.format.font = Some(font.into());
+    fn font<F: Into<SharedStr>>(self, font: F) -> &'a mut TextComponent {
+        self.format.font = Some(format::namespaced_font(font.into()));
         self
     }
 
@@ -525,7 +855,7 @@ impl<'a> Modifier for &'a mut TextComponent {
 
     fn reset(self) -> &'a mut TextComponent {
         self.format.color = Some(Color::White);
-        self.format.font = Some(Cow::Borrowed("minecraft:default"));
+        self.format.font = Some(SharedStr::from_static("minecraft:default"));
         self.format.bold = Some(false);
         self.format.italic = Some(false);
         self.format.underlined = Some(false);
@@ -534,4 +864,14 @@ impl<'a> Modifier for &'a mut TextComponent {
         self.format.shadow_color = None;
         self
     }
+
+    fn no_inherit(self) -> &'a mut TextComponent {
+        self.format.no_inherit = true;
+        self
+    }
+
+    fn style(self, format: Format) -> &'a mut TextComponent {
+        self.format = format.overlay(&self.format);
+        self
+    }
 }
@@ -1,10 +1,14 @@
+#![deny(clippy::unwrap_used, clippy::indexing_slicing)]
 #[cfg(feature = "custom")]
 use crate::custom::Payload;
+#[cfg(feature = "unknown-fields")]
+use crate::unknown::UnknownValue;
 use crate::{
     Modifier, TextComponent,
-    content::{Content, Object},
-    format::{Color, Format},
+    content::{Content, NbtSource, Object, Resolvable},
+    format::{Color, Format, ShadowColorFormat},
     interactivity::{ClickEvent, HoverEvent, Interactivity},
+    parse::escape_snbt,
     resolving::{BuildTarget, NoResolutor, TextResolutor},
 };
 use simdnbt::{
@@ -13,7 +17,29 @@ use simdnbt::{
 };
 use std::ops::Deref as _;
 
-pub struct NbtBuilder;
+/// Builds components as NBT, with the same keys the game reads from data packs.\
+/// Scores, selectors and NBT contents the resolutor leaves unresolved are written in
+/// their vanilla form, so commands like `tellraw` let the game resolve them.\
+/// Keys are written in the [canonical order](crate::CANONICAL_KEY_ORDER).\
+/// [NbtBuilder::new] writes shadow colors packed, like the game.
+/// ```
+/// # use text_components::{Modifier, format::ShadowColorFormat, nbt::NbtBuilder, resolving::NoResolutor};
+/// let component = "Hi".shadow_color(255, 255, 0, 0);
+/// // {text:"Hi",shadow_color:-65536}
+/// component.build(&NoResolutor, NbtBuilder::new());
+/// // {text:"Hi",shadow_color:[1.0f,0.0f,0.0f,1.0f]}
+/// component.build(&NoResolutor, NbtBuilder::new().shadow_colors(ShadowColorFormat::Floats));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NbtBuilder {
+    shadow_colors: ShadowColorFormat,
+}
+
+impl Default for NbtBuilder {
+    fn default() -> Self {
+        NbtBuilder::new()
+    }
+}
 
 impl BuildTarget for NbtBuilder {
     type Result = NbtTag;
@@ -22,173 +48,383 @@ impl BuildTarget for NbtBuilder {
         resolutor: &R,
         component: &TextComponent,
     ) -> NbtTag {
+        NbtTag::Compound(self.build_compound(resolutor, component))
+    }
+}
+
+impl NbtBuilder {
+    /// The builder writing components like the game.
+    pub const fn new() -> Self {
+        NbtBuilder {
+            shadow_colors: ShadowColorFormat::Packed,
+        }
+    }
+    /// Writes shadow colors in the given form.
+    pub const fn shadow_colors(mut self, format: ShadowColorFormat) -> Self {
+        self.shadow_colors = format;
+        self
+    }
+
+    /// [Builds](TextComponent::build) the component and appends it to `buffer` as binary
+    /// NBT, the nameless tag chat packets send, so a buffer kept between builds doesn't need
+    /// to grow again.
+    pub fn write_into<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+        component: &TextComponent,
+        buffer: &mut Vec<u8>,
+    ) {
+        component.build(resolutor, *self).write(buffer);
+    }
+
+    /// Components are always compounds, so children and arguments need no unwrapping.
+    fn build_compound<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+        component: &TextComponent,
+    ) -> NbtCompound {
         let mut items = vec![];
         component.content.to_compound(&mut items, self, resolutor);
-        component.format.to_compound(&mut items);
-        component.interactions.to_compound(resolutor, &mut items);
+        // The game rejects components without a content key, even as a child or argument
+        if items.is_empty() {
+            items.push(("text".into(), NbtTag::String("".into())));
+        }
         if !component.children.is_empty() {
             items.push((
                 "extra".into(),
-                NbtTag::List(NbtList::Compound(
-                    component
-                        .children
-                        .iter()
-                        .map(|nbt| {
-                            self.build_component(resolutor, nbt)
-                                .into_compound()
-                                .unwrap()
-                        })
-                        .collect(),
-                )),
+                self.build_list(resolutor, &component.children),
             ));
         }
-        NbtTag::Compound(NbtCompound::from_values(items))
+        component.format.to_compound(&mut items, self.shadow_colors);
+        component.interactions.to_compound(resolutor, &mut items);
+        // The font is the last key of the style, after the interactions
+        if let Some(font) = &component.format.font {
+            items.push(("font".into(), NbtTag::String(font.as_str().into())));
+        }
+        #[cfg(feature = "unknown-fields")]
+        for (key, value) in component.unknown_fields.iter().flat_map(|fields| &fields.0) {
+            #[allow(irrefutable_let_patterns)]
+            if let UnknownValue::Nbt(tag) = value {
+                items.push((key.as_str().into(), tag.clone()));
+            }
+        }
+        NbtCompound::from_values(items)
+    }
+
+    /// Children and arguments are written as strings when all of them are
+    /// [plain](TextComponent::is_plain), like in JSON. NBT lists hold a single type, so
+    /// one styled component makes them all compounds.
+    fn build_list<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+        components: &[TextComponent],
+    ) -> NbtTag {
+        // An empty string isn't read back as a child
+        let texts = components
+            .iter()
+            .map(|component| match component.as_text() {
+                Some(text) if component.is_plain() && !text.is_empty() => Some(text.into()),
+                _ => None,
+            })
+            .collect::<Option<Vec<Mutf8String>>>();
+        match texts {
+            Some(texts) => NbtTag::List(NbtList::String(texts)),
+            None => NbtTag::List(NbtList::Compound(
+                components
+                    .iter()
+                    .map(|component| self.build_compound(resolutor, component))
+                    .collect(),
+            )),
+        }
+    }
+}
+
+/// Colors and layout of [TextComponent::nbt_display].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NbtDisplayStyle {
+    pub key: Color,
+    pub string: Color,
+    pub number: Color,
+    /// Color of the type suffixes (`b`, `s`, `l`, `f`, `d`) and array prefixes (`B;`, `I;`, `L;`).
+    pub suffix: Color,
+    /// Color of the brackets, colons and commas.
+    pub punctuation: Color,
+    /// Compounds and lists with more entries than this are written one entry per line,
+    /// [None] keeps everything in a single line.
+    pub inline_threshold: Option<usize>,
+    /// Spaces added per level of nesting on multiline compounds and lists.
+    pub indent: usize,
+    /// Compounds and lists nested deeper than this are shortened to `{...}` and `[...]`.
+    pub max_depth: Option<usize>,
+    /// Entries shown of each compound, list or array before the rest is replaced with `...`.
+    pub max_length: Option<usize>,
+}
+
+impl Default for NbtDisplayStyle {
+    /// The palette of the vanilla `/data get` output, in a single line.
+    fn default() -> Self {
+        NbtDisplayStyle {
+            key: Color::Aqua,
+            string: Color::Green,
+            number: Color::Gold,
+            suffix: Color::Red,
+            punctuation: Color::White,
+            inline_threshold: None,
+            indent: 2,
+            max_depth: None,
+            max_length: None,
+        }
     }
 }
 
 impl TextComponent {
-    pub fn nbt_display<T: Into<NbtTag>>(tag: T) -> Self {
-        let tag = tag.into();
-        match tag {
-            NbtTag::Byte(n) => n
-                .to_string()
-                .color(Color::Gold)
-                .add_child("b".color(Color::Red)),
-            NbtTag::Short(n) => n
-                .to_string()
-                .color(Color::Gold)
-                .add_child("s".color(Color::Red)),
-            NbtTag::Int(n) => n.to_string().color(Color::Gold),
-            NbtTag::Long(n) => n
-                .to_string()
-                .color(Color::Gold)
-                .add_child("l".color(Color::Red)),
-            NbtTag::Float(n) => format!("{:?}", n)
-                .color(Color::Gold)
-                .add_child("f".color(Color::Red)),
-            NbtTag::Double(n) => format!("{:?}", n)
-                .color(Color::Gold)
-                .add_child("d".color(Color::Red)),
-            NbtTag::ByteArray(items) => {
-                let component = "["
-                    .color(Color::White)
-                    .add_children(vec!["B".color(Color::Red), "; ".into()]);
-                let mut children = vec![];
-                for (i, n) in items.iter().enumerate() {
-                    children.push(
-                        n.to_string()
-                            .color(Color::Gold)
-                            .add_child("b".color(Color::Red)),
-                    );
-                    if i + 1 != items.len() {
-                        children.push(", ".into());
-                    }
-                }
-                children.push(TextComponent::plain("]"));
-                component.add_children(children)
-            }
-            NbtTag::String(string) => {
-                "\"".add_children(vec![string.to_string().color(Color::Green), "\"".into()])
-            }
-            NbtTag::List(nbt_list) => {
-                let component = "[".color(Color::White);
-                let mut children = vec![];
-                for (i, tag) in nbt_list.as_nbt_tags().into_iter().enumerate() {
-                    children.push(TextComponent::nbt_display(tag));
-                    if i + 1 != nbt_list.as_nbt_tags().len() {
-                        children.push(", ".into());
-                    }
-                }
-                children.push(TextComponent::plain("]"));
-                component.add_children(children)
-            }
-            NbtTag::Compound(compound) => {
-                let component = "{".color(Color::White);
-                let mut children = vec![];
-                let len = compound.len();
-                for (i, (name, tag)) in compound.into_iter().enumerate() {
-                    if !name.is_empty() {
-                        children.push(name.to_string().color(Color::Aqua));
-                        children.push(": ".into());
-                    } else if len == 1 {
-                        return TextComponent::nbt_display(tag);
-                    }
-                    children.push(TextComponent::nbt_display(tag));
-                    if i + 1 != len {
-                        children.push(", ".into());
-                    }
-                }
-                children.push(TextComponent::plain("}"));
-                component.add_children(children)
+    /// Creates a syntax highlighted [TextComponent] displaying the given NBT,
+    /// with the [default](NbtDisplayStyle::default) style.
+    /// ## Example
+    /// ```
+    /// # use simdnbt::owned::{BaseNbt, Nbt, NbtCompound, NbtTag};
+    /// # use text_components::TextComponent;
+    /// let nbt = Nbt::Some(BaseNbt::new("", NbtCompound::from_values(vec![
+    ///     ("Health".into(), NbtTag::Float(20.)),
+    /// ])));
+    /// // Displays {Health: 20.0f}
+    /// TextComponent::nbt_display(&nbt);
+    /// ```
+    pub fn nbt_display(nbt: &Nbt) -> Self {
+        TextComponent::nbt_display_with(nbt, &NbtDisplayStyle::default())
+    }
+    /// Same as [nbt_display](TextComponent::nbt_display) with a custom [NbtDisplayStyle].
+    pub fn nbt_display_with(nbt: &Nbt, style: &NbtDisplayStyle) -> Self {
+        match nbt {
+            Nbt::Some(base) => display_compound(base, style, 0),
+            Nbt::None => TextComponent::new(),
+        }
+    }
+    /// Same as [nbt_display](TextComponent::nbt_display) for a single tag.
+    pub fn nbt_tag_display(tag: &NbtTag) -> Self {
+        TextComponent::nbt_tag_display_with(tag, &NbtDisplayStyle::default())
+    }
+    /// Same as [nbt_tag_display](TextComponent::nbt_tag_display) with a custom [NbtDisplayStyle].
+    pub fn nbt_tag_display_with(tag: &NbtTag, style: &NbtDisplayStyle) -> Self {
+        display_tag(tag, style, 0)
+    }
+}
+
+fn display_number(number: String, suffix: &'static str, style: &NbtDisplayStyle) -> TextComponent {
+    let component = number.color(style.number);
+    if suffix.is_empty() {
+        return component;
+    }
+    component.add_child(suffix.color(style.suffix))
+}
+
+fn display_tag(tag: &NbtTag, style: &NbtDisplayStyle, depth: usize) -> TextComponent {
+    match tag {
+        NbtTag::Byte(n) => display_number(n.to_string(), "b", style),
+        NbtTag::Short(n) => display_number(n.to_string(), "s", style),
+        NbtTag::Int(n) => display_number(n.to_string(), "", style),
+        NbtTag::Long(n) => display_number(n.to_string(), "l", style),
+        NbtTag::Float(n) => display_number(format!("{:?}", n), "f", style),
+        NbtTag::Double(n) => display_number(format!("{:?}", n), "d", style),
+        NbtTag::String(string) => display_string(&string.to_string(), style),
+        NbtTag::ByteArray(items) => display_array(
+            "B",
+            items
+                .iter()
+                .map(|n| display_number((*n as i8).to_string(), "b", style)),
+            items.len(),
+            style,
+        ),
+        NbtTag::IntArray(items) => display_array(
+            "I",
+            items
+                .iter()
+                .map(|n| display_number(n.to_string(), "", style)),
+            items.len(),
+            style,
+        ),
+        NbtTag::LongArray(items) => display_array(
+            "L",
+            items
+                .iter()
+                .map(|n| display_number(n.to_string(), "l", style)),
+            items.len(),
+            style,
+        ),
+        NbtTag::List(list) => {
+            let tags = list.as_nbt_tags();
+            if style.max_depth.is_some_and(|max| depth >= max) && !tags.is_empty() {
+                return "[...]".color(style.punctuation);
             }
-            NbtTag::IntArray(items) => {
-                let component = "["
-                    .color(Color::White)
-                    .add_children(vec!["I".color(Color::Red), "; ".into()]);
-                let mut children = vec![];
-                for (i, n) in items.iter().enumerate() {
-                    children.push(n.to_string().color(Color::Gold));
-                    if i + 1 != items.len() {
-                        children.push(", ".into());
-                    }
-                }
-                children.push(TextComponent::plain("]"));
-                component.add_children(children)
+            let entries = tags
+                .iter()
+                .map(|tag| display_tag(tag, style, depth + 1))
+                .collect::<Vec<_>>();
+            display_container(("[", "]"), entries, style, depth)
+        }
+        NbtTag::Compound(compound) => display_compound(compound, style, depth),
+    }
+}
+
+fn display_compound(
+    compound: &NbtCompound,
+    style: &NbtDisplayStyle,
+    depth: usize,
+) -> TextComponent {
+    if compound.len() == 1
+        && let Some((name, tag)) = compound.iter().next()
+        && name.is_empty()
+    {
+        return display_tag(tag, style, depth);
+    }
+    if style.max_depth.is_some_and(|max| depth >= max) && !compound.is_empty() {
+        return "{...}".color(style.punctuation);
+    }
+    let entries = compound
+        .iter()
+        .map(|(name, tag)| {
+            let name = name.to_string();
+            let key = if !name.is_empty()
+                && name.chars().all(|char| {
+                    char.is_ascii_alphanumeric() || matches!(char, '_' | '-' | '.' | '+')
+                }) {
+                name.color(style.key)
+            } else {
+                display_string(&name, style).color(style.key)
+            };
+            TextComponent::new().add_children(vec![
+                key,
+                ": ".into(),
+                display_tag(tag, style, depth + 1),
+            ])
+        })
+        .collect::<Vec<_>>();
+    display_container(("{", "}"), entries, style, depth)
+}
+
+fn display_string(string: &str, style: &NbtDisplayStyle) -> TextComponent {
+    "\"".add_children(vec![escape_snbt(string).color(style.string), "\"".into()])
+}
+
+fn display_array(
+    prefix: &'static str,
+    items: impl Iterator<Item = TextComponent>,
+    len: usize,
+    style: &NbtDisplayStyle,
+) -> TextComponent {
+    let mut children = vec![prefix.color(style.suffix), "; ".into()];
+    let shown = style.max_length.unwrap_or(usize::MAX);
+    for (i, item) in items.take(shown).enumerate() {
+        children.push(item);
+        if i + 1 != len {
+            children.push(", ".into());
+        }
+    }
+    if len > shown {
+        children.push("...".into());
+    }
+    children.push(TextComponent::plain("]"));
+    "[".color(style.punctuation).add_children(children)
+}
+
+fn display_container(
+    (open, close): (&'static str, &'static str),
+    entries: Vec<TextComponent>,
+    style: &NbtDisplayStyle,
+    depth: usize,
+) -> TextComponent {
+    let len = entries.len();
+    let multiline = style.inline_threshold.is_some_and(|max| len > max);
+    let indent = |depth: usize| format!("\n{}", " ".repeat(style.indent * depth));
+    let mut children = vec![];
+    let shown = style.max_length.unwrap_or(usize::MAX);
+    for (i, entry) in entries.into_iter().take(shown).enumerate() {
+        if multiline {
+            children.push(TextComponent::plain(indent(depth + 1)));
+        }
+        children.push(entry);
+        if i + 1 != len {
+            children.push(if multiline { ",".into() } else { ", ".into() });
+        }
+    }
+    if len > shown {
+        if multiline {
+            children.push(TextComponent::plain(indent(depth + 1)));
+        }
+        children.push("...".into());
+    }
+    if multiline {
+        children.push(TextComponent::plain(indent(depth)));
+    }
+    children.push(TextComponent::plain(close));
+    open.color(style.punctuation).add_children(children)
+}
+
+/// A value SNBT can't write.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnbtWriteError {
+    /// A NaN or infinite float, which the game has no literal for.
+    NonFiniteFloat(f32),
+    /// A NaN or infinite double.
+    NonFiniteDouble(f64),
+}
+impl std::fmt::Display for SnbtWriteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnbtWriteError::NonFiniteFloat(n) => {
+                write!(f, "The float {n} can't be written as SNBT.")
             }
-            NbtTag::LongArray(items) => {
-                let component = "["
-                    .color(Color::White)
-                    .add_children(vec!["L".color(Color::Red), "; ".into()]);
-                let mut children = vec![];
-                for (i, n) in items.iter().enumerate() {
-                    children.push(
-                        n.to_string()
-                            .color(Color::Gold)
-                            .add_child("l".color(Color::Red)),
-                    );
-                    if i + 1 != items.len() {
-                        children.push(", ".into());
-                    }
-                }
-                children.push(TextComponent::plain("]"));
-                component.add_children(children)
+            SnbtWriteError::NonFiniteDouble(n) => {
+                write!(f, "The double {n} can't be written as SNBT.")
             }
         }
     }
 }
+impl std::error::Error for SnbtWriteError {}
 
+/// Writes NBT as the SNBT of commands and data packs.\
+/// Numbers are written in the form the game reads back to the same value: floats in
+/// full without exponent, and NaN or infinite ones are an error.
 pub trait ToSNBT {
-    fn to_snbt(&self) -> String;
+    fn try_to_snbt(&self) -> Result<String, SnbtWriteError>;
+
+    /// Like [try_to_snbt](ToSNBT::try_to_snbt), panicking on NaN and infinite floats.\
+    /// The NBT built from components only has them when it carries
+    /// [unknown fields](crate::unknown) read from NBT, use
+    /// [try_to_snbt](ToSNBT::try_to_snbt) with those.
+    fn to_snbt(&self) -> String {
+        self.try_to_snbt().unwrap_or_else(|err| panic!("{err}"))
+    }
 }
 
 impl ToSNBT for Nbt {
-    fn to_snbt(&self) -> String {
+    fn try_to_snbt(&self) -> Result<String, SnbtWriteError> {
         match self {
-            Nbt::Some(base) => base.to_snbt(),
-            Nbt::None => String::new(),
+            Nbt::Some(base) => base.try_to_snbt(),
+            Nbt::None => Ok(String::new()),
         }
     }
 }
 impl ToSNBT for BaseNbt {
-    fn to_snbt(&self) -> String {
+    fn try_to_snbt(&self) -> Result<String, SnbtWriteError> {
         let mut child = String::new();
         if !self.name().is_empty() {
             if self.name().to_str().contains(':') {
-                child = format!("\"{}\":", self.name());
+                child = format!("\"{}\":", escape_snbt(&self.name().to_str()));
             } else {
                 child = format!("{}:", self.name());
             }
         }
-        child.push_str(&self.deref().to_snbt());
-        child
+        child.push_str(&self.deref().try_to_snbt()?);
+        Ok(child)
     }
 }
 impl ToSNBT for NbtCompound {
-    fn to_snbt(&self) -> String {
+    fn try_to_snbt(&self) -> Result<String, SnbtWriteError> {
         if self.len() == 1 {
             for (name, tag) in self.iter() {
                 if name.is_empty() || name.to_str() == "text" {
-                    return tag.to_snbt();
+                    return tag.try_to_snbt();
                 }
             }
         }
@@ -197,26 +433,47 @@ impl ToSNBT for NbtCompound {
             let mut child = String::new();
             if !name.is_empty() {
                 if name.to_str().contains(':') {
-                    child = format!("\"{}\":", name);
+                    child = format!("\"{}\":", escape_snbt(&name.to_str()));
                 } else {
                     child = format!("{}:", name);
                 }
             }
-            child.push_str(&tag.to_snbt());
+            child.push_str(&tag.try_to_snbt()?);
             snbt.push(child);
         }
-        format!("{{{}}}", snbt.join(","))
+        Ok(format!("{{{}}}", snbt.join(",")))
     }
 }
+
+/// `{:?}` gives the shortest digits that read back to the same value, but switches to
+/// an exponent for very small and big numbers, which older versions don't read.
+fn snbt_number<N: std::fmt::Debug + std::fmt::Display>(n: N) -> String {
+    let shortest = format!("{n:?}");
+    if shortest.contains('e') {
+        let full = n.to_string();
+        if full.contains('.') {
+            full
+        } else {
+            full + ".0"
+        }
+    } else {
+        shortest
+    }
+}
+
 impl ToSNBT for NbtTag {
-    fn to_snbt(&self) -> String {
-        match self {
+    fn try_to_snbt(&self) -> Result<String, SnbtWriteError> {
+        Ok(match self {
             NbtTag::Byte(n) => format!("{n}b"),
             NbtTag::Short(n) => format!("{n}s"),
             NbtTag::Int(n) => n.to_string(),
             NbtTag::Long(n) => format!("{n}l"),
-            NbtTag::Float(n) => format!("{:?}f", n),
-            NbtTag::Double(n) => format!("{:?}d", n),
+            NbtTag::Float(n) if !n.is_finite() => return Err(SnbtWriteError::NonFiniteFloat(*n)),
+            NbtTag::Float(n) => format!("{}f", snbt_number(*n)),
+            NbtTag::Double(n) if !n.is_finite() => {
+                return Err(SnbtWriteError::NonFiniteDouble(*n));
+            }
+            NbtTag::Double(n) => format!("{}d", snbt_number(*n)),
             NbtTag::ByteArray(items) => format!(
                 "[B;{}]",
                 items
@@ -225,25 +482,17 @@ impl ToSNBT for NbtTag {
                     .collect::<Vec<String>>()
                     .join(",")
             ),
-            NbtTag::String(str) => format!(
-                "\"{}\"",
-                // TODO: Check escapable characters
-                str.to_string()
-                    .replace('\\', "\\\\")
-                    .replace('\n', "\\n")
-                    .replace('"', "\\\"")
-                    .replace('\'', "\\'")
-            ),
+            NbtTag::String(str) => format!("\"{}\"", escape_snbt(&str.to_string())),
             NbtTag::List(items) => format!(
                 "[{}]",
                 items
                     .as_nbt_tags()
                     .iter()
-                    .map(|item| item.to_snbt())
-                    .collect::<Vec<String>>()
+                    .map(|item| item.try_to_snbt())
+                    .collect::<Result<Vec<String>, _>>()?
                     .join(",")
             ),
-            NbtTag::Compound(nbt) => nbt.to_snbt(),
+            NbtTag::Compound(nbt) => nbt.try_to_snbt()?,
             NbtTag::IntArray(items) => format!(
                 "[I;{}]",
                 items
@@ -260,7 +509,7 @@ impl ToSNBT for NbtTag {
                     .collect::<Vec<String>>()
                     .join(",")
             ),
-        }
+        })
     }
 }
 
@@ -274,6 +523,7 @@ impl Content {
         match self {
             Content::Text { text } => compound.push(("text".into(), text.to_nbt_tag())),
             Content::Object(Object::Atlas { atlas, sprite }) => {
+                compound.push(("object".into(), "atlas".into()));
                 if let Some(atlas) = atlas {
                     compound.push(("atlas".into(), atlas.to_nbt_tag()));
                 }
@@ -322,33 +572,96 @@ impl Content {
             }
             Content::Keybind { keybind } => compound.push(("keybind".into(), keybind.to_nbt_tag())),
             Content::Translate(msg) => {
-                compound.push(("translate".into(), msg.key.to_nbt_tag()));
+                compound.push(("translate".into(), NbtTag::String(msg.key.as_str().into())));
                 if let Some(fallback) = &msg.fallback {
                     compound.push(("fallback".into(), fallback.to_nbt_tag()));
                 }
                 if let Some(args) = &msg.args {
-                    compound.push((
-                        "with".into(),
-                        NbtTag::List(NbtList::Compound(
-                            args.iter()
-                                .map(|nbt| {
-                                    target
-                                        .build_component(resolutor, nbt)
-                                        .into_compound()
-                                        .unwrap()
-                                })
-                                .collect(),
-                        )),
-                    ))
+                    compound.push(("with".into(), target.build_list(resolutor, args)))
                 }
             }
-            _ => (),
+            // Written as the game reads them, so it resolves what was left unresolved
+            Content::Resolvable(resolvable) => resolvable.to_compound(compound, target, resolutor),
+            // Custom contents have no vanilla form, they only exist until resolved and are
+            // an empty text otherwise
+            #[cfg(feature = "custom")]
+            Content::Custom(_) => (),
         };
     }
 }
 
+impl Resolvable {
+    /// Writes the keys the game reads this resolvable from, like
+    /// `{score:{name:"@p",objective:"kills"}}`, without resolving it.\
+    /// Default separators are left out.
+    pub fn to_nbt(&self) -> NbtCompound {
+        let mut compound = vec![];
+        self.to_compound(&mut compound, &NbtBuilder::new(), &NoResolutor);
+        NbtCompound::from_values(compound)
+    }
+    fn to_compound<R: TextResolutor + ?Sized>(
+        &self,
+        compound: &mut Vec<(Mutf8String, NbtTag)>,
+        target: &NbtBuilder,
+        resolutor: &R,
+    ) {
+        match self {
+            Resolvable::Scoreboard {
+                selector,
+                objective,
+            } => compound.push((
+                "score".into(),
+                NbtTag::Compound(NbtCompound::from_values(vec![
+                    ("name".into(), selector.to_nbt_tag()),
+                    ("objective".into(), objective.to_nbt_tag()),
+                ])),
+            )),
+            Resolvable::Entity {
+                selector,
+                separator,
+            } => {
+                compound.push(("selector".into(), selector.to_nbt_tag()));
+                if *separator != Resolvable::entity_separator() {
+                    compound.push((
+                        "separator".into(),
+                        target.build_component(resolutor, separator),
+                    ));
+                }
+            }
+            Resolvable::NBT {
+                path,
+                interpret,
+                separator,
+                source,
+            } => {
+                compound.push(("nbt".into(), path.to_nbt_tag()));
+                let (key, value) = match source {
+                    NbtSource::Block(block) => ("block", block),
+                    NbtSource::Entity(selector) => ("entity", selector),
+                    NbtSource::Storage(storage) => ("storage", storage),
+                };
+                if let Some(interpret) = interpret {
+                    compound.push(("interpret".into(), NbtTag::Byte(*interpret as i8)));
+                }
+                compound.push((key.into(), value.to_nbt_tag()));
+                compound.push(("source".into(), key.into()));
+                if *separator != Resolvable::nbt_separator() {
+                    compound.push((
+                        "separator".into(),
+                        target.build_component(resolutor, separator),
+                    ));
+                }
+            }
+        }
+    }
+}
+
 impl Format {
-    fn to_compound(&self, compound: &mut Vec<(Mutf8String, NbtTag)>) {
+    fn to_compound(
+        &self,
+        compound: &mut Vec<(Mutf8String, NbtTag)>,
+        shadow_colors: ShadowColorFormat,
+    ) {
         if let Some(color) = &self.color {
             compound.push((
                 "color".into(),
@@ -375,8 +688,17 @@ impl Format {
                 },
             ));
         }
-        if let Some(value) = &self.font {
-            compound.push(("font".into(), value.to_nbt_tag()));
+        if let Some(color) = self.shadow_color {
+            // Colors past an int are clamped instead of cut to their low bits, the
+            // negative ones in range are already the signed form the game writes
+            let color = color.clamp(i32::MIN.into(), u32::MAX.into());
+            let tag = match shadow_colors {
+                ShadowColorFormat::Packed => NbtTag::Int(color as i32),
+                ShadowColorFormat::Floats => {
+                    NbtTag::List(NbtList::Float(Format::shadow_color_floats(color).to_vec()))
+                }
+            };
+            compound.push(("shadow_color".into(), tag));
         }
         if let Some(value) = self.bold {
             compound.push(("bold".into(), NbtTag::Byte(value as i8)));
@@ -393,9 +715,6 @@ impl Format {
         if let Some(value) = self.obfuscated {
             compound.push(("obfuscated".into(), NbtTag::Byte(value as i8)));
         }
-        if let Some(color) = self.shadow_color {
-            compound.push(("shadow_color".into(), NbtTag::Long(color)));
-        }
     }
 }
 
@@ -405,27 +724,32 @@ impl Interactivity {
         resolutor: &R,
         compound: &mut Vec<(Mutf8String, NbtTag)>,
     ) {
+        if let Some(click) = &self.click {
+            compound.push(("click_event".into(), click.to_nbt_tag()));
+        }
+        if let Some(hover) = &self.hover {
+            compound.push(("hover_event".into(), hover.to_nbt_tag(resolutor)));
+        }
         if let Some(insertion) = &self.insertion {
             compound.push((
                 "insertion".into(),
                 NbtTag::String(insertion.to_string().into()),
             ));
         }
-        if let Some(hover) = &self.hover {
-            compound.push(("hover_event".into(), hover.to_nbt_tag(resolutor)));
-        }
-        if let Some(click) = &self.click {
-            compound.push(("click_event".into(), click.to_nbt_tag()));
-        }
     }
 }
 
 impl HoverEvent {
     fn to_nbt_tag<R: TextResolutor + ?Sized>(&self, resolutor: &R) -> NbtTag {
         match self {
+            // Hover texts were resolved with the rest of the component, building them again
+            // would also nest inline translation resolutors without end
             HoverEvent::ShowText { value } => NbtTag::Compound(NbtCompound::from_values(vec![
                 ("action".into(), NbtTag::String("show_text".into())),
-                ("value".into(), value.build(resolutor, NbtBuilder)),
+                (
+                    "value".into(),
+                    NbtBuilder::new().build_component(resolutor, value),
+                ),
             ])),
             HoverEvent::ShowItem {
                 id,
@@ -455,11 +779,14 @@ impl HoverEvent {
                 let mut compound = vec![
                     ("action".into(), NbtTag::String("show_entity".into())),
                     ("id".into(), id.to_nbt_tag()),
-                    ("uuid".into(), NbtTag::List(NbtList::Int(uuid))),
                 ];
                 if let Some(name) = name {
-                    compound.push(("name".into(), name.build(resolutor, NbtBuilder)));
+                    compound.push((
+                        "name".into(),
+                        NbtBuilder::new().build_component(resolutor, name),
+                    ));
                 }
+                compound.push(("uuid".into(), NbtTag::List(NbtList::Int(uuid))));
                 NbtTag::Compound(NbtCompound::from_values(compound))
             }
         }
@@ -492,7 +819,7 @@ impl ClickEvent {
             }
             ClickEvent::ShowDialog { dialog } => {
                 values.push(("action".into(), "show_dialog".into()));
-                values.push(("dialog".into(), dialog.to_nbt_tag()));
+                values.push(("dialog".into(), dialog.to_nbt(&NoResolutor)));
             }
             #[cfg(feature = "custom")]
             ClickEvent::Custom(data) => {
@@ -509,12 +836,12 @@ impl ClickEvent {
 
 impl ToNbtTag for TextComponent {
     fn to_nbt_tag(self) -> NbtTag {
-        NbtBuilder.build_component(&NoResolutor, &self)
+        NbtBuilder::new().build_component(&NoResolutor, &self)
     }
 }
 impl ToNbtTag for &TextComponent {
     fn to_nbt_tag(self) -> NbtTag {
-        NbtBuilder.build_component(&NoResolutor, self)
+        NbtBuilder::new().build_component(&NoResolutor, self)
     }
 }
 impl FromNbtTag for TextComponent {
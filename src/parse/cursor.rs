@@ -0,0 +1,58 @@
+use super::ParseMode;
+use std::str::Chars;
+
+/// The characters left to parse, knowing how far into the input they are.
+#[derive(Debug, Clone)]
+pub(crate) struct Cursor<'a> {
+    input: &'a str,
+    chars: Chars<'a>,
+    peeked: Option<char>,
+    mode: &'a ParseMode,
+}
+
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(input: &'a str, mode: &'a ParseMode) -> Self {
+        Cursor {
+            input,
+            chars: input.chars(),
+            peeked: None,
+            mode,
+        }
+    }
+
+    /// How the input is parsed, the same for every clone of the cursor so errors recovered
+    /// from aren't lost when going back to one.
+    pub(crate) fn mode(&self) -> &'a ParseMode {
+        self.mode
+    }
+
+    /// Bytes of the input consumed so far.
+    pub(crate) fn offset(&self) -> usize {
+        self.input.len() - self.chars.as_str().len() - self.peeked.map_or(0, char::len_utf8)
+    }
+
+    pub(crate) fn peek(&mut self) -> Option<&char> {
+        if self.peeked.is_none() {
+            self.peeked = self.chars.next();
+        }
+        self.peeked.as_ref()
+    }
+
+    pub(crate) fn next_if(&mut self, func: impl FnOnce(&char) -> bool) -> Option<char> {
+        match self.peek() {
+            Some(char) if func(char) => self.next(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn next_if_eq(&mut self, expected: &char) -> Option<char> {
+        self.next_if(|char| char == expected)
+    }
+}
+
+impl Iterator for Cursor<'_> {
+    type Item = char;
+    fn next(&mut self) -> Option<char> {
+        self.peeked.take().or_else(|| self.chars.next())
+    }
+}
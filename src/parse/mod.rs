@@ -1,15 +1,28 @@
+#![deny(clippy::unwrap_used, clippy::indexing_slicing)]
 #[cfg(feature = "custom")]
 use crate::custom::{CustomData, Payload};
 use crate::{
     Modifier, TextComponent,
-    content::{Content, NbtSource, Object, ObjectPlayer, PlayerProperties, Resolvable},
-    format::{Color, Format},
+    content::{
+        Content, NbtSource, Object, ObjectPlayer, PlayerProperties, Resolvable, normalize_keybind,
+    },
+    dialog::DialogRef,
+    format::{Color, Format, HexColorError, namespaced_font},
     interactivity::{ClickEvent, HoverEvent, Interactivity},
+    intern::ComponentInterner,
     translation::TranslatedMessage,
 };
-use std::{borrow::Cow, error::Error, fmt::Display, iter::Peekable, ops::AddAssign, str::Chars};
+use cursor::Cursor;
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    error::Error,
+    fmt::Display,
+    ops::AddAssign,
+};
 use uuid::Uuid;
 
+mod cursor;
 #[cfg(feature = "nbt")]
 pub mod nbt;
 
@@ -21,8 +34,15 @@ pub enum SnbtError {
     UnknownKey(String),
     MissingContent,
     UnknownColor(String),
+    /// A `#` color that isn't a valid hex color.
+    HexColor(HexColorError),
     NumberOverflow(String, String),
     Required(String, String),
+    TooDeep,
+    InvalidEscape(String),
+    /// An error found by [from_snbt_lossy](TextComponent::from_snbt_lossy) and the byte
+    /// of the input where it was found.
+    At(usize, Box<SnbtError>),
 }
 impl Error for SnbtError {}
 impl Display for SnbtError {
@@ -44,25 +64,255 @@ impl Display for SnbtError {
             SnbtError::UnknownKey(key) => write!(f, "The key \"{key}\" is unknown."),
             SnbtError::MissingContent => write!(f, "There's a component without any content."),
             SnbtError::UnknownColor(color) => write!(f, "The color \"{color}\" can't be parsed."),
+            SnbtError::HexColor(error) => error.fmt(f),
             SnbtError::NumberOverflow(content, num) => {
                 write!(f, "In {content}, the value marked as {num} overflows.")
             }
             SnbtError::Required(content, val) => {
                 write!(f, "{content} requires \"{val}\" to work, but it's missing.")
             }
+            SnbtError::TooDeep => write!(
+                f,
+                "The components are nested deeper than {MAX_DEPTH} levels."
+            ),
+            SnbtError::InvalidEscape(escape) => {
+                write!(f, "The escape sequence \"\\{escape}\" is not valid.")
+            }
+            SnbtError::At(offset, error) => write!(f, "{error} (At byte {offset})"),
         }
     }
 }
 
 pub type SnbtResult<T> = Result<T, SnbtError>;
 
+/// How many components can be nested inside each other before parsing fails,
+/// keeps untrusted input from overflowing the stack.
+pub const MAX_DEPTH: u32 = 64;
+
+thread_local! {
+    static DEPTH: Cell<u32> = const { Cell::new(0) };
+}
+
+/// How the parser treats what it doesn't expect, given to it with the input by the
+/// [Cursor] so a parse never changes how another one behaves.
+#[derive(Debug, Default)]
+pub(crate) enum ParseMode {
+    #[default]
+    Normal,
+    /// Unknown keys fail, see [from_snbt_strict](TextComponent::from_snbt_strict).
+    Strict,
+    /// Errors are recovered from and collected, see
+    /// [from_snbt_lossy](TextComponent::from_snbt_lossy).
+    Lossy(RefCell<Vec<SnbtError>>),
+}
+
+impl ParseMode {
+    fn is_strict(&self) -> bool {
+        matches!(self, ParseMode::Strict)
+    }
+
+    fn is_lossy(&self) -> bool {
+        matches!(self, ParseMode::Lossy(_))
+    }
+
+    /// When parsing lossily records the error found at the offset and gives [Ok] so the
+    /// parser recovers, otherwise gives the error back.\
+    /// Running out of input is only recorded once, by the innermost value it cut.
+    fn recover(&self, error: SnbtError, offset: usize) -> SnbtResult<()> {
+        let ParseMode::Lossy(errors) = self else {
+            return Err(error);
+        };
+        let mut errors = errors.borrow_mut();
+        if let SnbtError::EndedAbruptely(_) = error
+            && let Some(SnbtError::At(last, last_error)) = errors.last()
+            && *last == offset
+            && let SnbtError::EndedAbruptely(_) = **last_error
+        {
+            return Ok(());
+        }
+        errors.push(SnbtError::At(offset, Box::new(error)));
+        Ok(())
+    }
+
+    /// Amount of errors recorded, to [forget](ParseMode::forget_errors) the ones of a value
+    /// that is skipped.
+    fn recorded_errors(&self) -> usize {
+        match self {
+            ParseMode::Lossy(errors) => errors.borrow().len(),
+            _ => 0,
+        }
+    }
+
+    fn forget_errors(&self, recorded: usize) {
+        if let ParseMode::Lossy(errors) = self {
+            errors.borrow_mut().truncate(recorded);
+        }
+    }
+
+    fn into_errors(self) -> Vec<SnbtError> {
+        match self {
+            ParseMode::Lossy(errors) => errors.into_inner(),
+            _ => vec![],
+        }
+    }
+}
+
 impl TextComponent {
+    /// Parses a component from its SNBT form.\
+    /// Keys that aren't known are skipped with their value, so components
+    /// from newer versions of the game still parse.\
+    /// Never panics, whatever the input.
     pub fn from_snbt(string: &str) -> SnbtResult<TextComponent> {
-        parse_body(None, &mut string.chars().peekable())
+        parse_body(None, &mut Cursor::new(string, &ParseMode::Normal))
+    }
+    /// Parses the component at the start of the input, giving it with the amount of bytes
+    /// it took (whitespace before it included), the rest of the input is left untouched.
+    /// ```
+    /// # use text_components::TextComponent;
+    /// let (component, used) = TextComponent::from_snbt_prefix(r#"{text:"Hi"} @a"#)?;
+    /// assert_eq!(used, 11);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_snbt_prefix(string: &str) -> SnbtResult<(TextComponent, usize)> {
+        let mode = ParseMode::Normal;
+        let mut chars = Cursor::new(string, &mode);
+        let component = parse_body(None, &mut chars)?;
+        Ok((component, chars.offset()))
+    }
+    /// Same as [from_snbt](TextComponent::from_snbt), but fails with
+    /// [SnbtError::UnknownKey] on any key of a component that isn't known.
+    pub fn from_snbt_strict(string: &str) -> SnbtResult<TextComponent> {
+        parse_body(None, &mut Cursor::new(string, &ParseMode::Strict))
+    }
+    /// Same as [from_snbt](TextComponent::from_snbt), but recovering from the usual mistakes
+    /// of hand written SNBT instead of failing, giving every problem found wrapped in
+    /// [SnbtError::At] with its position:
+    /// * Unknown keys are skipped.
+    /// * Keys with a wrong value are left out.
+    /// * Components in lists that can't be parsed are left out.
+    /// * Strings, lists and components cut by the end of the input end there.
+    ///
+    /// If the component can't be recovered at all it's empty.
+    pub fn from_snbt_lossy(string: &str) -> (TextComponent, Vec<SnbtError>) {
+        let mode = ParseMode::Lossy(RefCell::new(vec![]));
+        let mut chars = Cursor::new(string, &mode);
+        let component = parse_body(None, &mut chars).map_err(|error| (error, chars.offset()));
+        let mut errors = mode.into_errors();
+        match component {
+            Ok(component) => (component, errors),
+            Err((error, offset)) => {
+                errors.push(SnbtError::At(offset, Box::new(error)));
+                (TextComponent::new(), errors)
+            }
+        }
+    }
+    /// Parses a component kept as a string inside other data, like the
+    /// `'{"text":"Sword","italic":false}'` of an item's custom name, written either as JSON
+    /// (read with the `serde` feature) or as SNBT.
+    /// ```
+    /// # use text_components::TextComponent;
+    /// let name = TextComponent::from_embedded_str(r#"{"text":"Sword","italic":false}"#)?;
+    /// let lore = TextComponent::from_embedded_str("{text:'Sharp',color:'gray'}")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_embedded_str(string: &str) -> SnbtResult<TextComponent> {
+        #[cfg(feature = "serde")]
+        if let Ok(component) = TextComponent::from_json(string) {
+            return Ok(component);
+        }
+        TextComponent::from_snbt(string)
+    }
+    /// Same as [from_snbt](TextComponent::from_snbt), sharing the fonts, translation keys
+    /// and insertions with the other components parsed with the same [ComponentInterner].
+    pub fn from_snbt_interned(
+        string: &str,
+        interner: &ComponentInterner,
+    ) -> SnbtResult<TextComponent> {
+        let mut component = TextComponent::from_snbt(string)?;
+        interner.intern_component(&mut component);
+        Ok(component)
+    }
+}
+
+/// The component embedded in a string holding a text, like a hover text given as
+/// `'{"text":"Hi"}'`, or the text itself if it doesn't look like one.
+pub(crate) fn embedded_or_plain(text: String) -> TextComponent {
+    if text.trim_start().starts_with(['{', '['])
+        && let Ok(component) = TextComponent::from_embedded_str(&text)
+    {
+        return component;
+    }
+    TextComponent::plain(text)
+}
+
+/// Iterates over the components written one after another in the input, separated by
+/// whitespace or commas, see [iter_snbt_components].
+#[derive(Debug, Clone)]
+pub struct SnbtComponents<'a> {
+    rest: &'a str,
+    failed: bool,
+}
+
+impl<'a> SnbtComponents<'a> {
+    /// The input not parsed yet, where the failing component starts after an error.
+    pub fn rest(&self) -> &'a str {
+        self.rest
     }
 }
 
-fn parse_body(first: Option<char>, chars: &mut Peekable<Chars>) -> SnbtResult<TextComponent> {
+impl Iterator for SnbtComponents<'_> {
+    type Item = SnbtResult<TextComponent>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        self.rest = self
+            .rest
+            .trim_start_matches(|char: char| char.is_whitespace() || char == ',');
+        if self.rest.is_empty() {
+            return None;
+        }
+        match TextComponent::from_snbt_prefix(self.rest) {
+            Ok((component, used)) => {
+                self.rest = &self.rest[used..];
+                Some(Ok(component))
+            }
+            Err(error) => {
+                self.failed = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+/// Parses the components written one after another in the input, like the ones extracted
+/// from many commands, stopping after the first one that fails.
+/// ```
+/// # use text_components::parse::iter_snbt_components;
+/// for component in iter_snbt_components(r#"{text:"a"} "b", ["c"]"#) {
+///     println!("{}", component?);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn iter_snbt_components(string: &str) -> SnbtComponents<'_> {
+    SnbtComponents {
+        rest: string,
+        failed: false,
+    }
+}
+
+fn parse_body(first: Option<char>, chars: &mut Cursor) -> SnbtResult<TextComponent> {
+    let depth = DEPTH.get();
+    if depth >= MAX_DEPTH {
+        return Err(SnbtError::TooDeep);
+    }
+    DEPTH.set(depth + 1);
+    let component = parse_component(first, chars);
+    DEPTH.set(depth);
+    component
+}
+
+fn parse_component(first: Option<char>, chars: &mut Cursor) -> SnbtResult<TextComponent> {
     let char = match first {
         Some(first) => first,
         None => {
@@ -99,73 +349,217 @@ fn parse_body(first: Option<char>, chars: &mut Peekable<Chars>) -> SnbtResult<Te
     Err(SnbtError::EndedAbruptely(line!()))
 }
 
-fn parse_string(opener: char, chars: &mut Peekable<Chars>) -> SnbtResult<String> {
+/// Reads a quoted string, the opening quote already read.\
+/// Understands the escapes of SNBT: `\"`, `\'`, `\\`, `\b`, `\f`, `\n`, `\r`, `\s`
+/// (a space), `\t`, and unicode ones as `\xXX`, `\uXXXX` (UTF-16 surrogate pairs included)
+/// and `\UXXXXXXXX`. Any other escape is an [InvalidEscape](SnbtError::InvalidEscape) error.
+fn parse_string(opener: char, chars: &mut Cursor) -> SnbtResult<String> {
     let mut content = String::new();
     while let Some(char) = chars.next() {
         if char == opener {
             return Ok(content);
         }
-        if char == '\\'
-            && let Some(escaped) = chars.next()
-        {
-            // TODO: Check escapable characters
-            match escaped {
-                '"' => content.push('"'),
-                '\'' => content.push('\''),
-                'n' => content.push('\n'),
-                '\\' => content.push('\\'),
-                _ => (),
-            }
+        if char != '\\' {
+            content.push(char);
             continue;
         }
-        content.push(char);
+        let escape = chars.clone();
+        match parse_escape(chars) {
+            Ok(Some(escaped)) => content.push(escaped),
+            Ok(None) => break,
+            Err(error) => {
+                // Kept as written when parsing lossily
+                chars.mode().recover(error, escape.offset() - 1)?;
+                *chars = escape;
+                content.push('\\');
+            }
+        }
     }
-    Err(SnbtError::EndedAbruptely(line!()))
+    chars
+        .mode()
+        .recover(SnbtError::EndedAbruptely(line!()), chars.offset())?;
+    Ok(content)
 }
 
-fn parse_vec(chars: &mut Peekable<Chars>) -> SnbtResult<Vec<TextComponent>> {
-    let mut component = vec![];
-    let Ok(child) = parse_body(None, chars) else {
-        return Err(SnbtError::UnfinishedComponent(line!()));
+/// Reads the escape after a backslash, [None] if the input ends before it.
+fn parse_escape(chars: &mut Cursor) -> SnbtResult<Option<char>> {
+    let Some(escape) = chars.next() else {
+        return Ok(None);
     };
-    component.push(child);
-    while let Some(char) = chars.next() {
-        if char.is_whitespace() {
-            continue;
+    let digits = match escape {
+        '"' | '\'' | '\\' => return Ok(Some(escape)),
+        'b' => return Ok(Some('\u{8}')),
+        'f' => return Ok(Some('\u{c}')),
+        'n' => return Ok(Some('\n')),
+        'r' => return Ok(Some('\r')),
+        's' => return Ok(Some(' ')),
+        't' => return Ok(Some('\t')),
+        'x' => 2,
+        'u' => 4,
+        'U' => 8,
+        _ => return Err(SnbtError::InvalidEscape(escape.to_string())),
+    };
+    let code = hex_escape(escape, digits, chars)?;
+    if escape == 'u' && (0xD800..0xDC00).contains(&code) {
+        // High surrogate, the low one must follow
+        let mut low = chars.clone();
+        if low.next_if_eq(&'\\').is_some()
+            && low.next_if_eq(&'u').is_some()
+            && let Ok(low_code) = hex_escape('u', 4, &mut low)
+            && (0xDC00..0xE000).contains(&low_code)
+        {
+            *chars = low;
+            let code = 0x10000 + ((code - 0xD800) << 10) + (low_code - 0xDC00);
+            return Ok(char::from_u32(code));
+        }
+    }
+    char::from_u32(code)
+        .map(Some)
+        .ok_or_else(|| SnbtError::InvalidEscape(format!("{escape}{code:0digits$X}")))
+}
+
+fn hex_escape(escape: char, digits: usize, chars: &mut Cursor) -> SnbtResult<u32> {
+    let mut hex = String::with_capacity(digits);
+    for _ in 0..digits {
+        match chars.next_if(|char| char.is_ascii_hexdigit()) {
+            Some(digit) => hex.push(digit),
+            None => return Err(SnbtError::InvalidEscape(format!("{escape}{hex}"))),
         }
+    }
+    u32::from_str_radix(&hex, 16).map_err(|_| SnbtError::InvalidEscape(format!("{escape}{hex}")))
+}
+
+/// Escapes a string to be written between double quotes in SNBT, it is read back as it was
+/// by [from_snbt](TextComponent::from_snbt).\
+/// Backslashes, double quotes and control characters are escaped, everything else
+/// (emoji included) is written as it is.
+pub fn escape_snbt(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len());
+    for char in string.chars() {
         match char {
-            ']' => return Ok(component),
-            ',' => {
-                let child = parse_body(None, chars)?;
-                component.push(child);
-            }
-            _ => return Err(SnbtError::UnfinishedComponent(line!())),
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\u{8}' => escaped.push_str("\\b"),
+            '\u{c}' => escaped.push_str("\\f"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            char if char.is_control() => {
+                escaped.push_str(&format!("\\u{:04X}", char as u32));
+            }
+            char => escaped.push(char),
         }
     }
-    Err(SnbtError::EndedAbruptely(line!()))
+    escaped
 }
 
-struct CompoundParts {
-    pub content: String,
-    pub object: String,
-    pub contents: [Option<Content>; 9],
-    pub nbt: String,
-    pub nbt_sources: [Option<NbtSource>; 3],
+fn parse_vec(chars: &mut Cursor) -> SnbtResult<Vec<TextComponent>> {
+    let mut component = vec![];
+    loop {
+        while chars.next_if(|char| char.is_whitespace()).is_some() {}
+        if chars.mode().is_lossy() && chars.next_if_eq(&']').is_some() {
+            // Trailing commas and empty lists are let through
+            return Ok(component);
+        }
+        let start = chars.clone();
+        let recorded = chars.mode().recorded_errors();
+        match parse_body(None, chars) {
+            Ok(child) => component.push(child),
+            Err(_) if component.is_empty() && !chars.mode().is_lossy() => {
+                return Err(SnbtError::UnfinishedComponent(line!()));
+            }
+            Err(error) => {
+                // Broken children are skipped whole when parsing lossily
+                *chars = start;
+                chars.mode().forget_errors(recorded);
+                chars.mode().recover(error, chars.offset())?;
+                if let Some(first) = chars.next() {
+                    skip_value(first, chars)?;
+                }
+            }
+        }
+        loop {
+            match chars.next() {
+                Some(']') => return Ok(component),
+                Some(',') => break,
+                Some(char) if char.is_whitespace() => (),
+                Some(_) => return Err(SnbtError::UnfinishedComponent(line!())),
+                None => {
+                    chars
+                        .mode()
+                        .recover(SnbtError::EndedAbruptely(line!()), chars.offset())?;
+                    return Ok(component);
+                }
+            }
+        }
+    }
 }
-impl CompoundParts {
-    pub fn new() -> Self {
-        CompoundParts {
-            content: String::new(),
-            object: String::new(),
-            contents: [None, None, None, None, None, None, None, None, None],
-            nbt: String::new(),
-            nbt_sources: [None, None, None],
+
+/// Consumes the value of an unknown key, whatever its type.
+fn skip_value(first: char, chars: &mut Cursor) -> SnbtResult<()> {
+    match first {
+        '"' | '\'' => parse_string(first, chars).map(|_| ()),
+        '{' | '[' => {
+            let mut depth = 1u32;
+            while let Some(char) = chars.next() {
+                match char {
+                    '"' | '\'' => {
+                        parse_string(char, chars)?;
+                    }
+                    '{' | '[' => depth += 1,
+                    '}' | ']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Ok(());
+                        }
+                    }
+                    _ => (),
+                }
+            }
+            chars
+                .mode()
+                .recover(SnbtError::EndedAbruptely(line!()), chars.offset())
+        }
+        _ => {
+            while let Some(next) = chars.peek() {
+                if matches!(next, ',' | '}' | ']') || next.is_whitespace() {
+                    break;
+                }
+                chars.next();
+            }
+            Ok(())
         }
     }
 }
 
-fn parse_compound(chars: &mut Peekable<Chars>) -> SnbtResult<TextComponent> {
-    let mut compound = CompoundParts::new();
+/// The raw values of the content keys of a compound, only turned
+/// into a [Content] once the whole compound is read so key order doesn't matter.
+#[derive(Default)]
+struct CompoundParts {
+    pub content: Option<String>,
+    pub text: Option<String>,
+    pub translate: Option<String>,
+    pub fallback: Option<String>,
+    pub with: Option<Vec<TextComponent>>,
+    pub score: Option<Content>,
+    pub selector: Option<String>,
+    pub separator: Option<TextComponent>,
+    pub keybind: Option<String>,
+    pub nbt: Option<String>,
+    pub interpret: Option<bool>,
+    pub source: Option<String>,
+    pub nbt_sources: [Option<NbtSource>; 3],
+    pub object: Option<String>,
+    pub atlas: Option<String>,
+    pub sprite: Option<String>,
+    pub player: Option<ObjectPlayer>,
+    pub hat: Option<bool>,
+    #[cfg(feature = "custom")]
+    pub custom: Option<CustomData>,
+}
+
+fn parse_compound(chars: &mut Cursor) -> SnbtResult<TextComponent> {
+    let mut compound = CompoundParts::default();
     let mut format = Format::new();
     let mut interactions = Interactivity::new();
     let mut children = vec![];
@@ -182,6 +576,9 @@ fn parse_compound(chars: &mut Peekable<Chars>) -> SnbtResult<TextComponent> {
                     children,
                     format,
                     interactions,
+                    inline_translations: None,
+                    #[cfg(feature = "unknown-fields")]
+                    unknown_fields: None,
                 });
             }
             ',' => in_name = true,
@@ -205,18 +602,43 @@ fn parse_compound(chars: &mut Peekable<Chars>) -> SnbtResult<TextComponent> {
                     break;
                 }
                 if first == ' ' {
-                    return Err(SnbtError::EndedAbruptely(line!()));
-                }
-                if name == "extra" {
-                    children = parse_vec(chars)?;
-                    name = String::new();
-                    continue;
+                    chars
+                        .mode()
+                        .recover(SnbtError::EndedAbruptely(line!()), chars.offset())?;
+                    break;
                 }
-                match_content(&name, &mut compound, first, chars, &mut unknown)?;
-                match_format(&name, &mut format, first, chars, &mut unknown)?;
-                match_interactions(&name, &mut interactions, first, chars, &mut unknown)?;
-                if unknown == 3 {
-                    return Err(SnbtError::UnknownKey(name));
+                let start = chars.clone();
+                let recorded = chars.mode().recorded_errors();
+                let matched = match name.as_str() {
+                    "extra" if first == '[' => parse_vec(chars).map(|extra| children = extra),
+                    "extra" => Err(SnbtError::WrongContentType(name.clone())),
+                    _ => match_key(
+                        &name,
+                        (&mut compound, &mut format, &mut interactions),
+                        first,
+                        chars,
+                        &mut unknown,
+                    ),
+                };
+                if let Err(error) = matched {
+                    // The key is left out when parsing lossily
+                    *chars = start;
+                    chars.mode().forget_errors(recorded);
+                    chars
+                        .mode()
+                        .recover(error, chars.offset() - first.len_utf8())?;
+                    skip_value(first, chars)?;
+                } else if unknown == 3 {
+                    if chars.mode().is_strict() {
+                        return Err(SnbtError::UnknownKey(name));
+                    }
+                    if chars.mode().is_lossy() {
+                        chars.mode().recover(
+                            SnbtError::UnknownKey(name),
+                            chars.offset() - first.len_utf8(),
+                        )?;
+                    }
+                    skip_value(first, chars)?;
                 }
                 name = String::new();
             }
@@ -224,297 +646,119 @@ fn parse_compound(chars: &mut Peekable<Chars>) -> SnbtResult<TextComponent> {
             _ => return Err(SnbtError::UnfinishedComponent(line!())),
         }
     }
-    Err(SnbtError::EndedAbruptely(line!()))
+    chars
+        .mode()
+        .recover(SnbtError::EndedAbruptely(line!()), chars.offset())?;
+    Ok(TextComponent {
+        content: retrieve_content(compound)?,
+        children,
+        format,
+        interactions,
+        inline_translations: None,
+        #[cfg(feature = "unknown-fields")]
+        unknown_fields: None,
+    })
+}
+
+/// Reads the value of a key of a component into whichever part it belongs to, counting
+/// in `unknown` the parts it doesn't belong to.
+fn match_key(
+    name: &str,
+    (compound, format, interactions): (&mut CompoundParts, &mut Format, &mut Interactivity),
+    first: char,
+    chars: &mut Cursor,
+    unknown: &mut u8,
+) -> SnbtResult<()> {
+    match_content(name, compound, first, chars, unknown)?;
+    match_format(name, format, first, chars, unknown)?;
+    match_interactions(name, interactions, first, chars, unknown)
 }
 
 fn match_content(
     name: &str,
     compound: &mut CompoundParts,
     first: char,
-    chars: &mut Peekable<Chars>,
+    chars: &mut Cursor,
     unknown: &mut u8,
 ) -> SnbtResult<()> {
-    match name {
-        "type" => {
-            if first == '\'' || first == '"' {
-                compound.content = parse_string(first, chars)?;
-                return Ok(());
-            }
-            Err(SnbtError::WrongContentType(name.to_string()))
-        }
-        "text" => {
-            if first == '\'' || first == '"' {
-                compound.contents[0] = Some(Content::Text {
-                    text: Cow::Owned(parse_string(first, chars)?),
-                });
-                return Ok(());
-            }
-            Err(SnbtError::WrongContentType(name.to_string()))
-        }
-        "translate" => {
-            if first == '\'' || first == '"' {
-                if let Some(Content::Translate(msg)) = &mut compound.contents[1] {
-                    msg.key = Cow::Owned(parse_string(first, chars)?);
-                } else {
-                    compound.contents[1] = Some(Content::Translate(TranslatedMessage {
-                        key: Cow::Owned(parse_string(first, chars)?),
-                        fallback: None,
-                        args: None,
-                    }));
-                }
-                return Ok(());
-            }
-            Err(SnbtError::WrongContentType(name.to_string()))
-        }
-        "fallback" => {
-            if first == '\'' || first == '"' {
-                if let Some(Content::Translate(msg)) = &mut compound.contents[1] {
-                    msg.fallback = Some(Cow::Owned(parse_string(first, chars)?));
-                } else {
-                    compound.contents[1] = Some(Content::Translate(TranslatedMessage {
-                        key: Cow::Borrowed(""),
-                        fallback: Some(Cow::Owned(parse_string(first, chars)?)),
-                        args: None,
-                    }));
-                }
-                return Ok(());
-            }
-            Err(SnbtError::WrongContentType(name.to_string()))
+    let string = |chars: &mut Cursor| {
+        if first == '\'' || first == '"' {
+            return parse_string(first, chars);
         }
+        Err(SnbtError::WrongContentType(name.to_string()))
+    };
+    match name {
+        "type" => compound.content = Some(string(chars)?),
+        "text" => compound.text = Some(string(chars)?),
+        "translate" => compound.translate = Some(string(chars)?),
+        "fallback" => compound.fallback = Some(string(chars)?),
         "with" => {
-            if first == '[' {
-                if let Some(Content::Translate(msg)) = &mut compound.contents[1] {
-                    msg.args = Some(parse_vec(chars)?.into_boxed_slice());
-                } else {
-                    compound.contents[1] = Some(Content::Translate(TranslatedMessage {
-                        key: Cow::Borrowed(""),
-                        fallback: None,
-                        args: Some(parse_vec(chars)?.into_boxed_slice()),
-                    }));
-                }
-                return Ok(());
+            if first != '[' {
+                return Err(SnbtError::WrongContentType(name.to_string()));
             }
-            Err(SnbtError::WrongContentType(name.to_string()))
+            compound.with = Some(parse_vec(chars)?);
         }
         "score" => {
-            if first == '{' {
-                compound.contents[2] = Some(parse_scoreboard(chars)?);
-            }
-            Err(SnbtError::WrongContentType(name.to_string()))
-        }
-        "selector" => {
-            if first == '\'' || first == '"' {
-                if let Some(Content::Resolvable(Resolvable::Entity { selector, .. })) =
-                    &mut compound.contents[3]
-                {
-                    *selector = Cow::Owned(parse_string(first, chars)?);
-                } else {
-                    compound.contents[3] = Some(Content::Resolvable(Resolvable::Entity {
-                        selector: Cow::Owned(parse_string(first, chars)?),
-                        separator: Resolvable::entity_separator(),
-                    }));
-                }
-                return Ok(());
+            if first != '{' {
+                return Err(SnbtError::WrongContentType(name.to_string()));
             }
-            Err(SnbtError::WrongContentType(name.to_string()))
+            compound.score = Some(parse_scoreboard(chars)?);
         }
+        "selector" => compound.selector = Some(string(chars)?),
         "separator" => {
-            if first == '\'' || first == '"' {
-                if let Some(Content::Resolvable(Resolvable::Entity { separator, .. })) =
-                    &mut compound.contents[3]
-                {
-                    **separator = parse_body(Some(first), chars)?;
-                } else {
-                    compound.contents[3] = Some(Content::Resolvable(Resolvable::Entity {
-                        selector: Cow::Borrowed("-None-"),
-                        separator: Box::new(parse_body(Some(first), chars)?),
-                    }));
-                }
-                if let Some(Content::Resolvable(Resolvable::NBT { separator, .. })) =
-                    &mut compound.contents[5]
-                {
-                    **separator = parse_body(Some(first), chars)?;
-                } else {
-                    compound.contents[5] = Some(Content::Resolvable(Resolvable::NBT {
-                        path: Cow::Borrowed("-None-"),
-                        interpret: None,
-                        separator: Box::new(parse_body(Some(first), chars)?),
-                        source: NbtSource::Block(Cow::Borrowed("")),
-                    }));
-                }
-                return Ok(());
-            }
-            Err(SnbtError::WrongContentType(name.to_string()))
-        }
-        "keybind" => {
-            if first == '\'' || first == '"' {
-                compound.contents[4] = Some(Content::Keybind {
-                    keybind: Cow::Owned(parse_string(first, chars)?),
-                });
-                return Ok(());
+            if !matches!(first, '\'' | '"' | '{' | '[') {
+                return Err(SnbtError::WrongContentType(name.to_string()));
             }
-            Err(SnbtError::WrongContentType(name.to_string()))
+            compound.separator = Some(parse_body(Some(first), chars)?);
         }
+        "keybind" => compound.keybind = Some(string(chars)?),
+        "nbt" => compound.nbt = Some(string(chars)?),
+        "interpret" => compound.interpret = Some(parse_bool(first, chars, "interpret")?),
         "source" => {
-            if first == '\'' || first == '"' {
-                compound.nbt = parse_string(first, chars)?;
-                return match compound.object.as_str() {
-                    "block" | "entity" | "storage" => Ok(()),
-                    _ => Err(SnbtError::UnknownKey(compound.object.clone())),
-                };
-            }
-            Err(SnbtError::WrongContentType(name.to_string()))
-        }
-        "nbt" => {
-            if first == '\'' || first == '"' {
-                if let Some(Content::Resolvable(Resolvable::NBT { path, .. })) =
-                    &mut compound.contents[5]
-                {
-                    *path = Cow::Owned(parse_string(first, chars)?);
-                } else {
-                    compound.contents[5] = Some(Content::Resolvable(Resolvable::NBT {
-                        path: Cow::Owned(parse_string(first, chars)?),
-                        interpret: None,
-                        separator: Resolvable::nbt_separator(),
-                        source: NbtSource::Block(Cow::Borrowed("")),
-                    }));
-                }
-                return Ok(());
+            let source = string(chars)?;
+            if !matches!(source.as_str(), "block" | "entity" | "storage") {
+                return Err(SnbtError::UnknownKey(source));
             }
-            Err(SnbtError::WrongContentType(name.to_string()))
-        }
-        "interpret" => {
-            if let Some(Content::Resolvable(Resolvable::NBT { interpret, .. })) =
-                &mut compound.contents[5]
-            {
-                *interpret = Some(parse_bool(first, chars, "interpret")?);
-            } else {
-                compound.contents[5] = Some(Content::Resolvable(Resolvable::NBT {
-                    path: Cow::Borrowed("-None-"),
-                    interpret: Some(parse_bool(first, chars, "interpret")?),
-                    separator: Resolvable::nbt_separator(),
-                    source: NbtSource::Block(Cow::Borrowed("")),
-                }));
-            }
-            Ok(())
+            compound.source = Some(source);
         }
         "entity" => {
-            if first == '\'' || first == '"' {
-                compound.nbt_sources[0] =
-                    Some(NbtSource::Entity(Cow::Owned(parse_string(first, chars)?)));
-                return Ok(());
-            }
-            Err(SnbtError::WrongContentType(name.to_string()))
+            compound.nbt_sources[0] = Some(NbtSource::Entity(Cow::Owned(string(chars)?)));
         }
         "block" => {
-            if first == '\'' || first == '"' {
-                compound.nbt_sources[1] =
-                    Some(NbtSource::Block(Cow::Owned(parse_string(first, chars)?)));
-                return Ok(());
-            }
-            Err(SnbtError::WrongContentType(name.to_string()))
+            compound.nbt_sources[1] = Some(NbtSource::Block(Cow::Owned(string(chars)?)));
         }
         "storage" => {
-            if first == '\'' || first == '"' {
-                compound.nbt_sources[2] =
-                    Some(NbtSource::Storage(Cow::Owned(parse_string(first, chars)?)));
-                return Ok(());
-            }
-            Err(SnbtError::WrongContentType(name.to_string()))
+            compound.nbt_sources[2] = Some(NbtSource::Storage(Cow::Owned(string(chars)?)));
         }
         "object" => {
-            if first == '\'' || first == '"' {
-                compound.object = parse_string(first, chars)?;
-                return match compound.object.as_str() {
-                    "player" | "atlas" => Ok(()),
-                    _ => Err(SnbtError::UnknownKey(compound.object.clone())),
-                };
+            let object = string(chars)?;
+            if !matches!(object.as_str(), "player" | "atlas") {
+                return Err(SnbtError::UnknownKey(object));
             }
-            Err(SnbtError::WrongContentType(name.to_string()))
-        }
-        "atlas" => {
-            if first == '\'' || first == '"' {
-                if let Some(Content::Object(Object::Atlas { atlas, .. })) =
-                    &mut compound.contents[6]
-                {
-                    *atlas = Some(Cow::Owned(parse_string(first, chars)?));
-                } else {
-                    compound.contents[6] = Some(Content::Object(Object::Atlas {
-                        atlas: Some(Cow::Owned(parse_string(first, chars)?)),
-                        sprite: Cow::Borrowed("-None-"),
-                    }));
-                }
-                return Ok(());
-            }
-            Err(SnbtError::WrongContentType(name.to_string()))
-        }
-        "sprite" => {
-            if first == '\'' || first == '"' {
-                if let Some(Content::Object(Object::Atlas { sprite, .. })) =
-                    &mut compound.contents[6]
-                {
-                    *sprite = Cow::Owned(parse_string(first, chars)?);
-                } else {
-                    compound.contents[6] = Some(Content::Object(Object::Atlas {
-                        atlas: None,
-                        sprite: Cow::Owned(parse_string(first, chars)?),
-                    }));
-                }
-                return Ok(());
-            }
-            Err(SnbtError::WrongContentType(name.to_string()))
+            compound.object = Some(object);
         }
+        "atlas" => compound.atlas = Some(string(chars)?),
+        "sprite" => compound.sprite = Some(string(chars)?),
         "player" => {
-            if first == '{' {
-                if let Some(Content::Object(Object::Player { player, .. })) =
-                    &mut compound.contents[7]
-                {
-                    *player = parse_player(chars)?;
-                } else {
-                    compound.contents[7] = Some(Content::Object(Object::Player {
-                        player: parse_player(chars)?,
-                        hat: true,
-                    }));
-                }
-                return Ok(());
-            }
-            Err(SnbtError::WrongContentType(name.to_string()))
-        }
-        "hat" => {
-            if first == '{' {
-                if let Some(Content::Object(Object::Player { hat, .. })) = &mut compound.contents[7]
-                {
-                    *hat = parse_bool(first, chars, "hat")?;
-                } else {
-                    compound.contents[7] = Some(Content::Object(Object::Player {
-                        player: ObjectPlayer {
-                            name: None,
-                            id: None,
-                            texture: None,
-                            properties: vec![],
-                        },
-                        hat: parse_bool(first, chars, "hat")?,
-                    }));
-                }
-                return Ok(());
-            }
-            Err(SnbtError::WrongContentType(name.to_string()))
+            compound.player = Some(match first {
+                '{' => parse_player(chars)?,
+                _ => ObjectPlayer::name(string(chars)?),
+            });
         }
+        "hat" => compound.hat = Some(parse_bool(first, chars, "hat")?),
         #[cfg(feature = "custom")]
         "custom" => {
-            if first == '{' {
-                compound.contents[8] = Some(Content::Custom(parse_custom(chars)?));
+            if first != '{' {
+                return Err(SnbtError::WrongContentType(name.to_string()));
             }
-            Err(SnbtError::WrongContentType(name.to_string()))
-        }
-        _ => {
-            unknown.add_assign(1);
-            Ok(())
+            compound.custom = Some(parse_custom(chars)?);
         }
+        _ => unknown.add_assign(1),
     }
+    Ok(())
 }
 
-fn parse_scoreboard(chars: &mut Peekable<Chars>) -> SnbtResult<Content> {
+fn parse_scoreboard(chars: &mut Cursor) -> SnbtResult<Content> {
     let mut selector = None;
     let mut objective = None;
     let mut name = String::new();
@@ -555,11 +799,13 @@ fn parse_scoreboard(chars: &mut Peekable<Chars>) -> SnbtResult<Content> {
                 in_name = false;
                 while let Some(next) = chars.peek() {
                     if next.is_whitespace() {
+                        chars.next();
                         continue;
                     }
                     match next {
                         '\'' | '"' => {
-                            let next = chars.next().unwrap();
+                            let next = *next;
+                            chars.next();
                             match name.as_str() {
                                 "name" => selector = Some(parse_string(next, chars)?),
                                 "objective" => objective = Some(parse_string(next, chars)?),
@@ -578,13 +824,8 @@ fn parse_scoreboard(chars: &mut Peekable<Chars>) -> SnbtResult<Content> {
     }
     Err(SnbtError::EndedAbruptely(line!()))
 }
-fn parse_player(chars: &mut Peekable<Chars>) -> SnbtResult<ObjectPlayer> {
-    let mut player = ObjectPlayer {
-        name: None,
-        id: None,
-        texture: None,
-        properties: vec![],
-    };
+fn parse_player(chars: &mut Cursor) -> SnbtResult<ObjectPlayer> {
+    let mut player = ObjectPlayer::empty();
     let mut name = String::new();
     let mut in_name = true;
     while let Some(char) = chars.next() {
@@ -614,11 +855,13 @@ fn parse_player(chars: &mut Peekable<Chars>) -> SnbtResult<ObjectPlayer> {
                 in_name = false;
                 while let Some(next) = chars.peek() {
                     if next.is_whitespace() {
+                        chars.next();
                         continue;
                     }
                     match next {
                         '\'' | '"' => {
-                            let next = chars.next().unwrap();
+                            let next = *next;
+                            chars.next();
                             match name.as_str() {
                                 "name" => {
                                     player.name = Some(Cow::Owned(parse_string(next, chars)?))
@@ -632,14 +875,14 @@ fn parse_player(chars: &mut Peekable<Chars>) -> SnbtResult<ObjectPlayer> {
                             break;
                         }
                         '[' => {
-                            chars.next().unwrap();
+                            chars.next();
                             match name.as_str() {
                                 "id" => {
                                     let nums = parse_int_vec(chars, "Player id")?;
-                                    if nums.len() != 4 {
+                                    let [a, b, c, d] = nums[..] else {
                                         return Err(SnbtError::UnfinishedComponent(line!()));
-                                    }
-                                    player.id = Some([nums[0], nums[1], nums[2], nums[3]]);
+                                    };
+                                    player.id = Some([a, b, c, d]);
                                 }
                                 "properties" => {
                                     let mut properties = vec![];
@@ -675,7 +918,7 @@ fn parse_player(chars: &mut Peekable<Chars>) -> SnbtResult<ObjectPlayer> {
     }
     Err(SnbtError::EndedAbruptely(line!()))
 }
-fn parse_player_property(chars: &mut Peekable<Chars>) -> SnbtResult<PlayerProperties> {
+fn parse_player_property(chars: &mut Cursor) -> SnbtResult<PlayerProperties> {
     let mut property = PlayerProperties {
         name: Cow::Borrowed("-None-"),
         value: Cow::Borrowed("-None-"),
@@ -716,11 +959,13 @@ fn parse_player_property(chars: &mut Peekable<Chars>) -> SnbtResult<PlayerProper
                 in_name = false;
                 while let Some(next) = chars.peek() {
                     if next.is_whitespace() {
+                        chars.next();
                         continue;
                     }
                     match next {
                         '\'' | '"' => {
-                            let next = chars.next().unwrap();
+                            let next = *next;
+                            chars.next();
                             match name.as_str() {
                                 "name" => property.name = Cow::Owned(parse_string(next, chars)?),
                                 "value" => property.value = Cow::Owned(parse_string(next, chars)?),
@@ -744,7 +989,7 @@ fn parse_player_property(chars: &mut Peekable<Chars>) -> SnbtResult<PlayerProper
     Err(SnbtError::EndedAbruptely(line!()))
 }
 #[cfg(feature = "custom")]
-fn parse_custom(chars: &mut Peekable<Chars>) -> SnbtResult<CustomData> {
+fn parse_custom(chars: &mut Cursor) -> SnbtResult<CustomData> {
     let mut id = None;
     let mut name = String::new();
     let mut in_name = true;
@@ -778,11 +1023,13 @@ fn parse_custom(chars: &mut Peekable<Chars>) -> SnbtResult<CustomData> {
                 in_name = false;
                 while let Some(next) = chars.peek() {
                     if next.is_whitespace() {
+                        chars.next();
                         continue;
                     }
                     match next {
                         '\'' | '"' => {
-                            let next = chars.next().unwrap();
+                            let next = *next;
+                            chars.next();
                             match name.as_str() {
                                 "id" => id = Some(parse_string(next, chars)?),
                                 key => return Err(SnbtError::UnknownKey(key.to_string())),
@@ -802,132 +1049,140 @@ fn parse_custom(chars: &mut Peekable<Chars>) -> SnbtResult<CustomData> {
     Err(SnbtError::EndedAbruptely(line!()))
 }
 
-fn retrieve_content(compound: CompoundParts) -> SnbtResult<Content> {
-    let mut error = SnbtError::MissingContent;
-    let pos = match compound.content.as_str() {
-        "text" => Some(0),
-        "translatable" => Some(1),
-        "score" => Some(2),
-        "selector" => Some(3),
-        "keybind" => Some(4),
-        "nbt" => Some(5),
-        "object" => {
-            if compound.object == "player" {
-                Some(7)
-            } else {
-                Some(6)
-            }
-        }
-        #[cfg(feature = "custom")]
-        "custom" => Some(8),
-        "" => None,
-        _ => return Err(SnbtError::UnknownKey(compound.content)),
-    };
-    for (i, content) in compound.contents.into_iter().enumerate() {
-        if let Some(pos) = pos
-            && i != pos
-        {
-            continue;
-        }
-        if i == 6 && compound.object == "player" {
-            continue;
+/// The content types in the order the game tries them when there's no `type` key.
+const CONTENT_TYPES: [&str; 8] = [
+    "text",
+    "translatable",
+    "score",
+    "selector",
+    "keybind",
+    "nbt",
+    "object",
+    "custom",
+];
+
+fn retrieve_content(mut compound: CompoundParts) -> SnbtResult<Content> {
+    if let Some(content) = compound.content.take() {
+        if !CONTENT_TYPES.contains(&content.as_str()) {
+            return Err(SnbtError::UnknownKey(content));
         }
-        let Some(content) = content else {
-            continue;
-        };
-        match match_content_type(content, &compound.nbt, &compound.nbt_sources) {
-            Ok(content) => return Ok(content),
-            Err(err) => error = err,
+        return build_content(&content, &mut compound).unwrap_or(Err(SnbtError::MissingContent));
+    }
+    let mut error = SnbtError::MissingContent;
+    for content in CONTENT_TYPES {
+        match build_content(content, &mut compound) {
+            Some(Ok(content)) => return Ok(content),
+            Some(Err(err)) => error = err,
+            None => (),
         }
     }
     Err(error)
 }
-fn match_content_type(
-    mut content: Content,
-    nbt: &str,
-    nbt_sources: &[Option<NbtSource>; 3],
-) -> SnbtResult<Content> {
-    match &mut content {
-        Content::Translate(msg) => {
-            if !msg.key.is_empty() {
-                return Ok(content);
-            }
-            Err(SnbtError::Required(
-                String::from("Translations"),
-                String::from("key"),
-            ))
-        }
-        Content::Resolvable(Resolvable::Entity { selector, .. }) => {
-            if selector != "-None-" {
-                return Ok(content);
-            }
-            Err(SnbtError::Required(
-                String::from("Entities"),
-                String::from("selector"),
-            ))
-        }
-        Content::Resolvable(Resolvable::NBT { path, source, .. }) => {
-            match nbt {
-                "entity" => {
-                    let Some(entity) = &nbt_sources[0] else {
-                        return Err(SnbtError::Required(
-                            String::from("Nbt"),
-                            String::from("entity"),
-                        ));
-                    };
-                    *source = entity.clone();
-                }
-                "block" => {
-                    let Some(block) = &nbt_sources[1] else {
-                        return Err(SnbtError::Required(
-                            String::from("Nbt"),
-                            String::from("block"),
-                        ));
-                    };
-                    *source = block.clone();
-                }
-                "storage" => {
-                    let Some(storage) = &nbt_sources[2] else {
-                        return Err(SnbtError::Required(
-                            String::from("Nbt"),
-                            String::from("storage"),
-                        ));
-                    };
-                    *source = storage.clone();
-                }
-                _ => {
-                    if let Some(nbt) = nbt_sources.iter().flatten().next() {
-                        *source = nbt.clone();
-                    }
-                }
-            }
-            if path != "-None-" {
-                return Ok(content);
-            }
-            Err(SnbtError::Required(
-                String::from("Nbt"),
-                String::from("entity, \"block, or \"storage"),
-            ))
-        }
-        Content::Object(Object::Atlas { sprite, .. }) => {
-            if sprite != "-None-" {
-                return Ok(content);
+
+/// Builds the content of the given type, [None] if none of its keys are present.
+fn build_content(content: &str, compound: &mut CompoundParts) -> Option<SnbtResult<Content>> {
+    match content {
+        "text" => Some(Ok(Content::Text {
+            text: Cow::Owned(compound.text.take()?),
+        })),
+        "translatable" => {
+            if compound.translate.is_none()
+                && compound.fallback.is_none()
+                && compound.with.is_none()
+            {
+                return None;
             }
-            Err(SnbtError::Required(
-                String::from("Atlas object"),
-                String::from("sprite"),
-            ))
+            let Some(key) = compound.translate.take() else {
+                return Some(Err(SnbtError::Required(
+                    String::from("Translations"),
+                    String::from("translate"),
+                )));
+            };
+            Some(Ok(Content::Translate(TranslatedMessage {
+                key: key.into(),
+                fallback: compound.fallback.take().map(Cow::Owned),
+                args: compound.with.take().map(Vec::into_boxed_slice),
+            })))
+        }
+        "score" => compound.score.take().map(Ok),
+        "selector" => Some(Ok(Content::Resolvable(Resolvable::Entity {
+            selector: Cow::Owned(compound.selector.take()?),
+            separator: match compound.separator.clone() {
+                Some(separator) => Box::new(separator),
+                None => Resolvable::entity_separator(),
+            },
+        }))),
+        "keybind" => Some(Ok(Content::Keybind {
+            keybind: normalize_keybind(Cow::Owned(compound.keybind.take()?)),
+        })),
+        "nbt" => {
+            if compound.nbt.is_none() && compound.interpret.is_none() && compound.source.is_none() {
+                return None;
+            }
+            let Some(path) = compound.nbt.take() else {
+                return Some(Err(SnbtError::Required(
+                    String::from("Nbt"),
+                    String::from("nbt"),
+                )));
+            };
+            let source = match compound.source.as_deref() {
+                Some("entity") => compound.nbt_sources[0].take(),
+                Some("block") => compound.nbt_sources[1].take(),
+                Some("storage") => compound.nbt_sources[2].take(),
+                _ => compound.nbt_sources.iter_mut().find_map(Option::take),
+            };
+            let Some(source) = source else {
+                return Some(Err(SnbtError::Required(
+                    String::from("Nbt"),
+                    compound
+                        .source
+                        .clone()
+                        .unwrap_or(String::from("entity\", \"block\", or \"storage")),
+                )));
+            };
+            Some(Ok(Content::Resolvable(Resolvable::NBT {
+                path: Cow::Owned(path),
+                interpret: compound.interpret,
+                separator: match compound.separator.clone() {
+                    Some(separator) => Box::new(separator),
+                    None => Resolvable::nbt_separator(),
+                },
+                source,
+            })))
         }
-        Content::Object(Object::Player { player, .. }) => {
-            if !player.is_empty() {
-                return Ok(content);
-            }
-            Err(SnbtError::Required(
-                String::from("Player object"),
-                String::from("player"),
-            ))
+        "object" => {
+            let player = match compound.object.as_deref() {
+                Some(object) => object == "player",
+                None if compound.player.is_some() || compound.hat.is_some() => true,
+                None if compound.sprite.is_some() || compound.atlas.is_some() => false,
+                None => return None,
+            };
+            if player {
+                let Some(player) = compound.player.take() else {
+                    return Some(Err(SnbtError::Required(
+                        String::from("Player object"),
+                        String::from("player"),
+                    )));
+                };
+                return Some(Ok(Content::Object(Object::Player {
+                    player,
+                    hat: compound.hat.unwrap_or(true),
+                })));
+            }
+            let Some(sprite) = compound.sprite.take() else {
+                return Some(Err(SnbtError::Required(
+                    String::from("Atlas object"),
+                    String::from("sprite"),
+                )));
+            };
+            Some(Ok(Content::Object(Object::Atlas {
+                atlas: compound.atlas.take().map(Cow::Owned),
+                sprite: Cow::Owned(sprite),
+            })))
         }
-        _ => Ok(content),
+        #[cfg(feature = "custom")]
+        "custom" => compound.custom.take().map(|data| Ok(Content::Custom(data))),
+        _ => None,
     }
 }
 
@@ -935,7 +1190,7 @@ fn match_format(
     name: &str,
     format: &mut Format,
     first: char,
-    chars: &mut Peekable<Chars>,
+    chars: &mut Cursor,
     unknown: &mut u8,
 ) -> SnbtResult<()> {
     match name {
@@ -959,13 +1214,16 @@ fn match_format(
                     "red" => format.color = Some(Color::Red),
                     "white" => format.color = Some(Color::White),
                     "yellow" => format.color = Some(Color::Yellow),
-                    color => {
-                        if let Some(color) = Color::from_hex(color) {
-                            format.color = Some(color);
-                        } else {
-                            return Err(SnbtError::UnknownColor(color.to_string()));
+                    color => match Color::parse_hex(color) {
+                        Ok(color) => format.color = Some(color),
+                        Err(error @ HexColorError::Argb(_)) => {
+                            return Err(SnbtError::HexColor(error));
                         }
-                    }
+                        Err(error) if color.starts_with('#') => {
+                            return Err(SnbtError::HexColor(error));
+                        }
+                        Err(_) => return Err(SnbtError::UnknownColor(color.to_string())),
+                    },
                 }
                 return Ok(());
             }
@@ -973,7 +1231,7 @@ fn match_format(
         }
         "font" => {
             if first == '\'' || first == '"' {
-                format.font = Some(Cow::Owned(parse_string(first, chars)?));
+                format.font = Some(namespaced_font(parse_string(first, chars)?.into()));
                 return Ok(());
             }
             Err(SnbtError::WrongContentType(String::from("font")))
@@ -1000,44 +1258,19 @@ fn match_format(
         }
         "shadow_color" => {
             if first == '[' {
-                let mut nums = vec![];
-                let mut num = String::new();
-                for char in chars.by_ref() {
-                    if char == ']' {
-                        nums.push(num.clone());
-                        break;
-                    }
-                    if char.is_whitespace() {
-                        continue;
-                    }
-                    if char == ',' {
-                        nums.push(num.clone());
-                        num = String::new();
-                    }
-                    if char.is_numeric() || char == '.' {
-                        num.push(char);
-                    }
-                }
-                if nums.len() == 4 {
-                    let mut nums = nums.iter().enumerate();
-                    let mut num = 0;
-                    let (_, n) = nums.next_back().unwrap();
-                    let Ok(n) = n.parse::<f32>() else {
-                        return Err(SnbtError::WrongContentType(String::from("shadow_color")));
-                    };
-                    num += (((n as u32) * 255) << 24) as i64;
-                    for (i, n) in nums {
-                        let Ok(n) = n.parse::<f32>() else {
-                            return Err(SnbtError::WrongContentType(String::from("shadow_color")));
-                        };
-                        num += (((n as u32) * 255) << (24 - 8 * ((i + 1) % 3))) as i64;
-                    }
-                    format.shadow_color = Some(num);
-                    return Ok(());
+                let nums = parse_float_vec(chars, "shadow_color")?;
+                let [r, g, b, a] = nums[..] else {
+                    return Err(SnbtError::Required(
+                        String::from("Shadow color lists"),
+                        String::from("4 numbers"),
+                    ));
                 };
-                return Err(SnbtError::WrongContentType(String::from("shadow_color")));
+                format.shadow_color = Some(Format::float_shadow_color(r, g, b, a));
+                return Ok(());
             }
-            format.shadow_color = Some(parse_num(first, chars, "shadow_color")?.as_i64());
+            format.shadow_color = Some(Format::packed_shadow_color(
+                parse_num(first, chars, "shadow_color")?.as_i64(),
+            ));
             Ok(())
         }
         _ => {
@@ -1051,13 +1284,13 @@ fn match_interactions(
     name: &str,
     interactions: &mut Interactivity,
     first: char,
-    chars: &mut Peekable<Chars>,
+    chars: &mut Cursor,
     unknown: &mut u8,
 ) -> SnbtResult<()> {
     match name {
         "insertion" => {
-            if first == '\'' && first == '"' {
-                interactions.insertion = Some(Cow::Owned(parse_string(first, chars)?));
+            if first == '\'' || first == '"' {
+                interactions.insertion = Some(parse_string(first, chars)?.into());
                 return Ok(());
             }
             Err(SnbtError::WrongContentType(String::from("insertion")))
@@ -1083,7 +1316,7 @@ fn match_interactions(
     }
 }
 
-fn parse_click(chars: &mut Peekable<Chars>) -> SnbtResult<ClickEvent> {
+fn parse_click(chars: &mut Cursor) -> SnbtResult<ClickEvent> {
     let mut action = String::new();
     let mut events = [None, None, None, None, None, None, None, None];
     let mut name = String::new();
@@ -1175,11 +1408,13 @@ fn parse_click(chars: &mut Peekable<Chars>) -> SnbtResult<ClickEvent> {
                 in_name = false;
                 while let Some(next) = chars.peek() {
                     if next.is_whitespace() {
+                        chars.next();
                         continue;
                     }
                     match next {
                         '\'' | '"' => {
-                            let next = chars.next().unwrap();
+                            let next = *next;
+                            chars.next();
                             match name.as_str() {
                                 "action" => action = parse_string(next, chars)?,
                                 "url" => {
@@ -1195,11 +1430,6 @@ fn parse_click(chars: &mut Peekable<Chars>) -> SnbtResult<ClickEvent> {
                                     });
                                     events[3] = Some(ClickEvent::SuggestCommand { command })
                                 }
-                                "page" => {
-                                    events[4] = Some(ClickEvent::ChangePage {
-                                        page: parse_num(next, chars, "page")?.as_i32(),
-                                    })
-                                }
                                 "value" => {
                                     events[5] = Some(ClickEvent::CopyToClipboard {
                                         value: Cow::Owned(parse_string(next, chars)?),
@@ -1207,7 +1437,9 @@ fn parse_click(chars: &mut Peekable<Chars>) -> SnbtResult<ClickEvent> {
                                 }
                                 "dialog" => {
                                     events[6] = Some(ClickEvent::ShowDialog {
-                                        dialog: Cow::Owned(parse_string(next, chars)?),
+                                        dialog: DialogRef::Id(Cow::Owned(parse_string(
+                                            next, chars,
+                                        )?)),
                                     })
                                 }
                                 #[cfg(feature = "custom")]
@@ -1224,6 +1456,13 @@ fn parse_click(chars: &mut Peekable<Chars>) -> SnbtResult<ClickEvent> {
                                 key => return Err(SnbtError::UnknownKey(key.to_string())),
                             }
                         }
+                        _ if name == "page" => {
+                            let next = *next;
+                            chars.next();
+                            events[4] = Some(ClickEvent::ChangePage {
+                                page: parse_num(next, chars, "page")?.as_i32(),
+                            })
+                        }
                         // TODO: Add parsing for payloads
                         _ => return Err(SnbtError::UnfinishedComponent(line!())),
                     }
@@ -1237,7 +1476,7 @@ fn parse_click(chars: &mut Peekable<Chars>) -> SnbtResult<ClickEvent> {
     }
     Err(SnbtError::EndedAbruptely(line!()))
 }
-fn parse_hover(chars: &mut Peekable<Chars>) -> SnbtResult<HoverEvent> {
+fn parse_hover(chars: &mut Cursor) -> SnbtResult<HoverEvent> {
     let mut action = String::new();
     let mut events = [None, None, None];
     let mut name = String::new();
@@ -1314,8 +1553,12 @@ fn parse_hover(chars: &mut Peekable<Chars>) -> SnbtResult<HoverEvent> {
                     match name.as_str() {
                         "action" => action = parse_string(next, chars)?,
                         "value" => {
+                            let value = match next {
+                                '\'' | '"' => embedded_or_plain(parse_string(next, chars)?),
+                                _ => parse_body(Some(next), chars)?,
+                            };
                             events[0] = Some(HoverEvent::ShowText {
-                                value: Box::new(parse_body(Some(next), chars)?),
+                                value: Box::new(value),
                             })
                         }
                         "id" => match next {
@@ -1406,16 +1649,14 @@ fn parse_hover(chars: &mut Peekable<Chars>) -> SnbtResult<HoverEvent> {
                                 }
                                 '[' => {
                                     let nums = parse_int_vec(chars, "uuid")?;
-                                    if nums.len() != 4 {
+                                    let [a, b, c, d] = nums[..] else {
                                         return Err(SnbtError::WrongContentType(String::from(
                                             "uuid",
                                         )));
-                                    }
+                                    };
                                     Uuid::from_u64_pair(
-                                        (((nums[0] as u32) as u64) << 32)
-                                            + ((nums[1] as u32) as u64),
-                                        (((nums[2] as u32) as u64) << 32)
-                                            + ((nums[3] as u32) as u64),
+                                        (((a as u32) as u64) << 32) + ((b as u32) as u64),
+                                        (((c as u32) as u64) << 32) + ((d as u32) as u64),
                                     )
                                 }
                                 _ => return Err(SnbtError::WrongContentType(String::from("uuid"))),
@@ -1447,7 +1688,7 @@ fn parse_hover(chars: &mut Peekable<Chars>) -> SnbtResult<HoverEvent> {
     Err(SnbtError::EndedAbruptely(line!()))
 }
 
-fn parse_bool(first: char, chars: &mut Peekable<Chars>, content_type: &str) -> SnbtResult<bool> {
+fn parse_bool(first: char, chars: &mut Cursor, content_type: &str) -> SnbtResult<bool> {
     if first.is_numeric() || first == '-' {
         return match parse_num(first, chars, content_type)? {
             Num::I8(num) => Ok(num != 0),
@@ -1476,7 +1717,7 @@ fn parse_bool(first: char, chars: &mut Peekable<Chars>, content_type: &str) -> S
                 text.push(*next);
                 if text == "false" {
                     let _ = chars.next();
-                    return Ok(true);
+                    return Ok(false);
                 }
                 if "false".starts_with(&text) {
                     let _ = chars.next();
@@ -1490,7 +1731,9 @@ fn parse_bool(first: char, chars: &mut Peekable<Chars>, content_type: &str) -> S
     Err(SnbtError::WrongContentType(content_type.to_string()))
 }
 
-enum Num {
+/// A SNBT number, unsigned ones are kept in the signed type of the same size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Num {
     /// Snbt byte
     I8(i8),
     /// Snbt short
@@ -1505,6 +1748,19 @@ enum Num {
     F64(f64),
 }
 impl Num {
+    /// Parses a whole string as a SNBT number, like `12`, `-3b`, `255ub`, `.5f` or `1.5e3`.
+    pub fn from_snbt(string: &str) -> SnbtResult<Num> {
+        let mode = ParseMode::Normal;
+        let mut chars = Cursor::new(string.trim(), &mode);
+        let Some(first) = chars.next() else {
+            return Err(SnbtError::WrongContentType(String::from("number")));
+        };
+        let num = parse_num(first, &mut chars, "number")?;
+        match chars.next() {
+            Some(_) => Err(SnbtError::WrongContentType(String::from("number"))),
+            None => Ok(num),
+        }
+    }
     pub fn as_i32(&self) -> i32 {
         match self {
             Num::I8(n) => *n as i32,
@@ -1515,6 +1771,16 @@ impl Num {
             Num::F64(n) => *n as i32,
         }
     }
+    pub fn as_f32(&self) -> f32 {
+        match self {
+            Num::I8(n) => *n as f32,
+            Num::I16(n) => *n as f32,
+            Num::I32(n) => *n as f32,
+            Num::I64(n) => *n as f32,
+            Num::F32(n) => *n,
+            Num::F64(n) => *n as f32,
+        }
+    }
     pub fn as_i64(&self) -> i64 {
         match self {
             Num::I8(n) => *n as i64,
@@ -1527,121 +1793,175 @@ impl Num {
     }
 }
 
-fn parse_num(first: char, chars: &mut Peekable<Chars>, content_type: &str) -> SnbtResult<Num> {
-    if !first.is_numeric() && first != '-' && first != '.' {
-        return Err(SnbtError::WrongContentType(content_type.to_string()));
+#[derive(Clone, Copy, PartialEq)]
+enum NumState {
+    Start,
+    Sign,
+    Integer,
+    /// A dot without digits before it
+    Dot,
+    Fraction,
+    Exponent,
+    ExponentSign,
+    ExponentDigits,
+}
+
+fn parse_num(first: char, chars: &mut Cursor, content_type: &str) -> SnbtResult<Num> {
+    let wrong = || SnbtError::WrongContentType(content_type.to_string());
+    let mut num = String::new();
+    let mut state = NumState::Start;
+    let mut next = Some(first);
+    while let Some(char) = next {
+        state = match (state, char) {
+            (NumState::Start, '+' | '-') => NumState::Sign,
+            (NumState::Start | NumState::Sign | NumState::Integer, '0'..='9') => NumState::Integer,
+            (NumState::Start | NumState::Sign, '.') => NumState::Dot,
+            (NumState::Integer, '.') => NumState::Fraction,
+            (NumState::Dot | NumState::Fraction, '0'..='9') => NumState::Fraction,
+            (NumState::Integer | NumState::Fraction, 'e' | 'E') => NumState::Exponent,
+            (NumState::Exponent, '+' | '-') => NumState::ExponentSign,
+            (NumState::Exponent | NumState::ExponentSign | NumState::ExponentDigits, '0'..='9') => {
+                NumState::ExponentDigits
+            }
+            _ => break,
+        };
+        num.push(char);
+        // The first char was already taken out of the iterator
+        if num.len() > 1 {
+            chars.next();
+        }
+        next = chars.peek().copied();
     }
-    let mut num = String::from(first);
-    while let Some(next) = chars.peek() {
-        if !next.is_numeric() && next != &'-' && first != '.' {
-            match next.to_lowercase().last().unwrap() {
-                'b' => {
-                    let _ = chars.next();
-                    let Ok(num) = num.parse::<i8>() else {
-                        return Err(SnbtError::NumberOverflow(
-                            content_type.to_string(),
-                            String::from("byte"),
-                        ));
-                    };
-                    return Ok(Num::I8(num));
-                }
-                's' => {
-                    let _ = chars.next();
-                    let Ok(num) = num.parse::<i16>() else {
-                        return Err(SnbtError::NumberOverflow(
-                            content_type.to_string(),
-                            String::from("short"),
-                        ));
-                    };
-                    return Ok(Num::I16(num));
-                }
-                'l' => {
-                    let _ = chars.next();
-                    let Ok(num) = num.parse::<i64>() else {
-                        return Err(SnbtError::NumberOverflow(
-                            content_type.to_string(),
-                            String::from("long"),
-                        ));
-                    };
-                    return Ok(Num::I64(num));
-                }
-                'f' => {
-                    let _ = chars.next();
-                    let Ok(num) = num.parse::<f32>() else {
-                        return Err(SnbtError::NumberOverflow(
-                            content_type.to_string(),
-                            String::from("float"),
-                        ));
-                    };
-                    return Ok(Num::F32(num));
-                }
-                'd' => {
-                    let _ = chars.next();
-                    let Ok(num) = num.parse::<f64>() else {
-                        return Err(SnbtError::NumberOverflow(
-                            content_type.to_string(),
-                            String::from("double"),
-                        ));
-                    };
-                    return Ok(Num::F64(num));
-                }
-                _ => {
-                    if num.contains('.') {
-                        let Ok(num) = num.parse::<f64>() else {
-                            return Err(SnbtError::NumberOverflow(
-                                content_type.to_string(),
-                                String::from("double"),
-                            ));
-                        };
-                        return Ok(Num::F64(num));
-                    }
-                    let Ok(num) = num.parse::<i32>() else {
-                        return Err(SnbtError::NumberOverflow(
-                            content_type.to_string(),
-                            String::from("int"),
-                        ));
-                    };
-                    return Ok(Num::I32(num));
-                }
+    let integer = match state {
+        NumState::Integer => true,
+        NumState::Fraction | NumState::ExponentDigits => false,
+        _ => return Err(wrong()),
+    };
+
+    let mut suffix = String::new();
+    while let Some(char) = chars.peek()
+        && char.is_ascii_alphabetic()
+        && suffix.len() < 2
+    {
+        suffix.push(char.to_ascii_lowercase());
+        chars.next();
+    }
+    if chars
+        .peek()
+        .is_some_and(|char| char.is_alphanumeric() || matches!(char, '.' | '+' | '-'))
+    {
+        return Err(wrong());
+    }
+
+    let overflow = |kind: &str| SnbtError::NumberOverflow(content_type.to_string(), kind.into());
+    match suffix.as_str() {
+        "f" => {
+            let num = num.parse::<f32>().map_err(|_| wrong())?;
+            if num.is_infinite() {
+                return Err(overflow("float"));
+            }
+            return Ok(Num::F32(num));
+        }
+        "d" => {
+            let num = num.parse::<f64>().map_err(|_| wrong())?;
+            if num.is_infinite() {
+                return Err(overflow("double"));
+            }
+            return Ok(Num::F64(num));
+        }
+        "" if !integer => {
+            let num = num.parse::<f64>().map_err(|_| wrong())?;
+            if num.is_infinite() {
+                return Err(overflow("double"));
             }
+            return Ok(Num::F64(num));
         }
-        num.push(*next);
-        let _ = chars.next();
+        _ if !integer => return Err(wrong()),
+        _ => (),
+    }
+    match suffix.as_str() {
+        "b" | "sb" => num.parse().map(Num::I8).map_err(|_| overflow("byte")),
+        "s" | "ss" => num.parse().map(Num::I16).map_err(|_| overflow("short")),
+        "" | "i" | "si" => num.parse().map(Num::I32).map_err(|_| overflow("int")),
+        "l" | "sl" => num.parse().map(Num::I64).map_err(|_| overflow("long")),
+        "ub" => num
+            .parse::<u8>()
+            .map(|num| Num::I8(num as i8))
+            .map_err(|_| overflow("unsigned byte")),
+        "us" => num
+            .parse::<u16>()
+            .map(|num| Num::I16(num as i16))
+            .map_err(|_| overflow("unsigned short")),
+        "ui" => num
+            .parse::<u32>()
+            .map(|num| Num::I32(num as i32))
+            .map_err(|_| overflow("unsigned int")),
+        "ul" => num
+            .parse::<u64>()
+            .map(|num| Num::I64(num as i64))
+            .map_err(|_| overflow("unsigned long")),
+        _ => Err(wrong()),
     }
-    Err(SnbtError::WrongContentType(content_type.to_string()))
 }
 
-fn parse_int_vec(chars: &mut Peekable<Chars>, content_type: &str) -> SnbtResult<Vec<i32>> {
+fn parse_float_vec(chars: &mut Cursor, content_type: &str) -> SnbtResult<Vec<f32>> {
     let mut nums = vec![];
-    let mut inside = false;
-    while let Some(char) = chars.next() {
-        if char.is_whitespace() {
-            continue;
+    loop {
+        while chars.next_if(|char| char.is_whitespace()).is_some() {}
+        let Some(first) = chars.next() else {
+            return Err(SnbtError::EndedAbruptely(line!()));
+        };
+        if first == ']' && nums.is_empty() {
+            return Ok(nums);
         }
-        match char {
-            ']' => return Ok(nums),
-            ',' => inside = false,
-            char if !inside => {
-                let num = parse_num(char, chars, content_type)?;
-                match num {
-                    Num::I32(n) => nums.push(n),
-                    _ => {
-                        return Err(SnbtError::Required(
-                            content_type.to_string(),
-                            String::from("ints"),
-                        ));
-                    }
-                }
-            }
-            'I' => {
-                if let Some(&';') = chars.peek() {
-                    chars.next();
-                } else {
-                    return Err(SnbtError::UnfinishedComponent(line!()));
-                };
-            }
-            _ => return Err(SnbtError::UnfinishedComponent(line!())),
+        nums.push(parse_num(first, chars, content_type)?.as_f32());
+        while chars.next_if(|char| char.is_whitespace()).is_some() {}
+        match chars.next() {
+            Some(']') => return Ok(nums),
+            Some(',') => (),
+            Some(_) => return Err(SnbtError::UnfinishedComponent(line!())),
+            None => return Err(SnbtError::EndedAbruptely(line!())),
+        }
+    }
+}
+
+fn parse_int_vec(chars: &mut Cursor, content_type: &str) -> SnbtResult<Vec<i32>> {
+    let mut nums = vec![];
+    let skip_whitespace = |chars: &mut Cursor| {
+        while chars.next_if(|char| char.is_whitespace()).is_some() {}
+    };
+    // Int arrays can be typed as [I;1,2,3] or written as plain lists
+    skip_whitespace(chars);
+    if chars.next_if_eq(&'I').is_some() {
+        skip_whitespace(chars);
+        if chars.next_if_eq(&';').is_none() {
+            return Err(SnbtError::UnfinishedComponent(line!()));
+        }
+        skip_whitespace(chars);
+    }
+    if chars.next_if_eq(&']').is_some() {
+        return Ok(nums);
+    }
+    loop {
+        skip_whitespace(chars);
+        let Some(first) = chars.next() else {
+            return Err(SnbtError::EndedAbruptely(line!()));
+        };
+        match parse_num(first, chars, content_type)? {
+            Num::I32(num) => nums.push(num),
+            _ => {
+                return Err(SnbtError::Required(
+                    content_type.to_string(),
+                    String::from("ints"),
+                ));
+            }
+        }
+        skip_whitespace(chars);
+        match chars.next() {
+            Some(']') => return Ok(nums),
+            Some(',') => (),
+            Some(_) => return Err(SnbtError::UnfinishedComponent(line!())),
+            None => return Err(SnbtError::EndedAbruptely(line!())),
         }
     }
-    Err(SnbtError::EndedAbruptely(line!()))
 }
@@ -1,619 +1,708 @@
+#[cfg(feature = "custom")]
+use crate::custom::CustomData;
 use crate::{
-    TextComponent,
-    content::{Content, NbtSource, Object, ObjectPlayer, PlayerProperties, Resolvable},
-    custom::CustomData,
+    Modifier, TextComponent,
+    content::{
+        Content, NbtSource, Object, ObjectPlayer, PlayerProperties, Resolvable, normalize_keybind,
+    },
+    dialog::DialogRef,
     format::{Color, Format},
     interactivity::{ClickEvent, HoverEvent, Interactivity},
+    intern::ComponentInterner,
+    nbt::ToSNBT,
+    parse::embedded_or_plain,
     translation::TranslatedMessage,
 };
 
+#[cfg(feature = "unknown-fields")]
+use crate::unknown::{UnknownFields, UnknownValue, is_preserving, is_unknown};
 use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
 use uuid::Uuid;
 
-use std::borrow::Cow;
+use std::{borrow::Cow, error::Error, fmt::Display};
+
+/// Why a tag couldn't be read as a component by [try_from_nbt](TextComponent::try_from_nbt).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NbtParseError {
+    /// A tag without any content, like an empty string or a compound without a content key.
+    MissingContent,
+    Required(String, String),
+    /// The expected type of the tag and the type it has.
+    WrongType(&'static str, &'static str),
+    /// A uuid that isn't a hyphenated string nor 4 ints.
+    InvalidUuid(String),
+    InvalidColor(String),
+    /// An action, object or nbt source the game doesn't have.
+    UnknownValue(String),
+    /// A NaN or infinite number in the `components` of an item, which are kept as SNBT.
+    NonFiniteNumber(String),
+    /// An error and the keys leading to the tag it was found in, like
+    /// `extra[1].hover_event.uuid`.
+    At(String, Box<NbtParseError>),
+}
+impl Error for NbtParseError {}
+impl Display for NbtParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NbtParseError::MissingContent => write!(f, "There's a component without any content."),
+            NbtParseError::Required(content, key) => {
+                write!(f, "{content} requires \"{key}\" to work, but it's missing.")
+            }
+            NbtParseError::WrongType(expected, found) => {
+                write!(f, "Expected a tag of type {expected}, found a {found}.")
+            }
+            NbtParseError::InvalidUuid(uuid) => write!(f, "The uuid {uuid} isn't valid."),
+            NbtParseError::InvalidColor(color) => {
+                write!(f, "The color \"{color}\" can't be parsed.")
+            }
+            NbtParseError::UnknownValue(value) => write!(f, "The value \"{value}\" is unknown."),
+            NbtParseError::NonFiniteNumber(error) => error.fmt(f),
+            NbtParseError::At(path, error) => write!(f, "{error} (At {path})"),
+        }
+    }
+}
+
+impl NbtParseError {
+    /// Puts the key in front of the path of the error, as it goes up to the parsed tag.
+    fn at(self, key: &str) -> Self {
+        match self {
+            NbtParseError::At(path, error) if path.starts_with('[') => {
+                NbtParseError::At(format!("{key}{path}"), error)
+            }
+            NbtParseError::At(path, error) => NbtParseError::At(format!("{key}.{path}"), error),
+            error => NbtParseError::At(key.to_string(), Box::new(error)),
+        }
+    }
+    fn at_index(self, index: usize) -> Self {
+        self.at(&format!("[{index}]"))
+    }
+    /// Whether the error is about the parsed tag itself not being a component.
+    fn is_root(&self) -> bool {
+        !matches!(self, NbtParseError::At(..))
+    }
+}
+
+pub type NbtResult<T> = Result<T, NbtParseError>;
+
+/// Whether a malformed key fails the whole tag, or is left out the way
+/// [from_nbt](TextComponent::from_nbt) always did.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Strict,
+    Lenient,
+}
 
 impl TextComponent {
+    /// Same as [from_nbt](TextComponent::from_nbt), sharing the fonts, translation keys
+    /// and insertions with the other components parsed with the same [ComponentInterner].
+    pub fn from_nbt_interned(tag: &NbtTag, interner: &ComponentInterner) -> Option<Self> {
+        let mut component = TextComponent::from_nbt(tag)?;
+        interner.intern_component(&mut component);
+        Some(component)
+    }
+
+    /// Reads a component from its NBT form, [None] if the tag isn't one.\
+    /// Keys of the wrong type, invalid colors and malformed events or children are left
+    /// out, [try_from_nbt](TextComponent::try_from_nbt) reports them instead.\
+    /// Never panics, whatever the tag.
     pub fn from_nbt(tag: &NbtTag) -> Option<Self> {
+        TextComponent::read_nbt(tag, Mode::Lenient).ok()
+    }
+
+    /// Reads a component from its NBT form, giving what's wrong and where if the tag
+    /// isn't one.
+    /// ## Example
+    /// ```
+    /// # use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+    /// # use text_components::TextComponent;
+    /// # let text = |text: &str| ("text".into(), NbtTag::String(text.into()));
+    /// # let tag = NbtTag::Compound(NbtCompound::from_values(vec![
+    /// #     text("Hi"),
+    /// #     (
+    /// #         "extra".into(),
+    /// #         NbtTag::List(NbtList::Compound(vec![
+    /// #             NbtCompound::from_values(vec![text("a")]),
+    /// #             NbtCompound::from_values(vec![
+    /// #                 text("b"),
+    /// #                 ("color".into(), NbtTag::String("bleu".into())),
+    /// #             ]),
+    /// #         ])),
+    /// #     ),
+    /// # ]));
+    /// // {text:"Hi",extra:["a",{text:"b",color:"bleu"}]}
+    /// let error = TextComponent::try_from_nbt(&tag).unwrap_err();
+    /// // The color "bleu" can't be parsed. (At extra[1].color)
+    /// println!("{error}");
+    /// ```
+    pub fn try_from_nbt(tag: &NbtTag) -> NbtResult<Self> {
+        TextComponent::read_nbt(tag, Mode::Strict)
+    }
+
+    fn read_nbt(tag: &NbtTag, mode: Mode) -> NbtResult<Self> {
         match tag {
-            NbtTag::String(string) => {
-                if string.is_empty() {
-                    return None;
-                }
-                Some(TextComponent::plain(string.to_string()))
-            }
+            NbtTag::String(string) if string.is_empty() => Err(NbtParseError::MissingContent),
+            NbtTag::String(string) => Ok(TextComponent::plain(string.to_string())),
             NbtTag::Compound(compound) => {
-                if let Some(tag) = compound.get("") {
-                    match tag {
-                        NbtTag::String(..) | NbtTag::List(..) => {
-                            return TextComponent::from_nbt(tag);
-                        }
-                        _ => (),
-                    }
+                if let Some(tag @ (NbtTag::String(..) | NbtTag::List(..))) = compound.get("") {
+                    return TextComponent::read_nbt(tag, mode);
                 }
                 let mut children = vec![];
-                if let Some(tag) = compound.get("extra")
-                    && let NbtTag::List(list) = tag
-                {
-                    for child in list.as_nbt_tags() {
-                        let child = TextComponent::from_nbt(&child);
-                        if let Some(child) = child {
-                            children.push(child);
+                match compound.get("extra") {
+                    Some(NbtTag::List(list)) => {
+                        for (i, child) in list.as_nbt_tags().iter().enumerate() {
+                            match TextComponent::read_nbt(child, mode) {
+                                Ok(child) => children.push(child),
+                                // Children without content, like empty strings, are left out
+                                Err(NbtParseError::MissingContent) => (),
+                                Err(_) if mode == Mode::Lenient => (),
+                                Err(error) => return Err(error.at_index(i).at("extra")),
+                            }
                         }
                     }
+                    Some(tag) if mode == Mode::Strict => {
+                        return Err(wrong_type("list", tag).at("extra"));
+                    }
+                    _ => (),
                 }
-                Some(TextComponent {
-                    content: Content::from_compound(compound)?,
+                Ok(TextComponent {
+                    content: Content::from_compound(compound, mode)?,
                     children,
-                    format: Format::from_compound(compound),
-                    interactions: Interactivity::from_compound(compound),
+                    format: Format::from_compound(compound, mode)?,
+                    interactions: Interactivity::from_compound(compound, mode)?,
+                    inline_translations: None,
+                    #[cfg(feature = "unknown-fields")]
+                    unknown_fields: unknown_fields(compound),
                 })
             }
-            _ => None,
+            // Like in SNBT, lists are their first component with the rest as its children
+            NbtTag::List(list) => {
+                let mut components = vec![];
+                for (i, tag) in list.as_nbt_tags().iter().enumerate() {
+                    components.push(
+                        TextComponent::read_nbt(tag, mode).map_err(|error| error.at_index(i))?,
+                    );
+                }
+                let mut components = components.into_iter();
+                let first = components
+                    .next()
+                    .ok_or(required("Lists", "at least one component"))?;
+                Ok(first.add_children(components.collect::<Vec<_>>()))
+            }
+            tag => Err(wrong_type("string, list or compound", tag)),
         }
     }
 }
 
+fn required(content: &str, key: &str) -> NbtParseError {
+    NbtParseError::Required(content.to_string(), key.to_string())
+}
+
+fn wrong_type(expected: &'static str, tag: &NbtTag) -> NbtParseError {
+    let found = match tag {
+        NbtTag::Byte(_) => "byte",
+        NbtTag::Short(_) => "short",
+        NbtTag::Int(_) => "int",
+        NbtTag::Long(_) => "long",
+        NbtTag::Float(_) => "float",
+        NbtTag::Double(_) => "double",
+        NbtTag::ByteArray(_) => "byte array",
+        NbtTag::String(_) => "string",
+        NbtTag::List(_) => "list",
+        NbtTag::Compound(_) => "compound",
+        NbtTag::IntArray(_) => "int array",
+        NbtTag::LongArray(_) => "long array",
+    };
+    NbtParseError::WrongType(expected, found)
+}
+
+/// The string of the key, [None] if the compound doesn't have it.
+fn string(compound: &NbtCompound, key: &str, mode: Mode) -> NbtResult<Option<String>> {
+    match compound.get(key) {
+        Some(NbtTag::String(string)) => Ok(Some(string.to_string())),
+        Some(tag) if mode == Mode::Strict => Err(wrong_type("string", tag).at(key)),
+        _ => Ok(None),
+    }
+}
+
+fn required_string(
+    compound: &NbtCompound,
+    content: &str,
+    key: &str,
+    mode: Mode,
+) -> NbtResult<String> {
+    string(compound, key, mode)?.ok_or_else(|| required(content, key))
+}
+
+/// Any number as an integer, floats truncated.\
+/// Tools often write bytes or shorts where the game takes any number, so all of them are
+/// read wherever one is.
+fn as_int(tag: &NbtTag) -> Option<i64> {
+    match *tag {
+        NbtTag::Byte(n) => Some(n as i64),
+        NbtTag::Short(n) => Some(n as i64),
+        NbtTag::Int(n) => Some(n as i64),
+        NbtTag::Long(n) => Some(n),
+        NbtTag::Float(n) => Some(n as i64),
+        NbtTag::Double(n) => Some(n as i64),
+        _ => None,
+    }
+}
+
+/// The number of the key as an int, a wrong type if it doesn't fit in one.
+fn int(compound: &NbtCompound, key: &str, mode: Mode) -> NbtResult<Option<i32>> {
+    match compound.get(key) {
+        Some(tag) => match as_int(tag).and_then(|n| i32::try_from(n).ok()) {
+            Some(n) => Ok(Some(n)),
+            None if mode == Mode::Strict => Err(wrong_type("int", tag).at(key)),
+            None => Ok(None),
+        },
+        None => Ok(None),
+    }
+}
+
+/// The ints of an int array or a list of numbers, like the ones of uuids.
+fn ints(tag: &NbtTag) -> Option<Vec<i32>> {
+    match tag {
+        NbtTag::IntArray(ints) => Some(ints.clone()),
+        NbtTag::List(list) => list
+            .as_nbt_tags()
+            .iter()
+            .map(|tag| as_int(tag).map(|n| n as i32))
+            .collect(),
+        _ => None,
+    }
+}
+
+/// Booleans are numbers, or the strings `true` and `false`.
+fn boolean(compound: &NbtCompound, key: &str, mode: Mode) -> NbtResult<Option<bool>> {
+    match compound.get(key) {
+        Some(NbtTag::String(value)) if value.to_str() == "true" => Ok(Some(true)),
+        Some(NbtTag::String(value)) if value.to_str() == "false" => Ok(Some(false)),
+        Some(tag) => match as_int(tag) {
+            Some(n) => Ok(Some(n != 0)),
+            None if mode == Mode::Strict => Err(wrong_type("byte", tag).at(key)),
+            None => Ok(None),
+        },
+        None => Ok(None),
+    }
+}
+
+fn compound<'a>(
+    compound: &'a NbtCompound,
+    key: &str,
+    mode: Mode,
+) -> NbtResult<Option<&'a NbtCompound>> {
+    match compound.get(key) {
+        Some(NbtTag::Compound(value)) => Ok(Some(value)),
+        Some(tag) if mode == Mode::Strict => Err(wrong_type("compound", tag).at(key)),
+        _ => Ok(None),
+    }
+}
+
+/// The unknown keys of the compound when [preserving](crate::unknown::preserve_unknown) them.
+#[cfg(feature = "unknown-fields")]
+fn unknown_fields(compound: &NbtCompound) -> Option<Box<UnknownFields>> {
+    if !is_preserving() {
+        return None;
+    }
+    let fields = compound
+        .iter()
+        .filter(|(key, _)| is_unknown(&key.to_str()))
+        .map(|(key, tag)| (key.to_string(), UnknownValue::Nbt(tag.clone())))
+        .collect::<Vec<_>>();
+    (!fields.is_empty()).then(|| Box::new(UnknownFields(fields)))
+}
+
 impl Content {
-    fn from_compound(compound: &NbtCompound) -> Option<Self> {
-        if let Some(tag) = compound.get("text")
-            && let NbtTag::String(text) = tag
-        {
-            return Some(Content::Text {
-                text: text.to_string().into(),
-            });
+    fn from_compound(compound: &NbtCompound, mode: Mode) -> NbtResult<Self> {
+        if let Some(text) = string(compound, "text", mode)? {
+            return Ok(Content::Text { text: text.into() });
         }
-        if let Some(tag) = compound.get("translate")
-            && let NbtTag::String(key) = tag
-        {
-            let mut fallback = None;
-            let mut args = None;
-            if let Some(tag) = compound.get("fallback")
-                && let NbtTag::String(text) = tag
-            {
-                fallback = Some(Cow::Owned(text.to_string()));
-            }
-            if let Some(tag) = compound.get("with")
-                && let NbtTag::List(list) = tag
-            {
-                let mut args_vec = vec![];
-                for arg in list.as_nbt_tags() {
-                    if let Some(arg) = TextComponent::from_nbt(&arg) {
-                        args_vec.push(arg);
+        if let Some(key) = string(compound, "translate", mode)? {
+            let args = match compound.get("with") {
+                Some(NbtTag::List(list)) => {
+                    let mut args = vec![];
+                    for (i, arg) in list.as_nbt_tags().iter().enumerate() {
+                        // Arguments that aren't components (like empty strings) are kept as
+                        // empty texts, leaving them out would shift the rest
+                        args.push(match TextComponent::read_nbt(arg, mode) {
+                            Ok(arg) => arg,
+                            Err(error) if error.is_root() || mode == Mode::Lenient => {
+                                TextComponent::default()
+                            }
+                            Err(error) => return Err(error.at_index(i).at("with")),
+                        });
                     }
+                    Some(args.into_boxed_slice())
                 }
-                args = Some(args_vec.into_boxed_slice());
-            }
-
-            return Some(Content::Translate(TranslatedMessage {
-                key: key.to_string().into(),
-                fallback,
+                Some(tag) if mode == Mode::Strict => {
+                    return Err(wrong_type("list", tag).at("with"));
+                }
+                _ => None,
+            };
+            return Ok(Content::Translate(TranslatedMessage {
+                key: key.into(),
+                fallback: string(compound, "fallback", mode)?.map(Cow::Owned),
                 args,
             }));
         }
-        if let Some(tag) = compound.get("keybind")
-            && let NbtTag::String(key) = tag
-        {
-            return Some(Content::Keybind {
-                keybind: key.to_string().into(),
+        if let Some(keybind) = string(compound, "keybind", mode)? {
+            return Ok(Content::Keybind {
+                keybind: normalize_keybind(keybind.into()),
             });
         }
-        if let Some(tag) = compound.get("score")
-            && let NbtTag::Compound(compound) = tag
-        {
-            let NbtTag::String(selector) = compound.get("name")? else {
-                return None;
-            };
-            let NbtTag::String(objective) = compound.get("objective")? else {
-                return None;
-            };
-            return Some(Content::Resolvable(Resolvable::Scoreboard {
-                selector: selector.to_string().into(),
-                objective: objective.to_string().into(),
+        if let Some(score) = self::compound(compound, "score", mode)? {
+            let name = |key| required_string(score, "Scoreboards", key, mode);
+            return Ok(Content::Resolvable(Resolvable::Scoreboard {
+                selector: name("name").map_err(|error| error.at("score"))?.into(),
+                objective: name("objective").map_err(|error| error.at("score"))?.into(),
             }));
         }
-        if let Some(tag) = compound.get("selector")
-            && let NbtTag::String(selector) = tag
-        {
-            let mut separator = Resolvable::entity_separator();
-            if let Some(tag) = compound.get("separator")
-                && let Some(component) = TextComponent::from_nbt(tag)
-            {
-                *separator = component;
-            };
-            return Some(Content::Resolvable(Resolvable::Entity {
-                selector: selector.to_string().into(),
-                separator,
+        if let Some(selector) = string(compound, "selector", mode)? {
+            return Ok(Content::Resolvable(Resolvable::Entity {
+                selector: selector.into(),
+                separator: separator(compound, Resolvable::entity_separator(), mode)?,
             }));
         }
-        if let Some(tag) = compound.get("nbt")
-            && let NbtTag::String(path) = tag
-        {
-            let mut interpret = None;
-            let mut separator = Resolvable::entity_separator();
-            let mut source = NbtSource::Block(Cow::Borrowed(""));
-            let mut continues = true;
-            if let Some(tag) = compound.get("interpret") {
-                match tag {
-                    NbtTag::String(str) => {
-                        if str.to_str() == "true" {
-                            interpret = Some(true);
-                        } else if str.to_str() == "false" {
-                            interpret = Some(false);
-                        }
-                    }
-                    NbtTag::Byte(val) => interpret = Some(*val != 0),
-                    _ => (),
+        if let Some(path) = string(compound, "nbt", mode)? {
+            let source = match string(compound, "source", mode)? {
+                Some(source) if matches!(source.as_str(), "block" | "entity" | "storage") => {
+                    let value = required_string(compound, "Nbt", &source, mode)?;
+                    nbt_source(&source, value)
                 }
-            }
-            if let Some(tag) = compound.get("separator")
-                && let Some(component) = TextComponent::from_nbt(tag)
-            {
-                *separator = component;
-            };
-            if let Some(tag) = compound.get("source")
-                && let NbtTag::String(s_type) = tag
-            {
-                continues = false;
-                match &*s_type.to_str() {
-                    "block" => {
-                        if let Some(tag) = compound.get("block")
-                            && let NbtTag::String(text) = tag
-                        {
-                            source = NbtSource::Block(Cow::Owned(text.to_string()))
-                        }
-                    }
-                    "entity" => {
-                        if let Some(tag) = compound.get("entity")
-                            && let NbtTag::String(text) = tag
-                        {
-                            source = NbtSource::Entity(Cow::Owned(text.to_string()))
+                Some(source) if mode == Mode::Strict => {
+                    return Err(NbtParseError::UnknownValue(source).at("source"));
+                }
+                // An unknown source is told by the keys too when lenient
+                _ => {
+                    let mut source = None;
+                    for key in ["block", "entity", "storage"] {
+                        if let Some(value) = string(compound, key, mode)? {
+                            source = Some(nbt_source(key, value));
+                            break;
                         }
                     }
-                    "storage" => {
-                        if let Some(tag) = compound.get("storage")
-                            && let NbtTag::String(text) = tag
-                        {
-                            source = NbtSource::Storage(Cow::Owned(text.to_string()))
+                    match (source, mode) {
+                        (Some(source), _) => source,
+                        (None, Mode::Lenient) => NbtSource::Block(Cow::Borrowed("")),
+                        (None, Mode::Strict) => {
+                            return Err(required("Nbt", "entity\", \"block\", or \"storage"));
                         }
                     }
-                    _ => continues = true,
                 }
-            }
-            if continues
-                && let Some(tag) = compound.get("block")
-                && let NbtTag::String(text) = tag
-            {
-                source = NbtSource::Block(Cow::Owned(text.to_string()))
-            } else if continues
-                && let Some(tag) = compound.get("entity")
-                && let NbtTag::String(text) = tag
-            {
-                source = NbtSource::Entity(Cow::Owned(text.to_string()))
-            } else if continues
-                && let Some(tag) = compound.get("storage")
-                && let NbtTag::String(text) = tag
-            {
-                source = NbtSource::Storage(Cow::Owned(text.to_string()))
-            }
-            return Some(Content::Resolvable(Resolvable::NBT {
-                path: path.to_string().into(),
-                interpret,
-                separator,
+            };
+            return Ok(Content::Resolvable(Resolvable::NBT {
+                path: path.into(),
+                interpret: boolean(compound, "interpret", mode)?,
+                separator: separator(compound, Resolvable::nbt_separator(), mode)?,
                 source,
             }));
         }
-        if let Some(tag) = compound.get("sprite")
-            && let NbtTag::String(sprite) = tag
-        {
-            let mut atlas = None;
-            if let Some(tag) = compound.get("atlas")
-                && let NbtTag::String(text) = tag
-            {
-                atlas = Some(text.to_string().into())
-            }
-
-            return Some(Content::Object(Object::Atlas {
-                atlas,
-                sprite: sprite.to_string().into(),
-            }));
-        }
-        if let Some(tag) = compound.get("object")
-            && let NbtTag::String(obj_type) = tag
-        {
-            match &*obj_type.to_str() {
+        // Older drafts had no discriminator, the kind of object is told by its keys
+        let object = match string(compound, "object", mode)? {
+            Some(object) => Some(object),
+            None if compound.get("player").is_some() => Some(String::from("player")),
+            None if compound.get("sprite").is_some() => Some(String::from("atlas")),
+            None => None,
+        };
+        if let Some(object) = object {
+            return match object.as_str() {
+                "atlas" => Ok(Content::Object(Object::Atlas {
+                    atlas: string(compound, "atlas", mode)?.map(Into::into),
+                    sprite: required_string(compound, "Atlas object", "sprite", mode)?.into(),
+                })),
                 "player" => {
-                    let mut player = ObjectPlayer {
-                        name: None,
-                        id: None,
-                        texture: None,
-                        properties: vec![],
-                    };
-                    let mut hat = true;
-                    if let Some(tag) = compound.get("player")
-                        && let NbtTag::Compound(compound) = tag
-                    {
-                        if let Some(tag) = compound.get("name")
-                            && let NbtTag::String(name) = tag
-                        {
-                            player.name = Some(Cow::Owned(name.to_string()))
-                        }
-                        if let Some(tag) = compound.get("id")
-                            && let NbtTag::IntArray(nums) = tag
-                            && nums.len() == 4
-                        {
-                            player.id = Some([nums[0], nums[1], nums[2], nums[3]])
-                        }
-                        if let Some(tag) = compound.get("texture")
-                            && let NbtTag::String(texture) = tag
-                        {
-                            player.texture = Some(Cow::Owned(texture.to_string()))
+                    let player = match compound.get("player") {
+                        Some(NbtTag::String(name)) => ObjectPlayer {
+                            name: Some(Cow::Owned(name.to_string())),
+                            ..ObjectPlayer::empty()
+                        },
+                        Some(NbtTag::Compound(profile)) => {
+                            player_profile(profile, mode).map_err(|error| error.at("player"))?
                         }
-                        if let Some(tag) = compound.get("properties")
-                            && let NbtTag::List(NbtList::Compound(compounds)) = tag
-                        {
-                            for compound in compounds {
-                                if let Some(name_tag) = compound.get("name")
-                                    && let Some(value_tag) = compound.get("value")
-                                    && let NbtTag::String(name) = name_tag
-                                    && let NbtTag::String(value) = value_tag
-                                {
-                                    let mut signature = None;
-                                    if let Some(tag) = compound.get("signature")
-                                        && let NbtTag::String(text) = tag
-                                    {
-                                        signature = Some(text.to_string().into())
-                                    }
-                                    player.properties.push(PlayerProperties {
-                                        name: name.to_string().into(),
-                                        value: value.to_string().into(),
-                                        signature,
-                                    });
-                                }
-                            }
-                        }
-                    }
-                    if let Some(tag) = compound.get("hat") {
-                        match tag {
-                            NbtTag::String(str) if str.to_str() == "false" => {
-                                hat = false;
-                            }
-                            NbtTag::Byte(val) => hat = *val != 0,
-                            _ => (),
+                        Some(tag) => {
+                            return Err(wrong_type("string or compound", tag).at("player"));
                         }
-                    }
-                    return Some(Content::Object(Object::Player { player, hat }));
+                        None => return Err(required("Player object", "player")),
+                    };
+                    Ok(Content::Object(Object::Player {
+                        player,
+                        hat: boolean(compound, "hat", mode)?.unwrap_or(true),
+                    }))
                 }
-                _ => return None,
-            }
+                _ => Err(NbtParseError::UnknownValue(object).at("object")),
+            };
         }
         #[cfg(feature = "custom")]
-        if let Some(tag) = compound.get("custom")
-            && let NbtTag::Compound(compound) = tag
-        {
-            return Some(Content::Custom(CustomData::from_compound(compound)?));
+        if let Some(custom) = self::compound(compound, "custom", mode)? {
+            return CustomData::from_compound(custom, mode)
+                .map(Content::Custom)
+                .map_err(|error| error.at("custom"));
         }
 
-        None
+        Err(NbtParseError::MissingContent)
     }
 }
 
-impl Format {
-    fn from_compound(compound: &NbtCompound) -> Self {
-        let mut format = Format::new();
-        if let Some(tag) = compound.get("color")
-            && let NbtTag::String(color) = tag
-        {
-            let color = color.to_string();
-            if color.starts_with("#") {
-                format = format.color_hex(&color);
-            }
-            match color.as_str() {
-                "aqua" => format = format.color(Color::Aqua),
-                "black" => format = format.color(Color::Black),
-                "blue" => format = format.color(Color::Blue),
-                "dark_aqua" => format = format.color(Color::DarkAqua),
-                "dark_blue" => format = format.color(Color::DarkBlue),
-                "dark_gray" => format = format.color(Color::DarkGray),
-                "dark_green" => format = format.color(Color::DarkGreen),
-                "dark_purple" => format = format.color(Color::DarkPurple),
-                "dark_red" => format = format.color(Color::DarkRed),
-                "gold" => format = format.color(Color::Gold),
-                "gray" => format = format.color(Color::Gray),
-                "green" => format = format.color(Color::Green),
-                "light_purple" => format = format.color(Color::LightPurple),
-                "red" => format = format.color(Color::Red),
-                "white" => format = format.color(Color::White),
-                "yellow" => format = format.color(Color::Yellow),
-                _ => (),
-            }
-        }
-        if let Some(tag) = compound.get("font")
-            && let NbtTag::String(color) = tag
-        {
-            format = format.font(color.to_string());
-        }
-        if let Some(tag) = compound.get("bold") {
-            match tag {
-                NbtTag::String(str) => {
-                    if str.to_str() == "true" {
-                        format = format.bold(true);
-                    } else if str.to_str() == "false" {
-                        format = format.bold(false);
-                    }
-                }
-                NbtTag::Byte(val) => format = format.bold(*val != 0),
-                _ => (),
+fn nbt_source(kind: &str, value: String) -> NbtSource {
+    match kind {
+        "block" => NbtSource::Block(Cow::Owned(value)),
+        "entity" => NbtSource::Entity(Cow::Owned(value)),
+        _ => NbtSource::Storage(Cow::Owned(value)),
+    }
+}
+
+/// The profile of a player object, given as a compound.
+fn player_profile(compound: &NbtCompound, mode: Mode) -> NbtResult<ObjectPlayer> {
+    let mut player = ObjectPlayer::empty();
+    player.name = string(compound, "name", mode)?.map(Cow::Owned);
+    player.id = match compound.get("id") {
+        Some(tag) => match ints(tag).as_deref() {
+            Some(&[a, b, c, d]) => Some([a, b, c, d]),
+            _ if mode == Mode::Lenient => None,
+            Some(ints) => {
+                let uuid = format!("of {} ints", ints.len());
+                return Err(NbtParseError::InvalidUuid(uuid).at("id"));
             }
-        }
-        if let Some(tag) = compound.get("italic") {
-            match tag {
-                NbtTag::String(str) => {
-                    if str.to_str() == "true" {
-                        format = format.italic(true);
-                    } else if str.to_str() == "false" {
-                        format = format.italic(false);
-                    }
+            None => return Err(wrong_type("int array", tag).at("id")),
+        },
+        None => None,
+    };
+    player.texture = string(compound, "texture", mode)?.map(Cow::Owned);
+    match compound.get("properties") {
+        Some(NbtTag::List(NbtList::Compound(properties))) => {
+            for (i, property) in properties.iter().enumerate() {
+                match player_property(property, mode) {
+                    Ok(property) => player.properties.push(property),
+                    Err(_) if mode == Mode::Lenient => (),
+                    Err(error) => return Err(error.at_index(i).at("properties")),
                 }
-                NbtTag::Byte(val) => format = format.italic(*val != 0),
-                _ => (),
             }
         }
-        if let Some(tag) = compound.get("underlined") {
-            match tag {
-                NbtTag::String(str) => {
-                    if str.to_str() == "true" {
-                        format = format.underlined(true);
-                    } else if str.to_str() == "false" {
-                        format = format.underlined(false);
-                    }
-                }
-                NbtTag::Byte(val) => format = format.underlined(*val != 0),
-                _ => (),
-            }
+        Some(NbtTag::List(list)) if list.as_nbt_tags().is_empty() => (),
+        Some(tag) if mode == Mode::Strict => {
+            return Err(wrong_type("list of compounds", tag).at("properties"));
         }
-        if let Some(tag) = compound.get("strikethrough") {
-            match tag {
-                NbtTag::String(str) => {
-                    if str.to_str() == "true" {
-                        format = format.strikethrough(true);
-                    } else if str.to_str() == "false" {
-                        format = format.strikethrough(false);
-                    }
-                }
-                NbtTag::Byte(val) => format = format.strikethrough(*val != 0),
-                _ => (),
+        _ => (),
+    }
+    Ok(player)
+}
+
+fn player_property(compound: &NbtCompound, mode: Mode) -> NbtResult<PlayerProperties> {
+    Ok(PlayerProperties {
+        name: required_string(compound, "Player property", "name", mode)?.into(),
+        value: required_string(compound, "Player property", "value", mode)?.into(),
+        signature: string(compound, "signature", mode)?.map(Into::into),
+    })
+}
+
+impl Format {
+    fn from_compound(compound: &NbtCompound, mode: Mode) -> NbtResult<Self> {
+        let mut format = Format::new();
+        if let Some(color) = string(compound, "color", mode)? {
+            match Color::from_name(&color).or_else(|| Color::from_hex(&color)) {
+                Some(color) => format.color = Some(color),
+                None if mode == Mode::Lenient => (),
+                None => return Err(NbtParseError::InvalidColor(color).at("color")),
             }
         }
-        if let Some(tag) = compound.get("obfuscated") {
-            match tag {
-                NbtTag::String(str) => {
-                    if str.to_str() == "true" {
-                        format = format.obfuscated(true);
-                    } else if str.to_str() == "false" {
-                        format = format.obfuscated(false);
-                    }
-                }
-                NbtTag::Byte(val) => format = format.obfuscated(*val != 0),
-                _ => (),
-            }
+        if let Some(font) = string(compound, "font", mode)? {
+            format = format.font(font);
         }
-        if let Some(tag) = compound.get("shadow_color") {
-            match tag {
-                NbtTag::Short(n) => format.shadow_color = Some(*n as i64),
-                NbtTag::Int(n) => format.shadow_color = Some(*n as i64),
-                NbtTag::Long(n) => format.shadow_color = Some(*n),
-                NbtTag::List(list) => {
-                    let list = list.as_nbt_tags();
-                    if list.len() == 4 {
-                        let mut nums = vec![];
-                        for item in list {
-                            match item {
-                                NbtTag::Float(n) => nums.push((n * 255.) as u8),
-                                NbtTag::Double(n) => nums.push((n * 255.) as u8),
-                                _ => break,
-                            }
-                        }
-                        if nums.len() == 4 {
-                            format = format.shadow_color(nums[3], nums[0], nums[1], nums[2]);
-                        }
+        format.bold = boolean(compound, "bold", mode)?;
+        format.italic = boolean(compound, "italic", mode)?;
+        format.underlined = boolean(compound, "underlined", mode)?;
+        format.strikethrough = boolean(compound, "strikethrough", mode)?;
+        format.obfuscated = boolean(compound, "obfuscated", mode)?;
+        format.shadow_color = match compound.get("shadow_color") {
+            Some(NbtTag::List(list)) => {
+                let channels = list
+                    .as_nbt_tags()
+                    .iter()
+                    .map(|tag| match tag {
+                        NbtTag::Float(n) => Some(*n),
+                        NbtTag::Double(n) => Some(*n as f32),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>();
+                match channels.as_deref() {
+                    Some(&[r, g, b, a]) => Some(Format::float_shadow_color(r, g, b, a)),
+                    _ if mode == Mode::Lenient => None,
+                    _ => {
+                        let error = required("Shadow color lists", "4 numbers");
+                        return Err(error.at("shadow_color"));
                     }
                 }
-                _ => (),
             }
-        }
-        format
+            Some(tag) => match as_int(tag) {
+                Some(n) => Some(Format::packed_shadow_color(n)),
+                None if mode == Mode::Lenient => None,
+                None => return Err(wrong_type("int or list", tag).at("shadow_color")),
+            },
+            None => None,
+        };
+        Ok(format)
     }
 }
 
 impl Interactivity {
-    fn from_compound(compound: &NbtCompound) -> Self {
+    fn from_compound(compound: &NbtCompound, mode: Mode) -> NbtResult<Self> {
         let mut interaction = Interactivity::new();
-        if let Some(tag) = compound.get("insertion")
-            && let NbtTag::String(insertion) = tag
-        {
-            interaction.insertion = Some(Cow::Owned(insertion.to_string()));
-        }
-
-        if let Some(tag) = compound.get("click_event")
-            && let NbtTag::Compound(event) = tag
-        {
-            interaction.click = ClickEvent::from_compound(event);
+        interaction.insertion = string(compound, "insertion", mode)?.map(Into::into);
+        // Malformed events are dropped when lenient, the component is still shown
+        if let Some(event) = self::compound(compound, "click_event", mode)? {
+            interaction.click = match ClickEvent::from_compound(event, mode) {
+                Ok(click) => Some(click),
+                Err(_) if mode == Mode::Lenient => None,
+                Err(error) => return Err(error.at("click_event")),
+            };
         }
-        if let Some(tag) = compound.get("hover_event")
-            && let NbtTag::Compound(event) = tag
-        {
-            interaction.hover = HoverEvent::from_compound(event);
+        if let Some(event) = self::compound(compound, "hover_event", mode)? {
+            interaction.hover = match HoverEvent::from_compound(event, mode) {
+                Ok(hover) => Some(hover),
+                Err(_) if mode == Mode::Lenient => None,
+                Err(error) => return Err(error.at("hover_event")),
+            };
         }
-        interaction
+        Ok(interaction)
     }
 }
 
 impl HoverEvent {
-    fn from_compound(compound: &NbtCompound) -> Option<Self> {
-        let tag = compound.get("action")?;
-        if let NbtTag::String(event) = tag {
-            return match &*event.to_str() {
-                "show_text" => {
-                    let value = compound.get("value")?;
-                    let compound = TextComponent::from_nbt(value)?;
-                    Some(HoverEvent::ShowText {
-                        value: Box::new(compound),
-                    })
-                }
-                "show_item" => {
-                    let id = compound.get("id")?;
-                    match id {
-                        NbtTag::String(id) => {
-                            let mut count = None;
-                            let mut components = None;
-                            if let Some(tag) = compound.get("count")
-                                && let NbtTag::Int(n) = tag
-                            {
-                                count = Some(*n);
-                            }
-                            if let Some(tag) = compound.get("components")
-                                && let NbtTag::Compound(comps) = tag
-                            {
-                                let mut data = vec![];
-                                comps.write(&mut data);
-                                if let Ok(comps) = String::from_utf8(data) {
-                                    components = Some(comps.into());
-                                }
-                            }
-                            Some(HoverEvent::ShowItem {
-                                id: id.to_string().into(),
-                                count,
-                                components,
-                            })
-                        }
-                        _ => None,
+    fn from_compound(compound: &NbtCompound, mode: Mode) -> NbtResult<Self> {
+        let action = required_string(compound, "Hover events", "action", mode)?;
+        match action.as_str() {
+            "show_text" => {
+                let value = match compound.get("value") {
+                    Some(NbtTag::String(text)) => embedded_or_plain(text.to_string()),
+                    Some(value) => {
+                        TextComponent::read_nbt(value, mode).map_err(|error| error.at("value"))?
                     }
-                }
-                "show_entity" => {
-                    let id = compound.get("id")?;
-                    let uuid = compound.get("uuid")?;
-                    let uuid: Uuid = match uuid {
-                        NbtTag::String(uuid) => Uuid::parse_str(&uuid.to_string()).ok()?,
-                        NbtTag::IntArray(nums) | NbtTag::List(NbtList::Int(nums)) => {
-                            if nums.len() != 4 {
-                                return None;
-                            }
-                            Uuid::from_u64_pair(
-                                (((nums[0] as u32) as u64) << 32) + ((nums[1] as u32) as u64),
-                                (((nums[2] as u32) as u64) << 32) + ((nums[3] as u32) as u64),
-                            )
+                    None => return Err(required("\"show_text\"", "value")),
+                };
+                Ok(HoverEvent::ShowText {
+                    value: Box::new(value),
+                })
+            }
+            "show_item" => Ok(HoverEvent::ShowItem {
+                id: required_string(compound, "\"show_item\"", "id", mode)?.into(),
+                count: int(compound, "count", mode)?,
+                components: match compound.get("components") {
+                    Some(NbtTag::String(components)) => Some(components.to_string().into()),
+                    Some(NbtTag::Compound(components)) => match components.try_to_snbt() {
+                        Ok(snbt) => Some(snbt.into()),
+                        Err(error) if mode == Mode::Strict => {
+                            return Err(
+                                NbtParseError::NonFiniteNumber(error.to_string()).at("components")
+                            );
                         }
-                        _ => return None,
-                    };
-                    match id {
-                        NbtTag::String(id) => {
-                            let mut name = None;
-                            if let Some(name_nbt) = compound.get("name")
-                                && let Some(compound) = TextComponent::from_nbt(name_nbt)
-                            {
-                                name = Some(Box::new(compound))
-                            }
-                            Some(HoverEvent::ShowEntity {
-                                name,
-                                id: id.to_string().into(),
-                                uuid,
-                            })
+                        Err(_) => None,
+                    },
+                    Some(tag) if mode == Mode::Strict => {
+                        return Err(wrong_type("string or compound", tag).at("components"));
+                    }
+                    _ => None,
+                },
+            }),
+            "show_entity" => {
+                let id = required_string(compound, "\"show_entity\"", "id", mode)?;
+                let uuid = match compound.get("uuid") {
+                    Some(NbtTag::String(uuid)) => {
+                        let uuid = uuid.to_string();
+                        match Uuid::parse_str(&uuid) {
+                            Ok(uuid) => uuid,
+                            Err(_) => return Err(NbtParseError::InvalidUuid(uuid).at("uuid")),
                         }
-                        _ => None,
                     }
-                }
-                _ => None,
-            };
+                    Some(tag) => match ints(tag).as_deref() {
+                        Some(&[a, b, c, d]) => Uuid::from_u64_pair(
+                            (((a as u32) as u64) << 32) + ((b as u32) as u64),
+                            (((c as u32) as u64) << 32) + ((d as u32) as u64),
+                        ),
+                        Some(ints) => {
+                            let uuid = format!("of {} ints", ints.len());
+                            return Err(NbtParseError::InvalidUuid(uuid).at("uuid"));
+                        }
+                        None => return Err(wrong_type("string or int array", tag).at("uuid")),
+                    },
+                    None => return Err(required("\"show_entity\"", "uuid")),
+                };
+                let name = match compound
+                    .get("name")
+                    .map(|name| TextComponent::read_nbt(name, mode))
+                {
+                    Some(Ok(name)) => Some(Box::new(name)),
+                    Some(Err(error)) if mode == Mode::Strict => return Err(error.at("name")),
+                    _ => None,
+                };
+                Ok(HoverEvent::ShowEntity {
+                    name,
+                    id: id.into(),
+                    uuid,
+                })
+            }
+            _ => Err(NbtParseError::UnknownValue(action).at("action")),
         }
-        None
     }
 }
 
 impl ClickEvent {
-    fn from_compound(compound: &NbtCompound) -> Option<Self> {
-        let tag = compound.get("action")?;
-        if let NbtTag::String(event) = tag {
-            return match &*event.to_str() {
-                "open_url" => {
-                    if let Some(tag) = compound.get("url")
-                        && let NbtTag::String(url) = tag
-                    {
-                        return Some(ClickEvent::OpenUrl {
-                            url: url.to_string().into(),
-                        });
-                    }
-                    None
-                }
-                "run_command" => {
-                    if let Some(tag) = compound.get("command")
-                        && let NbtTag::String(command) = tag
-                    {
-                        return Some(ClickEvent::RunCommand {
-                            command: command.to_string().into(),
-                        });
-                    }
-                    None
-                }
-                "suggest_command" => {
-                    if let Some(tag) = compound.get("command")
-                        && let NbtTag::String(command) = tag
-                    {
-                        return Some(ClickEvent::SuggestCommand {
-                            command: command.to_string().into(),
-                        });
-                    }
-                    None
-                }
-                "change_page" => {
-                    if let Some(tag) = compound.get("page")
-                        && let NbtTag::Int(page) = tag
-                    {
-                        return Some(ClickEvent::ChangePage { page: *page });
-                    }
-                    None
-                }
-                "copy_to_clipboard" => {
-                    if let Some(tag) = compound.get("value")
-                        && let NbtTag::String(value) = tag
-                    {
-                        return Some(ClickEvent::CopyToClipboard {
-                            value: value.to_string().into(),
-                        });
-                    }
-                    None
-                }
-                "show_dialog" => {
-                    if let Some(tag) = compound.get("dialog")
-                        && let NbtTag::String(dialog) = tag
-                    {
-                        return Some(ClickEvent::ShowDialog {
-                            dialog: dialog.to_string().into(),
-                        });
-                    }
-                    None
-                }
-                #[cfg(feature = "custom")]
-                "custom" => Some(ClickEvent::Custom(CustomData::from_compound(compound)?)),
-                _ => None,
-            };
-        }
-        None
+    fn from_compound(compound: &NbtCompound, mode: Mode) -> NbtResult<Self> {
+        let action = required_string(compound, "Click events", "action", mode)?;
+        let value = |key| required_string(compound, &format!("\"{action}\""), key, mode);
+        Ok(match action.as_str() {
+            "open_url" => ClickEvent::OpenUrl {
+                url: value("url")?.into(),
+            },
+            "run_command" => ClickEvent::RunCommand {
+                command: value("command")?.into(),
+            },
+            "suggest_command" => ClickEvent::SuggestCommand {
+                command: value("command")?.into(),
+            },
+            "change_page" => ClickEvent::ChangePage {
+                page: int(compound, "page", mode)?
+                    .ok_or_else(|| required("\"change_page\"", "page"))?,
+            },
+            "copy_to_clipboard" => ClickEvent::CopyToClipboard {
+                value: value("value")?.into(),
+            },
+            "show_dialog" => ClickEvent::ShowDialog {
+                dialog: DialogRef::Id(value("dialog")?.into()),
+            },
+            #[cfg(feature = "custom")]
+            "custom" => ClickEvent::Custom(CustomData::from_compound(compound, mode)?),
+            _ => return Err(NbtParseError::UnknownValue(action.clone()).at("action")),
+        })
     }
 }
 
 #[cfg(feature = "custom")]
 impl CustomData {
-    fn from_compound(compound: &NbtCompound) -> Option<Self> {
+    fn from_compound(compound: &NbtCompound, mode: Mode) -> NbtResult<Self> {
         use crate::custom::{CustomData, Payload};
 
-        let tag = compound.get("id")?;
-        if let NbtTag::String(id) = tag {
-            return Some(CustomData {
-                id: id.to_string().into(),
-                // TODO: End payload serialization
-                payload: Payload::Empty,
-            });
-        }
-        None
+        Ok(CustomData {
+            id: required_string(compound, "Custom", "id", mode)?.into(),
+            // TODO: End payload serialization
+            payload: Payload::Empty,
+        })
+    }
+}
+
+/// Separators may be a string, a list or a compound, an empty string is an empty separator.
+fn separator(
+    compound: &NbtCompound,
+    default: Box<TextComponent>,
+    mode: Mode,
+) -> NbtResult<Box<TextComponent>> {
+    match compound.get("separator") {
+        Some(NbtTag::String(text)) => Ok(Box::new(TextComponent::plain(text.to_string()))),
+        Some(tag) => match TextComponent::read_nbt(tag, mode) {
+            Ok(separator) => Ok(Box::new(separator)),
+            Err(_) if mode == Mode::Lenient => Ok(default),
+            Err(error) => Err(error.at("separator")),
+        },
+        None => Ok(default),
     }
 }
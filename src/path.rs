@@ -0,0 +1,199 @@
+//! Addressing of the components nested inside others.
+//! ```
+//! # use text_components::{TextComponent, path::ComponentPath};
+//! # let component = TextComponent::new();
+//! // extra[2].with[0].hover_event.value
+//! let path = ComponentPath::new().child(2).arg(0).hover_value();
+//! let nested = component.get_path(&path);
+//! ```
+use crate::{
+    TextComponent,
+    content::{Content, Resolvable},
+    interactivity::HoverEvent,
+};
+use std::fmt;
+
+/// A step from a component to one nested in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    /// A child, in `extra`.
+    Child(usize),
+    /// An argument of a translation, in `with`.
+    TranslateArg(usize),
+    /// The separator of an entity or NBT component.
+    Separator,
+    /// The text of a `show_text` hover event.
+    HoverValue,
+    /// The name of a `show_entity` hover event.
+    HoverName,
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathSegment::Child(i) => write!(f, "extra[{i}]"),
+            PathSegment::TranslateArg(i) => write!(f, "with[{i}]"),
+            PathSegment::Separator => write!(f, "separator"),
+            PathSegment::HoverValue => write!(f, "hover_event.value"),
+            PathSegment::HoverName => write!(f, "hover_event.name"),
+        }
+    }
+}
+
+/// The steps to follow from a component to one nested inside it, an empty path is the
+/// component itself.\
+/// Displayed like the keys of the JSON form, `extra[2].with[0].hover_event.value`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ComponentPath(pub Vec<PathSegment>);
+
+impl ComponentPath {
+    pub const fn new() -> Self {
+        ComponentPath(Vec::new())
+    }
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// Gives the path with one more segment.
+    pub fn join(mut self, segment: PathSegment) -> Self {
+        self.0.push(segment);
+        self
+    }
+    pub fn child(self, index: usize) -> Self {
+        self.join(PathSegment::Child(index))
+    }
+    pub fn arg(self, index: usize) -> Self {
+        self.join(PathSegment::TranslateArg(index))
+    }
+    pub fn separator(self) -> Self {
+        self.join(PathSegment::Separator)
+    }
+    pub fn hover_value(self) -> Self {
+        self.join(PathSegment::HoverValue)
+    }
+    pub fn hover_name(self) -> Self {
+        self.join(PathSegment::HoverName)
+    }
+}
+
+impl From<Vec<PathSegment>> for ComponentPath {
+    fn from(value: Vec<PathSegment>) -> Self {
+        ComponentPath(value)
+    }
+}
+
+impl FromIterator<PathSegment> for ComponentPath {
+    fn from_iter<T: IntoIterator<Item = PathSegment>>(iter: T) -> Self {
+        ComponentPath(iter.into_iter().collect())
+    }
+}
+
+impl fmt::Display for ComponentPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+            segment.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl TextComponent {
+    /// Gives the component found following the path, [None] if it isn't there.
+    pub fn get_path(&self, path: &ComponentPath) -> Option<&TextComponent> {
+        path.0
+            .iter()
+            .try_fold(self, |component, segment| component.get_segment(*segment))
+    }
+
+    /// Same as [get_path](TextComponent::get_path) but mutable.
+    pub fn get_path_mut(&mut self, path: &ComponentPath) -> Option<&mut TextComponent> {
+        path.0.iter().try_fold(self, |component, segment| {
+            component.get_segment_mut(*segment)
+        })
+    }
+
+    fn get_segment(&self, segment: PathSegment) -> Option<&TextComponent> {
+        match (segment, &self.content, &self.interactions.hover) {
+            (PathSegment::Child(i), _, _) => self.children.get(i),
+            (PathSegment::TranslateArg(i), Content::Translate(message), _) => {
+                message.args.as_ref()?.get(i)
+            }
+            (
+                PathSegment::Separator,
+                Content::Resolvable(
+                    Resolvable::Entity { separator, .. } | Resolvable::NBT { separator, .. },
+                ),
+                _,
+            ) => Some(&**separator),
+            (PathSegment::HoverValue, _, Some(HoverEvent::ShowText { value })) => Some(&**value),
+            (
+                PathSegment::HoverName,
+                _,
+                Some(HoverEvent::ShowEntity {
+                    name: Some(name), ..
+                }),
+            ) => Some(&**name),
+            _ => None,
+        }
+    }
+
+    fn get_segment_mut(&mut self, segment: PathSegment) -> Option<&mut TextComponent> {
+        match (segment, &mut self.content, &mut self.interactions.hover) {
+            (PathSegment::Child(i), _, _) => self.children.get_mut(i),
+            (PathSegment::TranslateArg(i), Content::Translate(message), _) => {
+                message.args.as_mut()?.get_mut(i)
+            }
+            (
+                PathSegment::Separator,
+                Content::Resolvable(
+                    Resolvable::Entity { separator, .. } | Resolvable::NBT { separator, .. },
+                ),
+                _,
+            ) => Some(&mut **separator),
+            (PathSegment::HoverValue, _, Some(HoverEvent::ShowText { value })) => {
+                Some(&mut **value)
+            }
+            (
+                PathSegment::HoverName,
+                _,
+                Some(HoverEvent::ShowEntity {
+                    name: Some(name), ..
+                }),
+            ) => Some(&mut **name),
+            _ => None,
+        }
+    }
+
+    /// Calls `f` with every component nested in this one and the segment leading to it.
+    pub(crate) fn for_each_segment<'a>(
+        &'a self,
+        mut f: impl FnMut(PathSegment, &'a TextComponent),
+    ) {
+        match &self.content {
+            Content::Translate(message) => {
+                for (i, arg) in message.args.iter().flatten().enumerate() {
+                    f(PathSegment::TranslateArg(i), arg);
+                }
+            }
+            Content::Resolvable(
+                Resolvable::Entity { separator, .. } | Resolvable::NBT { separator, .. },
+            ) => f(PathSegment::Separator, separator),
+            _ => (),
+        }
+        match &self.interactions.hover {
+            Some(HoverEvent::ShowText { value }) => f(PathSegment::HoverValue, value),
+            Some(HoverEvent::ShowEntity {
+                name: Some(name), ..
+            }) => f(PathSegment::HoverName, name),
+            _ => (),
+        }
+        for (i, child) in self.children.iter().enumerate() {
+            f(PathSegment::Child(i), child);
+        }
+    }
+}
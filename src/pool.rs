@@ -0,0 +1,91 @@
+//! Reuse of the allocations of components built many times, like an actionbar updated
+//! every tick.
+//! ```ignore
+//! let mut pool = ComponentPool::new();
+//! loop {
+//!     let mut children = pool.children();
+//!     children.push(TextComponent::scoreboard("@s", "health"));
+//!     let hover = pool.boxed("Your health".into());
+//!     let bar = TextComponent {
+//!         children,
+//!         interactions: Interactivity { hover: Some(HoverEvent::ShowText { value: hover }), ..Interactivity::new() },
+//!         ..TextComponent::plain("Health: ")
+//!     };
+//!     player.send(bar.build(&world, NbtBuilder::new()));
+//!     pool.recycle(bar);
+//! }
+//! ```
+use crate::{
+    TextComponent,
+    content::{Content, Resolvable},
+    interactivity::HoverEvent,
+};
+
+/// Keeps the boxes and children vectors of the components given back to it, handing them
+/// out again instead of allocating new ones.
+#[derive(Debug, Default)]
+pub struct ComponentPool {
+    // The boxes themselves are what gets reused
+    #[allow(clippy::vec_box)]
+    boxes: Vec<Box<TextComponent>>,
+    vecs: Vec<Vec<TextComponent>>,
+}
+
+impl ComponentPool {
+    pub fn new() -> Self {
+        ComponentPool::default()
+    }
+
+    /// Boxes the component for a separator or a hover event, in a recycled box if there's one.
+    pub fn boxed(&mut self, component: TextComponent) -> Box<TextComponent> {
+        match self.boxes.pop() {
+            Some(mut boxed) => {
+                *boxed = component;
+                boxed
+            }
+            None => Box::new(component),
+        }
+    }
+
+    /// An empty children vector, keeping the capacity of a recycled one if there's one.
+    pub fn children(&mut self) -> Vec<TextComponent> {
+        self.vecs.pop().unwrap_or_default()
+    }
+
+    /// Takes the boxes and children vectors of the component and every nested one.
+    pub fn recycle(&mut self, component: TextComponent) {
+        match component.content {
+            Content::Translate(message) => {
+                if let Some(args) = message.args {
+                    args.into_vec()
+                        .into_iter()
+                        .for_each(|arg| self.recycle(arg));
+                }
+            }
+            Content::Resolvable(Resolvable::Entity { separator, .. })
+            | Content::Resolvable(Resolvable::NBT { separator, .. }) => self.recycle_box(separator),
+            _ => (),
+        }
+        match component.interactions.hover {
+            Some(HoverEvent::ShowText { value })
+            | Some(HoverEvent::ShowEntity {
+                name: Some(value), ..
+            }) => self.recycle_box(value),
+            _ => (),
+        }
+        let mut children = component.children;
+        for child in children.drain(..) {
+            self.recycle(child);
+        }
+        if children.capacity() > 0 {
+            self.vecs.push(children);
+        }
+    }
+
+    /// Takes the box and the boxes and children vectors of the component inside it.
+    pub fn recycle_box(&mut self, mut boxed: Box<TextComponent>) {
+        let component = std::mem::take(&mut *boxed);
+        self.recycle(component);
+        self.boxes.push(boxed);
+    }
+}
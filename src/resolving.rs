@@ -1,75 +1,299 @@
-use std::sync::Arc;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    sync::Arc,
+};
 
 #[cfg(feature = "custom")]
 use crate::custom::CustomData;
 use crate::{
     TextComponent,
-    content::{Content, Resolvable},
+    content::{Content, ObjectPlayer, Resolvable},
+    fmt::ObfuscatedText,
+    format::Format,
+    interactivity::{HoverEvent, Interactivity},
+    parse::embedded_or_plain,
+    path::{ComponentPath, PathSegment},
+    translation::{TranslatedMessage, TranslationTable},
 };
 
-/// Recommendation: Implement this on the World and Player
+/// Recommendation: Implement this on the World and Player\
+/// It can be used as a trait object, like `Arc<dyn TextResolutor + Send + Sync>` to share
+/// one between threads, references, [Box]es and [Arc]s of resolutors are resolutors too.\
+/// The resolvables given to [resolve_content](TextResolutor::resolve_content) have their separator
+/// already resolved, use [join_resolved](Resolvable::join_resolved) to put it between the
+/// entities or values found.\
+/// NBT resolvables with `interpret` set may be resolved to the plain SNBT text found,
+/// [resolve](TextComponent::resolve) parses it into a component, see [resolve_nbt_values].
 pub trait TextResolutor {
     fn resolve_other(&self, content: &Content) -> TextComponent {
         TextComponent::from(content.clone())
     }
     fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent;
+    /// Same as [resolve_content](TextResolutor::resolve_content) telling when the data can't
+    /// be fetched, used by [try_resolve](TextComponent::try_resolve).\
+    /// By default it never fails.
+    fn try_resolve_content(
+        &self,
+        resolvable: &Resolvable,
+    ) -> Result<TextComponent, Box<dyn Error + Send + Sync>> {
+        Ok(self.resolve_content(resolvable))
+    }
     #[cfg(feature = "custom")]
     fn resolve_custom(&self, data: &CustomData) -> Option<TextComponent>;
-    fn translate(&self, key: &str) -> Option<String>;
-    fn split_translation(&self, text: String) -> Vec<(String, usize)> {
-        let mut positions = vec![(0, 0, 0), (text.len(), 0, 0)];
-        for i in 1..=8 {
-            for (pos, _) in text.match_indices(&format!("%{i}$s")) {
-                positions.push((pos, i, 4usize));
+    /// The translated text of the key, borrowed when the resolutor holds it.
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>>;
+    /// The key bound to a keybind, like `Space` for `key.jump`.\
+    /// Servers rarely know the bindings of the players, by default there are none and
+    /// builders show the [translation](TextResolutor::translate) of the keybind's name.
+    fn keybind(&self, _keybind: &str) -> Option<Cow<'_, str>> {
+        None
+    }
+    /// The placeholders the builders show for what the resolutor can't give, like
+    /// translations it doesn't know, the [defaults](PlaceholderConfig::new) unless overridden.
+    fn placeholders(&self) -> &PlaceholderConfig {
+        &DEFAULT_PLACEHOLDERS
+    }
+    /// Splits a translated text into its literal parts and the arguments placed between them,
+    /// `%s` takes the next argument and `%N$s` the Nth one.\
+    /// Empty literals are left out, so two adjacent placeholders give two adjacent arguments.
+    fn translation_segments<'a>(&self, text: &'a str) -> Vec<TranslationSegment<'a>> {
+        let mut segments = vec![];
+        let mut literal_start = 0;
+        let mut next_arg = 0;
+        let mut rest = text;
+        while let Some(pos) = rest.find('%') {
+            let placeholder = &rest[pos + 1..];
+            let digits = placeholder
+                .find(|char: char| !char.is_ascii_digit())
+                .unwrap_or(placeholder.len());
+            let (arg, size) = if placeholder.starts_with('s') {
+                next_arg += 1;
+                (next_arg - 1, 2)
+            } else if digits > 0
+                && placeholder[digits..].starts_with("$s")
+                && let Ok(index) = placeholder[..digits].parse::<usize>()
+                && index > 0
+            {
+                (index - 1, digits + 3)
+            } else {
+                rest = &rest[pos + 1..];
+                continue;
+            };
+            let offset = text.len() - rest.len() + pos;
+            if offset > literal_start {
+                segments.push(TranslationSegment::Literal(Cow::Borrowed(
+                    &text[literal_start..offset],
+                )));
             }
+            segments.push(TranslationSegment::Argument(arg));
+            literal_start = offset + size;
+            rest = &text[literal_start..];
         }
-        for (counter, (pos, _)) in (1..).zip(text.match_indices("%s")) {
-            positions.push((pos, counter, 2usize));
+        if literal_start < text.len() {
+            segments.push(TranslationSegment::Literal(Cow::Borrowed(
+                &text[literal_start..],
+            )));
         }
-        positions.sort_by_key(|(pos, _, _)| *pos);
-        let mut translation = vec![];
-        let mut positions = positions.into_iter().peekable();
-        while let Some((pos, _, size)) = positions.next() {
-            let Some(next) = positions.peek() else {
-                break;
-            };
-            translation.push((text[pos + size..next.0].to_string(), next.1));
+        segments
+    }
+    /// Each part of the text paired with the argument following it, counted from 1 with 0
+    /// meaning no argument follows.
+    #[deprecated(since = "0.1.8", note = "use translation_segments instead")]
+    fn split_translation(&self, text: String) -> Vec<(String, usize)> {
+        let mut parts = vec![];
+        let mut literal = String::new();
+        for segment in self.translation_segments(&text) {
+            match segment {
+                TranslationSegment::Literal(text) => literal.push_str(&text),
+                TranslationSegment::Argument(index) => {
+                    parts.push((std::mem::take(&mut literal), index + 1))
+                }
+            }
         }
-        translation
+        parts.push((literal, 0));
+        parts
     }
 }
 
-impl<T: TextResolutor> TextResolutor for Arc<T> {
+/// A part of a translated text, see [TextResolutor::translation_segments].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TranslationSegment<'a> {
+    Literal(Cow<'a, str>),
+    /// Index of the argument in the translation's `with`, counted from 0.
+    Argument(usize),
+}
+
+// Resolutors behind references and pointers, so any of them can be given to the builders
+impl<T: TextResolutor + ?Sized> TextResolutor for &T {
+    fn resolve_other(&self, content: &Content) -> TextComponent {
+        (**self).resolve_other(content)
+    }
+
     fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
         (**self).resolve_content(resolvable)
     }
 
+    fn try_resolve_content(
+        &self,
+        resolvable: &Resolvable,
+    ) -> Result<TextComponent, Box<dyn Error + Send + Sync>> {
+        (**self).try_resolve_content(resolvable)
+    }
+
     #[cfg(feature = "custom")]
     fn resolve_custom(&self, data: &CustomData) -> Option<TextComponent> {
         (**self).resolve_custom(data)
     }
 
-    fn translate(&self, key: &str) -> Option<String> {
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>> {
         (**self).translate(key)
     }
 
-    fn split_translation(&self, text: String) -> Vec<(String, usize)> {
-        (**self).split_translation(text)
+    fn keybind(&self, keybind: &str) -> Option<Cow<'_, str>> {
+        (**self).keybind(keybind)
+    }
+
+    fn placeholders(&self) -> &PlaceholderConfig {
+        (**self).placeholders()
+    }
+
+    fn translation_segments<'a>(&self, text: &'a str) -> Vec<TranslationSegment<'a>> {
+        (**self).translation_segments(text)
     }
 }
 
+impl<T: TextResolutor + ?Sized> TextResolutor for Box<T> {
+    fn resolve_other(&self, content: &Content) -> TextComponent {
+        (**self).resolve_other(content)
+    }
+
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        (**self).resolve_content(resolvable)
+    }
+
+    fn try_resolve_content(
+        &self,
+        resolvable: &Resolvable,
+    ) -> Result<TextComponent, Box<dyn Error + Send + Sync>> {
+        (**self).try_resolve_content(resolvable)
+    }
+
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, data: &CustomData) -> Option<TextComponent> {
+        (**self).resolve_custom(data)
+    }
+
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>> {
+        (**self).translate(key)
+    }
+
+    fn keybind(&self, keybind: &str) -> Option<Cow<'_, str>> {
+        (**self).keybind(keybind)
+    }
+
+    fn placeholders(&self) -> &PlaceholderConfig {
+        (**self).placeholders()
+    }
+
+    fn translation_segments<'a>(&self, text: &'a str) -> Vec<TranslationSegment<'a>> {
+        (**self).translation_segments(text)
+    }
+}
+
+impl<T: TextResolutor + ?Sized> TextResolutor for Arc<T> {
+    fn resolve_other(&self, content: &Content) -> TextComponent {
+        (**self).resolve_other(content)
+    }
+
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        (**self).resolve_content(resolvable)
+    }
+
+    fn try_resolve_content(
+        &self,
+        resolvable: &Resolvable,
+    ) -> Result<TextComponent, Box<dyn Error + Send + Sync>> {
+        (**self).try_resolve_content(resolvable)
+    }
+
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, data: &CustomData) -> Option<TextComponent> {
+        (**self).resolve_custom(data)
+    }
+
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>> {
+        (**self).translate(key)
+    }
+
+    fn keybind(&self, keybind: &str) -> Option<Cow<'_, str>> {
+        (**self).keybind(keybind)
+    }
+
+    fn placeholders(&self) -> &PlaceholderConfig {
+        (**self).placeholders()
+    }
+
+    fn translation_segments<'a>(&self, text: &'a str) -> Vec<TranslationSegment<'a>> {
+        (**self).translation_segments(text)
+    }
+}
+
+/// Looks the translations up in a component's inline table before asking the resolutor.
+pub(crate) struct InlineResolutor<'a, R: ?Sized> {
+    pub(crate) table: &'a TranslationTable,
+    pub(crate) resolutor: &'a R,
+}
+impl<R: TextResolutor + ?Sized> TextResolutor for InlineResolutor<'_, R> {
+    fn resolve_other(&self, content: &Content) -> TextComponent {
+        self.resolutor.resolve_other(content)
+    }
+
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        self.resolutor.resolve_content(resolvable)
+    }
+
+    fn try_resolve_content(
+        &self,
+        resolvable: &Resolvable,
+    ) -> Result<TextComponent, Box<dyn Error + Send + Sync>> {
+        self.resolutor.try_resolve_content(resolvable)
+    }
+
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, data: &CustomData) -> Option<TextComponent> {
+        self.resolutor.resolve_custom(data)
+    }
+
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>> {
+        match self.table.get(key) {
+            Some(text) => Some(Cow::Borrowed(text)),
+            None => self.resolutor.translate(key),
+        }
+    }
+
+    fn keybind(&self, keybind: &str) -> Option<Cow<'_, str>> {
+        self.resolutor.keybind(keybind)
+    }
+
+    fn placeholders(&self) -> &PlaceholderConfig {
+        self.resolutor.placeholders()
+    }
+
+    fn translation_segments<'a>(&self, text: &'a str) -> Vec<TranslationSegment<'a>> {
+        self.resolutor.translation_segments(text)
+    }
+}
+
+/// Resolves everything to the [default](PlaceholderConfig::new) placeholders, like
+/// `[Entity: @a]`, a real resolutor would give `resolvable.join_resolved(names)` for the
+/// entities matched instead.
 pub struct NoResolutor;
 impl TextResolutor for NoResolutor {
     fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
-        match resolvable {
-            Resolvable::Scoreboard { objective, .. } => {
-                TextComponent::plain(format!("[Score: {objective}]"))
-            }
-            Resolvable::Entity { selector, .. } => {
-                TextComponent::plain(format!("[Entity: {selector}]"))
-            }
-            Resolvable::NBT { path, .. } => TextComponent::plain(format!("[Nbt: {path}]")),
-        }
+        TextComponent::plain(self.placeholders().placeholder(resolvable))
     }
 
     #[cfg(feature = "custom")]
@@ -77,62 +301,696 @@ impl TextResolutor for NoResolutor {
         Some(TextComponent::plain(data.id.clone()))
     }
 
-    fn translate(&self, _key: &str) -> Option<String> {
+    fn translate(&self, _key: &str) -> Option<Cow<'_, str>> {
         None
     }
 }
 
+static DEFAULT_PLACEHOLDERS: PlaceholderConfig = PlaceholderConfig::new();
+
+/// The texts [NoResolutor] gives for each resolvable, where `{selector}`, `{objective}`
+/// and `{path}` are replaced with the resolvable's ones.\
+/// It also sets what the builders show for translations: the text of the resolutor,
+/// or the fallback if it doesn't know the key, or the `translation` placeholder
+/// (with `{key}` replaced) if there's no fallback either.\
+/// Each resolutor gives its own with [placeholders](TextResolutor::placeholders), and a
+/// config is a resolutor too, resolving everything like [NoResolutor] with its placeholders.
+/// ## Example
+/// ```
+/// # use std::borrow::Cow;
+/// # use text_components::{TextComponent, resolving::PlaceholderConfig};
+/// let placeholders = PlaceholderConfig {
+///     score: Cow::Borrowed("[{objective} of {selector}]"),
+///     ..PlaceholderConfig::new()
+/// };
+/// // "[kills of @p]"
+/// TextComponent::scoreboard("@p", "kills").to_plain(&placeholders);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaceholderConfig {
+    /// `[Score {objective}@{selector}]` by default.
+    pub score: Cow<'static, str>,
+    /// `[Entity: {selector}]` by default.
+    pub entity: Cow<'static, str>,
+    /// `[Nbt: {path}]` by default.
+    pub nbt: Cow<'static, str>,
+    /// `[Head: {player}]` by default, `{player}` is the name of the player, else its id, its
+    /// texture or a short hash of its properties.
+    pub head: Cow<'static, str>,
+    /// `[Head]` by default, for a head without any of them.
+    pub unknown_head: Cow<'static, str>,
+    /// `[Translation: {key}]` by default, vanilla shows the bare key (`{key}`).
+    pub translation: Cow<'static, str>,
+    /// Uses the fallback of a translation even if the resolutor knows its key,
+    /// `false` by default like vanilla.
+    pub fallback_first: bool,
+    /// How the plain [TextBuilder](crate::fmt::TextBuilder) writes obfuscated text,
+    /// [revealed](ObfuscatedText::Reveal) by default.
+    pub obfuscated: ObfuscatedText,
+}
+
+impl PlaceholderConfig {
+    pub const fn new() -> Self {
+        PlaceholderConfig {
+            score: Cow::Borrowed("[Score {objective}@{selector}]"),
+            entity: Cow::Borrowed("[Entity: {selector}]"),
+            nbt: Cow::Borrowed("[Nbt: {path}]"),
+            head: Cow::Borrowed("[Head: {player}]"),
+            unknown_head: Cow::Borrowed("[Head]"),
+            translation: Cow::Borrowed("[Translation: {key}]"),
+            fallback_first: false,
+            obfuscated: ObfuscatedText::Reveal,
+        }
+    }
+
+    /// The placeholder of the resolvable with this config.
+    pub fn placeholder(&self, resolvable: &Resolvable) -> String {
+        match resolvable {
+            Resolvable::Scoreboard {
+                selector,
+                objective,
+            } => self
+                .score
+                .replace("{objective}", objective)
+                .replace("{selector}", selector),
+            Resolvable::Entity { selector, .. } => self.entity.replace("{selector}", selector),
+            Resolvable::NBT { path, .. } => self.nbt.replace("{path}", path),
+        }
+    }
+
+    /// The placeholder of a player head with this config, like `[Head: Notch]`,
+    /// `[Head: texture entity/player/wide/steve]` or `[Head: prop 9f3a…]`.
+    pub fn head_placeholder(&self, player: &ObjectPlayer) -> String {
+        let label = if let Some(name) = &player.name {
+            name.to_string()
+        } else if let Some(id) = &player.id {
+            format!("{id:?}")
+        } else if let Some(texture) = &player.texture {
+            let path = texture.split_once(':').map_or(&**texture, |(_, path)| path);
+            format!("texture {}", path.strip_suffix(".png").unwrap_or(path))
+        } else if let Some(property) = player
+            .properties
+            .iter()
+            .find(|property| property.name == "textures")
+            .or(player.properties.first())
+        {
+            // FNV-1a, so the same skin always gives the same text
+            let hash = property.value.bytes().fold(0x811c9dc5_u32, |hash, byte| {
+                (hash ^ byte as u32).wrapping_mul(0x01000193)
+            });
+            format!("prop {:04x}…", hash >> 16)
+        } else {
+            return self.unknown_head.to_string();
+        };
+        self.head.replace("{player}", &label)
+    }
+
+    /// What the message shows with this config, its arguments are still to be
+    /// placed if it's [translated](TranslationText::Translated).\
+    /// Like in the game, the arguments are placed in the fallback too.
+    pub fn translation_text<'a, R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &'a R,
+        message: &'a TranslatedMessage,
+    ) -> TranslationText<'a> {
+        if self.fallback_first
+            && let Some(fallback) = &message.fallback
+        {
+            return TranslationText::Translated(Cow::Borrowed(fallback));
+        }
+        if let Some(translated) = resolutor.translate(&message.key) {
+            return TranslationText::Translated(translated);
+        }
+        match &message.fallback {
+            Some(fallback) => TranslationText::Translated(Cow::Borrowed(fallback)),
+            None => TranslationText::Literal(Cow::Owned(self.translation_placeholder(message))),
+        }
+    }
+
+    /// The placeholder of a translation that can't be translated with this config.
+    pub fn translation_placeholder(&self, message: &TranslatedMessage) -> String {
+        self.translation.replace("{key}", &message.key)
+    }
+}
+
+/// The text builders show for a keybind: the [bound key](TextResolutor::keybind), else the
+/// [translation](TextResolutor::translate) of its name, taken as is so a `%` in it isn't
+/// read as an argument.\
+/// [None] when the resolutor has neither, the builders then show a placeholder.
+pub fn keybind_text<'a, R: TextResolutor + ?Sized>(
+    resolutor: &'a R,
+    keybind: &str,
+) -> Option<Cow<'a, str>> {
+    resolutor
+        .keybind(keybind)
+        .or_else(|| resolutor.translate(keybind))
+}
+
+/// The text of a [TranslatedMessage], given by [PlaceholderConfig::translation_text].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranslationText<'a> {
+    /// The text the resolutor has for the key or the fallback, with the arguments to place
+    /// in it.
+    Translated(Cow<'a, str>),
+    /// Shown as it is, the placeholder.
+    Literal(Cow<'a, str>),
+}
+
+impl Default for PlaceholderConfig {
+    fn default() -> Self {
+        PlaceholderConfig::new()
+    }
+}
+
+impl TextResolutor for PlaceholderConfig {
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        TextComponent::plain(self.placeholder(resolvable))
+    }
+
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, data: &CustomData) -> Option<TextComponent> {
+        NoResolutor.resolve_custom(data)
+    }
+
+    fn translate(&self, _key: &str) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    fn placeholders(&self) -> &PlaceholderConfig {
+        self
+    }
+}
+
+/// Resolves scores from a fixed table keyed by selector and objective, and everything
+/// else like [NoResolutor], useful for examples and tests needing a stable output.
+/// ## Example
+/// ```
+/// # use text_components::{TextComponent, resolving::StaticScores};
+/// let scores = StaticScores::new().score("@p", "kills", 5);
+/// // "5"
+/// TextComponent::scoreboard("@p", "kills").to_plain(&scores);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StaticScores(pub HashMap<(String, String), i64>);
+
+impl StaticScores {
+    pub fn new() -> Self {
+        StaticScores::default()
+    }
+
+    /// Sets the score of the selector in the objective.
+    pub fn score<S: Into<String>, O: Into<String>>(
+        mut self,
+        selector: S,
+        objective: O,
+        value: i64,
+    ) -> Self {
+        self.0.insert((selector.into(), objective.into()), value);
+        self
+    }
+}
+
+impl TextResolutor for StaticScores {
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        if let Resolvable::Scoreboard {
+            selector,
+            objective,
+        } = resolvable
+            && let Some(value) = self.0.get(&(selector.to_string(), objective.to_string()))
+        {
+            return TextComponent::plain(value.to_string());
+        }
+        NoResolutor.resolve_content(resolvable)
+    }
+
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, data: &CustomData) -> Option<TextComponent> {
+        NoResolutor.resolve_custom(data)
+    }
+
+    fn translate(&self, _key: &str) -> Option<Cow<'_, str>> {
+        None
+    }
+}
+
+/// Turns the values found for an [NBT](Resolvable::NBT) resolvable into its component,
+/// parsing each value as SNBT when `interpret` is set (keeping the raw text if it fails)
+/// and joining them with the separator.
+/// ## Example
+/// ```
+/// # use std::borrow::Cow;
+/// # use text_components::{
+/// #     TextComponent,
+/// #     content::{NbtSource, Resolvable},
+/// #     resolving::{NoResolutor, TextResolutor, resolve_nbt_values},
+/// # };
+/// struct Bank;
+/// impl TextResolutor for Bank {
+///     fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+///         match resolvable {
+///             Resolvable::NBT { path, .. } if path == "coins" => {
+///                 resolve_nbt_values(vec!["12".into(), "30".into()], resolvable)
+///             }
+///             _ => NoResolutor.resolve_content(resolvable),
+///         }
+///     }
+/// #   #[cfg(feature = "custom")]
+/// #   fn resolve_custom(&self, data: &text_components::custom::CustomData) -> Option<TextComponent> {
+/// #       NoResolutor.resolve_custom(data)
+/// #   }
+///     fn translate(&self, _key: &str) -> Option<Cow<'_, str>> {
+///         None
+///     }
+/// }
+/// let coins = TextComponent::nbt("coins", NbtSource::storage("bank:accounts"), false, None);
+/// assert_eq!(coins.to_plain(&Bank), "12, 30");
+/// ```
+pub fn resolve_nbt_values(values: Vec<String>, resolvable: &Resolvable) -> TextComponent {
+    let interpret = matches!(
+        resolvable,
+        Resolvable::NBT {
+            interpret: Some(true),
+            ..
+        }
+    );
+    resolvable.join_resolved(
+        values
+            .into_iter()
+            .map(|value| match interpret {
+                true => interpret_nbt(value),
+                false => TextComponent::plain(value),
+            })
+            .collect(),
+    )
+}
+
+/// How deep the components given by a resolutor are resolved again, like the game does
+/// with interpreted NBT.
+pub const MAX_RESOLVE_DEPTH: usize = 100;
+
+fn needs_resolution(content: &Content) -> bool {
+    match content {
+        #[cfg(feature = "custom")]
+        Content::Custom(_) => true,
+        Content::Resolvable(_) => true,
+        _ => false,
+    }
+}
+
+/// Like the game, a string tag is read as the component written in it.
+fn interpret_nbt(value: String) -> TextComponent {
+    match TextComponent::from_snbt(&value) {
+        Ok(TextComponent {
+            content: Content::Text { text },
+            ..
+        }) if value.trim_start().starts_with(['"', '\'']) => embedded_or_plain(text.into_owned()),
+        Ok(component) => component,
+        Err(_) => TextComponent::plain(value),
+    }
+}
+
 impl TextComponent {
     pub fn build<R: TextResolutor + ?Sized, S: BuildTarget>(
         &self,
         resolutor: &R,
         target: S,
     ) -> S::Result {
-        target.build_component(resolutor, &self.resolve(resolutor))
+        match &self.inline_translations {
+            Some(table) => {
+                let resolutor = InlineResolutor { table, resolutor };
+                target.build_component(&resolutor, &self.resolve(&resolutor))
+            }
+            None => target.build_component(resolutor, &self.resolve(resolutor)),
+        }
     }
 
+    /// Resolves the contents needing the resolutor, the inline translations of the component
+    /// are kept and written as the fallback of the translations using them.\
+    /// Hover texts and the names of shown entities are resolved too, the components of
+    /// shown items are an SNBT string and are kept as they are.\
+    /// The components the resolutor gives are resolved too, so resolving again changes
+    /// nothing: `c.resolve(r).resolve(r) == c.resolve(r)` as long as the resolutor gives the
+    /// same components each time and they aren't nested deeper than [MAX_RESOLVE_DEPTH].
     pub fn resolve<R: TextResolutor + ?Sized>(&self, resolutor: &R) -> TextComponent {
-        let mut component = match &self.content {
-            #[cfg(feature = "custom")]
-            Content::Custom(data) => resolutor
-                .resolve_custom(data)
-                .unwrap_or(TextComponent::new()),
-            Content::Resolvable(resolvable) => resolutor.resolve_content(resolvable),
-            content => resolutor.resolve_other(content),
+        let component = Resolution::new(resolutor, false)
+            .component(self, 0)
+            .unwrap_or_else(|_| unreachable!("only fallible resolutions fail"));
+        self.keep_inline_translations(component)
+    }
+
+    /// Same as [resolve](TextComponent::resolve), stopping at the first resolvable the
+    /// resolutor [fails](TextResolutor::try_resolve_content) to resolve.
+    /// ## Example
+    /// ```ignore
+    /// match component.try_resolve(&world) {
+    ///     Ok(resolved) => player.send(resolved),
+    ///     // "Objective not found at with[1]"
+    ///     Err(error) => metrics.failed_resolution(&error.path),
+    /// }
+    /// ```
+    pub fn try_resolve<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+    ) -> Result<TextComponent, ResolveError> {
+        let mut resolution = Resolution::new(resolutor, true);
+        match resolution.component(self, 0) {
+            Ok(component) => Ok(self.keep_inline_translations(component)),
+            Err(error) => Err(ResolveError {
+                path: ComponentPath(resolution.path),
+                error,
+            }),
+        }
+    }
+
+    /// Same as [resolve](TextComponent::resolve), also telling what the resolutor gave for
+    /// each resolvable, custom and translation found, to see where a placeholder comes from.
+    /// ## Example
+    /// ```
+    /// # use text_components::{TextComponent, resolving::NoResolutor};
+    /// # let (component, world) = (TextComponent::scoreboard("@p", "kills"), NoResolutor);
+    /// let (resolved, trace) = component.resolve_traced(&world);
+    /// for node in &trace.nodes {
+    ///     // "extra[1]: Scoreboard { .. } gave [Score kills@@p]"
+    ///     println!("{}: {:?}", node.path, node.kind);
+    /// }
+    /// ```
+    pub fn resolve_traced<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+    ) -> (TextComponent, ResolveTrace) {
+        // The trace shows the translations of the inline table, like building does
+        match &self.inline_translations {
+            Some(table) => self.resolve_traced_with(&InlineResolutor { table, resolutor }),
+            None => self.resolve_traced_with(resolutor),
+        }
+    }
+
+    fn resolve_traced_with<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+    ) -> (TextComponent, ResolveTrace) {
+        let mut resolution = Resolution::new(resolutor, false);
+        resolution.trace = Some(Vec::new());
+        let component = resolution
+            .component(self, 0)
+            .unwrap_or_else(|_| unreachable!("only fallible resolutions fail"));
+        let trace = ResolveTrace {
+            nodes: resolution.trace.unwrap_or_default(),
         };
+        (self.keep_inline_translations(component), trace)
+    }
+
+    /// Keeps the inline translations and writes the values of the
+    /// [no_inherit](Format::no_inherit) formats.
+    fn keep_inline_translations(&self, mut component: TextComponent) -> TextComponent {
+        component.expand(&Format::new(), false);
+        if let Some(table) = &self.inline_translations {
+            component.inline_fallbacks(table);
+            component.inline_translations = Some(table.clone());
+        }
+        component
+    }
+
+    fn inline_fallbacks(&mut self, table: &TranslationTable) {
+        if let Content::Translate(message) = &mut self.content
+            && let Some(text) = table.get(message.key.as_str())
+        {
+            message.fallback = Some(text.clone());
+        }
+        self.for_each_nested_mut(|nested| nested.inline_fallbacks(table));
+    }
+}
 
-        match &mut component.content {
-            Content::Translate(message) => {
-                message.args = message.args.as_ref().map(|args| {
-                    args.iter()
-                        .map(|arg| arg.resolve(resolutor))
-                        .collect::<Vec<TextComponent>>()
-                        .into_boxed_slice()
+/// A resolvable the resolutor failed to resolve, given by [TextComponent::try_resolve].
+#[derive(Debug)]
+pub struct ResolveError {
+    /// Path from the resolved component to the resolvable, the components given by the
+    /// resolutor aren't part of it.
+    pub path: ComponentPath,
+    pub error: Box<dyn Error + Send + Sync>,
+}
+impl Display for ResolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        if !self.path.is_root() {
+            write!(f, " at {}", self.path)?;
+        }
+        Ok(())
+    }
+}
+impl Error for ResolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&*self.error)
+    }
+}
+
+/// What the resolutor gave while [tracing a resolution](TextComponent::resolve_traced),
+/// in the order the nodes were resolved.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ResolveTrace {
+    pub nodes: Vec<TracedNode>,
+}
+
+impl ResolveTrace {
+    /// The first node traced at the path, the ones given by the resolutor share the path
+    /// of the node that gave them and come after it.
+    pub fn get(&self, path: &ComponentPath) -> Option<&TracedNode> {
+        self.nodes.iter().find(|node| &node.path == path)
+    }
+}
+
+/// A node replaced or looked up by the resolutor, see [ResolveTrace].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TracedNode {
+    /// Path from the resolved component to the node, like [ResolveError::path].
+    pub path: ComponentPath,
+    pub kind: TracedKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TracedKind {
+    /// A resolvable, with its separator resolved, and the component given for it before
+    /// its own contents were resolved.
+    Resolvable {
+        resolvable: Resolvable,
+        resolved: Box<TextComponent>,
+    },
+    /// A custom component, [None] when the resolutor had nothing for it and it was
+    /// left empty.
+    #[cfg(feature = "custom")]
+    Custom {
+        data: CustomData,
+        resolved: Option<Box<TextComponent>>,
+    },
+    /// A translation is kept by the resolution, this is the text the resolutor has for the
+    /// key, [None] when builders show the fallback or the placeholder.
+    Translate {
+        key: String,
+        translated: Option<String>,
+    },
+}
+
+/// A walk resolving the contents of a component, with the path to the one being resolved.
+struct Resolution<'a, R: ?Sized> {
+    resolutor: &'a R,
+    /// Uses [try_resolve_content](TextResolutor::try_resolve_content), the infallible
+    /// methods are used otherwise.
+    fallible: bool,
+    path: Vec<PathSegment>,
+    /// Only kept by [resolve_traced](TextComponent::resolve_traced).
+    trace: Option<Vec<TracedNode>>,
+}
+
+impl<'a, R: TextResolutor + ?Sized> Resolution<'a, R> {
+    fn new(resolutor: &'a R, fallible: bool) -> Self {
+        Resolution {
+            resolutor,
+            fallible,
+            path: Vec::new(),
+            trace: None,
+        }
+    }
+
+    fn traced(&mut self, kind: impl FnOnce() -> TracedKind) {
+        if let Some(trace) = &mut self.trace {
+            trace.push(TracedNode {
+                path: ComponentPath(self.path.clone()),
+                kind: kind(),
+            });
+        }
+    }
+
+    /// `depth` counts the components given by the resolutor this one is nested in, their
+    /// nested components are resolved too, up to [MAX_RESOLVE_DEPTH].
+    fn component(
+        &mut self,
+        component: &TextComponent,
+        depth: usize,
+    ) -> Result<TextComponent, BoxedError> {
+        let mut resolved = match &component.content {
+            #[cfg(feature = "custom")]
+            Content::Custom(data) => {
+                let resolved = self.resolutor.resolve_custom(data);
+                self.traced(|| TracedKind::Custom {
+                    data: data.clone(),
+                    resolved: resolved.clone().map(Box::new),
+                });
+                resolved.unwrap_or(TextComponent::new())
+            }
+            Content::Resolvable(resolvable) => {
+                let resolvable = self.separator(resolvable, depth)?;
+                let resolved = match self.fallible {
+                    true => self.resolutor.try_resolve_content(&resolvable)?,
+                    false => self.resolutor.resolve_content(&resolvable),
+                };
+                self.traced(|| TracedKind::Resolvable {
+                    resolved: Box::new(resolved.clone()),
+                    resolvable,
                 });
+                resolved
             }
-            Content::Resolvable(Resolvable::Entity { separator, .. }) => {
-                **separator = separator.resolve(resolutor);
+            content => {
+                if let Content::Translate(message) = content {
+                    let resolutor = self.resolutor;
+                    self.traced(|| TracedKind::Translate {
+                        key: message.key.to_string(),
+                        translated: resolutor.translate(&message.key).map(Cow::into_owned),
+                    });
+                }
+                self.resolutor.resolve_other(content)
             }
-            Content::Resolvable(Resolvable::NBT { separator, .. }) => {
-                **separator = separator.resolve(resolutor);
+        };
+        let from_resolutor = needs_resolution(&component.content);
+        let nested_depth = depth + 1;
+        if nested_depth <= MAX_RESOLVE_DEPTH {
+            if resolved.content != component.content && needs_resolution(&resolved.content) {
+                resolved = self.given(&resolved, nested_depth)?;
+            } else {
+                for child in &mut resolved.children {
+                    *child = self.given(child, nested_depth)?;
+                }
+            }
+        }
+        // Resolutors may give the raw SNBT of interpreted NBT back as plain text
+        if let Content::Resolvable(Resolvable::NBT {
+            interpret: Some(true),
+            ..
+        }) = &component.content
+            && let Content::Text { text } = &resolved.content
+            && resolved.children.is_empty()
+            && resolved.format.is_none()
+            && resolved.interactions.is_none()
+            && let Ok(interpreted) = TextComponent::from_snbt(text)
+        {
+            resolved = match nested_depth <= MAX_RESOLVE_DEPTH {
+                true => self.given(&interpreted, nested_depth)?,
+                false => interpreted,
+            };
+        }
+
+        if let Content::Translate(message) = &mut resolved.content
+            && let Some(args) = &message.args
+        {
+            let mut resolved_args = Vec::with_capacity(args.len());
+            for (i, arg) in args.iter().enumerate() {
+                resolved_args.push(match from_resolutor {
+                    true => self.given(arg, nested_depth)?,
+                    false => self.nested(PathSegment::TranslateArg(i), arg, nested_depth)?,
+                });
             }
-            _ => (),
+            message.args = Some(resolved_args.into_boxed_slice());
         }
 
-        component.children.append(
-            &mut self
-                .children
-                .iter()
-                .map(|child| child.resolve(resolutor))
-                .collect(),
-        );
-        self.interactions.mix(&mut component.interactions);
-        component.format = self.format.mix(&component.format);
+        for (i, child) in component.children.iter().enumerate() {
+            let child = self.nested(PathSegment::Child(i), child, depth)?;
+            resolved.children.push(child);
+        }
+        component.interactions.mix(&mut resolved.interactions);
+        resolved.format = component.format.overlay(&resolved.format);
+        #[cfg(feature = "unknown-fields")]
+        if component.unknown_fields.is_some() {
+            resolved
+                .unknown_fields
+                .clone_from(&component.unknown_fields);
+        }
+        if let Some(hover) = &mut resolved.interactions.hover {
+            self.hover(hover, component.interactions.hover.is_some(), depth)?;
+        }
 
-        component
+        Ok(resolved)
+    }
+
+    /// Resolves the text or entity name shown by the hover, `from_component` tells if it's
+    /// the hover of the component or one given by the resolutor.\
+    /// The components of a shown item are an SNBT string, they're kept as they are.
+    fn hover(
+        &mut self,
+        hover: &mut HoverEvent,
+        from_component: bool,
+        depth: usize,
+    ) -> Result<(), BoxedError> {
+        let (segment, value) = match hover {
+            HoverEvent::ShowText { value } => (PathSegment::HoverValue, value),
+            HoverEvent::ShowEntity {
+                name: Some(name), ..
+            } => (PathSegment::HoverName, name),
+            _ => return Ok(()),
+        };
+        **value = match from_component {
+            true => self.nested(segment, value, depth)?,
+            false if depth < MAX_RESOLVE_DEPTH => self.given(value, depth + 1)?,
+            false => return Ok(()),
+        };
+        Ok(())
+    }
+
+    /// Resolves a component nested in the one being resolved, keeping its segment in the
+    /// path if it fails.
+    fn nested(
+        &mut self,
+        segment: PathSegment,
+        component: &TextComponent,
+        depth: usize,
+    ) -> Result<TextComponent, BoxedError> {
+        self.path.push(segment);
+        let resolved = self.component(component, depth)?;
+        self.path.pop();
+        Ok(resolved)
+    }
+
+    /// Resolves a component given by the resolutor, the path stays at the resolvable
+    /// that gave it if it fails.
+    fn given(
+        &mut self,
+        component: &TextComponent,
+        depth: usize,
+    ) -> Result<TextComponent, BoxedError> {
+        let len = self.path.len();
+        self.component(component, depth)
+            .inspect_err(|_| self.path.truncate(len))
+    }
+
+    /// The resolvable with its separator resolved, [resolve_content](TextResolutor::resolve_content)
+    /// gets them like this.
+    fn separator(
+        &mut self,
+        resolvable: &Resolvable,
+        depth: usize,
+    ) -> Result<Resolvable, BoxedError> {
+        let mut resolvable = resolvable.clone();
+        if let Resolvable::Entity { separator, .. } | Resolvable::NBT { separator, .. } =
+            &mut resolvable
+        {
+            **separator = self.nested(PathSegment::Separator, separator, depth)?;
+        }
+        Ok(resolvable)
     }
 }
 
+type BoxedError = Box<dyn Error + Send + Sync>;
+
+/// Can't be a trait object because of the generic resolutor, targets can be shared in an
+/// [Arc] instead.
 pub trait BuildTarget {
     type Result;
     fn build_component<R: TextResolutor + ?Sized>(
@@ -140,4 +998,30 @@ pub trait BuildTarget {
         resolutor: &R,
         component: &TextComponent,
     ) -> Self::Result;
+    /// Called on the text built for every component's content with its insertion, click and
+    /// hover, letting the target render them (e.g. a `(link: url)` suffix in logs).\
+    /// Targets using [stringify_content](crate::fmt::TextBuilder::stringify_content) get it
+    /// for free, by default interactions are dropped.
+    fn decorate_interactions(
+        &self,
+        text: Self::Result,
+        _interactions: &Interactivity,
+    ) -> Self::Result {
+        text
+    }
+}
+
+impl<S: BuildTarget> BuildTarget for Arc<S> {
+    type Result = S::Result;
+    fn build_component<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+        component: &TextComponent,
+    ) -> S::Result {
+        (**self).build_component(resolutor, component)
+    }
+
+    fn decorate_interactions(&self, text: S::Result, interactions: &Interactivity) -> S::Result {
+        (**self).decorate_interactions(text, interactions)
+    }
 }
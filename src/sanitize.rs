@@ -0,0 +1,165 @@
+//! Stripping of dangerous interactions from components sent by untrusted sources,
+//! like player written books or messages relayed from other servers.
+use crate::{
+    TextComponent,
+    content::{Content, Resolvable},
+    interactivity::{ClickAction, HoverAction, HoverEvent},
+};
+use std::borrow::Cow;
+
+/// What [TextComponent::sanitize] keeps from a component.
+/// ## Example
+/// ```
+/// # use text_components::{
+/// #     TextComponent,
+/// #     interactivity::ClickAction,
+/// #     sanitize::SanitizePolicy,
+/// # };
+/// # let mut component = TextComponent::new();
+/// // Only let players link websites, without nesting abuse
+/// let policy = SanitizePolicy {
+///     click_actions: vec![ClickAction::OpenUrl],
+///     max_depth: Some(8),
+///     ..Default::default()
+/// };
+/// component.sanitize(&policy);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SanitizePolicy {
+    /// Click events with any other action are removed.
+    pub click_actions: Vec<ClickAction>,
+    /// Hover events with any other action are removed.
+    pub hover_actions: Vec<HoverAction>,
+    /// Whether Shift+Click insertions are kept.
+    pub keep_insertions: bool,
+    /// Components nested deeper than this are removed, the root has a depth of 1.\
+    /// Translation arguments and separators are emptied instead so their placeholders stay.
+    pub max_depth: Option<usize>,
+    /// Maximum amount of text characters, texts past it are truncated
+    /// and the children left after it removed.
+    pub max_length: Option<usize>,
+    /// Whether custom contents are replaced with empty text, does nothing without the `custom` feature.
+    pub drop_custom: bool,
+}
+
+impl Default for SanitizePolicy {
+    /// Keeps everything harmless to the receiver, removing the `run_command`,
+    /// `show_dialog` and custom click events as well as custom contents.
+    fn default() -> Self {
+        SanitizePolicy {
+            click_actions: vec![
+                ClickAction::OpenUrl,
+                ClickAction::SuggestCommand,
+                ClickAction::ChangePage,
+                ClickAction::CopyToClipboard,
+            ],
+            hover_actions: vec![
+                HoverAction::ShowText,
+                HoverAction::ShowItem,
+                HoverAction::ShowEntity,
+            ],
+            keep_insertions: true,
+            max_depth: None,
+            max_length: None,
+            drop_custom: true,
+        }
+    }
+}
+
+impl SanitizePolicy {
+    /// Removes every click event and insertion, only keeping hover texts.
+    pub fn strict() -> Self {
+        SanitizePolicy {
+            click_actions: vec![],
+            hover_actions: vec![HoverAction::ShowText],
+            keep_insertions: false,
+            ..Default::default()
+        }
+    }
+}
+
+impl TextComponent {
+    /// Removes everything the [policy](SanitizePolicy) doesn't allow from this component,
+    /// including the children, translation arguments, separators and hover texts.
+    pub fn sanitize(&mut self, policy: &SanitizePolicy) {
+        let mut remaining = policy.max_length.unwrap_or(usize::MAX);
+        self.sanitize_at(policy, 1, &mut remaining);
+    }
+
+    fn sanitize_at(&mut self, policy: &SanitizePolicy, depth: usize, remaining: &mut usize) {
+        let nest = policy.max_depth.is_none_or(|max| depth < max);
+
+        #[cfg(feature = "custom")]
+        if policy.drop_custom && matches!(self.content, Content::Custom(_)) {
+            self.content = Content::Text {
+                text: Cow::Borrowed(""),
+            };
+        }
+        match &mut self.content {
+            Content::Text { text } => truncate(text, remaining),
+            Content::Translate(message) => {
+                if let Some(args) = &mut message.args {
+                    for arg in args.iter_mut() {
+                        match nest {
+                            true => arg.sanitize_at(policy, depth + 1, remaining),
+                            false => *arg = TextComponent::new(),
+                        }
+                    }
+                }
+            }
+            Content::Resolvable(Resolvable::Entity { separator, .. })
+            | Content::Resolvable(Resolvable::NBT { separator, .. }) => match nest {
+                true => separator.sanitize_at(policy, depth + 1, remaining),
+                false => **separator = TextComponent::new(),
+            },
+            _ => (),
+        }
+
+        let interactions = &mut self.interactions;
+        if !policy.keep_insertions {
+            interactions.insertion = None;
+        }
+        if let Some(click) = &interactions.click
+            && !policy.click_actions.contains(&click.action())
+        {
+            interactions.click = None;
+        }
+        if let Some(hover) = &interactions.hover
+            && !policy.hover_actions.contains(&hover.action())
+        {
+            interactions.hover = None;
+        }
+        match &mut interactions.hover {
+            Some(HoverEvent::ShowText { value }) if nest => {
+                value.sanitize_at(policy, depth + 1, remaining)
+            }
+            Some(HoverEvent::ShowText { .. }) => interactions.hover = None,
+            Some(HoverEvent::ShowEntity { name, .. }) => match name {
+                Some(value) if nest => value.sanitize_at(policy, depth + 1, remaining),
+                _ => *name = None,
+            },
+            _ => (),
+        }
+
+        if !nest {
+            self.children.clear();
+        }
+        for i in 0..self.children.len() {
+            if *remaining == 0 {
+                self.children.truncate(i);
+                break;
+            }
+            self.children[i].sanitize_at(policy, depth + 1, remaining);
+        }
+    }
+}
+
+fn truncate(text: &mut Cow<'static, str>, remaining: &mut usize) {
+    match text.char_indices().nth(*remaining) {
+        Some((end, _)) => {
+            text.to_mut().truncate(end);
+            *remaining = 0;
+        }
+        None => *remaining -= text.chars().count(),
+    }
+}
@@ -0,0 +1,382 @@
+//! JSON Schema of the serde form of components, written by hand where the serde
+//! implementation is, the other types derive it.
+#[cfg(feature = "custom")]
+use crate::custom::CustomData;
+use crate::{
+    TextComponent,
+    content::{Content, NbtSource, Object, ObjectPlayer, Resolvable},
+    dialog::DialogRef,
+    format::{Color, Format},
+    interactivity::{ClickEvent, HoverEvent, Interactivity},
+    translation::TranslatedMessage,
+};
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use std::borrow::Cow;
+
+impl TextComponent {
+    /// The JSON Schema of components in their JSON form, so editors can validate and
+    /// complete the messages of config files.
+    /// ## Example
+    /// ```no_run
+    /// # use text_components::TextComponent;
+    /// std::fs::write("message.schema.json", TextComponent::json_schema_string())?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn json_schema_string() -> String {
+        format!("{:#}", schemars::schema_for!(TextComponent).as_value())
+    }
+}
+
+/// Booleans as read by [bool_lenient](crate::serde::bool_lenient).
+fn lenient_bool() -> Schema {
+    json_schema!({
+        "anyOf": [
+            { "type": "boolean" },
+            { "type": "number" },
+            { "enum": ["true", "false", "1", "0", "1b", "0b", "1B", "0B"] }
+        ]
+    })
+}
+
+fn string() -> Schema {
+    json_schema!({ "type": "string" })
+}
+
+/// A component where vanilla also takes a plain string or a list of components:
+/// children, translation arguments, separators and hover texts.
+struct FlexibleComponent;
+impl JsonSchema for FlexibleComponent {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("FlexibleTextComponent")
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "anyOf": [
+                { "type": "string" },
+                { "type": "array", "items": generator.subschema_for::<FlexibleComponent>() },
+                generator.subschema_for::<TextComponent>()
+            ]
+        })
+    }
+}
+
+impl JsonSchema for TextComponent {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("TextComponent")
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "object",
+            "allOf": [
+                generator.subschema_for::<Content>(),
+                generator.subschema_for::<Format>(),
+                generator.subschema_for::<Interactivity>()
+            ],
+            "properties": {
+                "extra": { "type": "array", "items": generator.subschema_for::<FlexibleComponent>() }
+            }
+        })
+    }
+}
+
+// Untagged, the first variant whose keys are there is the one read
+impl JsonSchema for Content {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("Content")
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let variants = vec![
+            json_schema!({
+                "type": "object",
+                "properties": { "text": string() },
+                "required": ["text"]
+            }),
+            generator.subschema_for::<TranslatedMessage>(),
+            json_schema!({
+                "type": "object",
+                "properties": { "keybind": string() },
+                "required": ["keybind"]
+            }),
+            #[cfg(feature = "custom")]
+            generator.subschema_for::<CustomData>(),
+            generator.subschema_for::<Object>(),
+            generator.subschema_for::<Resolvable>(),
+        ];
+        json_schema!({ "anyOf": variants })
+    }
+}
+
+impl JsonSchema for TranslatedMessage {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("TranslatedMessage")
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "object",
+            "properties": {
+                "translate": string(),
+                "fallback": string(),
+                "with": { "type": "array", "items": generator.subschema_for::<FlexibleComponent>() }
+            },
+            "required": ["translate"]
+        })
+    }
+}
+
+// Which object it is comes from the `object` key, or from `player` being there
+impl JsonSchema for Object {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("Object")
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "anyOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "object": { "const": "atlas" },
+                        "atlas": string(),
+                        "sprite": string()
+                    },
+                    "required": ["sprite"]
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "object": { "const": "player" },
+                        "player": {
+                            "anyOf": [string(), generator.subschema_for::<ObjectPlayer>()]
+                        },
+                        "hat": lenient_bool()
+                    },
+                    "required": ["player"]
+                }
+            ]
+        })
+    }
+}
+
+impl JsonSchema for Resolvable {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("Resolvable")
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let separator = generator.subschema_for::<FlexibleComponent>();
+        json_schema!({
+            "anyOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "score": {
+                            "type": "object",
+                            "properties": { "name": string(), "objective": string() },
+                            "required": ["name", "objective"]
+                        }
+                    },
+                    "required": ["score"]
+                },
+                {
+                    "type": "object",
+                    "properties": { "selector": string(), "separator": separator },
+                    "required": ["selector"]
+                },
+                {
+                    "type": "object",
+                    "allOf": [generator.subschema_for::<NbtSource>()],
+                    "properties": {
+                        "nbt": string(),
+                        "interpret": lenient_bool(),
+                        "separator": separator,
+                        "source": { "enum": ["entity", "block", "storage"] }
+                    },
+                    "required": ["nbt"]
+                }
+            ]
+        })
+    }
+}
+
+// Always flattened into the nbt component, so other keys are allowed beside it
+impl JsonSchema for NbtSource {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("NbtSource")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "anyOf": [
+                { "type": "object", "properties": { "entity": string() }, "required": ["entity"] },
+                { "type": "object", "properties": { "block": string() }, "required": ["block"] },
+                { "type": "object", "properties": { "storage": string() }, "required": ["storage"] }
+            ]
+        })
+    }
+}
+
+impl JsonSchema for Format {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("Format")
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "object",
+            "properties": {
+                "color": generator.subschema_for::<Color>(),
+                "font": string(),
+                "bold": lenient_bool(),
+                "italic": lenient_bool(),
+                "underlined": lenient_bool(),
+                "strikethrough": lenient_bool(),
+                "obfuscated": lenient_bool(),
+                "shadow_color": {
+                    "anyOf": [
+                        { "type": "integer" },
+                        { "type": "array", "items": { "type": "number" }, "minItems": 4, "maxItems": 4 }
+                    ]
+                }
+            }
+        })
+    }
+}
+
+impl JsonSchema for Color {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("Color")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "anyOf": [
+                {
+                    "enum": [
+                        "black", "dark_blue", "dark_green", "dark_aqua", "dark_red", "dark_purple",
+                        "gold", "gray", "dark_gray", "blue", "green", "aqua", "red",
+                        "light_purple", "yellow", "white"
+                    ]
+                },
+                { "type": "string", "pattern": "^#?([0-9a-fA-F]{3}|[0-9a-fA-F]{6})$" }
+            ]
+        })
+    }
+}
+
+impl JsonSchema for Interactivity {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("Interactivity")
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "object",
+            "properties": {
+                "insertion": string(),
+                "click_event": generator.subschema_for::<ClickEvent>(),
+                "hover_event": generator.subschema_for::<HoverEvent>()
+            }
+        })
+    }
+}
+
+// Both events are tagged by their `action` key
+impl JsonSchema for ClickEvent {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("ClickEvent")
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let variants = vec![
+            json_schema!({
+                "type": "object",
+                "properties": { "action": { "const": "open_url" }, "url": string() },
+                "required": ["action", "url"]
+            }),
+            json_schema!({
+                "type": "object",
+                "properties": { "action": { "const": "run_command" }, "command": string() },
+                "required": ["action", "command"]
+            }),
+            json_schema!({
+                "type": "object",
+                "properties": { "action": { "const": "suggest_command" }, "command": string() },
+                "required": ["action", "command"]
+            }),
+            json_schema!({
+                "type": "object",
+                "properties": {
+                    "action": { "const": "change_page" },
+                    "page": generator.subschema_for::<i32>()
+                },
+                "required": ["action", "page"]
+            }),
+            json_schema!({
+                "type": "object",
+                "properties": { "action": { "const": "copy_to_clipboard" }, "value": string() },
+                "required": ["action", "value"]
+            }),
+            json_schema!({
+                "type": "object",
+                "properties": {
+                    "action": { "const": "show_dialog" },
+                    "dialog": generator.subschema_for::<DialogRef>()
+                },
+                "required": ["action", "dialog"]
+            }),
+            #[cfg(feature = "custom")]
+            json_schema!({
+                "type": "object",
+                "allOf": [generator.subschema_for::<CustomData>()],
+                "properties": { "action": { "const": "custom" } },
+                "required": ["action"]
+            }),
+        ];
+        json_schema!({ "oneOf": variants })
+    }
+}
+
+impl JsonSchema for HoverEvent {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("HoverEvent")
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "action": { "const": "show_text" },
+                        "value": generator.subschema_for::<FlexibleComponent>()
+                    },
+                    "required": ["action", "value"]
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "action": { "const": "show_item" },
+                        "id": string(),
+                        "count": generator.subschema_for::<i32>(),
+                        "components": string()
+                    },
+                    "required": ["action", "id"]
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "action": { "const": "show_entity" },
+                        "name": generator.subschema_for::<TextComponent>(),
+                        "id": string(),
+                        "uuid": { "type": "string", "format": "uuid" }
+                    },
+                    "required": ["action", "id", "uuid"]
+                }
+            ]
+        })
+    }
+}
@@ -0,0 +1,619 @@
+//! A deserialization target that borrows its strings from the input.
+//!
+//! Every string of a [TextComponent] is a `Cow<'static, str>`, so deserializing
+//! always allocates, [TextComponentDe] mirrors the vanilla JSON shape with
+//! `Cow<'a, str>` instead, borrowing whenever the input allows it:
+//! ```
+//! # use text_components::{TextComponent, serde::borrowed::TextComponentDe};
+//! # let bytes = br#"{"text":"Hi"}"#;
+//! let component = serde_json::from_slice::<TextComponentDe>(bytes)?;
+//! // Only allocate when the component needs to outlive the input
+//! let component: TextComponent = component.into_owned();
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+#[cfg(feature = "custom")]
+use crate::custom::{CustomData, Payload};
+use crate::{
+    TextComponent,
+    content::{
+        Content, NbtSource, Object, ObjectPlayer, PlayerProperties, Resolvable, normalize_keybind,
+    },
+    dialog::DialogRef,
+    format::{Color, Format},
+    interactivity::{ClickEvent, HoverEvent, Interactivity},
+    parse::embedded_or_plain,
+    serde::{ObjectKind, object_kind},
+    translation::TranslatedMessage,
+};
+use ::serde::{
+    Deserialize, Deserializer,
+    de::{self, MapAccess, SeqAccess, Visitor, value::MapAccessDeserializer},
+};
+use std::borrow::Cow;
+use uuid::Uuid;
+
+fn owned(text: Cow<'_, str>) -> Cow<'static, str> {
+    Cow::Owned(text.into_owned())
+}
+
+/// serde only borrows a bare `Cow<str>`, an optional one needs to be wrapped.
+fn option_cow<'de: 'a, 'a, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Cow<'a, str>>, D::Error> {
+    #[derive(Deserialize)]
+    struct Borrowed<'a>(#[serde(borrow)] Cow<'a, str>);
+    Ok(Option::<Borrowed>::deserialize(deserializer)?.map(|text| text.0))
+}
+
+/// A component that may also be given as a plain string or a list of components.
+enum Flexible<'a> {
+    Text(Cow<'a, str>),
+    List(Vec<Flexible<'a>>),
+    Component(Box<TextComponentDe<'a>>),
+}
+// Not untagged, buffering every component and building an error for each
+// shape it isn't would allocate more than borrowing saves.
+impl<'de: 'a, 'a> Deserialize<'de> for Flexible<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(FlexibleVisitor(std::marker::PhantomData))
+    }
+}
+
+struct FlexibleVisitor<'a>(std::marker::PhantomData<&'a ()>);
+impl<'de: 'a, 'a> Visitor<'de> for FlexibleVisitor<'a> {
+    type Value = Flexible<'a>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a component, a string or a list of components")
+    }
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Flexible<'a>, E> {
+        Ok(Flexible::Text(Cow::Borrowed(v)))
+    }
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Flexible<'a>, E> {
+        Ok(Flexible::Text(Cow::Owned(v.to_owned())))
+    }
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Flexible<'a>, E> {
+        Ok(Flexible::Text(Cow::Owned(v)))
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Flexible<'a>, A::Error> {
+        let mut list = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(element) = seq.next_element()? {
+            list.push(element);
+        }
+        Ok(Flexible::List(list))
+    }
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Flexible<'a>, A::Error> {
+        TextComponentDe::deserialize(MapAccessDeserializer::new(map))
+            .map(|component| Flexible::Component(Box::new(component)))
+    }
+}
+impl<'a> Flexible<'a> {
+    fn component(self) -> Box<TextComponentDe<'a>> {
+        match self {
+            Flexible::Text(text) => Box::new(TextComponentDe {
+                content: ContentDe::Text { text },
+                children: vec![],
+                format: FormatDe::default(),
+                interactions: InteractivityDe::default(),
+            }),
+            Flexible::List(list) => {
+                let mut list = list.into_iter().map(Flexible::component);
+                let mut first = list
+                    .next()
+                    .unwrap_or_else(|| Flexible::Text("".into()).component());
+                first.children.extend(list.map(|child| *child));
+                first
+            }
+            Flexible::Component(component) => component,
+        }
+    }
+}
+
+/// Separators may also be given as a plain string or a list of components.
+fn option_separator<'de: 'a, 'a, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Box<TextComponentDe<'a>>>, D::Error> {
+    Ok(Option::<Flexible>::deserialize(deserializer)?.map(Flexible::component))
+}
+
+/// Hover texts may also be given as a plain string or a list of components, a string
+/// holding a whole component is read as it.
+fn hover_text<'de: 'a, 'a, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Box<TextComponentDe<'a>>, D::Error> {
+    match Flexible::deserialize(deserializer)? {
+        // Embedded components are unescaped copies, there's nothing to borrow
+        Flexible::Text(text) if text.trim_start().starts_with(['{', '[']) => {
+            let component = embedded_or_plain(text.into_owned());
+            let value = serde_json::to_value(component).map_err(de::Error::custom)?;
+            TextComponentDe::deserialize(value)
+                .map(Box::new)
+                .map_err(de::Error::custom)
+        }
+        value => Ok(value.component()),
+    }
+}
+
+/// Translation arguments may also be given as plain strings.
+fn option_args<'de: 'a, 'a, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Vec<TextComponentDe<'a>>>, D::Error> {
+    Ok(Option::<Vec<Flexible>>::deserialize(deserializer)?
+        .map(|args| args.into_iter().map(|arg| *arg.component()).collect()))
+}
+
+/// Children may also be given as plain strings.
+fn children<'de: 'a, 'a, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<TextComponentDe<'a>>, D::Error> {
+    Ok(Vec::<Flexible>::deserialize(deserializer)?
+        .into_iter()
+        .map(|child| *child.component())
+        .collect())
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TextComponentDe<'a> {
+    #[serde(flatten, borrow)]
+    pub content: ContentDe<'a>,
+    #[serde(rename = "extra", deserialize_with = "children", default, borrow)]
+    pub children: Vec<TextComponentDe<'a>>,
+    #[serde(flatten, borrow)]
+    pub format: FormatDe<'a>,
+    #[serde(flatten, borrow)]
+    pub interactions: InteractivityDe<'a>,
+}
+impl TextComponentDe<'_> {
+    /// Copies every borrowed string, detaching the component from the input.
+    pub fn into_owned(self) -> TextComponent {
+        TextComponent {
+            content: self.content.into_owned(),
+            children: self
+                .children
+                .into_iter()
+                .map(TextComponentDe::into_owned)
+                .collect(),
+            format: self.format.into_owned(),
+            interactions: self.interactions.into_owned(),
+            inline_translations: None,
+            #[cfg(feature = "unknown-fields")]
+            unknown_fields: None,
+        }
+    }
+}
+impl From<TextComponentDe<'_>> for TextComponent {
+    fn from(value: TextComponentDe<'_>) -> Self {
+        value.into_owned()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum ContentDe<'a> {
+    Text {
+        #[serde(borrow)]
+        text: Cow<'a, str>,
+    },
+    Translate(#[serde(borrow)] TranslatedMessageDe<'a>),
+    Keybind {
+        #[serde(borrow)]
+        keybind: Cow<'a, str>,
+    },
+    #[cfg(feature = "custom")]
+    Custom(#[serde(borrow)] CustomDataDe<'a>),
+    Object(#[serde(borrow)] ObjectDe<'a>),
+    Resolvable(#[serde(borrow)] ResolvableDe<'a>),
+}
+impl ContentDe<'_> {
+    pub fn into_owned(self) -> Content {
+        match self {
+            ContentDe::Text { text } => Content::Text { text: owned(text) },
+            ContentDe::Translate(message) => Content::Translate(message.into_owned()),
+            ContentDe::Keybind { keybind } => Content::Keybind {
+                keybind: normalize_keybind(owned(keybind)),
+            },
+            #[cfg(feature = "custom")]
+            ContentDe::Custom(data) => Content::Custom(data.into_owned()),
+            ContentDe::Object(object) => Content::Object(object.into_owned()),
+            ContentDe::Resolvable(resolvable) => Content::Resolvable(resolvable.into_owned()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TranslatedMessageDe<'a> {
+    #[serde(rename = "translate", borrow)]
+    pub key: Cow<'a, str>,
+    #[serde(borrow, deserialize_with = "option_cow", default)]
+    pub fallback: Option<Cow<'a, str>>,
+    #[serde(rename = "with", default, borrow, deserialize_with = "option_args")]
+    pub args: Option<Vec<TextComponentDe<'a>>>,
+}
+impl TranslatedMessageDe<'_> {
+    pub fn into_owned(self) -> TranslatedMessage {
+        TranslatedMessage {
+            key: owned(self.key).into(),
+            fallback: self.fallback.map(owned),
+            args: self.args.map(|args| {
+                args.into_iter()
+                    .map(TextComponentDe::into_owned)
+                    .collect::<Vec<TextComponent>>()
+                    .into_boxed_slice()
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObjectDe<'a> {
+    Atlas {
+        atlas: Option<Cow<'a, str>>,
+        sprite: Cow<'a, str>,
+    },
+    Player {
+        player: ObjectPlayerDe<'a>,
+        hat: bool,
+    },
+}
+impl<'de: 'a, 'a> Deserialize<'de> for ObjectDe<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Keys<'a> {
+            #[serde(borrow, deserialize_with = "option_cow", default)]
+            object: Option<Cow<'a, str>>,
+            #[serde(borrow, deserialize_with = "option_cow", default)]
+            atlas: Option<Cow<'a, str>>,
+            #[serde(borrow, deserialize_with = "option_cow", default)]
+            sprite: Option<Cow<'a, str>>,
+            #[serde(borrow, default)]
+            player: Option<ProfileDe<'a>>,
+            #[serde(deserialize_with = "crate::serde::option_bool_lenient", default)]
+            hat: Option<bool>,
+        }
+        let keys = Keys::deserialize(deserializer)?;
+        match object_kind(keys.object.as_deref(), keys.player.is_some())? {
+            ObjectKind::Atlas => Ok(ObjectDe::Atlas {
+                atlas: keys.atlas,
+                sprite: keys
+                    .sprite
+                    .ok_or_else(|| de::Error::missing_field("sprite"))?,
+            }),
+            ObjectKind::Player => Ok(ObjectDe::Player {
+                player: keys
+                    .player
+                    .ok_or_else(|| de::Error::missing_field("player"))?
+                    .into(),
+                hat: keys.hat.unwrap_or(true),
+            }),
+        }
+    }
+}
+impl ObjectDe<'_> {
+    pub fn into_owned(self) -> Object {
+        match self {
+            ObjectDe::Atlas { atlas, sprite } => Object::Atlas {
+                atlas: atlas.map(owned),
+                sprite: owned(sprite),
+            },
+            ObjectDe::Player { player, hat } => Object::Player {
+                player: player.into_owned(),
+                hat,
+            },
+        }
+    }
+}
+
+/// The `player` of a head, given as a whole profile or just the name of the player.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ProfileDe<'a> {
+    Name(#[serde(borrow)] Cow<'a, str>),
+    Profile(#[serde(borrow)] ObjectPlayerDe<'a>),
+}
+impl<'a> From<ProfileDe<'a>> for ObjectPlayerDe<'a> {
+    fn from(profile: ProfileDe<'a>) -> Self {
+        match profile {
+            ProfileDe::Name(name) => ObjectPlayerDe {
+                name: Some(name),
+                id: None,
+                texture: None,
+                properties: vec![],
+            },
+            ProfileDe::Profile(player) => player,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ObjectPlayerDe<'a> {
+    #[serde(borrow, deserialize_with = "option_cow", default)]
+    pub name: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub id: Option<[i32; 4]>,
+    #[serde(borrow, deserialize_with = "option_cow", default)]
+    pub texture: Option<Cow<'a, str>>,
+    #[serde(default, borrow)]
+    pub properties: Vec<PlayerPropertiesDe<'a>>,
+}
+impl ObjectPlayerDe<'_> {
+    pub fn into_owned(self) -> ObjectPlayer {
+        ObjectPlayer {
+            name: self.name.map(owned),
+            id: self.id,
+            texture: self.texture.map(owned),
+            properties: self
+                .properties
+                .into_iter()
+                .map(PlayerPropertiesDe::into_owned)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PlayerPropertiesDe<'a> {
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    #[serde(borrow)]
+    pub value: Cow<'a, str>,
+    #[serde(borrow, deserialize_with = "option_cow", default)]
+    pub signature: Option<Cow<'a, str>>,
+}
+impl PlayerPropertiesDe<'_> {
+    pub fn into_owned(self) -> PlayerProperties {
+        PlayerProperties {
+            name: owned(self.name),
+            value: owned(self.value),
+            signature: self.signature.map(owned),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum ResolvableDe<'a> {
+    #[serde(untagged, deserialize_with = "crate::serde::deserialize_score")]
+    Scoreboard {
+        selector: Cow<'a, str>,
+        objective: Cow<'a, str>,
+    },
+    #[serde(untagged)]
+    Entity {
+        #[serde(borrow)]
+        selector: Cow<'a, str>,
+        #[serde(default, borrow, deserialize_with = "option_separator")]
+        separator: Option<Box<TextComponentDe<'a>>>,
+    },
+    #[serde(untagged)]
+    NBT {
+        #[serde(rename = "nbt", borrow)]
+        path: Cow<'a, str>,
+        #[serde(deserialize_with = "crate::serde::option_bool_lenient", default)]
+        interpret: Option<bool>,
+        #[serde(default, borrow, deserialize_with = "option_separator")]
+        separator: Option<Box<TextComponentDe<'a>>>,
+        #[serde(flatten, borrow)]
+        source: NbtSourceDe<'a>,
+    },
+}
+impl ResolvableDe<'_> {
+    pub fn into_owned(self) -> Resolvable {
+        match self {
+            ResolvableDe::Scoreboard {
+                selector,
+                objective,
+            } => Resolvable::Scoreboard {
+                selector: owned(selector),
+                objective: owned(objective),
+            },
+            ResolvableDe::Entity {
+                selector,
+                separator,
+            } => Resolvable::Entity {
+                selector: owned(selector),
+                separator: match separator {
+                    Some(separator) => Box::new(separator.into_owned()),
+                    None => Resolvable::entity_separator(),
+                },
+            },
+            ResolvableDe::NBT {
+                path,
+                interpret,
+                separator,
+                source,
+            } => Resolvable::NBT {
+                path: owned(path),
+                interpret,
+                separator: match separator {
+                    Some(separator) => Box::new(separator.into_owned()),
+                    None => Resolvable::nbt_separator(),
+                },
+                source: source.into_owned(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NbtSourceDe<'a> {
+    Entity(#[serde(borrow)] Cow<'a, str>),
+    Block(#[serde(borrow)] Cow<'a, str>),
+    Storage(#[serde(borrow)] Cow<'a, str>),
+}
+impl NbtSourceDe<'_> {
+    pub fn into_owned(self) -> NbtSource {
+        match self {
+            NbtSourceDe::Entity(selector) => NbtSource::Entity(owned(selector)),
+            NbtSourceDe::Block(position) => NbtSource::Block(owned(position)),
+            NbtSourceDe::Storage(identifier) => NbtSource::Storage(owned(identifier)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+pub struct FormatDe<'a> {
+    #[serde(default)]
+    pub color: Option<Color>,
+    #[serde(borrow, deserialize_with = "option_cow", default)]
+    pub font: Option<Cow<'a, str>>,
+    #[serde(deserialize_with = "crate::serde::option_bool_lenient", default)]
+    pub bold: Option<bool>,
+    #[serde(deserialize_with = "crate::serde::option_bool_lenient", default)]
+    pub italic: Option<bool>,
+    #[serde(deserialize_with = "crate::serde::option_bool_lenient", default)]
+    pub underlined: Option<bool>,
+    #[serde(deserialize_with = "crate::serde::option_bool_lenient", default)]
+    pub strikethrough: Option<bool>,
+    #[serde(deserialize_with = "crate::serde::option_bool_lenient", default)]
+    pub obfuscated: Option<bool>,
+    #[serde(deserialize_with = "crate::serde::option_shadow_color", default)]
+    pub shadow_color: Option<i64>,
+}
+impl FormatDe<'_> {
+    pub fn into_owned(self) -> Format {
+        Format {
+            color: self.color,
+            font: self.font.map(|font| owned(font).into()),
+            bold: self.bold,
+            italic: self.italic,
+            underlined: self.underlined,
+            strikethrough: self.strikethrough,
+            obfuscated: self.obfuscated,
+            shadow_color: self.shadow_color,
+            no_inherit: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+pub struct InteractivityDe<'a> {
+    #[serde(borrow, deserialize_with = "option_cow", default)]
+    pub insertion: Option<Cow<'a, str>>,
+    #[serde(rename = "click_event", default, borrow)]
+    pub click: Option<ClickEventDe<'a>>,
+    #[serde(rename = "hover_event", default, borrow)]
+    pub hover: Option<HoverEventDe<'a>>,
+}
+impl InteractivityDe<'_> {
+    pub fn into_owned(self) -> Interactivity {
+        Interactivity {
+            insertion: self.insertion.map(|insertion| owned(insertion).into()),
+            click: self.click.map(ClickEventDe::into_owned),
+            hover: self.hover.map(HoverEventDe::into_owned),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ClickEventDe<'a> {
+    OpenUrl {
+        #[serde(borrow)]
+        url: Cow<'a, str>,
+    },
+    RunCommand {
+        #[serde(borrow)]
+        command: Cow<'a, str>,
+    },
+    SuggestCommand {
+        #[serde(borrow)]
+        command: Cow<'a, str>,
+    },
+    ChangePage {
+        page: i32,
+    },
+    CopyToClipboard {
+        #[serde(borrow)]
+        value: Cow<'a, str>,
+    },
+    ShowDialog {
+        dialog: DialogRef,
+    },
+    #[cfg(feature = "custom")]
+    Custom(#[serde(borrow)] CustomDataDe<'a>),
+}
+impl ClickEventDe<'_> {
+    pub fn into_owned(self) -> ClickEvent {
+        match self {
+            ClickEventDe::OpenUrl { url } => ClickEvent::OpenUrl { url: owned(url) },
+            ClickEventDe::RunCommand { command } => ClickEvent::RunCommand {
+                command: owned(command),
+            },
+            ClickEventDe::SuggestCommand { command } => ClickEvent::SuggestCommand {
+                command: owned(command),
+            },
+            ClickEventDe::ChangePage { page } => ClickEvent::ChangePage { page },
+            ClickEventDe::CopyToClipboard { value } => ClickEvent::CopyToClipboard {
+                value: owned(value),
+            },
+            ClickEventDe::ShowDialog { dialog } => ClickEvent::ShowDialog { dialog },
+            #[cfg(feature = "custom")]
+            ClickEventDe::Custom(data) => ClickEvent::Custom(data.into_owned()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum HoverEventDe<'a> {
+    ShowText {
+        #[serde(borrow, deserialize_with = "hover_text")]
+        value: Box<TextComponentDe<'a>>,
+    },
+    ShowItem {
+        #[serde(borrow)]
+        id: Cow<'a, str>,
+        #[serde(default)]
+        count: Option<i32>,
+        #[serde(borrow, deserialize_with = "option_cow", default)]
+        components: Option<Cow<'a, str>>,
+    },
+    ShowEntity {
+        #[serde(default, borrow)]
+        name: Option<Box<TextComponentDe<'a>>>,
+        #[serde(borrow)]
+        id: Cow<'a, str>,
+        uuid: Uuid,
+    },
+}
+impl HoverEventDe<'_> {
+    pub fn into_owned(self) -> HoverEvent {
+        match self {
+            HoverEventDe::ShowText { value } => HoverEvent::ShowText {
+                value: Box::new(value.into_owned()),
+            },
+            HoverEventDe::ShowItem {
+                id,
+                count,
+                components,
+            } => HoverEvent::ShowItem {
+                id: owned(id),
+                count,
+                components: components.map(owned),
+            },
+            HoverEventDe::ShowEntity { name, id, uuid } => HoverEvent::ShowEntity {
+                name: name.map(|name| Box::new(name.into_owned())),
+                id: owned(id),
+                uuid,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "custom")]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CustomDataDe<'a> {
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    #[serde(default)]
+    pub payload: Payload,
+}
+#[cfg(feature = "custom")]
+impl CustomDataDe<'_> {
+    pub fn into_owned(self) -> CustomData {
+        CustomData {
+            id: owned(self.id),
+            payload: self.payload,
+        }
+    }
+}
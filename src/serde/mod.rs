@@ -0,0 +1,601 @@
+pub use crate::CANONICAL_KEY_ORDER;
+#[cfg(feature = "unknown-fields")]
+use crate::unknown::{UnknownFields, UnknownValue, is_preserving, is_unknown};
+use crate::{
+    TextComponent,
+    content::{Object, ObjectPlayer, Resolvable, normalize_keybind},
+    format::{Color, Format, HexColorError, ShadowColorFormat},
+    parse::embedded_or_plain,
+    resolving::TextResolutor,
+};
+use ::serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeMap,
+};
+use serde_json::Value;
+use std::{borrow::Cow, cell::Cell, fmt};
+
+pub mod borrowed;
+
+impl TextComponent {
+    /// Resolves this component and serializes it into a JSON string.
+    /// ## Example
+    /// ```
+    /// # use text_components::{Modifier, resolving::NoResolutor};
+    /// // Results in {"text":"Hello","bold":true}
+    /// "Hello".bold(true).to_json(&NoResolutor)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_json<R: TextResolutor + ?Sized>(&self, resolutor: &R) -> serde_json::Result<String> {
+        serde_json::to_string(&self.resolve(resolutor))
+    }
+    /// Resolves this component and serializes it into a [serde_json::Value].
+    pub fn to_json_value<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+    ) -> serde_json::Result<Value> {
+        serde_json::to_value(self.resolve(resolutor))
+    }
+    /// Serializes this component (without resolving it) into a JSON string that is always
+    /// the same for the same component, for snapshot tests and generated files.\
+    /// Keys are written in the order of [CANONICAL_KEY_ORDER], keys not listed there go
+    /// alphabetically right before the children. Unset values, `interpret:false` and the
+    /// default separators are left out.
+    /// ## Example
+    /// ```
+    /// # use text_components::{Modifier, format::Color};
+    /// // Results in {"text":"Hello","color":"#ff0044","bold":true}
+    /// "Hello".color(Color::Rgb(255, 0, 68)).bold(true).to_canonical_json()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_canonical_json(&self) -> serde_json::Result<String> {
+        let mut value = serde_json::to_value(self)?;
+        strip_defaults(&mut value)?;
+        let mut json = String::new();
+        write_canonical(&value, &mut json)?;
+        Ok(json)
+    }
+    /// Parses a [TextComponent] from a JSON string.
+    /// ## Example
+    /// ```
+    /// # use text_components::TextComponent;
+    /// let component = TextComponent::from_json(r#"{"text":"Hello","bold":true}"#)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_json(json: &str) -> serde_json::Result<TextComponent> {
+        serde_json::from_str(json)
+    }
+    /// Parses a [TextComponent] from a [serde_json::Value].
+    pub fn from_json_value(value: Value) -> serde_json::Result<TextComponent> {
+        TextComponent::deserialize(value)
+    }
+}
+
+/// Position of `extra` in [CANONICAL_KEY_ORDER].
+const CANONICAL_EXTRA: usize = 36;
+
+/// Position of the key in the canonical order, unknown keys go right before the children.
+fn canonical_rank(key: &str) -> usize {
+    match CANONICAL_KEY_ORDER.iter().position(|known| *known == key) {
+        Some(i) if i < CANONICAL_EXTRA => i * 2,
+        Some(i) => i * 2 + 1,
+        None => CANONICAL_EXTRA * 2 - 1,
+    }
+}
+
+fn strip_defaults(value: &mut Value) -> serde_json::Result<()> {
+    match value {
+        Value::Object(map) => {
+            if map.get("interpret") == Some(&Value::Bool(false)) {
+                map.remove("interpret");
+            }
+            let default_separator = match (map.contains_key("selector"), map.contains_key("nbt")) {
+                (true, _) => Some(serde_json::to_value(Resolvable::entity_separator())?),
+                (_, true) => Some(serde_json::to_value(Resolvable::nbt_separator())?),
+                _ => None,
+            };
+            if default_separator.is_some() && map.get("separator") == default_separator.as_ref() {
+                map.remove("separator");
+            }
+            map.values_mut().try_for_each(strip_defaults)
+        }
+        Value::Array(values) => values.iter_mut().try_for_each(strip_defaults),
+        _ => Ok(()),
+    }
+}
+
+fn write_canonical(value: &Value, json: &mut String) -> serde_json::Result<()> {
+    match value {
+        Value::Object(map) => {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries
+                .sort_by(|(a, _), (b, _)| canonical_rank(a).cmp(&canonical_rank(b)).then(a.cmp(b)));
+            json.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                json.push_str(&serde_json::to_string(key)?);
+                json.push(':');
+                write_canonical(value, json)?;
+            }
+            json.push('}');
+        }
+        Value::Array(values) => {
+            json.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                write_canonical(value, json)?;
+            }
+            json.push(']');
+        }
+        value => json.push_str(&serde_json::to_string(value)?),
+    }
+    Ok(())
+}
+
+/// Deserializes a [bool] the same way vanilla does, accepting `true`/`false`,
+/// `0`/`1` (or any other number, non-zero being `true`) and the strings
+/// `"true"`, `"false"`, `"1b"` or `"0b"` left behind by NBT conversions.
+pub fn bool_lenient<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    deserializer.deserialize_any(LenientBool)
+}
+
+/// Same as [bool_lenient] for optional fields, `null` becomes [None].
+pub fn option_bool_lenient<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<bool>, D::Error> {
+    deserializer.deserialize_option(OptionLenientBool)
+}
+
+pub(crate) fn default_true() -> bool {
+    true
+}
+
+struct LenientBool;
+impl<'de> Visitor<'de> for LenientBool {
+    type Value = bool;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a boolean, a number or a boolean string")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<bool, E> {
+        Ok(v)
+    }
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<bool, E> {
+        Ok(v != 0)
+    }
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<bool, E> {
+        Ok(v != 0)
+    }
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<bool, E> {
+        Ok(v != 0.)
+    }
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<bool, E> {
+        match v.trim() {
+            "true" | "1" | "1b" | "1B" => Ok(true),
+            "false" | "0" | "0b" | "0B" => Ok(false),
+            _ => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+        }
+    }
+}
+
+struct OptionLenientBool;
+impl<'de> Visitor<'de> for OptionLenientBool {
+    type Value = Option<bool>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an optional boolean, number or boolean string")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Option<bool>, E> {
+        Ok(None)
+    }
+    fn visit_unit<E: de::Error>(self) -> Result<Option<bool>, E> {
+        Ok(None)
+    }
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Option<bool>, D::Error> {
+        bool_lenient(deserializer).map(Some)
+    }
+}
+
+impl Serialize for Object {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        match self {
+            Object::Atlas { atlas, sprite } => {
+                map.serialize_entry("object", "atlas")?;
+                if let Some(atlas) = atlas {
+                    map.serialize_entry("atlas", atlas)?;
+                }
+                map.serialize_entry("sprite", sprite)?;
+            }
+            Object::Player { player, hat } => {
+                map.serialize_entry("object", "player")?;
+                map.serialize_entry("player", player)?;
+                if !hat {
+                    map.serialize_entry("hat", hat)?;
+                }
+            }
+        }
+        map.end()
+    }
+}
+
+/// The keys of an [Object], which one it is comes from its `object` key
+/// or, when it's missing, from the `player` key being there or not.
+#[derive(Deserialize)]
+struct ObjectKeys {
+    #[serde(default)]
+    object: Option<String>,
+    #[serde(default)]
+    atlas: Option<Cow<'static, str>>,
+    #[serde(default)]
+    sprite: Option<Cow<'static, str>>,
+    #[serde(default)]
+    player: Option<Profile>,
+    #[serde(deserialize_with = "option_bool_lenient", default)]
+    hat: Option<bool>,
+}
+
+/// The `player` of a head, given as a whole profile or just the name of the player.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Profile {
+    Name(Cow<'static, str>),
+    Profile(ObjectPlayer),
+}
+impl From<Profile> for ObjectPlayer {
+    fn from(profile: Profile) -> Self {
+        match profile {
+            Profile::Name(name) => ObjectPlayer::name(name),
+            Profile::Profile(player) => player,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Object {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let keys = ObjectKeys::deserialize(deserializer)?;
+        match object_kind(keys.object.as_deref(), keys.player.is_some())? {
+            ObjectKind::Atlas => Ok(Object::Atlas {
+                atlas: keys.atlas,
+                sprite: keys
+                    .sprite
+                    .ok_or_else(|| de::Error::missing_field("sprite"))?,
+            }),
+            ObjectKind::Player => Ok(Object::Player {
+                player: keys
+                    .player
+                    .ok_or_else(|| de::Error::missing_field("player"))?
+                    .into(),
+                hat: keys.hat.unwrap_or(true),
+            }),
+        }
+    }
+}
+
+pub(crate) enum ObjectKind {
+    Atlas,
+    Player,
+}
+
+pub(crate) fn object_kind<E: de::Error>(
+    object: Option<&str>,
+    player: bool,
+) -> Result<ObjectKind, E> {
+    match object {
+        Some("atlas") => Ok(ObjectKind::Atlas),
+        Some("player") => Ok(ObjectKind::Player),
+        Some(object) => Err(E::unknown_variant(object, &["atlas", "player"])),
+        None if player => Ok(ObjectKind::Player),
+        None => Ok(ObjectKind::Atlas),
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Color::Rgb(r, g, b) => serializer.serialize_str(&format!("#{r:02x}{g:02x}{b:02x}")),
+            color => serializer.collect_str(color),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ColorVisitor)
+    }
+}
+
+struct ColorVisitor;
+impl<'de> Visitor<'de> for ColorVisitor {
+    type Value = Color;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a color name or a #rrggbb hex color")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Color, E> {
+        if let Some(color) = Color::from_name(v) {
+            return Ok(color);
+        }
+        match Color::parse_hex(v) {
+            Ok(color) => Ok(color),
+            Err(error @ HexColorError::Argb(_)) => Err(E::custom(error)),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+        }
+    }
+}
+
+thread_local! {
+    static SHADOW_COLORS: Cell<ShadowColorFormat> = const { Cell::new(ShadowColorFormat::Packed) };
+}
+
+/// Serializes the value with its shadow colors in the given form, instead of the
+/// [packed](ShadowColorFormat::Packed) one.
+/// ## Example
+/// ```
+/// # use text_components::{Modifier, format::ShadowColorFormat, serde::WithShadowColors};
+/// let component = "Hi".shadow_color(255, 255, 0, 0);
+/// // {"text":"Hi","shadow_color":[1.0,0.0,0.0,1.0]}
+/// serde_json::to_string(&WithShadowColors(ShadowColorFormat::Floats, &component))?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct WithShadowColors<'a, T: ?Sized>(pub ShadowColorFormat, pub &'a T);
+
+impl<T: Serialize + ?Sized> Serialize for WithShadowColors<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // The derived impls can't be handed the form, so it's only set while this serializes
+        let previous = SHADOW_COLORS.replace(self.0);
+        let serialized = self.1.serialize(serializer);
+        SHADOW_COLORS.set(previous);
+        serialized
+    }
+}
+
+/// Writes a shadow color in the form [WithShadowColors] asks for, packed otherwise.
+pub(crate) fn serialize_shadow_color<S: Serializer>(
+    color: &Option<i64>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match (color, SHADOW_COLORS.get()) {
+        (Some(color), ShadowColorFormat::Floats) => {
+            Format::shadow_color_floats(*color).serialize(serializer)
+        }
+        (color, _) => color.serialize(serializer),
+    }
+}
+
+/// Reads a shadow color either as a packed ARGB int or a `[r, g, b, a]` float list.
+pub(crate) fn option_shadow_color<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<i64>, D::Error> {
+    deserializer.deserialize_option(OptionShadowColor)
+}
+
+struct ShadowColor;
+impl<'de> Visitor<'de> for ShadowColor {
+    type Value = i64;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a packed ARGB int or a list of 4 floats")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<i64, E> {
+        Ok(Format::packed_shadow_color(v))
+    }
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<i64, E> {
+        i64::try_from(v)
+            .map(Format::packed_shadow_color)
+            .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<i64, A::Error> {
+        let mut channels = [0f32; 4];
+        for (i, channel) in channels.iter_mut().enumerate() {
+            *channel = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<f32>()?.is_some() {
+            return Err(de::Error::invalid_length(5, &self));
+        }
+        let [r, g, b, a] = channels;
+        Ok(Format::float_shadow_color(r, g, b, a))
+    }
+}
+
+struct OptionShadowColor;
+impl<'de> Visitor<'de> for OptionShadowColor {
+    type Value = Option<i64>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an optional packed ARGB int or list of 4 floats")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Option<i64>, E> {
+        Ok(None)
+    }
+    fn visit_unit<E: de::Error>(self) -> Result<Option<i64>, E> {
+        Ok(None)
+    }
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Option<i64>, D::Error> {
+        deserializer.deserialize_any(ShadowColor).map(Some)
+    }
+}
+
+// Vanilla nests the score data under a "score" key, as an externally tagged
+// variant serde can't read it back once other component keys sit beside it.
+/// A component that may also be given as a plain string or a list of components.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Flexible {
+    Text(String),
+    List(Vec<Flexible>),
+    Component(Box<TextComponent>),
+}
+impl From<Flexible> for TextComponent {
+    fn from(value: Flexible) -> Self {
+        match value {
+            Flexible::Text(text) => TextComponent::plain(text),
+            Flexible::List(list) => {
+                let mut list = list.into_iter().map(TextComponent::from);
+                let mut first = list.next().unwrap_or_default();
+                first.children.extend(list);
+                first
+            }
+            Flexible::Component(component) => *component,
+        }
+    }
+}
+
+pub(crate) fn deserialize_separator<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Box<TextComponent>, D::Error> {
+    Flexible::deserialize(deserializer).map(|separator| match separator {
+        Flexible::Component(component) => component,
+        separator => Box::new(separator.into()),
+    })
+}
+
+/// Hover texts may also be given as a string, holding text or an embedded component.
+pub(crate) fn deserialize_hover_text<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Box<TextComponent>, D::Error> {
+    Ok(Box::new(match Flexible::deserialize(deserializer)? {
+        Flexible::Text(text) => embedded_or_plain(text),
+        value => value.into(),
+    }))
+}
+
+/// Translation arguments may also be given as plain strings.
+pub(crate) fn deserialize_args<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Box<[TextComponent]>>, D::Error> {
+    Ok(Option::<Vec<Flexible>>::deserialize(deserializer)?
+        .map(|args| args.into_iter().map(TextComponent::from).collect()))
+}
+
+/// Plain children are written as bare strings, like the game does.
+pub(crate) fn serialize_children<S: Serializer>(
+    children: &[TextComponent],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    struct Child<'a>(&'a TextComponent);
+    impl Serialize for Child<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self.0.as_text() {
+                Some(text) if self.0.is_plain() => serializer.serialize_str(text),
+                _ => self.0.serialize(serializer),
+            }
+        }
+    }
+    serializer.collect_seq(children.iter().map(Child))
+}
+
+/// Children may also be given as plain strings.
+pub(crate) fn deserialize_children<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<TextComponent>, D::Error> {
+    Ok(Vec::<Flexible>::deserialize(deserializer)?
+        .into_iter()
+        .map(TextComponent::from)
+        .collect())
+}
+
+/// The unknown keys are written next to the known ones, only the JSON values are.
+#[cfg(feature = "unknown-fields")]
+pub(crate) fn serialize_unknown<S: Serializer>(
+    fields: &Option<Box<UnknownFields>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let fields = fields.iter().flat_map(|fields| &fields.0);
+    serializer.collect_map(fields.filter_map(|(key, value)| match value {
+        UnknownValue::Json(value) => Some((key, value)),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }))
+}
+
+/// Gets every key of the component, keeping the unknown ones when preserving them.
+#[cfg(feature = "unknown-fields")]
+pub(crate) fn deserialize_unknown<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Box<UnknownFields>>, D::Error> {
+    struct UnknownVisitor;
+    impl<'de> Visitor<'de> for UnknownVisitor {
+        type Value = Option<Box<UnknownFields>>;
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("the keys of a component")
+        }
+        fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let preserving = is_preserving();
+            let mut fields = vec![];
+            while let Some(key) = map.next_key::<String>()? {
+                if preserving && is_unknown(&key) {
+                    fields.push((key, UnknownValue::Json(map.next_value()?)));
+                } else {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+            Ok((!fields.is_empty()).then(|| Box::new(UnknownFields(fields))))
+        }
+    }
+    deserializer.deserialize_map(UnknownVisitor)
+}
+
+pub(crate) fn deserialize_keybind<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Cow<'static, str>, D::Error> {
+    Ok(normalize_keybind(Cow::Owned(String::deserialize(
+        deserializer,
+    )?)))
+}
+
+#[derive(Serialize, Deserialize)]
+struct ScoreKey<'a> {
+    #[serde(borrow)]
+    score: Score<'a>,
+}
+#[derive(Serialize, Deserialize)]
+struct Score<'a> {
+    #[serde(borrow)]
+    name: Cow<'a, str>,
+    #[serde(borrow)]
+    objective: Cow<'a, str>,
+}
+
+pub(crate) fn serialize_score<S: Serializer>(
+    selector: &str,
+    objective: &str,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    ScoreKey {
+        score: Score {
+            name: Cow::Borrowed(selector),
+            objective: Cow::Borrowed(objective),
+        },
+    }
+    .serialize(serializer)
+}
+
+pub(crate) fn deserialize_score<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<(Cow<'de, str>, Cow<'de, str>), D::Error> {
+    let ScoreKey { score } = ScoreKey::deserialize(deserializer)?;
+    Ok((score.name, score.objective))
+}
+
+pub(crate) fn deserialize_score_owned<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<(Cow<'static, str>, Cow<'static, str>), D::Error> {
+    let (selector, objective) = deserialize_score(deserializer)?;
+    Ok((
+        Cow::Owned(selector.into_owned()),
+        Cow::Owned(objective.into_owned()),
+    ))
+}
@@ -0,0 +1,132 @@
+//! Quick introspection of a [TextComponent] tree, for rate limiting and debugging.
+use crate::{
+    TextComponent,
+    content::{Content, Resolvable},
+    interactivity::{ClickEvent, HoverEvent},
+};
+
+/// Counts gathered by [TextComponent::stats].
+///
+/// Every nested component is taken into account, not only the children:
+/// translation arguments, resolvable separators and the components
+/// shown by hover events count as nodes too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ComponentStats {
+    /// Amount of components in the tree, the root included.
+    pub node_count: usize,
+    /// Deepest level of nesting, a component without nested ones has a depth of 1.
+    pub max_depth: usize,
+    /// Sum of the bytes of every text content.
+    pub text_bytes: usize,
+    pub click_events: usize,
+    pub hover_events: usize,
+    pub translations: usize,
+    pub resolvables: usize,
+    /// Custom contents, always 0 without the `custom` feature.
+    pub customs: usize,
+}
+
+impl TextComponent {
+    /// Walks the whole tree once and counts its nodes, depth, text and events.
+    /// ## Example
+    /// ```
+    /// # use text_components::{Modifier, interactivity::HoverEvent};
+    /// let stats = "Hello".hover_event(HoverEvent::show_text("World")).stats();
+    /// assert_eq!(stats.node_count, 2);
+    /// assert_eq!(stats.max_depth, 2);
+    /// ```
+    pub fn stats(&self) -> ComponentStats {
+        let mut stats = ComponentStats::default();
+        self.add_stats(&mut stats, 1);
+        stats
+    }
+
+    fn add_stats(&self, stats: &mut ComponentStats, depth: usize) {
+        stats.node_count += 1;
+        stats.max_depth = stats.max_depth.max(depth);
+        match &self.content {
+            Content::Text { text } => stats.text_bytes += text.len(),
+            Content::Translate(_) => stats.translations += 1,
+            Content::Resolvable(_) => stats.resolvables += 1,
+            #[cfg(feature = "custom")]
+            Content::Custom(_) => stats.customs += 1,
+            _ => (),
+        }
+        if self.interactions.click.is_some() {
+            stats.click_events += 1;
+        }
+        if self.interactions.hover.is_some() {
+            stats.hover_events += 1;
+        }
+        self.for_each_nested(|nested| nested.add_stats(stats, depth + 1));
+    }
+
+    /// Whether any click event in the tree matches `f`.
+    /// ## Example
+    /// ```
+    /// # use text_components::{TextComponent, interactivity::ClickEvent};
+    /// # let component = TextComponent::new();
+    /// // Refuse forwarding components that would run commands
+    /// if component.contains_click_action(|click| matches!(click, ClickEvent::RunCommand { .. })) {
+    ///     return;
+    /// }
+    /// ```
+    pub fn contains_click_action(&self, f: impl Fn(&ClickEvent) -> bool) -> bool {
+        self.any_nested(&f)
+    }
+
+    fn any_nested(&self, f: &impl Fn(&ClickEvent) -> bool) -> bool {
+        if let Some(click) = &self.interactions.click
+            && f(click)
+        {
+            return true;
+        }
+        let mut found = false;
+        self.for_each_nested(|nested| found = found || nested.any_nested(f));
+        found
+    }
+
+    /// Calls `f` with every component nested directly inside this one:
+    /// translation arguments, resolvable separators, hover texts and children.
+    pub(crate) fn for_each_nested<'a>(&'a self, mut f: impl FnMut(&'a TextComponent)) {
+        match &self.content {
+            Content::Translate(message) => {
+                if let Some(args) = &message.args {
+                    args.iter().for_each(&mut f);
+                }
+            }
+            Content::Resolvable(Resolvable::Entity { separator, .. })
+            | Content::Resolvable(Resolvable::NBT { separator, .. }) => f(separator),
+            _ => (),
+        }
+        match &self.interactions.hover {
+            Some(HoverEvent::ShowText { value }) => f(value),
+            Some(HoverEvent::ShowEntity {
+                name: Some(name), ..
+            }) => f(name),
+            _ => (),
+        }
+        self.children.iter().for_each(f);
+    }
+
+    pub(crate) fn for_each_nested_mut(&mut self, mut f: impl FnMut(&mut TextComponent)) {
+        match &mut self.content {
+            Content::Translate(message) => {
+                if let Some(args) = &mut message.args {
+                    args.iter_mut().for_each(&mut f);
+                }
+            }
+            Content::Resolvable(Resolvable::Entity { separator, .. })
+            | Content::Resolvable(Resolvable::NBT { separator, .. }) => f(separator),
+            _ => (),
+        }
+        match &mut self.interactions.hover {
+            Some(HoverEvent::ShowText { value }) => f(value),
+            Some(HoverEvent::ShowEntity {
+                name: Some(name), ..
+            }) => f(name),
+            _ => (),
+        }
+        self.children.iter_mut().for_each(f);
+    }
+}
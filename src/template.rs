@@ -0,0 +1,178 @@
+//! Messages with named placeholders, filled with components when sent.
+//! ```
+//! # use std::collections::HashMap;
+//! # use text_components::{Modifier, TextComponent, format::Color, template::ComponentTemplate};
+//! # struct Player;
+//! # impl Player {
+//! #     fn display_name(&self) -> TextComponent {
+//! #         "Steve".into()
+//! #     }
+//! # }
+//! # let (player, online) = (Player, 3);
+//! let joined = ComponentTemplate::new("{prefix} {player} joined ({count} online)");
+//! let values = HashMap::from([
+//!     ("prefix", "[+]".color(Color::Green)),
+//!     ("player", player.display_name()),
+//!     ("count", TextComponent::plain(online.to_string())),
+//! ]);
+//! let message = joined.render(&values)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+use crate::{TextComponent, content::Content};
+use std::{borrow::Cow, collections::HashMap, error::Error, fmt};
+
+/// A component with `{name}` placeholders in its texts, `{{` and `}}` write the braces.\
+/// Placeholders are looked for in the texts of the component and its children, values
+/// take the place of the placeholder as a child of its component, inheriting its format.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ComponentTemplate {
+    tree: TextComponent,
+    slots: Vec<(Vec<usize>, Cow<'static, str>)>,
+}
+
+impl ComponentTemplate {
+    /// Creates a template from plain text.
+    pub fn new<T: Into<Cow<'static, str>>>(template: T) -> Self {
+        ComponentTemplate::from_component(TextComponent::plain(template))
+    }
+
+    /// Creates a template looking for placeholders in the texts of an already built or
+    /// parsed component, so styled templates keep their format around the values.
+    /// ## Example
+    /// ```
+    /// # use text_components::{Modifier, format::Color, template::ComponentTemplate};
+    /// // The player name will be gold and bold unless it sets them itself
+    /// let template = ComponentTemplate::from_component(
+    ///     "Welcome ".add_child("{player}".color(Color::Gold).bold(true)),
+    /// );
+    /// ```
+    pub fn from_component(component: TextComponent) -> Self {
+        let mut template = ComponentTemplate {
+            tree: component,
+            slots: vec![],
+        };
+        split_placeholders(&mut template.tree, &mut vec![], &mut template.slots);
+        template
+    }
+
+    /// The names of the placeholders in order of appearance, repeated ones included.
+    pub fn placeholders(&self) -> impl Iterator<Item = &str> {
+        self.slots.iter().map(|(_, name)| name.as_ref())
+    }
+
+    /// Fills every placeholder with its value.
+    pub fn render(
+        &self,
+        values: &HashMap<&str, TextComponent>,
+    ) -> Result<TextComponent, MissingPlaceholder> {
+        let mut component = self.tree.clone();
+        for (path, name) in &self.slots {
+            let value = values
+                .get(name.as_ref())
+                .ok_or_else(|| MissingPlaceholder(name.to_string()))?;
+            let mut slot = &mut component;
+            for &index in path {
+                slot = &mut slot.children[index];
+            }
+            *slot = value.clone();
+        }
+        Ok(component)
+    }
+}
+
+impl From<TextComponent> for ComponentTemplate {
+    fn from(value: TextComponent) -> Self {
+        ComponentTemplate::from_component(value)
+    }
+}
+
+/// A placeholder of a [ComponentTemplate] had no value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MissingPlaceholder(pub String);
+
+impl fmt::Display for MissingPlaceholder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No value given for the placeholder {{{}}}", self.0)
+    }
+}
+
+impl Error for MissingPlaceholder {}
+
+enum Segment {
+    Text(String),
+    Placeholder(String),
+}
+
+/// Moves the segments of a text with placeholders into children, in front of the others.
+fn split_placeholders(
+    component: &mut TextComponent,
+    path: &mut Vec<usize>,
+    slots: &mut Vec<(Vec<usize>, Cow<'static, str>)>,
+) {
+    let mut inserted = 0;
+    if let Content::Text { text } = &component.content
+        && text.contains(['{', '}'])
+    {
+        let mut segments = segments(text);
+        if let [Segment::Text(text)] = segments.as_mut_slice() {
+            // Only escaped braces
+            component.content = Content::Text {
+                text: Cow::Owned(std::mem::take(text)),
+            };
+            segments.clear();
+        } else {
+            component.content = Content::Text {
+                text: Cow::Borrowed(""),
+            };
+        }
+        inserted = segments.len();
+        let children = segments
+            .into_iter()
+            .enumerate()
+            .map(|(i, segment)| match segment {
+                Segment::Text(text) => TextComponent::plain(text),
+                Segment::Placeholder(name) => {
+                    let mut slot = path.clone();
+                    slot.push(i);
+                    slots.push((slot, Cow::Owned(name)));
+                    TextComponent::new()
+                }
+            })
+            .collect::<Vec<_>>();
+        component.children.splice(0..0, children);
+    }
+    for (i, child) in component.children.iter_mut().enumerate().skip(inserted) {
+        path.push(i);
+        split_placeholders(child, path, slots);
+        path.pop();
+    }
+}
+
+/// Splits a text into literals and placeholders, braces not making a placeholder are
+/// kept as they are.
+fn segments(text: &str) -> Vec<Segment> {
+    let mut segments = vec![];
+    let mut literal = String::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '{' if chars.next_if(|(_, c)| *c == '{').is_some() => literal.push('{'),
+            '}' if chars.next_if(|(_, c)| *c == '}').is_some() => literal.push('}'),
+            '{' => match text[i + 1..].find(['{', '}']) {
+                Some(len) if len > 0 && text[i + 1 + len..].starts_with('}') => {
+                    if !literal.is_empty() {
+                        segments.push(Segment::Text(std::mem::take(&mut literal)));
+                    }
+                    segments.push(Segment::Placeholder(text[i + 1..i + 1 + len].to_string()));
+                    while chars.next_if(|(j, _)| *j <= i + 1 + len).is_some() {}
+                }
+                _ => literal.push('{'),
+            },
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(Segment::Text(literal));
+    }
+    segments
+}
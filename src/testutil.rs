@@ -0,0 +1,651 @@
+//! [proptest] strategies and a semantic equality to check that components
+//! survive a trip through the serialization formats.
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # fn main() {
+//! # use proptest::proptest;
+//! # use text_components::{TextComponent, testutil::{self, KeepResolutor}};
+//! proptest!(|(component in testutil::component())| {
+//!     let json = component.to_json(&KeepResolutor).unwrap();
+//!     testutil::assert_semantic_eq(&component, &TextComponent::from_json(&json).unwrap());
+//! });
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
+//! ```
+//! [TestResolutor] resolves everything from in-memory tables, giving stable renderings
+//! to compare against golden files.\
+//! The fixtures at the end are built without randomness, the benchmarks and the tests
+//! measure and check the same trees.
+#[cfg(feature = "custom")]
+use crate::custom::{CustomData, Payload};
+use crate::{
+    Modifier, TextComponent,
+    content::{Content, NbtSource, Object, ObjectPlayer, PlayerProperties, Resolvable},
+    format::{Color, Format},
+    interactivity::{ClickEvent, HoverEvent, Interactivity},
+    resolving::{NoResolutor, TextResolutor, resolve_nbt_values},
+    translation::{TranslatedMessage, TranslationTable},
+};
+use proptest::{collection::vec, option, prelude::*};
+use std::{borrow::Cow, collections::HashMap, sync::Arc};
+use uuid::Uuid;
+
+/// A [TextResolutor] that leaves every component as it is,
+/// so resolvable and custom contents reach the serializers untouched.
+pub struct KeepResolutor;
+impl TextResolutor for KeepResolutor {
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        TextComponent::from(resolvable.clone())
+    }
+
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, data: &CustomData) -> Option<TextComponent> {
+        Some(TextComponent::from(data.clone()))
+    }
+
+    fn translate(&self, _key: &str) -> Option<Cow<'_, str>> {
+        None
+    }
+}
+
+/// A [TextResolutor] over in-memory scores, entities, NBT values, translations and
+/// custom contents, anything missing resolves like [NoResolutor].
+/// ## Example
+/// ```
+/// # use text_components::{TextComponent, testutil::TestResolutor};
+/// # use uuid::Uuid;
+/// let resolutor = TestResolutor::new()
+///     .score("@p", "kills", 5)
+///     .entity("@p", "Steve", Uuid::nil())
+///     .translation("greeting", "Hello %s!");
+/// // "Hello Steve!"
+/// TextComponent::translated_with("greeting", [TextComponent::entity("@p", None)])
+///     .to_plain(&resolutor);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TestResolutor {
+    pub scores: HashMap<(String, String), i64>,
+    /// The entities each selector matches, in order.
+    pub entities: HashMap<String, Vec<TestEntity>>,
+    /// The values found at each path of a source, as SNBT.
+    pub nbt: HashMap<(NbtSource, String), Vec<String>>,
+    pub translations: HashMap<String, String>,
+    /// What each custom content resolves to, by id.
+    #[cfg(feature = "custom")]
+    pub custom: HashMap<String, TextComponent>,
+}
+
+/// An entity matched by a selector of a [TestResolutor].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestEntity {
+    pub name: String,
+    /// The id of the entity's type, like `minecraft:player`.
+    pub id: String,
+    pub uuid: Uuid,
+}
+
+impl TestEntity {
+    /// The component the game shows for the entity: its name, inserted on shift click and
+    /// showing the entity on hover.
+    pub fn component(&self) -> TextComponent {
+        TextComponent::plain(self.name.clone())
+            .insertion(self.name.clone())
+            .hover_event(HoverEvent::show_entity(
+                self.id.clone(),
+                self.uuid,
+                Some(self.name.clone()),
+            ))
+    }
+}
+
+impl TestResolutor {
+    pub fn new() -> Self {
+        TestResolutor::default()
+    }
+
+    /// Sets the score of the selector in the objective.
+    pub fn score<S: Into<String>, O: Into<String>>(
+        mut self,
+        selector: S,
+        objective: O,
+        value: i64,
+    ) -> Self {
+        self.scores
+            .insert((selector.into(), objective.into()), value);
+        self
+    }
+
+    /// Adds a player to the entities the selector matches.
+    pub fn entity<S: Into<String>, N: Into<String>>(
+        self,
+        selector: S,
+        name: N,
+        uuid: Uuid,
+    ) -> Self {
+        self.entity_of(selector, "minecraft:player", name, uuid)
+    }
+
+    /// Adds an entity of the type to the entities the selector matches.
+    pub fn entity_of<S: Into<String>, I: Into<String>, N: Into<String>>(
+        mut self,
+        selector: S,
+        id: I,
+        name: N,
+        uuid: Uuid,
+    ) -> Self {
+        self.entities
+            .entry(selector.into())
+            .or_default()
+            .push(TestEntity {
+                name: name.into(),
+                id: id.into(),
+                uuid,
+            });
+        self
+    }
+
+    /// Sets the SNBT values found at the path of the source.
+    pub fn nbt<P: Into<String>, V: Into<String>>(
+        mut self,
+        source: NbtSource,
+        path: P,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        self.nbt.insert(
+            (source, path.into()),
+            values.into_iter().map(Into::into).collect(),
+        );
+        self
+    }
+
+    /// Sets the translated text of the key.
+    pub fn translation<K: Into<String>, T: Into<String>>(mut self, key: K, text: T) -> Self {
+        self.translations.insert(key.into(), text.into());
+        self
+    }
+
+    /// Sets what the custom contents with the id resolve to.
+    #[cfg(feature = "custom")]
+    pub fn custom<I: Into<String>, T: Into<TextComponent>>(mut self, id: I, component: T) -> Self {
+        self.custom.insert(id.into(), component.into());
+        self
+    }
+}
+
+impl TextResolutor for TestResolutor {
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        match resolvable {
+            Resolvable::Scoreboard {
+                selector,
+                objective,
+            } => {
+                if let Some(value) = self
+                    .scores
+                    .get(&(selector.to_string(), objective.to_string()))
+                {
+                    return TextComponent::plain(value.to_string());
+                }
+            }
+            Resolvable::Entity { selector, .. } => {
+                if let Some(entities) = self.entities.get(selector.as_ref()) {
+                    return resolvable
+                        .join_resolved(entities.iter().map(TestEntity::component).collect());
+                }
+            }
+            Resolvable::NBT { path, source, .. } => {
+                if let Some(values) = self.nbt.get(&(source.clone(), path.to_string())) {
+                    return resolve_nbt_values(values.clone(), resolvable);
+                }
+            }
+        }
+        NoResolutor.resolve_content(resolvable)
+    }
+
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, data: &CustomData) -> Option<TextComponent> {
+        match self.custom.get(data.id.as_ref()) {
+            Some(component) => Some(component.clone()),
+            None => NoResolutor.resolve_custom(data),
+        }
+    }
+
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>> {
+        self.translations
+            .get(key)
+            .map(|text| Cow::Borrowed(text.as_str()))
+    }
+}
+
+fn string() -> impl Strategy<Value = Cow<'static, str>> {
+    "\\PC{0,12}".prop_map(Cow::Owned)
+}
+fn non_empty_string() -> impl Strategy<Value = Cow<'static, str>> {
+    "\\PC{1,12}".prop_map(Cow::Owned)
+}
+fn identifier() -> impl Strategy<Value = Cow<'static, str>> {
+    "([a-z_]{1,8}:)?[a-z_/]{1,12}".prop_map(Cow::Owned)
+}
+fn keybind() -> impl Strategy<Value = Cow<'static, str>> {
+    "key\\.[a-z_.]{1,12}".prop_map(Cow::Owned)
+}
+
+pub fn color() -> impl Strategy<Value = Color> {
+    prop_oneof![
+        Just(Color::Aqua),
+        Just(Color::Black),
+        Just(Color::Blue),
+        Just(Color::DarkAqua),
+        Just(Color::DarkBlue),
+        Just(Color::DarkGray),
+        Just(Color::DarkGreen),
+        Just(Color::DarkPurple),
+        Just(Color::DarkRed),
+        Just(Color::Gold),
+        Just(Color::Gray),
+        Just(Color::Green),
+        Just(Color::LightPurple),
+        Just(Color::Red),
+        Just(Color::White),
+        Just(Color::Yellow),
+        any::<(u8, u8, u8)>().prop_map(|(r, g, b)| Color::Rgb(r, g, b)),
+    ]
+}
+
+pub fn format() -> impl Strategy<Value = Format> {
+    (
+        option::of(color()),
+        option::of("[a-z_]{1,8}:[a-z_/]{1,12}"),
+        any::<[Option<bool>; 5]>(),
+        option::of(any::<u32>()),
+    )
+        .prop_map(|(color, font, flags, shadow_color)| Format {
+            color,
+            font: font.map(Into::into),
+            bold: flags[0],
+            italic: flags[1],
+            underlined: flags[2],
+            strikethrough: flags[3],
+            obfuscated: flags[4],
+            shadow_color: shadow_color.map(|color| color as i64),
+            no_inherit: false,
+        })
+}
+
+pub fn click_event() -> impl Strategy<Value = ClickEvent> {
+    prop_oneof![
+        string().prop_map(|url| ClickEvent::OpenUrl { url }),
+        string().prop_map(|command| ClickEvent::RunCommand { command }),
+        string().prop_map(|command| ClickEvent::SuggestCommand { command }),
+        any::<i32>().prop_map(|page| ClickEvent::ChangePage { page }),
+        string().prop_map(|value| ClickEvent::CopyToClipboard { value }),
+        identifier().prop_map(|dialog| ClickEvent::ShowDialog {
+            dialog: dialog.into()
+        }),
+    ]
+}
+
+/// Hover events whose text is generated by `component`.
+pub fn hover_event<S: Strategy<Value = TextComponent> + Clone + 'static>(
+    component: S,
+) -> impl Strategy<Value = HoverEvent> {
+    prop_oneof![
+        component.clone().prop_map(HoverEvent::show_text),
+        (identifier(), option::of(any::<i32>()), option::of(string())).prop_map(
+            |(id, count, components)| HoverEvent::ShowItem {
+                id,
+                count,
+                components,
+            }
+        ),
+        (
+            option::of(component),
+            identifier(),
+            any::<u128>().prop_filter("a nil uuid", |uuid| *uuid != 0)
+        )
+            .prop_map(|(name, id, uuid)| HoverEvent::ShowEntity {
+                name: name.map(Box::new),
+                id,
+                uuid: Uuid::from_u128(uuid),
+            }),
+    ]
+}
+
+pub fn interactivity<S: Strategy<Value = TextComponent> + Clone + 'static>(
+    component: S,
+) -> impl Strategy<Value = Interactivity> {
+    (
+        option::of(string()),
+        option::of(click_event()),
+        option::of(hover_event(component)),
+    )
+        .prop_map(|(insertion, click, hover)| Interactivity {
+            insertion: insertion.map(Into::into),
+            click,
+            hover,
+        })
+}
+
+pub fn object() -> impl Strategy<Value = Object> {
+    let property = (non_empty_string(), non_empty_string(), option::of(string())).prop_map(
+        |(name, value, signature)| PlayerProperties {
+            name,
+            value,
+            signature,
+        },
+    );
+    let player = (
+        option::of(non_empty_string()),
+        option::of(any::<[i32; 4]>()),
+        option::of(identifier()),
+        vec(property, 0..3),
+    )
+        .prop_map(|(name, id, texture, properties)| ObjectPlayer {
+            name,
+            id,
+            texture,
+            properties,
+        })
+        .prop_filter("an empty player", |player| !player.is_empty());
+    prop_oneof![
+        (option::of(identifier()), identifier())
+            .prop_map(|(atlas, sprite)| Object::Atlas { atlas, sprite }),
+        (player, any::<bool>()).prop_map(|(player, hat)| Object::Player { player, hat }),
+    ]
+}
+
+fn translated<S: Strategy<Value = TextComponent>>(
+    component: S,
+) -> impl Strategy<Value = TranslatedMessage> {
+    (
+        identifier(),
+        option::of(string()),
+        option::of(vec(component, 1..4)),
+    )
+        .prop_map(|(key, fallback, args)| TranslatedMessage {
+            key: key.into(),
+            fallback,
+            args: args.map(Vec::into_boxed_slice),
+        })
+}
+
+/// Contents that are left after [resolution](TextComponent::resolve).
+pub fn resolved_content<S: Strategy<Value = TextComponent>>(
+    component: S,
+) -> impl Strategy<Value = Content> {
+    prop_oneof![
+        string().prop_map(|text| Content::Text { text }),
+        translated(component).prop_map(Content::Translate),
+        keybind().prop_map(|keybind| Content::Keybind { keybind }),
+        object().prop_map(Content::Object),
+    ]
+}
+
+pub fn resolvable<S: Strategy<Value = TextComponent> + Clone>(
+    component: S,
+) -> impl Strategy<Value = Resolvable> {
+    let source = prop_oneof![
+        non_empty_string().prop_map(NbtSource::Entity),
+        any::<(i32, i32, i32)>().prop_map(|(x, y, z)| NbtSource::block(x, y, z)),
+        identifier().prop_map(NbtSource::Storage),
+    ];
+    prop_oneof![
+        (non_empty_string(), identifier()).prop_map(|(selector, objective)| {
+            Resolvable::Scoreboard {
+                selector,
+                objective,
+            }
+        }),
+        (non_empty_string(), component.clone()).prop_map(|(selector, separator)| {
+            Resolvable::Entity {
+                selector,
+                separator: Box::new(separator),
+            }
+        }),
+        (
+            non_empty_string(),
+            option::of(any::<bool>()),
+            component,
+            source
+        )
+            .prop_map(|(path, interpret, separator, source)| Resolvable::NBT {
+                path,
+                interpret,
+                separator: Box::new(separator),
+                source,
+            }),
+    ]
+}
+
+/// Every kind of content, including the ones that need resolution.
+pub fn content<S: Strategy<Value = TextComponent> + Clone>(
+    component: S,
+) -> impl Strategy<Value = Content> {
+    #[cfg(feature = "custom")]
+    let custom = identifier().prop_map(|id| {
+        Content::Custom(CustomData {
+            id,
+            payload: Payload::Empty,
+        })
+    });
+    #[cfg(not(feature = "custom"))]
+    let custom = resolvable(component.clone()).prop_map(Content::Resolvable);
+    prop_oneof![
+        3 => resolved_content(component.clone()),
+        1 => resolvable(component).prop_map(Content::Resolvable),
+        1 => custom,
+    ]
+}
+
+fn tree<C, F>(content: F) -> impl Strategy<Value = TextComponent>
+where
+    C: Strategy<Value = Content> + 'static,
+    F: Fn(BoxedStrategy<TextComponent>) -> C + 'static,
+{
+    let leaf = (string(), format()).prop_map(|(text, format)| TextComponent {
+        content: Content::Text { text },
+        children: vec![],
+        format,
+        interactions: Interactivity::new(),
+        inline_translations: None,
+        #[cfg(feature = "unknown-fields")]
+        unknown_fields: None,
+    });
+    leaf.prop_recursive(2, 16, 3, move |inner| {
+        (
+            content(inner.clone()),
+            vec(inner.clone(), 0..3),
+            format(),
+            interactivity(inner),
+        )
+            .prop_map(|(content, children, format, interactions)| TextComponent {
+                content,
+                children,
+                format,
+                interactions,
+                inline_translations: None,
+                #[cfg(feature = "unknown-fields")]
+                unknown_fields: None,
+            })
+    })
+}
+
+/// Components of bounded depth using every content kind, format field and event.
+pub fn component() -> impl Strategy<Value = TextComponent> {
+    tree(content)
+}
+
+/// Like [component] but also giving some of the components
+/// [inline translations](TextComponent::inline_translations) and, with the `unknown-fields`
+/// and `serde` features, [unknown keys](TextComponent::unknown_fields), which the
+/// serialization formats don't all carry.
+pub fn component_with_extras() -> impl Strategy<Value = TextComponent> {
+    (component(), vec(extras(), 16)).prop_map(|(mut component, extras)| {
+        add_extras(&mut component, &mut extras.into_iter());
+        component
+    })
+}
+
+#[cfg(all(feature = "unknown-fields", feature = "serde"))]
+type Extras = (
+    Option<TranslationTable>,
+    Option<crate::unknown::UnknownFields>,
+);
+#[cfg(not(all(feature = "unknown-fields", feature = "serde")))]
+type Extras = (Option<TranslationTable>, ());
+
+fn extras() -> impl Strategy<Value = Extras> {
+    let translations = option::of(proptest::collection::btree_map(
+        identifier(),
+        string(),
+        1..3,
+    ));
+    #[cfg(all(feature = "unknown-fields", feature = "serde"))]
+    let unknown = option::of(vec(("x_[a-z]{1,6}", string()), 1..3).prop_map(|fields| {
+        crate::unknown::UnknownFields(
+            fields
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = serde_json::Value::String(value.into_owned());
+                    (key, crate::unknown::UnknownValue::Json(value))
+                })
+                .collect(),
+        )
+    }));
+    #[cfg(not(all(feature = "unknown-fields", feature = "serde")))]
+    let unknown = Just(());
+    (translations, unknown)
+}
+
+/// Gives the extras to the component and its children in order, until they run out.
+fn add_extras(component: &mut TextComponent, extras: &mut impl Iterator<Item = Extras>) {
+    let Some((translations, _unknown)) = extras.next() else {
+        return;
+    };
+    component.inline_translations = translations.map(Arc::new);
+    #[cfg(all(feature = "unknown-fields", feature = "serde"))]
+    {
+        component.unknown_fields = _unknown.map(Box::new);
+    }
+    for child in &mut component.children {
+        add_extras(child, extras);
+    }
+}
+
+/// Like [component] but only with contents that survive [resolution](TextComponent::resolve)
+/// on their own, which are the only ones a [BuildTarget](crate::resolving::BuildTarget) emits.
+pub fn resolved_component() -> impl Strategy<Value = TextComponent> {
+    tree(resolved_content)
+}
+
+/// Rewrites a component into the form all the formats agree on, two components
+/// are semantically equal when their normalized forms are equal.
+pub fn normalize(component: &TextComponent) -> TextComponent {
+    let mut component = component.clone();
+    normalize_in_place(&mut component);
+    component
+}
+
+fn normalize_in_place(component: &mut TextComponent) {
+    match &mut component.content {
+        Content::Translate(message) => {
+            if let Some(args) = &mut message.args {
+                if args.is_empty() {
+                    message.args = None;
+                } else {
+                    args.iter_mut().for_each(normalize_in_place);
+                }
+            }
+        }
+        Content::Resolvable(Resolvable::Entity { separator, .. })
+        | Content::Resolvable(Resolvable::NBT { separator, .. }) => normalize_in_place(separator),
+        _ => (),
+    }
+    match &mut component.interactions.hover {
+        Some(HoverEvent::ShowText { value }) => normalize_in_place(value),
+        Some(HoverEvent::ShowEntity {
+            name: Some(name), ..
+        }) => normalize_in_place(name),
+        _ => (),
+    }
+    component.children.iter_mut().for_each(normalize_in_place);
+}
+
+pub fn semantic_eq(a: &TextComponent, b: &TextComponent) -> bool {
+    normalize(a) == normalize(b)
+}
+
+/// Panics with both normalized components if they aren't [semantically equal](semantic_eq).
+#[track_caller]
+pub fn assert_semantic_eq(a: &TextComponent, b: &TextComponent) {
+    let (a, b) = (normalize(a), normalize(b));
+    assert!(a == b, "components differ:\n  left: {a:?}\n right: {b:?}");
+}
+
+/// The SNBT of the `snbt` example, with every object and most of the format keys.
+pub const EXAMPLE_SNBT: &str = r#"["\"Howdy!\"", { text:"\nThis is a text component!\n", color:'blue', "bold":1b, italic:true }, {text:"Texto" , type: "translatable", fallback:"lol\n", translate:"lmao"}, {sprite:"items/iron_sword"}, "\n", {object:"player", player:{name:"MrMelther"}, hover_event:{action:"show_text",value:{text:"Send msg to MrMelther"}}, click_event:{action:"suggest_command", command:"/msg MrMelther "} }, {object:"player", player:{properties:[{name:"textures", value:"[Put your base64 texture here!]"}]}}]"#;
+
+/// The `i`th component of the fixtures, cycling through texts, translations, keybinds and
+/// objects with some format and an event every few components.
+fn fixture_node(i: usize) -> TextComponent {
+    let content: TextComponent = match i % 5 {
+        0 | 1 => format!("Line {i} ").into(),
+        2 => TextComponent::translated_with("chat.type.text", [format!("Player{i}"), "Hi".into()]),
+        3 => Content::Keybind {
+            keybind: "key.jump".into(),
+        }
+        .into(),
+        _ => TextComponent::sprite("item/diamond"),
+    };
+    let mut format = Format::new().color(FIXTURE_COLORS[i % FIXTURE_COLORS.len()]);
+    if i.is_multiple_of(3) {
+        format = format.bold(true);
+    }
+    if i.is_multiple_of(7) {
+        format = format.shadow_color(0x80, i as u8, 0, 0);
+    }
+    let component = TextComponent { format, ..content };
+    match i % 4 {
+        0 => component.hover_event(HoverEvent::show_text(format!("Hover {i}"))),
+        1 => component.click_event(ClickEvent::run_command(format!("/say {i}"))),
+        _ => component,
+    }
+}
+
+const FIXTURE_COLORS: [Color; 4] = [
+    Color::Gold,
+    Color::Aqua,
+    Color::Rgb(255, 0, 68),
+    Color::Gray,
+];
+
+/// A component with `children` children, like a long chat line or a book page.
+pub fn wide_tree(children: usize) -> TextComponent {
+    TextComponent::new().add_children((0..children).map(fixture_node).collect::<Vec<_>>())
+}
+
+/// A chain of `depth` components each nested in the previous one, every level adding a
+/// child after the nested one.
+pub fn deep_tree(depth: usize) -> TextComponent {
+    (0..depth).rev().fold(fixture_node(depth), |nested, i| {
+        fixture_node(i).add_child(nested).add_child(format!(" {i}"))
+    })
+}
+
+/// A component with `count` scores, entities and NBT values to resolve.
+pub fn resolvable_tree(count: usize) -> TextComponent {
+    let resolvables = (0..count).map(|i| {
+        match i % 3 {
+            0 => TextComponent::scoreboard(format!("Player{i}"), "kills"),
+            1 => TextComponent::entity(format!("@e[limit={i}]"), None),
+            _ => TextComponent::nbt(
+                format!("Items[{i}]"),
+                NbtSource::block(0, 64, 0),
+                false,
+                None,
+            ),
+        }
+        .add_child(" ")
+    });
+    TextComponent::new().add_children(resolvables.collect::<Vec<_>>())
+}
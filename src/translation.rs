@@ -1,11 +1,21 @@
-use crate::TextComponent;
-use std::borrow::Cow;
+use crate::{
+    TextComponent,
+    content::Content,
+    intern::SharedStr,
+    resolving::{InlineResolutor, TextResolutor, TranslationSegment},
+};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    fmt::{self, Display, Formatter},
+    sync::Arc,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct TranslatedMessage {
     #[cfg_attr(feature = "serde", serde(rename = "translate"))]
-    pub key: Cow<'static, str>,
+    pub key: SharedStr,
     #[cfg_attr(
         feature = "serde",
         serde(skip_serializing_if = "Option::is_none", default)
@@ -13,7 +23,12 @@ pub struct TranslatedMessage {
     pub fallback: Option<Cow<'static, str>>,
     #[cfg_attr(
         feature = "serde",
-        serde(skip_serializing_if = "Option::is_none", rename = "with", default)
+        serde(
+            skip_serializing_if = "Option::is_none",
+            rename = "with",
+            deserialize_with = "crate::serde::deserialize_args",
+            default
+        )
     )]
     pub args: Option<Box<[TextComponent]>>,
 }
@@ -24,7 +39,7 @@ impl TranslatedMessage {
     /// Please use a compiled [Translation] instead.
     pub const fn new(key: &'static str, args: Option<Box<[TextComponent]>>) -> Self {
         Self {
-            key: Cow::Borrowed(key),
+            key: SharedStr::from_static(key),
             args,
             fallback: None,
         }
@@ -41,11 +56,43 @@ impl TranslatedMessage {
     }
 }
 
+/// Written as the key and the amount of arguments, like `translate(chat.type.text, 2 args)`.
+impl Display for TranslatedMessage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.args.as_deref().map(<[TextComponent]>::len) {
+            None | Some(0) => write!(f, "translate({})", self.key),
+            Some(1) => write!(f, "translate({}, 1 arg)", self.key),
+            Some(len) => write!(f, "translate({}, {len} args)", self.key),
+        }
+    }
+}
+
 impl From<TranslatedMessage> for TextComponent {
     fn from(value: TranslatedMessage) -> Self {
         value.component()
     }
 }
+impl From<&TranslatedMessage> for TextComponent {
+    fn from(value: &TranslatedMessage) -> Self {
+        value.clone().component()
+    }
+}
+/// A message of the key without arguments.
+impl From<&'static str> for TranslatedMessage {
+    fn from(key: &'static str) -> Self {
+        TranslatedMessage::new(key, None)
+    }
+}
+/// A message of the key without arguments.
+impl From<String> for TranslatedMessage {
+    fn from(key: String) -> Self {
+        TranslatedMessage {
+            key: key.into(),
+            fallback: None,
+            args: None,
+        }
+    }
+}
 
 pub struct Translation<const ARGS: usize>(pub &'static str);
 
@@ -70,3 +117,148 @@ impl From<&Translation<0>> for TextComponent {
         value.msg().component()
     }
 }
+
+/// Translation keys and their texts, carried by a component with
+/// [with_inline_translations](TextComponent::with_inline_translations).
+pub type TranslationTable = BTreeMap<Cow<'static, str>, Cow<'static, str>>;
+
+impl TextComponent {
+    /// Makes the component carry its own translations, useful when the receiving side
+    /// doesn't know the keys (like messages relayed between servers).\
+    /// Calling it again adds to the table, replacing the keys given again.
+    /// ### Fallback order
+    /// When [built](TextComponent::build) a translation takes its text from:
+    /// 1. The inline table of the component built, tables of nested components are ignored.
+    /// 2. The resolutor's [translate](crate::resolving::TextResolutor::translate).
+    /// 3. The translation's fallback.
+    ///
+    /// ### Serialization
+    /// The table is never serialized, [resolve](TextComponent::resolve) writes the inline
+    /// texts as the fallback of the translations using them so the serialized component
+    /// still shows them where the key is unknown.
+    /// ```
+    /// # use std::{borrow::Cow, collections::HashMap};
+    /// # use text_components::translation::Translation;
+    /// # const PLUGIN_GREETING: Translation<1> = Translation("plugin.greeting");
+    /// let component = PLUGIN_GREETING
+    ///     .message(["Steve"])
+    ///     .component()
+    ///     .with_inline_translations(HashMap::from([(
+    ///         Cow::Borrowed("plugin.greeting"),
+    ///         Cow::Borrowed("Welcome %s!"),
+    ///     )]));
+    /// ```
+    pub fn with_inline_translations(
+        mut self,
+        map: HashMap<Cow<'static, str>, Cow<'static, str>>,
+    ) -> Self {
+        let mut table = self
+            .inline_translations
+            .take()
+            .map(Arc::unwrap_or_clone)
+            .unwrap_or_default();
+        table.extend(map);
+        self.inline_translations = Some(Arc::new(table));
+        self
+    }
+}
+
+/// A mistake in a translation found by [check_translations](TextComponent::check_translations).\
+/// Argument indexes are counted from 0, so `%3$s` is the argument 2.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TranslationIssue {
+    /// The translated text uses an argument that wasn't given.
+    MissingArg { key: SharedStr, index: usize },
+    /// An argument was given but the translated text never uses it.
+    UnusedArg { key: SharedStr, index: usize },
+    /// The resolutor has no text for the key, even if the translation has a fallback.
+    UnknownKey { key: SharedStr },
+}
+
+impl Display for TranslationIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TranslationIssue::MissingArg { key, index } => {
+                write!(
+                    f,
+                    "The translation {key} uses the argument {index}, which wasn't given"
+                )
+            }
+            TranslationIssue::UnusedArg { key, index } => {
+                write!(f, "The translation {key} never uses its argument {index}")
+            }
+            TranslationIssue::UnknownKey { key } => {
+                write!(f, "The translation key {key} is unknown")
+            }
+        }
+    }
+}
+
+impl TextComponent {
+    /// Checks every translation in the component, nested ones included, against the texts
+    /// the resolutor (and the component's inline table) gives, without building it.
+    /// ```
+    /// # use text_components::{TextComponent, resolving::NoResolutor};
+    /// # let (message, manager) = (TextComponent::translated_key("greeting"), NoResolutor);
+    /// for issue in message.check_translations(&manager) {
+    ///     eprintln!("{issue}");
+    /// }
+    /// ```
+    pub fn check_translations<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+    ) -> Vec<TranslationIssue> {
+        let mut issues = vec![];
+        match &self.inline_translations {
+            Some(table) => {
+                let resolutor = InlineResolutor { table, resolutor };
+                self.collect_translation_issues(&resolutor, &mut issues);
+            }
+            None => self.collect_translation_issues(resolutor, &mut issues),
+        }
+        issues
+    }
+
+    fn collect_translation_issues<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+        issues: &mut Vec<TranslationIssue>,
+    ) {
+        if let Content::Translate(message) = &self.content {
+            let given = message.args.as_ref().map_or(0, |args| args.len());
+            match resolutor.translate(&message.key) {
+                Some(text) => {
+                    let mut used = vec![false; given];
+                    let mut missing = vec![];
+                    for segment in resolutor.translation_segments(&text) {
+                        if let TranslationSegment::Argument(index) = segment {
+                            match used.get_mut(index) {
+                                Some(used) => *used = true,
+                                None if !missing.contains(&index) => missing.push(index),
+                                None => (),
+                            }
+                        }
+                    }
+                    issues.extend(
+                        missing
+                            .into_iter()
+                            .map(|index| TranslationIssue::MissingArg {
+                                key: message.key.clone(),
+                                index,
+                            }),
+                    );
+                    issues.extend(used.into_iter().enumerate().filter(|(_, used)| !used).map(
+                        |(index, _)| TranslationIssue::UnusedArg {
+                            key: message.key.clone(),
+                            index,
+                        },
+                    ));
+                }
+                None => issues.push(TranslationIssue::UnknownKey {
+                    key: message.key.clone(),
+                }),
+            }
+        }
+        self.for_each_nested(|nested| nested.collect_translation_issues(resolutor, issues));
+    }
+}
@@ -0,0 +1,91 @@
+//! Keys this crate doesn't know, kept while passing components through so the ones of
+//! newer versions aren't lost.
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # fn main() -> serde_json::Result<()> {
+//! # use text_components::{TextComponent, unknown::preserve_unknown};
+//! # let json = r#"{"text":"Hi","futureKey":{"a":1}}"#;
+//! // {"text":"Hi","futureKey":{"a":1}} is written back with its futureKey
+//! let component = preserve_unknown(|| TextComponent::from_json(json))?;
+//! let json = serde_json::to_string(&component)?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
+//! ```
+//! JSON keeps them as [serde_json::Value]s and NBT as tags, each written back only by the
+//! format it was read from. The components inside them aren't parsed.\
+//! [from_snbt](crate::TextComponent::from_snbt) still skips them, there's no reader of SNBT
+//! values into tags to keep them with.
+use std::{
+    cell::Cell,
+    hash::{Hash, Hasher},
+};
+
+#[cfg(feature = "nbt")]
+use crate::nbt::ToSNBT;
+#[cfg(feature = "nbt")]
+use simdnbt::owned::NbtTag;
+
+thread_local! {
+    static PRESERVE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs the parsing in `parse` keeping the unknown keys of the components it reads in
+/// their [unknown_fields](crate::TextComponent::unknown_fields), instead of skipping them.
+pub fn preserve_unknown<T>(parse: impl FnOnce() -> T) -> T {
+    let preserve = PRESERVE.replace(true);
+    let parsed = parse();
+    PRESERVE.set(preserve);
+    parsed
+}
+
+/// Whether the parsers are inside [preserve_unknown].
+#[cfg(any(feature = "serde", feature = "nbt"))]
+pub(crate) fn is_preserving() -> bool {
+    PRESERVE.get()
+}
+
+/// Whether the key isn't one of a component, the ones in
+/// [CANONICAL_KEY_ORDER](crate::CANONICAL_KEY_ORDER).
+#[cfg(any(feature = "serde", feature = "nbt"))]
+pub(crate) fn is_unknown(key: &str) -> bool {
+    !crate::CANONICAL_KEY_ORDER.contains(&key)
+}
+
+/// The unknown keys of a component with their values, in the order they were read.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct UnknownFields(pub Vec<(String, UnknownValue)>);
+
+impl UnknownFields {
+    pub fn get(&self, key: &str) -> Option<&UnknownValue> {
+        self.0
+            .iter()
+            .find(|(known, _)| known == key)
+            .map(|(_, value)| value)
+    }
+}
+
+/// The value of an unknown key, as read.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UnknownValue {
+    #[cfg(feature = "serde")]
+    Json(serde_json::Value),
+    #[cfg(feature = "nbt")]
+    Nbt(NbtTag),
+}
+
+// For the Eq of TextComponent, only the NaN floats of tags aren't equal to themselves
+impl Eq for UnknownValue {}
+
+impl Hash for UnknownValue {
+    #[cfg_attr(not(any(feature = "serde", feature = "nbt")), allow(unused_variables))]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match *self {
+            #[cfg(feature = "serde")]
+            UnknownValue::Json(ref value) => value.hash(state),
+            #[cfg(feature = "nbt")]
+            UnknownValue::Nbt(ref tag) => tag.try_to_snbt().ok().hash(state),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Checking that a component will be accepted by the client before sending it.
+use crate::{
+    TextComponent,
+    content::Content,
+    interactivity::{ClickEvent, UrlError},
+    path::{ComponentPath, PathSegment},
+};
+use std::fmt::{self, Display, Formatter};
+
+/// Longest string the protocol allows, in characters.
+pub const MAX_STRING_LENGTH: usize = 32767;
+
+/// A problem found by [TextComponent::validate] and where it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// Path from the validated component to the one with the problem.
+    pub path: ComponentPath,
+    pub kind: ValidationErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationErrorKind {
+    /// A string is longer than [MAX_STRING_LENGTH].
+    StringTooLong { field: &'static str, length: usize },
+    /// The url of an `open_url` click event won't be opened.
+    Url(UrlError),
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ValidationErrorKind::StringTooLong { field, length } => write!(
+                f,
+                "The {field} has {length} characters, the maximum is {MAX_STRING_LENGTH}"
+            )?,
+            ValidationErrorKind::Url(error) => write!(f, "{error}")?,
+        }
+        if !self.path.is_root() {
+            write!(f, " at {}", self.path)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl TextComponent {
+    /// Checks the component and every nested one, giving the first problem found.
+    /// ```
+    /// # use text_components::TextComponent;
+    /// # let component = TextComponent::new();
+    /// if let Err(error) = component.validate() {
+    ///     // The text has 40000 characters, the maximum is 32767 at extra[1].hover_event.value
+    ///     eprintln!("{error}");
+    /// }
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut path = Vec::new();
+        self.validate_at(&mut path).map_err(|kind| ValidationError {
+            path: ComponentPath(path),
+            kind,
+        })
+    }
+
+    /// Leaves in `path` the segments leading to the invalid component.
+    fn validate_at(&self, path: &mut Vec<PathSegment>) -> Result<(), ValidationErrorKind> {
+        let check = |field: &'static str, text: &str| {
+            let length = text.chars().count();
+            if length > MAX_STRING_LENGTH {
+                return Err(ValidationErrorKind::StringTooLong { field, length });
+            }
+            Ok(())
+        };
+        match &self.content {
+            Content::Text { text } => check("text", text)?,
+            Content::Keybind { keybind } => check("keybind", keybind)?,
+            Content::Translate(message) => {
+                check("translation key", &message.key)?;
+                if let Some(fallback) = &message.fallback {
+                    check("fallback", fallback)?;
+                }
+            }
+            _ => (),
+        }
+        if let Some(font) = &self.format.font {
+            check("font", font)?;
+        }
+        if let Some(insertion) = &self.interactions.insertion {
+            check("insertion", insertion)?;
+        }
+        if let Some(click) = &self.interactions.click {
+            match click {
+                ClickEvent::OpenUrl { url } => check("url", url)?,
+                ClickEvent::RunCommand { command } | ClickEvent::SuggestCommand { command } => {
+                    check("command", command)?
+                }
+                ClickEvent::CopyToClipboard { value } => check("clipboard value", value)?,
+                _ => (),
+            }
+            click.validate().map_err(ValidationErrorKind::Url)?;
+        }
+        let mut result = Ok(());
+        self.for_each_segment(|segment, nested| {
+            if result.is_err() {
+                return;
+            }
+            path.push(segment);
+            result = nested.validate_at(path);
+            if result.is_ok() {
+                path.pop();
+            }
+        });
+        result
+    }
+}
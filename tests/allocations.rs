@@ -0,0 +1,72 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    borrow::Cow,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use text_components::{
+    TextComponent, content::Resolvable, fmt::TextBuilder, resolving::TextResolutor,
+    translation::TranslatedMessage,
+};
+
+/// Counts the allocations made.
+struct Counting;
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Holds its translations as static strings, borrowing them or giving copies.
+struct Static {
+    borrow: bool,
+}
+impl TextResolutor for Static {
+    fn resolve_content(&self, _resolvable: &Resolvable) -> TextComponent {
+        TextComponent::new()
+    }
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, _data: &text_components::custom::CustomData) -> Option<TextComponent> {
+        None
+    }
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>> {
+        let text = match key {
+            "chat.type.text" => "<%s> %s",
+            _ => return None,
+        };
+        match self.borrow {
+            true => Some(Cow::Borrowed(text)),
+            false => Some(Cow::Owned(text.to_string())),
+        }
+    }
+}
+
+fn build_many(resolutor: &Static) -> usize {
+    let component = TranslatedMessage::new(
+        "chat.type.text",
+        Some(Box::new(["Steve".into(), "hello".into()])),
+    )
+    .component();
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..10_000 {
+        assert_eq!(component.build(resolutor, TextBuilder), "<Steve> hello");
+    }
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+// A single test so no other one allocates while measuring
+#[test]
+fn borrowed_translations() {
+    let owned = build_many(&Static { borrow: false });
+    let borrowed = build_many(&Static { borrow: true });
+    // One less allocation per build, the translated text
+    assert!(borrowed + 10_000 <= owned, "{borrowed} {owned}");
+}
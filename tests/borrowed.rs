@@ -0,0 +1,126 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+use text_components::{
+    Modifier, TextComponent,
+    content::NbtSource,
+    format::Color,
+    interactivity::{ClickEvent, HoverEvent},
+    serde::borrowed::TextComponentDe,
+};
+
+/// Counts the allocations made by each thread, so the tests running next to
+/// the measurement don't change it.
+struct Counting;
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    static STRINGS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        // Strings are the only byte aligned allocations
+        if layout.align() == 1 {
+            let _ = STRINGS.try_with(|count| count.set(count.get() + 1));
+        }
+        unsafe { System.alloc(layout) }
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// The allocations and the string allocations made by `f`.
+fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize, usize) {
+    let (before, strings_before) = (ALLOCATIONS.get(), STRINGS.get());
+    let value = f();
+    (
+        value,
+        ALLOCATIONS.get() - before,
+        STRINGS.get() - strings_before,
+    )
+}
+
+fn assert_same(json: &str) {
+    let owned = TextComponent::from_json(json).expect(json);
+    let borrowed = serde_json::from_str::<TextComponentDe>(json).expect(json);
+    assert_eq!(borrowed.into_owned(), owned, "{json}");
+}
+
+#[test]
+fn same_as_owned() {
+    for json in [
+        r#"{"text":"","extra":["a",{"text":"b","bold":true},["c"]]}"#,
+        r##"{"text":"Hi","color":"#ff0044","font":"uniform","shadow_color":[1.0,0.5,0,1]}"##,
+        r#"{"text":"Line\nbreak \"quoted\" é"}"#,
+        r#"{"translate":"chat.type.text","with":["Steve",{"text":"hi","italic":true}],"fallback":"<%s> %s"}"#,
+        r#"{"keybind":"key.jump"}"#,
+        r#"{"keybind":"jump"}"#,
+        r#"{"score":{"name":"@p","objective":"kills"}}"#,
+        r#"{"selector":"@a","separator":" & "}"#,
+        r#"{"nbt":"Health","entity":"@p","interpret":1,"separator":[" | "]}"#,
+        r#"{"nbt":"Items[0]","block":"1 64 -3"}"#,
+        r#"{"nbt":"quest","storage":"minecraft:quests"}"#,
+        r#"{"object":"atlas","sprite":"item/porkchop"}"#,
+        r#"{"object":"player","player":{"name":"Jeb_","id":[1,2,3,4]},"hat":false}"#,
+        r#"{"player":"Jeb_"}"#,
+        r#"{"text":"a","click_event":{"action":"open_url","url":"https://example.com"}}"#,
+        r#"{"text":"a","click_event":{"action":"change_page","page":3}}"#,
+        r#"{"text":"a","clickEvent":{"action":"run_command","value":"/spawn"}}"#,
+        r#"{"text":"a","hover_event":{"action":"show_text","value":["b","c"]}}"#,
+        r#"{"text":"a","hoverEvent":{"action":"show_text","contents":"b"}}"#,
+        r#"{"text":"a","hover_event":{"action":"show_text","value":"{\"text\":\"b\",\"bold\":true}"}}"#,
+        r#"{"text":"a","hover_event":{"action":"show_text","value":"{text:'b',bold:1b}"}}"#,
+        r#"{"text":"a","hover_event":{"action":"show_item","id":"minecraft:stone","count":2}}"#,
+        r#"{"text":"a","hover_event":{"action":"show_entity","id":"minecraft:pig","uuid":"00000000-0000-0000-0000-000000000001","name":{"text":"Babe"}}}"#,
+        r#"{"text":"a","insertion":"b","extra":["c",{"translate":"d"}]}"#,
+    ] {
+        assert_same(json);
+    }
+}
+
+/// A book of about 1MB, the strings of which need no unescaping.
+fn big_book() -> String {
+    let mut pages = vec![];
+    for page in 0..3_000 {
+        pages.push(
+            format!("Page {page} ")
+                .color(Color::Gold)
+                .add_children(vec![
+                    format!("of a very long story about the number {page}").into(),
+                    "and what it did"
+                        .italic(true)
+                        .hover_event(HoverEvent::show_text(format!("Footnote {page}"))),
+                    TextComponent::translated_with("chat.type.text", ["Steve", "hello"]),
+                    TextComponent::nbt("Health", NbtSource::entity("@p"), false, None),
+                    "next".click_event(ClickEvent::change_page(page + 1)),
+                ]),
+        );
+    }
+    serde_json::to_string(&TextComponent::new().add_children(pages)).unwrap()
+}
+
+#[test]
+fn allocates_fewer_strings() {
+    let json = big_book();
+    assert!(json.len() > 1_000_000, "{}", json.len());
+
+    let (owned, owned_allocations, owned_strings) =
+        allocations(|| serde_json::from_str::<TextComponent>(&json).unwrap());
+    let (borrowed, borrowed_allocations, borrowed_strings) =
+        allocations(|| serde_json::from_str::<TextComponentDe>(&json).unwrap());
+    assert!(
+        borrowed_strings * 2 < owned_strings,
+        "{borrowed_strings} {owned_strings}"
+    );
+    assert!(
+        borrowed_allocations < owned_allocations,
+        "{borrowed_allocations} {owned_allocations}"
+    );
+    assert_eq!(borrowed.into_owned(), owned);
+}
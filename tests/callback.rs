@@ -0,0 +1,127 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::Duration,
+};
+use text_components::{
+    callback::{CallbackContext, CallbackError, CallbackOptions, CallbackRegistry},
+    interactivity::ClickEvent,
+};
+
+fn counter() -> (Arc<AtomicU32>, impl Fn(&CallbackContext) + Send + Sync) {
+    let count = Arc::new(AtomicU32::new(0));
+    let clone = count.clone();
+    (count, move |_: &_| {
+        clone.fetch_add(1, Ordering::Relaxed);
+    })
+}
+
+fn command(click: &ClickEvent) -> String {
+    match click {
+        ClickEvent::RunCommand { command } => command.to_string(),
+        _ => panic!("expected a run_command, got {click:?}"),
+    }
+}
+
+#[test]
+fn runs_until_removed() {
+    let registry = CallbackRegistry::new();
+    let (count, callback) = counter();
+    let command = command(&registry.register(callback));
+    assert!(command.starts_with("/tc_callback "));
+
+    registry.handle(&command).unwrap();
+    // The server may pass the command without the slash or only the argument
+    let context = registry.handle(command.trim_start_matches('/')).unwrap();
+    registry.handle(command.split(' ').nth(1).unwrap()).unwrap();
+    assert_eq!(count.load(Ordering::Relaxed), 3);
+    assert_eq!((context.uses, context.remaining), (2, None));
+
+    assert!(registry.remove(context.id));
+    assert_eq!(
+        registry.handle(&command),
+        Err(CallbackError::Unknown(context.id))
+    );
+}
+
+#[test]
+fn single_use() {
+    let registry = CallbackRegistry::new();
+    let (count, callback) = counter();
+    let command = command(&registry.register_with(CallbackOptions::once(), callback));
+
+    let context = registry.handle(&command).unwrap();
+    assert_eq!(context.remaining, Some(0));
+    assert_eq!(
+        registry.handle(&command),
+        Err(CallbackError::Unknown(context.id))
+    );
+    assert_eq!(count.load(Ordering::Relaxed), 1);
+    assert!(registry.is_empty());
+}
+
+#[test]
+fn expired() {
+    let registry = CallbackRegistry::new();
+    let (count, callback) = counter();
+    let expired =
+        command(&registry.register_with(CallbackOptions::expires_after(Duration::ZERO), callback));
+    let (_, callback) = counter();
+    let other =
+        command(&registry.register_with(CallbackOptions::expires_after(Duration::ZERO), callback));
+    assert_eq!(
+        registry.len(),
+        1,
+        "registering purges the expired callbacks"
+    );
+
+    let id = other.split(' ').nth(1).unwrap().parse().unwrap();
+    assert_eq!(registry.handle(&other), Err(CallbackError::Expired(id)));
+    assert!(matches!(
+        registry.handle(&expired),
+        Err(CallbackError::Unknown(_))
+    ));
+    assert_eq!(count.load(Ordering::Relaxed), 0);
+    assert!(registry.is_empty());
+}
+
+#[test]
+fn invalid_id() {
+    let registry = CallbackRegistry::new();
+    assert!(matches!(
+        registry.handle("/tc_callback nope"),
+        Err(CallbackError::InvalidId(_))
+    ));
+}
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_click() {
+    use text_components::callback::CallbackStyle;
+    let registry =
+        CallbackRegistry::with_style(CallbackStyle::Custom("my_plugin:callback/".into()));
+    let (count, callback) = counter();
+    let ClickEvent::Custom(data) = registry.register(callback) else {
+        panic!("expected a custom click event");
+    };
+    registry.handle(&data.id).unwrap();
+    assert_eq!(count.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn zero_uses() {
+    let registry = CallbackRegistry::new();
+    let (count, callback) = counter();
+    let options = CallbackOptions {
+        max_uses: Some(0),
+        ..Default::default()
+    };
+    let command = command(&registry.register_with(options, callback));
+    let id = command.split(' ').nth(1).unwrap().parse().unwrap();
+
+    assert_eq!(registry.handle(&command), Err(CallbackError::UsedUp(id)));
+    assert_eq!(count.load(Ordering::Relaxed), 0);
+    assert!(registry.is_empty());
+}
@@ -0,0 +1,120 @@
+use text_components::{
+    Modifier, TextComponent,
+    content::NbtSource,
+    format::Color,
+    interactivity::{ClickEvent, HoverEvent},
+};
+use uuid::Uuid;
+
+fn fixture() -> TextComponent {
+    TextComponent::new().add_children::<TextComponent>(vec![
+        "Hello "
+            .underlined(true)
+            .color(Color::Rgb(0xAB, 0xCD, 0xEF))
+            .bold(true)
+            .insertion("hi")
+            .hover_event(HoverEvent::show_text("World".italic(true)))
+            .click_event(ClickEvent::open_url("https://example.com")),
+        TextComponent::entity("@a", None),
+        TextComponent::entity("@p", Some(" | ".color(Color::Red))),
+        TextComponent::scoreboard("@s", "kills").font("minecraft:uniform"),
+    ])
+}
+
+#[test]
+fn fixed_order() {
+    assert_eq!(
+        fixture().to_canonical_json().unwrap(),
+        concat!(
+            r#"{"text":"","extra":["#,
+            r##"{"text":"Hello ","color":"#abcdef","bold":true,"underlined":true,"##,
+            r#""click_event":{"action":"open_url","url":"https://example.com"},"#,
+            r#""hover_event":{"action":"show_text","value":{"text":"World","italic":true}},"#,
+            r#""insertion":"hi"},"#,
+            r#"{"selector":"@a"},"#,
+            r#"{"selector":"@p","separator":{"text":" | ","color":"red"}},"#,
+            r#"{"score":{"name":"@s","objective":"kills"},"font":"minecraft:uniform"}"#,
+            r#"]}"#,
+        )
+    );
+}
+
+#[test]
+fn stable_output() {
+    let first = fixture().to_canonical_json().unwrap();
+    let parsed = TextComponent::from_json(&first).unwrap();
+    assert_eq!(parsed, fixture());
+    assert_eq!(parsed.to_canonical_json().unwrap(), first);
+    assert_eq!(fixture().to_canonical_json().unwrap(), first);
+}
+
+#[test]
+fn hex_colors() {
+    let component = TextComponent::from_json(r##"{"text":"a","color":"#FF00AA"}"##).unwrap();
+    assert_eq!(component.format.color, Some(Color::Rgb(255, 0, 170)));
+    assert_eq!(
+        component.to_canonical_json().unwrap(),
+        r##"{"text":"a","color":"#ff00aa"}"##
+    );
+    assert!(TextComponent::from_json(r#"{"text":"a","color":"purple"}"#).is_err());
+}
+
+/// A component with every key set.
+fn every_key() -> TextComponent {
+    TextComponent::nbt("Health", NbtSource::entity("@p"), true, Some(" | ".into()))
+        .add_child("!")
+        .color(Color::Red)
+        .shadow_color(255, 0, 0, 0)
+        .bold(true)
+        .italic(false)
+        .underlined(true)
+        .strikethrough(false)
+        .obfuscated(true)
+        .click_event(ClickEvent::run_command("/spawn"))
+        .hover_event(HoverEvent::show_entity(
+            "minecraft:pig",
+            Uuid::nil(),
+            Some("Babe"),
+        ))
+        .insertion("hi")
+        .font("minecraft:uniform")
+}
+
+#[test]
+fn every_key_order() {
+    assert_eq!(
+        every_key().to_canonical_json().unwrap(),
+        concat!(
+            r#"{"nbt":"Health","interpret":true,"entity":"@p","separator":{"text":" | "},"#,
+            r#""extra":["!"],"color":"red","shadow_color":4278190080,"bold":true,"#,
+            r#""italic":false,"underlined":true,"strikethrough":false,"obfuscated":true,"#,
+            r#""click_event":{"action":"run_command","command":"/spawn"},"#,
+            r#""hover_event":{"action":"show_entity","id":"minecraft:pig","name":{"text":"Babe"},"#,
+            r#""uuid":"00000000-0000-0000-0000-000000000000"},"#,
+            r#""insertion":"hi","font":"minecraft:uniform"}"#,
+        )
+    );
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn every_key_order_nbt() {
+    use text_components::{
+        nbt::{NbtBuilder, ToSNBT},
+        resolving::{BuildTarget, NoResolutor},
+    };
+    // Same order, NBT also writes the source of the nbt content, left unresolved
+    assert_eq!(
+        NbtBuilder::new()
+            .build_component(&NoResolutor, &every_key())
+            .to_snbt(),
+        concat!(
+            r#"{nbt:"Health",interpret:1b,entity:"@p",source:"entity",separator:" | ","#,
+            r#"extra:["!"],color:"red",shadow_color:-16777216,bold:1b,"#,
+            r#"italic:0b,underlined:1b,strikethrough:0b,obfuscated:1b,"#,
+            r#"click_event:{action:"run_command",command:"/spawn"},"#,
+            r#"hover_event:{action:"show_entity",id:"minecraft:pig",name:"Babe",uuid:[0,0,0,0]},"#,
+            r#"insertion:"hi",font:"minecraft:uniform"}"#,
+        )
+    );
+}
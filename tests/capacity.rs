@@ -0,0 +1,61 @@
+use text_components::{
+    Modifier, TextComponent,
+    content::{Object, ObjectPlayer, PlayerProperties},
+    interactivity::HoverEvent,
+};
+
+#[test]
+fn reserved_children() {
+    let mut board = TextComponent::with_children_capacity(100);
+    assert!(board.children.capacity() >= 100);
+    for i in 0..100 {
+        board.children.push(i.to_string().into());
+    }
+    assert_eq!(board.children.len(), 100);
+
+    let mut component = TextComponent::plain("a");
+    component.reserve_children(10);
+    assert!(component.children.capacity() >= 10);
+}
+
+#[test]
+fn add_children_reserves_once() {
+    let children = (0..50).map(|i| i.to_string()).collect::<Vec<_>>();
+    let component = "a".add_children(children);
+    assert_eq!(component.children.len(), 50);
+    assert_eq!(component.children.capacity(), 50);
+}
+
+#[test]
+fn shrink_is_recursive() {
+    let mut player = ObjectPlayer::name("Steve");
+    player.properties = Vec::with_capacity(8);
+    player.properties.push(PlayerProperties {
+        name: "textures".into(),
+        value: "e30=".into(),
+        signature: None,
+    });
+    let mut hover = TextComponent::with_children_capacity(16);
+    hover
+        .children
+        .push(TextComponent::player_head(player, true));
+    let mut child = TextComponent::with_children_capacity(16);
+    child.children.push("b".into());
+    let mut component = TextComponent::with_children_capacity(16)
+        .hover_event(HoverEvent::show_text(hover))
+        .add_child(child);
+    let before = component.clone();
+
+    component.shrink();
+    assert_eq!(component, before);
+    assert_eq!(component.children.capacity(), 1);
+    assert_eq!(component.children[0].children.capacity(), 1);
+    let Some(HoverEvent::ShowText { value }) = &component.interactions.hover else {
+        panic!("{component:?}")
+    };
+    assert_eq!(value.children.capacity(), 1);
+    let Some(Object::Player { player, .. }) = value.children[0].as_object() else {
+        panic!("{value:?}")
+    };
+    assert_eq!(player.properties.capacity(), 1);
+}
@@ -0,0 +1,123 @@
+use std::{borrow::Cow, collections::HashMap};
+use text_components::{
+    Modifier, TextComponent,
+    content::Resolvable,
+    interactivity::HoverEvent,
+    resolving::TextResolutor,
+    translation::{TranslatedMessage, TranslationIssue},
+};
+
+struct Manager;
+impl TextResolutor for Manager {
+    fn resolve_content(&self, _resolvable: &Resolvable) -> TextComponent {
+        TextComponent::new()
+    }
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, _data: &text_components::custom::CustomData) -> Option<TextComponent> {
+        None
+    }
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>> {
+        Some(Cow::Borrowed(match key {
+            "chat.type.text" => "<%s> %s",
+            "commands.give.success" => "Gave %3$s %2$s to %1$s",
+            "twice" => "%1$s and %1$s",
+            "plain" => "Nothing to fill",
+            _ => return None,
+        }))
+    }
+}
+
+fn message<const N: usize>(key: &'static str, args: [TextComponent; N]) -> TextComponent {
+    TranslatedMessage::new(key, Some(Box::new(args))).component()
+}
+
+#[test]
+fn correct_translations() {
+    let component = message("chat.type.text", ["Steve".into(), "hi".into()])
+        .add_child(message("twice", ["a".into()]))
+        .add_child(TranslatedMessage::new("plain", None).component());
+    assert_eq!(component.check_translations(&Manager), vec![]);
+}
+
+#[test]
+fn broken_translations() {
+    let hover = message("chat.type.text", ["Steve".into(), "hi".into(), "!".into()]);
+    let component = message(
+        "commands.give.success",
+        [
+            "Steve".into(),
+            message("missing.key", ["x".into()]).hover_event(HoverEvent::show_text(hover)),
+        ],
+    )
+    .add_child(message("twice", ["a".into(), "b".into()]))
+    .add_child(message("plain", ["unused".into()]))
+    .add_child(TranslatedMessage::new("chat.type.text", None).component());
+
+    let key = |key: &'static str| key.into();
+    assert_eq!(
+        component.check_translations(&Manager),
+        vec![
+            TranslationIssue::MissingArg {
+                key: key("commands.give.success"),
+                index: 2
+            },
+            TranslationIssue::UnknownKey {
+                key: key("missing.key")
+            },
+            TranslationIssue::UnusedArg {
+                key: key("chat.type.text"),
+                index: 2
+            },
+            TranslationIssue::UnusedArg {
+                key: key("twice"),
+                index: 1
+            },
+            TranslationIssue::UnusedArg {
+                key: key("plain"),
+                index: 0
+            },
+            TranslationIssue::MissingArg {
+                key: key("chat.type.text"),
+                index: 0
+            },
+            TranslationIssue::MissingArg {
+                key: key("chat.type.text"),
+                index: 1
+            },
+        ]
+    );
+}
+
+#[test]
+fn build_output_is_unchanged() {
+    let component = message("commands.give.success", ["Steve".into(), "5".into()]);
+    assert_eq!(component.to_plain(&Manager), "Gave  5 to Steve");
+    assert_eq!(component.check_translations(&Manager).len(), 1);
+    assert_eq!(component.to_plain(&Manager), "Gave  5 to Steve");
+}
+
+#[test]
+fn inline_translations() {
+    let component = message("plugin.welcome", ["Steve".into()]);
+    assert_eq!(
+        component.check_translations(&Manager),
+        vec![TranslationIssue::UnknownKey {
+            key: "plugin.welcome".into()
+        }]
+    );
+    let component = component.with_inline_translations(HashMap::from([(
+        Cow::Borrowed("plugin.welcome"),
+        Cow::Borrowed("Welcome!"),
+    )]));
+    assert_eq!(
+        component.check_translations(&Manager),
+        vec![TranslationIssue::UnusedArg {
+            key: "plugin.welcome".into(),
+            index: 0
+        }]
+    );
+    assert_eq!(
+        component.check_translations(&Manager)[0].to_string(),
+        "The translation plugin.welcome never uses its argument 0"
+    );
+}
@@ -0,0 +1,162 @@
+use text_components::{
+    Modifier, TextComponent,
+    format::{Color, Format, HexColorError},
+    parse::SnbtError,
+};
+
+#[test]
+fn hex_shapes() {
+    let magenta = Color::Rgb(255, 0, 255);
+    for hex in [
+        "#ff00ff", "#FF00FF", "#Ff00fF", "ff00ff", "#f0f", "#F0F", "f0f",
+    ] {
+        assert_eq!(Color::parse_hex(hex), Ok(magenta), "{hex}");
+    }
+    assert_eq!(Color::parse_hex("#123"), Ok(Color::Rgb(0x11, 0x22, 0x33)));
+    assert_eq!(Color::from_hex("#0a0B0c"), Some(Color::Rgb(10, 11, 12)));
+    // Written back in lowercase
+    assert_eq!(Color::parse_hex("#F0F").unwrap().to_string(), "#ff00ff");
+
+    assert_eq!(
+        Color::parse_hex("#80ff00ff"),
+        Err(HexColorError::Argb("#80ff00ff".into()))
+    );
+    assert!(
+        Color::parse_hex("80ff00ff")
+            .unwrap_err()
+            .to_string()
+            .contains("shadow_color")
+    );
+    for hex in ["", "#", "#ff", "#ff00", "#ff00f", "#ff00ff0", "#ff00ff000"] {
+        assert_eq!(
+            Color::parse_hex(hex),
+            Err(HexColorError::Length(hex.into())),
+            "{hex}"
+        );
+    }
+    assert_eq!(
+        Color::parse_hex("#gg00ff"),
+        Err(HexColorError::InvalidDigit("#gg00ff".into(), 'g'))
+    );
+    assert_eq!(
+        Color::parse_hex("#+f0"),
+        Err(HexColorError::InvalidDigit("#+f0".into(), '+'))
+    );
+    assert_eq!(
+        Color::parse_hex("##f0f"),
+        Err(HexColorError::InvalidDigit("##f0f".into(), '#'))
+    );
+    assert_eq!(Color::from_hex("#ffé"), None);
+}
+
+#[test]
+fn color_hex() {
+    assert_eq!(
+        Format::new().color_hex("#F0F").color,
+        Some(Color::Rgb(255, 0, 255))
+    );
+    // Invalid colors leave the previous one
+    assert_eq!(
+        "a".color(Color::Red).color_hex("#80ff00ff").format.color,
+        Some(Color::Red)
+    );
+}
+
+#[test]
+fn snbt_colors() {
+    for snbt in [
+        r##"{text:"a",color:"#abc"}"##,
+        r##"{text:"a",color:"#AABBCC"}"##,
+        r#"{text:"a",color:"aabbcc"}"#,
+    ] {
+        assert_eq!(
+            TextComponent::from_snbt(snbt).unwrap(),
+            "a".color(Color::Rgb(0xaa, 0xbb, 0xcc)),
+            "{snbt}"
+        );
+    }
+    assert!(matches!(
+        TextComponent::from_snbt(r##"{text:"a",color:"#80aabbcc"}"##),
+        Err(SnbtError::HexColor(HexColorError::Argb(_)))
+    ));
+    assert!(matches!(
+        TextComponent::from_snbt(r##"{text:"a",color:"#abcd"}"##),
+        Err(SnbtError::HexColor(HexColorError::Length(_)))
+    ));
+    assert!(matches!(
+        TextComponent::from_snbt(r#"{text:"a",color:"pink"}"#),
+        Err(SnbtError::UnknownColor(_))
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_colors() {
+    use text_components::resolving::NoResolutor;
+    let component = TextComponent::from_json(r##"{"text":"a","color":"#ABC"}"##).unwrap();
+    assert_eq!(component, "a".color(Color::Rgb(0xaa, 0xbb, 0xcc)));
+    // Always written back as 6 lowercase digits
+    assert_eq!(
+        component.to_json(&NoResolutor).unwrap(),
+        r##"{"text":"a","color":"#aabbcc"}"##
+    );
+    let error = TextComponent::from_json(r##"{"text":"a","color":"#80aabbcc"}"##).unwrap_err();
+    assert!(error.to_string().contains("shadow_color"), "{error}");
+    assert!(TextComponent::from_json(r#"{"text":"a","color":"pink"}"#).is_err());
+}
+
+#[test]
+fn palette() {
+    // The table of the wiki's formatting codes page
+    for (name, hex) in [
+        ("black", "#000000"),
+        ("dark_blue", "#0000AA"),
+        ("dark_green", "#00AA00"),
+        ("dark_aqua", "#00AAAA"),
+        ("dark_red", "#AA0000"),
+        ("dark_purple", "#AA00AA"),
+        ("gold", "#FFAA00"),
+        ("gray", "#AAAAAA"),
+        ("dark_gray", "#555555"),
+        ("blue", "#5555FF"),
+        ("green", "#55FF55"),
+        ("aqua", "#55FFFF"),
+        ("red", "#FF5555"),
+        ("light_purple", "#FF55FF"),
+        ("yellow", "#FFFF55"),
+        ("white", "#FFFFFF"),
+    ] {
+        let color = Color::from_name(name).unwrap();
+        assert_eq!(
+            color.as_rgb(),
+            Color::parse_hex(hex).unwrap().as_rgb(),
+            "{name}"
+        );
+    }
+    assert_eq!(Color::Rgb(1, 2, 3).as_rgb(), (1, 2, 3));
+}
+
+#[test]
+fn resolved_rgb() {
+    let gold = Format::new().color(Color::Gold);
+    assert_eq!(gold.color_rgb(), Some((0xFF, 0xAA, 0x00)));
+    assert_eq!(Format::new().color_rgb(), None);
+
+    assert_eq!(
+        Format::new().effective_color(&gold),
+        Some((0xFF, 0xAA, 0x00))
+    );
+    assert_eq!(
+        Format::new().color_hex("#123456").effective_color(&gold),
+        Some((0x12, 0x34, 0x56))
+    );
+    assert_eq!(Format::new().no_inherit().effective_color(&gold), None);
+    assert_eq!(Format::new().effective_color(&Format::new()), None);
+
+    let shadow = Format::new().shadow_color(0x80, 0x10, 0x20, 0x30);
+    assert_eq!(shadow.shadow_color_rgba(), Some((0x10, 0x20, 0x30, 0x80)));
+    let mut packed = Format::new();
+    packed.shadow_color = Some(Format::packed_shadow_color(-16777216));
+    assert_eq!(packed.shadow_color_rgba(), Some((0, 0, 0, 0xFF)));
+    assert_eq!(Format::new().shadow_color_rgba(), None);
+}
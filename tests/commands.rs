@@ -0,0 +1,123 @@
+#[cfg(feature = "nbt")]
+use text_components::command::CommandError;
+use text_components::command::{TargetError, check_target};
+
+#[test]
+fn targets() {
+    for target in [
+        "@a",
+        "@p",
+        "@n",
+        "@e[type=minecraft:pig, limit=1]",
+        "@a[nbt={Tags:[\"a]\"]},scores={kills=1..}]",
+        "Steve",
+        "MrMelther_",
+        "069a79f4-44e9-4726-a5be-fca90e38aaf5",
+    ] {
+        assert_eq!(check_target(target), Ok(()), "{target}");
+    }
+    let malformed = |target: &str| TargetError::MalformedArguments(target.to_string());
+    let invalid = |target: &str| TargetError::InvalidName(target.to_string());
+    for (target, error) in [
+        ("", TargetError::Empty),
+        ("@", TargetError::UnknownSelector("@".to_string())),
+        ("@x", TargetError::UnknownSelector("@x".to_string())),
+        ("@a[tag=x", malformed("@a[tag=x")),
+        ("@a[tag=x]y", malformed("@a[tag=x]y")),
+        ("@a tag", malformed("@a tag")),
+        (
+            "@a[nbt={Tags:[\"a\"}]]",
+            malformed("@a[nbt={Tags:[\"a\"}]]"),
+        ),
+        (
+            "@a[scores={kills=1..]}",
+            malformed("@a[scores={kills=1..]}"),
+        ),
+        ("@a[tag=x}", malformed("@a[tag=x}")),
+        ("two words", invalid("two words")),
+        ("ThisNameIsTooLong", invalid("ThisNameIsTooLong")),
+        ("Steve;kill", invalid("Steve;kill")),
+    ] {
+        assert_eq!(check_target(target), Err(error), "{target}");
+    }
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn commands() {
+    use text_components::{
+        Modifier, TextComponent,
+        containers::Book,
+        format::Color,
+        resolving::{NoResolutor, StaticScores},
+    };
+
+    assert_eq!(
+        "Hello"
+            .color(Color::Red)
+            .to_tellraw(&NoResolutor, "@a")
+            .unwrap(),
+        r#"tellraw @a {text:"Hello",color:"red"}"#
+    );
+    assert_eq!(
+        TextComponent::plain("Say \"hi\"\nnow")
+            .to_tellraw(&NoResolutor, "@p")
+            .unwrap(),
+        r#"tellraw @p "Say \"hi\"\nnow""#
+    );
+    assert_eq!(
+        "Welcome"
+            .bold(true)
+            .to_title(&NoResolutor, "@a[tag=new]")
+            .unwrap(),
+        r#"title @a[tag=new] title {text:"Welcome",bold:1b}"#
+    );
+    let scores = StaticScores::new().score("@s", "kills", 5);
+    assert_eq!(
+        "Kills: "
+            .add_child(TextComponent::scoreboard("@s", "kills"))
+            .to_actionbar(&scores, "Steve")
+            .unwrap(),
+        r#"title Steve actionbar {text:"Kills: ",extra:["5"]}"#
+    );
+    let book = Book::new(
+        "Story",
+        "Notch",
+        vec!["Page one".into(), "Page two".color(Color::Blue)],
+    );
+    assert_eq!(
+        book.to_give_book(&NoResolutor, "@p").unwrap(),
+        r#"give @p minecraft:written_book[written_book_content={title:"Story",author:"Notch",pages:["Page one",{text:"Page two",color:"blue"}]}]"#
+    );
+    assert_eq!(
+        TextComponent::plain("Hello").to_tellraw(&NoResolutor, "@x"),
+        Err(CommandError::Target(TargetError::UnknownSelector(
+            "@x".to_string()
+        )))
+    );
+}
+
+#[cfg(all(feature = "nbt", feature = "unknown-fields"))]
+#[test]
+fn non_finite_unknown_fields() {
+    use simdnbt::owned::NbtTag;
+    use text_components::{
+        TextComponent,
+        nbt::SnbtWriteError,
+        resolving::NoResolutor,
+        unknown::{UnknownFields, UnknownValue},
+    };
+    let component = TextComponent {
+        unknown_fields: Some(Box::new(UnknownFields(vec![(
+            "scale".to_string(),
+            UnknownValue::Nbt(NbtTag::Float(f32::INFINITY)),
+        )]))),
+        ..TextComponent::plain("Big")
+    };
+    assert_eq!(
+        component.to_tellraw(&NoResolutor, "@a"),
+        Err(CommandError::Snbt(SnbtWriteError::NonFiniteFloat(
+            f32::INFINITY
+        )))
+    );
+}
@@ -0,0 +1,82 @@
+use text_components::{
+    Modifier, TextComponent,
+    format::{Color, Format},
+    style,
+};
+
+const TITLE: Format = style!(color: "#ff0044", bold, italic: false, font: "minecraft:uniform");
+const MUTED: Format = style!(color: "dark_gray", shadow_color: 0x80000000,);
+
+#[test]
+fn expansion() {
+    assert_eq!(
+        TITLE,
+        Format::with(
+            Some(Color::Rgb(255, 0, 68)),
+            Some("minecraft:uniform"),
+            Some(true),
+            Some(false),
+            None,
+            None,
+            None,
+            None,
+        )
+    );
+    assert_eq!(
+        MUTED,
+        Format::new()
+            .color(Color::DarkGray)
+            .shadow_color(128, 0, 0, 0)
+    );
+    assert_eq!(style!(), Format::new());
+    assert_eq!(style!(color: "#f04"), style!(color: "ff0044"));
+    assert_eq!(
+        style!(underlined, strikethrough: true, obfuscated),
+        Format::new()
+            .underlined(true)
+            .strikethrough(true)
+            .obfuscated(true)
+    );
+}
+
+#[test]
+fn namespaced_fonts() {
+    const FONT: &str = "alt";
+    assert_eq!(style!(font: "uniform"), Format::new().font("uniform"));
+    assert_eq!(style!(font: FONT).font.unwrap(), "minecraft:alt");
+    assert_eq!(
+        style!(font: "example:fancy/big"),
+        Format::new().font("example:fancy/big")
+    );
+}
+
+#[test]
+fn styled_components() {
+    let component = TextComponent::plain("Welcome")
+        .color(Color::Gold)
+        .underlined(true)
+        .style(TITLE);
+    assert_eq!(component.format.color, Some(Color::Rgb(255, 0, 68)));
+    assert_eq!(component.format.underlined, Some(true));
+    assert_eq!(component.format.bold, Some(true));
+    assert_eq!(component.format.italic, Some(false));
+    assert_eq!("Welcome".style(TITLE).format, TITLE);
+}
+
+#[test]
+fn named_colors() {
+    for name in ["black", "dark_aqua", "gray", "light_purple", "white"] {
+        assert_eq!(Some(Color::const_parse(name)), Color::from_name(name));
+    }
+}
+
+#[test]
+#[should_panic]
+fn invalid_hex() {
+    Color::const_parse("#zz0044");
+}
+
+#[test]
+fn invalid_styles_fail_to_compile() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
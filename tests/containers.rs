@@ -0,0 +1,167 @@
+#![allow(unused_imports)]
+use text_components::{
+    Modifier, TextComponent,
+    containers::{Book, DyeColor, PageLayout, SignText, paginate},
+    format::Color,
+    resolving::NoResolutor,
+};
+
+const LOREM: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor \
+incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam, quis nostrud exercitation \
+ullamco laboris nisi ut aliquip ex ea commodo consequat.";
+
+fn lines(page: &TextComponent) -> Vec<String> {
+    page.to_plain(&NoResolutor)
+        .split('\n')
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn pages_fit_the_layout() {
+    let layout = PageLayout {
+        width: 12,
+        lines: 4,
+    };
+    let pages = paginate(&LOREM.into(), &NoResolutor, layout);
+    assert!(pages.len() > 1);
+    for page in &pages {
+        let lines = lines(page);
+        assert!(lines.len() <= layout.lines, "{lines:?}");
+        for line in lines {
+            assert!(line.trim_end().chars().count() <= layout.width, "{line:?}");
+        }
+    }
+    let words = |text: &str| {
+        text.split_whitespace()
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+    let joined = pages
+        .iter()
+        .map(|page| page.to_plain(&NoResolutor) + " ")
+        .collect::<String>();
+    assert_eq!(words(&joined), words(LOREM));
+}
+
+#[test]
+fn long_words_are_split() {
+    let layout = PageLayout {
+        width: 4,
+        lines: 14,
+    };
+    let pages = paginate(&"abcdefghij".into(), &NoResolutor, layout);
+    assert_eq!(lines(&pages[0]), ["abcd", "efgh", "ij"]);
+}
+
+#[test]
+fn pieces_keep_their_format() {
+    let component = TextComponent::plain("Red text ")
+        .color(Color::Red)
+        .add_child("bold".bold(true));
+    let pages = paginate(&component, &NoResolutor, PageLayout::default());
+    assert_eq!(pages.len(), 1);
+    let children = &pages[0].children;
+    assert_eq!(children[0], "Red text ".color(Color::Red));
+    assert_eq!(children[1], "bold".color(Color::Red).bold(true));
+}
+
+#[test]
+fn empty_component() {
+    assert_eq!(
+        paginate(&TextComponent::new(), &NoResolutor, PageLayout::default()).len(),
+        1
+    );
+}
+
+#[test]
+fn sign_lines() {
+    let sign = SignText::new(["Hello", "World"])
+        .color(DyeColor::Blue)
+        .glowing(true);
+    assert_eq!(sign.lines[1], TextComponent::plain("World"));
+    assert_eq!(sign.lines[3], TextComponent::new());
+    assert_eq!(DyeColor::from_name("light_blue"), Some(DyeColor::LightBlue));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn book_json() {
+    let fixture = r#"{"title":"Story","author":"Notch","pages":[{"text":"Once upon a time"},{"text":"The end","color":"red"}]}"#;
+    let book: Book = serde_json::from_str(fixture).unwrap();
+    assert_eq!(
+        book,
+        Book::new(
+            "Story",
+            "Notch",
+            vec!["Once upon a time".into(), "The end".color(Color::Red)]
+        )
+    );
+    assert_eq!(serde_json::to_string(&book).unwrap(), fixture);
+    assert_eq!(
+        book.legacy_pages(&NoResolutor).unwrap(),
+        [
+            r#"{"text":"Once upon a time"}"#,
+            r#"{"text":"The end","color":"red"}"#
+        ]
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn sign_json() {
+    let fixture = r#"{"messages":[{"text":"Hello"},{"text":""},{"text":""},{"text":""}],"color":"light_blue","has_glowing_text":true}"#;
+    let sign: SignText = serde_json::from_str(fixture).unwrap();
+    assert_eq!(
+        sign,
+        SignText::new(["Hello"])
+            .color(DyeColor::LightBlue)
+            .glowing(true)
+    );
+    assert_eq!(serde_json::to_string(&sign).unwrap(), fixture);
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn book_nbt() {
+    use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+    let raw = |tag: NbtTag| NbtCompound::from_values(vec![("raw".into(), tag)]);
+    // As stored by 1.21 in the written_book_content component:
+    // {title:{raw:"Story"},author:"Notch",pages:[{raw:"Once upon a time"},{raw:{text:"The end",color:"red"}}]}
+    let fixture = NbtTag::Compound(NbtCompound::from_values(vec![
+        (
+            "title".into(),
+            NbtTag::Compound(raw(NbtTag::String("Story".into()))),
+        ),
+        ("author".into(), NbtTag::String("Notch".into())),
+        (
+            "pages".into(),
+            NbtTag::List(NbtList::Compound(vec![
+                raw(NbtTag::String("Once upon a time".into())),
+                raw(NbtTag::Compound(NbtCompound::from_values(vec![
+                    ("text".into(), NbtTag::String("The end".into())),
+                    ("color".into(), NbtTag::String("red".into())),
+                ]))),
+            ])),
+        ),
+    ]));
+    let book = Book::from_nbt(&fixture).unwrap();
+    assert_eq!(
+        book,
+        Book::new(
+            "Story",
+            "Notch",
+            vec!["Once upon a time".into(), "The end".color(Color::Red)]
+        )
+    );
+    assert_eq!(Book::from_nbt(&book.to_nbt(&NoResolutor)), Some(book));
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn sign_nbt() {
+    let sign = SignText::new(["Hello", "", "", "World"])
+        .color(DyeColor::Red)
+        .glowing(true);
+    assert_eq!(SignText::from_nbt(&sign.to_nbt(&NoResolutor)), Some(sign));
+}
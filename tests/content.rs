@@ -0,0 +1,92 @@
+use text_components::{
+    Modifier, TextComponent,
+    content::{Content, NbtSource, Object, ObjectPlayer, Resolvable},
+    format::Color,
+    translation::TranslatedMessage,
+};
+
+fn keybind() -> TextComponent {
+    TextComponent {
+        content: Content::Keybind {
+            keybind: "key.jump".into(),
+        },
+        ..TextComponent::new()
+    }
+}
+
+#[test]
+fn accessors() {
+    let text = TextComponent::plain("Hi");
+    assert_eq!(text.as_text(), Some("Hi"));
+    assert!(text.is_text() && !text.is_translation());
+    assert_eq!(text.as_translation(), None);
+
+    let translated = TextComponent::translated(TranslatedMessage::new("gui.done", None));
+    assert_eq!(
+        translated.as_translation().map(|t| &*t.key),
+        Some("gui.done")
+    );
+    assert!(translated.is_translation() && !translated.is_text());
+    assert_eq!(translated.as_text(), None);
+
+    assert_eq!(keybind().as_keybind(), Some("key.jump"));
+    assert!(keybind().is_keybind() && !keybind().is_object());
+
+    let head = TextComponent::player_head(ObjectPlayer::name("Jeb_"), true);
+    assert!(matches!(
+        head.as_object(),
+        Some(Object::Player { hat: true, .. })
+    ));
+    assert!(head.is_object() && !head.is_resolvable());
+
+    let score = TextComponent::scoreboard("@s", "kills");
+    assert!(matches!(
+        score.as_resolvable(),
+        Some(Resolvable::Scoreboard { objective, .. }) if objective == "kills"
+    ));
+    assert!(score.is_resolvable() && !score.is_keybind());
+    assert!(TextComponent::nbt("Health", NbtSource::entity("@p"), false, None).is_resolvable());
+}
+
+#[test]
+fn content_accessors() {
+    let content = Content::from(String::from("Hi"));
+    assert_eq!(content.as_text(), Some("Hi"));
+    assert!(content.is_text());
+    assert_eq!(content.as_keybind(), None);
+    assert_eq!(content.as_object(), None);
+    assert_eq!(content.as_resolvable(), None);
+}
+
+#[test]
+fn translation_mut() {
+    let mut translated = TextComponent::translated(TranslatedMessage::new("gui.done", None));
+    translated.as_translation_mut().unwrap().fallback = Some("Done".into());
+    assert_eq!(
+        translated.as_translation().unwrap().fallback.as_deref(),
+        Some("Done")
+    );
+    assert!(TextComponent::plain("Hi").as_translation_mut().is_none());
+}
+
+#[test]
+fn set_text() {
+    let mut component = TextComponent::scoreboard("@s", "kills")
+        .color(Color::Red)
+        .add_child("!");
+    component.set_text("5");
+    assert_eq!(component, "5".color(Color::Red).add_child("!"));
+}
+
+#[cfg(feature = "custom")]
+#[test]
+fn custom_accessors() {
+    use text_components::custom::{CustomData, Payload};
+    let data = CustomData {
+        id: "time".into(),
+        payload: Payload::Empty,
+    };
+    let component = TextComponent::from(data.clone());
+    assert_eq!(component.as_custom(), Some(&data));
+    assert!(component.is_custom() && !component.is_text());
+}
@@ -0,0 +1,117 @@
+//! Runs the `convert` example over the fixtures in `tests/convert`.
+use assert_cmd::{Command, assert::Assert};
+use std::path::PathBuf;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/convert")
+        .join(name)
+}
+
+/// `cargo test` builds the examples next to the directory of the test binaries,
+/// but not when only this test is run.
+fn example() -> PathBuf {
+    let test = std::env::current_exe().unwrap();
+    let example = test
+        .parent()
+        .unwrap()
+        .with_file_name("examples")
+        .join("convert");
+    if !example.exists() {
+        let status = std::process::Command::new(env!("CARGO"))
+            .args([
+                "build",
+                "--example",
+                "convert",
+                "--features",
+                "serde,pretty",
+            ])
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+    example
+}
+
+fn convert(input: &str, args: &[&str]) -> Assert {
+    Command::new(example())
+        .args(args)
+        .write_stdin(input)
+        .assert()
+}
+
+#[test]
+fn json_and_snbt_inputs() {
+    let expected = r#"{"translate":"chat.type.text","with":[{"text":"Steve"},{"text":"Hi","bold":true}],"color":"gray"}"#;
+    for input in ["message.json", "message.snbt"] {
+        let input = std::fs::read_to_string(fixture(input)).unwrap();
+        convert(&input, &["--to", "json"])
+            .success()
+            .stdout(format!("{expected}\n"));
+        convert(&input, &[])
+            .success()
+            .stdout(format!("{expected}\n"));
+    }
+}
+
+#[test]
+fn legacy_and_minimessage_inputs() {
+    let expected = r#"{"text":"","extra":["Hi ",{"text":"there","color":"red","bold":true}]}"#;
+    for input in [
+        "Hi §c§lthere",
+        "Hi &c&lthere",
+        "Hi <red><bold>there</bold></red>",
+    ] {
+        convert(input, &["--to", "json"])
+            .success()
+            .stdout(format!("{expected}\n"));
+    }
+    convert("<click:run_command:/help>Help", &[])
+        .failure()
+        .stderr("Unsupported MiniMessage tag <click:run_command:/help>\n");
+}
+
+#[test]
+fn legacy_and_html_outputs() {
+    let input = std::fs::read_to_string(fixture("message.json")).unwrap();
+    let lang = fixture("en_us.json");
+    let lang = lang.to_str().unwrap();
+    convert(&input, &["--to", "legacy", "--lang", lang])
+        .success()
+        .stdout("§7<Steve> §r§7§lHi\n");
+    convert(&input, &["--to", "html", "--lang", lang])
+        .success()
+        .stdout(concat!(
+            r#"<span style="color:#aaaaaa;">&lt;Steve&gt; </span>"#,
+            r#"<span style="color:#aaaaaa;font-weight:bold;">Hi</span>"#,
+            "\n"
+        ));
+    convert("§x§f§f§0§0§4§4Hex", &["--to", "legacy"])
+        .success()
+        .stdout("§x§f§f§0§0§4§4Hex\n");
+}
+
+#[test]
+fn lang_file() {
+    let input = std::fs::read_to_string(fixture("message.json")).unwrap();
+    let lang = fixture("en_us.json");
+    let lang = lang.to_str().unwrap();
+    convert(&input, &["--to", "plain", "--lang", lang])
+        .success()
+        .stdout("<Steve> Hi\n");
+    convert(&input, &["--to", "plain"])
+        .success()
+        .stdout("[Translation: chat.type.text]\n");
+    let ansi = convert(&input, &["--to", "ansi", "--lang", lang]).success();
+    let ansi = String::from_utf8_lossy(&ansi.get_output().stdout).into_owned();
+    assert!(ansi.contains("\x1b["), "{ansi:?}");
+}
+
+#[test]
+fn errors() {
+    convert("{text:", &["--to", "json"]).failure();
+    let output = convert(r#""Hi""#, &["--to", "xml"]).failure();
+    let stderr = String::from_utf8_lossy(&output.get_output().stderr).into_owned();
+    assert!(stderr.starts_with("Unknown format xml"), "{stderr}");
+}
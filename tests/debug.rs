@@ -0,0 +1,106 @@
+use text_components::{
+    Modifier, TextComponent,
+    format::{Color, Format},
+    interactivity::{ClickEvent, HoverEvent},
+};
+use uuid::Uuid;
+
+#[test]
+fn format() {
+    assert_eq!(format!("{:?}", Format::new()), "{ }");
+    assert_eq!(format!("{:?}", Format::new().reset()), "{ RESET }");
+    assert_eq!(
+        format!("{:?}", Format::new().color(Color::DarkAqua).bold(true)),
+        "{ color: Dark Aqua, bold }"
+    );
+    let styled = Format::new()
+        .color(Color::Rgb(255, 0, 68))
+        .font("minecraft:uniform")
+        .italic(true)
+        .underlined(false)
+        .obfuscated(true)
+        .shadow_color(128, 16, 32, 48);
+    assert_eq!(
+        format!("{styled:?}"),
+        r#"{ color: #ff0044, font: "minecraft:uniform", italic, obfuscated, shadow_color: #80102030 }"#
+    );
+    assert_eq!(
+        format!("{styled:#?}"),
+        r#"{
+    color: #ff0044,
+    font: "minecraft:uniform",
+    italic,
+    obfuscated,
+    shadow_color: #80102030,
+}"#
+    );
+    // Anything changed from a reset is shown in full
+    assert_eq!(
+        format!("{:?}", Format::new().reset().bold(true)),
+        "{ color: White, bold }"
+    );
+}
+
+#[test]
+fn nested_format() {
+    let component = "Hi".color(Color::Gold).bold(true);
+    assert_eq!(
+        format!("{component:?}"),
+        r#"TextComponent { content: "Hi", format: { color: Gold, bold } }"#
+    );
+    assert_eq!(
+        format!("{component:#?}"),
+        r#"text("Hi") { color: Gold, bold }"#
+    );
+    assert_eq!(
+        format!("{:?}", TextComponent::plain("Hi").reset()),
+        r#"TextComponent { content: "Hi", format: { RESET } }"#
+    );
+}
+
+#[test]
+fn outline() {
+    let component = TextComponent::translated_with(
+        "greeting",
+        [
+            TextComponent::entity_sep_str("@a", " & "),
+            "12".color(Color::Red),
+        ],
+    )
+    .color(Color::Gold)
+    .hover_event(HoverEvent::show_text("Hi".add_child("!".bold(true))))
+    .add_child(
+        "Pig"
+            .click_event(ClickEvent::RunCommand {
+                command: "/tp @s ~ ~ ~".into(),
+            })
+            .hover_event(HoverEvent::show_entity(
+                "minecraft:pig",
+                Uuid::nil(),
+                Some("Babe"),
+            )),
+    );
+    assert_eq!(
+        format!("{component:#?}"),
+        r#"translate(greeting, 2 args) { color: Gold } hover_event: show_text
+  hover_event: text("Hi")
+    text("!") { bold }
+  with[0]: selector(@a)
+    separator: text(" & ")
+  with[1]: text("12") { color: Red }
+  text("Pig") click_event: run_command(/tp @s ~ ~ ~) hover_event: show_entity(minecraft:pig 00000000-0000-0000-0000-000000000000)
+    name: text("Babe")"#
+    );
+    // Each level only adds its indentation
+    let mut deep = TextComponent::plain("leaf");
+    for _ in 0..200 {
+        deep = TextComponent::plain("").add_child(deep);
+    }
+    let outline = format!("{deep:#?}");
+    assert_eq!(outline.lines().count(), 201);
+    let indents = (0..=200).map(|depth| depth * 2).sum::<usize>();
+    assert_eq!(
+        outline.len(),
+        indents + 200 * r#"text("")"#.len() + r#"text("leaf")"#.len() + 200
+    );
+}
@@ -0,0 +1,118 @@
+use text_components::{
+    Modifier, TextComponent,
+    content::{Content, NbtSource},
+    interactivity::{ClickEvent, HoverEvent},
+    translation::TranslatedMessage,
+};
+use uuid::Uuid;
+
+#[test]
+fn click_events() {
+    let events = [
+        (ClickEvent::run_command("/spawn"), "run_command(/spawn)"),
+        (
+            ClickEvent::suggest_command("/msg "),
+            "suggest_command(/msg )",
+        ),
+        (
+            ClickEvent::open_url("https://example.com"),
+            "open_url(https://example.com)",
+        ),
+        (ClickEvent::change_page(3), "change_page(3)"),
+        (
+            ClickEvent::copy_to_clipboard("1234"),
+            "copy_to_clipboard(1234)",
+        ),
+        (
+            ClickEvent::show_dialog("minecraft:server_links"),
+            "show_dialog(minecraft:server_links)",
+        ),
+    ];
+    for (event, expected) in events {
+        assert_eq!(event.to_string(), expected);
+    }
+}
+
+#[test]
+fn hover_events() {
+    assert_eq!(
+        HoverEvent::show_item("minecraft:diamond", Some(3), None::<&str>).to_string(),
+        "show_item(minecraft:diamond x3)"
+    );
+    assert_eq!(
+        HoverEvent::show_item("minecraft:stick", None, Some("[custom_name=\"A\"]")).to_string(),
+        "show_item(minecraft:stick [custom_name=\"A\"])"
+    );
+    assert_eq!(
+        HoverEvent::show_text("Hi ".add_child("there")).to_string(),
+        "show_text(\"Hi there\")"
+    );
+    assert_eq!(
+        HoverEvent::show_entity("minecraft:pig", Uuid::nil(), Some("Babe")).to_string(),
+        "show_entity(minecraft:pig 00000000-0000-0000-0000-000000000000 \"Babe\")"
+    );
+}
+
+#[test]
+fn resolvables() {
+    let content = |component: TextComponent| component.as_resolvable().unwrap().to_string();
+    assert_eq!(
+        content(TextComponent::scoreboard("@s", "kills")),
+        "score(@s, kills)"
+    );
+    assert_eq!(
+        content(TextComponent::entity("@e[type=pig]", None)),
+        "selector(@e[type=pig])"
+    );
+    assert_eq!(
+        content(TextComponent::nbt(
+            "Inventory[0]",
+            NbtSource::entity("@p"),
+            true,
+            None
+        )),
+        "nbt(entity @p Inventory[0] interpret)"
+    );
+    assert_eq!(NbtSource::block(0, 64, 0).to_string(), "block 0 64 0");
+    assert_eq!(
+        NbtSource::storage("minecraft:data").to_string(),
+        "storage minecraft:data"
+    );
+}
+
+#[test]
+fn translated_messages() {
+    assert_eq!(
+        TranslatedMessage::new("gui.done", None).to_string(),
+        "translate(gui.done)"
+    );
+    let message = TextComponent::translated_with("chat.type.text", ["Steve", "Hi"]);
+    assert_eq!(
+        message.as_translation().unwrap().to_string(),
+        "translate(chat.type.text, 2 args)"
+    );
+}
+
+#[test]
+fn describe() {
+    let component = TextComponent::plain("Hello")
+        .add_child(TextComponent::translated_key("gui.done"))
+        .add_child(TextComponent::scoreboard("@s", "kills"))
+        .add_child("!");
+    assert_eq!(
+        component.describe(),
+        "text(\"Hello\") + 3 children [translate, score, text]"
+    );
+    assert_eq!(
+        TextComponent::from(Content::Keybind {
+            keybind: "key.jump".into()
+        })
+        .add_child("")
+        .describe(),
+        "keybind(key.jump) + 1 child [text]"
+    );
+    assert_eq!(
+        TextComponent::sprite("item/diamond_sword").describe(),
+        "object(item/diamond_sword)"
+    );
+}
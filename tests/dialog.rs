@@ -0,0 +1,108 @@
+use text_components::{
+    Modifier,
+    dialog::{AfterAction, Dialog, DialogAction, DialogButton, DialogRef},
+    format::Color,
+    interactivity::ClickEvent,
+};
+
+fn rules() -> Dialog {
+    Dialog::multi_action("Rules".color(Color::Gold))
+        .body("Be nice to each other.")
+        .button(
+            DialogButton::new("Accept")
+                .tooltip("Join the server")
+                .action(DialogAction::RunCommand {
+                    command: "/accept".into(),
+                }),
+        )
+        .button(
+            DialogButton::new("Website")
+                .width(100)
+                .action(DialogAction::OpenUrl {
+                    url: "https://example.com".into(),
+                }),
+        )
+        .can_close_with_escape(false)
+        .after_action(AfterAction::WaitForResponse)
+}
+
+#[test]
+fn ids_stay_strings() {
+    assert_eq!(
+        ClickEvent::show_dialog("minecraft:server_links"),
+        ClickEvent::ShowDialog {
+            dialog: DialogRef::Id("minecraft:server_links".into())
+        }
+    );
+    assert_eq!(
+        ClickEvent::show_dialog(rules()),
+        ClickEvent::ShowDialog {
+            dialog: DialogRef::Inline(Box::new(rules()))
+        }
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json() {
+    use text_components::{TextComponent, resolving::NoResolutor};
+    let component = "Rules".click_event(ClickEvent::show_dialog(rules()));
+    let expected: serde_json::Value = serde_json::from_str(
+        r#"{
+            "text": "Rules",
+            "click_event": {
+                "action": "show_dialog",
+                "dialog": {
+                    "type": "minecraft:multi_action",
+                    "title": {"text": "Rules", "color": "gold"},
+                    "body": [{"type": "minecraft:plain_message", "contents": {"text": "Be nice to each other."}}],
+                    "can_close_with_escape": false,
+                    "after_action": "wait_for_response",
+                    "actions": [
+                        {
+                            "label": {"text": "Accept"},
+                            "tooltip": {"text": "Join the server"},
+                            "action": {"type": "run_command", "command": "/accept"}
+                        },
+                        {
+                            "label": {"text": "Website"},
+                            "width": 100,
+                            "action": {"type": "open_url", "url": "https://example.com"}
+                        }
+                    ]
+                }
+            }
+        }"#,
+    )
+    .unwrap();
+    let json = component.to_json_value(&NoResolutor).unwrap();
+    assert_eq!(json, expected);
+    assert_eq!(TextComponent::from_json_value(json).unwrap(), component);
+
+    let by_id = "Links".click_event(ClickEvent::show_dialog("minecraft:server_links"));
+    assert_eq!(
+        by_id.to_json(&NoResolutor).unwrap(),
+        r#"{"text":"Links","click_event":{"action":"show_dialog","dialog":"minecraft:server_links"}}"#
+    );
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn snbt() {
+    use text_components::{
+        nbt::{NbtBuilder, ToSNBT},
+        resolving::NoResolutor,
+    };
+    let component = "Rules".click_event(ClickEvent::show_dialog(rules()));
+    assert_eq!(
+        component.build(&NoResolutor, NbtBuilder::new()).to_snbt(),
+        concat!(
+            r#"{text:"Rules",click_event:{action:"show_dialog",dialog:{"#,
+            r#"type:"minecraft:multi_action",title:{text:"Rules",color:"gold"},"#,
+            r#"body:[{type:"minecraft:plain_message",contents:"Be nice to each other."}],"#,
+            r#"can_close_with_escape:0b,after_action:"wait_for_response",actions:["#,
+            r#"{label:"Accept",tooltip:"Join the server",action:{type:"run_command",command:"/accept"}},"#,
+            r#"{label:"Website",width:100,action:{type:"open_url",url:"https://example.com"}}]}}}"#,
+        )
+    );
+}
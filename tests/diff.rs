@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use text_components::{
+    Modifier, TextComponent,
+    diff::{ComponentDiff, DiffOp},
+    format::Color,
+    interactivity::ClickEvent,
+};
+
+fn scoreboard(kills: &str, deaths: &str) -> TextComponent {
+    TextComponent::new().add_children::<TextComponent>(vec![
+        "Stats".color(Color::Gold).bold(true),
+        TextComponent::new().add_children::<TextComponent>(vec![
+            "Kills: ".into(),
+            kills.to_string().color(Color::Green),
+        ]),
+        TextComponent::new().add_children::<TextComponent>(vec![
+            "Deaths: ".into(),
+            deaths.to_string().color(Color::Red),
+        ]),
+    ])
+}
+
+fn assert_applies(old: &TextComponent, new: &TextComponent) -> ComponentDiff {
+    let diff = old.diff(new);
+    let mut patched = old.clone();
+    patched.apply_diff(&diff);
+    assert_eq!(&patched, new);
+    diff
+}
+
+#[test]
+fn equal() {
+    assert!(scoreboard("1", "2").diff(&scoreboard("1", "2")).is_empty());
+}
+
+#[test]
+fn deep_text_change() {
+    let diff = assert_applies(&scoreboard("1", "2"), &scoreboard("10", "2"));
+    assert_eq!(diff.ops, vec![DiffOp::SetText(vec![1, 1], "10".into())]);
+}
+
+#[test]
+fn format_and_interactions() {
+    let old = scoreboard("1", "2");
+    let mut new = old.clone();
+    new.children[0] = "Stats".color(Color::Aqua).bold(true);
+    new.children[2] = new.children[2]
+        .clone()
+        .click_event(ClickEvent::run_command("/stats"));
+    let diff = assert_applies(&old, &new);
+    assert_eq!(
+        diff.ops,
+        vec![
+            DiffOp::SetFormat(vec![0], new.children[0].format.clone()),
+            DiffOp::SetInteractions(vec![2], new.children[2].interactions.clone()),
+        ]
+    );
+}
+
+#[test]
+fn children() {
+    let old = scoreboard("1", "2");
+    let mut new = old.clone();
+    new.children.insert(1, "Online: 3".into());
+    let diff = assert_applies(&old, &new);
+    assert_eq!(
+        diff.ops,
+        vec![DiffOp::InsertChild(vec![], 1, "Online: 3".into())]
+    );
+
+    let diff = assert_applies(&new, &old);
+    assert_eq!(diff.ops, vec![DiffOp::RemoveChild(vec![], 1)]);
+
+    let mut new = old.clone();
+    new.children.truncate(1);
+    let diff = assert_applies(&old, &new);
+    assert_eq!(
+        diff.ops,
+        vec![
+            DiffOp::RemoveChild(vec![], 1),
+            DiffOp::RemoveChild(vec![], 1)
+        ]
+    );
+}
+
+#[test]
+fn content_change() {
+    let old = "Player".add_child("!");
+    let new = TextComponent::scoreboard("@s", "kills").add_child("!");
+    let diff = assert_applies(&old, &new);
+    assert_eq!(diff.ops, vec![DiffOp::Replace(vec![], new)]);
+}
+
+#[test]
+fn inline_translations_change() {
+    let old = "Player".add_child("!");
+    let new = old
+        .clone()
+        .with_inline_translations(HashMap::from([("greeting".into(), "Hi %s".into())]));
+    let diff = assert_applies(&old, &new);
+    assert_eq!(diff.ops, vec![DiffOp::Replace(vec![], new)]);
+}
+
+#[test]
+fn missing_paths_are_skipped() {
+    let mut component = TextComponent::plain("a");
+    component.apply_diff(&ComponentDiff {
+        ops: vec![
+            DiffOp::SetText(vec![3], "b".into()),
+            DiffOp::RemoveChild(vec![], 0),
+            DiffOp::InsertChild(vec![], 2, "c".into()),
+        ],
+    });
+    assert_eq!(component, TextComponent::plain("a"));
+}
+
+#[cfg(feature = "test-util")]
+mod properties {
+    use proptest::prelude::*;
+    use text_components::{Modifier, TextComponent, testutil};
+
+    fn check(old: &TextComponent, new: &TextComponent) -> Result<(), TestCaseError> {
+        let mut patched = old.clone();
+        patched.apply_diff(&old.diff(new));
+        prop_assert_eq!(&patched, new);
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn apply_diff(
+            old in testutil::component_with_extras(),
+            new in testutil::component_with_extras(),
+        ) {
+            check(&old, &new)?;
+            prop_assert!(old.diff(&old).is_empty());
+        }
+
+        #[test]
+        fn apply_diff_shared(
+            shared in testutil::component_with_extras(),
+            old in testutil::component_with_extras(),
+            new in testutil::component_with_extras(),
+        ) {
+            let old = shared.clone().add_child(old).add_child(shared.clone());
+            let new = shared.clone().add_child(shared.clone()).add_child(new);
+            check(&old, &new)?;
+            check(&new, &old)?;
+        }
+    }
+}
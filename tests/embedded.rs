@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+use text_components::{
+    Modifier, TextComponent,
+    content::{NbtSource, Resolvable},
+    format::Color,
+    interactivity::HoverEvent,
+    resolving::{TextResolutor, resolve_nbt_values},
+};
+
+fn sword() -> TextComponent {
+    "Sword".color(Color::Aqua).italic(false)
+}
+
+fn hovered(value: TextComponent) -> TextComponent {
+    "Item".hover_event(HoverEvent::show_text(value))
+}
+
+#[test]
+fn from_embedded_str() {
+    assert_eq!(
+        TextComponent::from_embedded_str("{text:'Sword',color:'aqua',italic:0b}").unwrap(),
+        sword()
+    );
+    assert_eq!(
+        TextComponent::from_embedded_str("['a',{text:'b'}]").unwrap(),
+        "a".add_child("b")
+    );
+    assert!(TextComponent::from_embedded_str("{text:").is_err());
+}
+
+#[test]
+fn json_in_snbt() {
+    // The JSON quotes are escaped in a double quoted SNBT string
+    assert_eq!(
+        TextComponent::from_snbt(
+            r#"{text:"Item",hover_event:{action:"show_text",value:"{\"text\":\"Sword\",\"color\":\"aqua\",\"italic\":false}"}}"#
+        )
+        .unwrap(),
+        hovered(sword())
+    );
+    assert_eq!(
+        TextComponent::from_snbt(
+            r#"{text:"Item",hover_event:{action:"show_text",value:'{"text":"It\'s sharp"}'}}"#
+        )
+        .unwrap(),
+        hovered("It's sharp".into())
+    );
+    // Strings that don't hold a component stay as they are
+    for text in ["Sword", "{not a component", "[1, 2]"] {
+        let snbt = format!(r#"{{text:"Item",hover_event:{{action:"show_text",value:"{text}"}}}}"#);
+        assert_eq!(
+            TextComponent::from_snbt(&snbt).unwrap(),
+            hovered(text.into()),
+            "{snbt}"
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn snbt_in_json() {
+    assert_eq!(
+        TextComponent::from_embedded_str(r#"{"text":"Sword","color":"aqua","italic":false}"#)
+            .unwrap(),
+        sword()
+    );
+    assert_eq!(
+        TextComponent::from_json(
+            r#"{"text":"Item","hover_event":{"action":"show_text","value":"{text:'Sword',color:\"aqua\",italic:0b}"}}"#
+        )
+        .unwrap(),
+        hovered(sword())
+    );
+    // JSON escaped inside JSON
+    assert_eq!(
+        TextComponent::from_json(
+            r#"{"text":"Item","hover_event":{"action":"show_text","value":"{\"text\":\"Say \\\"hi\\\"\"}"}}"#
+        )
+        .unwrap(),
+        hovered(r#"Say "hi""#.into())
+    );
+    assert_eq!(
+        TextComponent::from_json(
+            r#"{"text":"Item","hover_event":{"action":"show_text","value":"Sword"}}"#
+        )
+        .unwrap(),
+        hovered("Sword".into())
+    );
+}
+
+/// Finds the given SNBT value for every NBT path.
+struct Value(&'static str);
+impl TextResolutor for Value {
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        resolve_nbt_values(vec![self.0.to_string()], resolvable)
+    }
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, _data: &text_components::custom::CustomData) -> Option<TextComponent> {
+        None
+    }
+    fn translate(&self, _key: &str) -> Option<Cow<'_, str>> {
+        None
+    }
+}
+
+#[test]
+fn interpreted_strings() {
+    let name = TextComponent::nbt(
+        "Item.components.\"minecraft:custom_name\"",
+        NbtSource::entity("@s"),
+        true,
+        None,
+    );
+    assert_eq!(
+        name.resolve(&Value(r#"'{text:"Sword",color:"aqua",italic:0b}'"#)),
+        sword()
+    );
+    #[cfg(feature = "serde")]
+    assert_eq!(
+        name.resolve(&Value(
+            r#"'{"text":"Sword","color":"aqua","italic":false}'"#
+        )),
+        sword()
+    );
+    assert_eq!(name.resolve(&Value(r#""Sword""#)), "Sword".into());
+}
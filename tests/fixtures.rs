@@ -0,0 +1,29 @@
+use text_components::{TextComponent, resolving::NoResolutor, testutil};
+
+#[test]
+fn deterministic() {
+    assert_eq!(testutil::wide_tree(50), testutil::wide_tree(50));
+    assert_eq!(testutil::deep_tree(20), testutil::deep_tree(20));
+    assert_eq!(testutil::resolvable_tree(30), testutil::resolvable_tree(30));
+}
+
+#[test]
+fn shapes() {
+    let wide = testutil::wide_tree(600).stats();
+    assert_eq!(wide.max_depth, 3);
+    assert!((950..1050).contains(&wide.node_count), "{wide:?}");
+    assert!(wide.click_events > 0 && wide.hover_events > 0 && wide.translations > 0);
+
+    let deep = testutil::deep_tree(100).stats();
+    assert!(deep.max_depth > 100, "{deep:?}");
+
+    let resolvables = testutil::resolvable_tree(30);
+    assert_eq!(resolvables.stats().resolvables, 30);
+    assert_eq!(resolvables.resolve(&NoResolutor).stats().resolvables, 0);
+}
+
+#[test]
+fn example_snbt() {
+    let example = TextComponent::from_snbt(testutil::EXAMPLE_SNBT).unwrap();
+    assert_eq!(example.children.len(), 6);
+}
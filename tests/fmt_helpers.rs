@@ -0,0 +1,131 @@
+use std::{
+    borrow::Cow,
+    time::{Duration, SystemTime},
+};
+use text_components::{
+    TextComponent,
+    content::Content,
+    fmt_helpers::{DurationStyle, NumberFormat},
+    resolving::{NoResolutor, TextResolutor},
+};
+
+#[test]
+fn numbers() {
+    let default = NumberFormat::new();
+    let compact = NumberFormat::new().compact(true).decimals(1);
+    let cases: [(f64, NumberFormat, &str); 17] = [
+        (0., default, "0"),
+        (7., default, "7"),
+        (999., default, "999"),
+        (1000., default, "1,000"),
+        (1_234_567., default, "1,234,567"),
+        (-1_234_567., default, "-1,234,567"),
+        (-0.2, default, "0"),
+        (1234.5678, default.decimals(2), "1,234.57"),
+        (
+            1234.5,
+            default
+                .thousands_separator(Some('.'))
+                .decimal_separator(',')
+                .decimals(1),
+            "1.234,5",
+        ),
+        (1_000_000., default.thousands_separator(None), "1000000"),
+        (999., compact, "999.0"),
+        (1234., compact, "1.2k"),
+        (999_999., compact, "1.0M"),
+        (1_234_567., compact.decimals(0), "1M"),
+        (-2_500_000_000., compact, "-2.5B"),
+        (f64::NAN, default, "NaN"),
+        (f64::NEG_INFINITY, default, "-∞"),
+    ];
+    for (n, format, expected) in cases {
+        assert_eq!(
+            TextComponent::number(n, format),
+            TextComponent::plain(expected),
+            "{n}"
+        );
+    }
+    assert_eq!(
+        TextComponent::number(42u8, NumberFormat::default()),
+        TextComponent::plain("42")
+    );
+}
+
+#[test]
+fn durations() {
+    let cases = [
+        (0, "0s", "0 seconds"),
+        (1, "1s", "1 second"),
+        (59, "59s", "59 seconds"),
+        (60, "1m", "1 minute"),
+        (8100, "2h15m", "2 hours 15 minutes"),
+        (3601, "1h1s", "1 hour 1 second"),
+        (90061, "1d1h1m1s", "1 day 1 hour 1 minute 1 second"),
+        (172800, "2d", "2 days"),
+    ];
+    for (seconds, compact, verbose) in cases {
+        let duration = Duration::from_secs(seconds);
+        assert_eq!(
+            TextComponent::duration(duration, DurationStyle::Compact),
+            TextComponent::plain(compact)
+        );
+        assert_eq!(
+            TextComponent::duration(duration, DurationStyle::Verbose).to_plain(&NoResolutor),
+            verbose
+        );
+    }
+}
+
+struct Spanish;
+impl TextResolutor for Spanish {
+    fn resolve_content(&self, resolvable: &text_components::content::Resolvable) -> TextComponent {
+        NoResolutor.resolve_content(resolvable)
+    }
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, data: &text_components::custom::CustomData) -> Option<TextComponent> {
+        NoResolutor.resolve_custom(data)
+    }
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>> {
+        Some(
+            match key {
+                "time.hours" => "%s horas",
+                "time.minutes" => "%s minutos",
+                "time.ago" => "hace %s",
+                "time.now" => "ahora",
+                _ => return None,
+            }
+            .into(),
+        )
+    }
+}
+
+#[test]
+fn relative_times() {
+    let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+    let cases = [
+        (0, "just now", "ahora"),
+        (-30, "just now", "ahora"),
+        (-200, "3 minutes ago", "hace 3 minutos"),
+        (-3600, "1 hour ago", "hace 1 hour"),
+        (-7300, "2 hours ago", "hace 2 horas"),
+        (-86400 * 3, "3 days ago", "hace 3 days"),
+        (120, "in 2 minutes", "in 2 minutes"),
+        (90000, "in 1 day", "in 1 day"),
+    ];
+    for (offset, english, spanish) in cases {
+        let time = now
+            .checked_add(Duration::from_secs(offset.max(0) as u64))
+            .unwrap()
+            - Duration::from_secs((-offset).max(0) as u64);
+        let component = TextComponent::relative_time_from(time, now);
+        assert_eq!(component.to_plain(&NoResolutor), english);
+        assert_eq!(component.to_plain(&Spanish), spanish);
+    }
+    let Content::Translate(message) =
+        TextComponent::relative_time_from(now - Duration::from_secs(200), now).content
+    else {
+        panic!("Relative times are translated");
+    };
+    assert_eq!(message.key, "time.ago");
+}
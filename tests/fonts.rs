@@ -0,0 +1,65 @@
+use text_components::{
+    Modifier, TextComponent,
+    format::{FontError, Format},
+};
+
+#[test]
+fn bare_fonts_are_namespaced() {
+    assert_eq!(
+        Format::new().font("uniform"),
+        Format::new().font("minecraft:uniform")
+    );
+    assert_eq!("a".font("alt").format.font.unwrap(), "minecraft:alt");
+    let mut component = TextComponent::plain("a");
+    (&mut component).font("illageralt");
+    assert_eq!(component.format.font.unwrap(), "minecraft:illageralt");
+}
+
+#[test]
+fn namespaced_fonts_are_kept() {
+    let format = Format::new().font("example:fonts/title");
+    assert_eq!(format.font.unwrap(), "example:fonts/title");
+    assert_eq!(
+        Format::new().try_font("example:fonts/title.v2").unwrap(),
+        Format::new().font("example:fonts/title.v2")
+    );
+    assert_eq!(
+        Format::new().try_font("uniform").unwrap(),
+        Format::new().font("minecraft:uniform")
+    );
+}
+
+#[test]
+fn invalid_fonts() {
+    assert_eq!(
+        Format::new().try_font("example:Fancy"),
+        Err(FontError::InvalidCharacter("example:Fancy".into(), 'F'))
+    );
+    assert_eq!(
+        Format::new().try_font("my font"),
+        Err(FontError::InvalidCharacter("my font".into(), ' '))
+    );
+    assert_eq!(
+        Format::new().try_font("a:b:c"),
+        Err(FontError::InvalidCharacter("a:b:c".into(), ':'))
+    );
+    assert_eq!(
+        Format::new().try_font("example:"),
+        Err(FontError::EmptyPath("example:".into()))
+    );
+    assert_eq!(
+        Format::new()
+            .try_font("Example:title")
+            .unwrap_err()
+            .to_string(),
+        "The font \"Example:title\" has 'E', which can't be in a resource location."
+    );
+}
+
+#[test]
+fn parsed_fonts_are_namespaced() {
+    let parsed = TextComponent::from_snbt(r#"{text:"a",font:"uniform"}"#).unwrap();
+    assert_eq!(parsed, "a".font("minecraft:uniform"));
+    let parsed = TextComponent::from_snbt(r#"{text:"a",font:"example:title"}"#).unwrap();
+    assert_eq!(parsed.format.font.unwrap(), "example:title");
+}
@@ -0,0 +1,456 @@
+//! Renders a fixture of every content and event against [TestResolutor] and compares
+//! it with the files in `tests/golden`, `BLESS=1` writes them, a missing file
+//! fails the test otherwise.
+#[cfg(feature = "custom")]
+use std::borrow::Cow;
+use std::{fs, path::PathBuf};
+#[cfg(feature = "custom")]
+use text_components::custom::{CustomData, Payload};
+use text_components::{
+    Modifier, TextComponent,
+    content::{Atlas, Content, NbtSource, ObjectPlayer, PlayerProperties},
+    format::Color,
+    interactivity::{ClickEvent, HoverEvent},
+    testutil::TestResolutor,
+    translation::TranslatedMessage,
+};
+use uuid::Uuid;
+
+fn resolutor() -> TestResolutor {
+    let resolutor = TestResolutor::new()
+        .score("@s", "kills", 12)
+        .entity("@s", "Steve", Uuid::from_u128(1))
+        .entity("@a", "Steve", Uuid::from_u128(1))
+        .entity("@a", "Alex", Uuid::from_u128(2))
+        .entity_of("@e[type=pig]", "minecraft:pig", "Pig", Uuid::from_u128(3))
+        .nbt(NbtSource::entity("@s"), "Health", ["20.0f"])
+        .nbt(
+            NbtSource::storage("minecraft:messages"),
+            "lines[]",
+            ["\"first\"", "\"second\""],
+        )
+        .nbt(
+            NbtSource::block(0, 64, 0),
+            "CustomName",
+            [r#"{text:"Chest",color:"gold"}"#],
+        )
+        .nbt(
+            NbtSource::entity("@s"),
+            "Message",
+            [r#"{text:"Kills: ",extra:[{score:{name:"@s",objective:"kills"}}]}"#],
+        )
+        .translation("greeting", "Hello %s, you have %s kills!")
+        .translation("swapped", "%2$s before %1$s");
+    #[cfg(feature = "custom")]
+    let resolutor = resolutor
+        .custom("clock", "12:00".color(Color::Aqua))
+        .custom(
+            "kills",
+            TextComponent::translated_with(
+                "greeting",
+                [
+                    TextComponent::entity("@s", None),
+                    TextComponent::scoreboard("@s", "kills"),
+                ],
+            )
+            .add_child(" (custom)"),
+        );
+    resolutor
+}
+
+fn fixtures() -> Vec<(&'static str, TextComponent)> {
+    #[cfg_attr(not(feature = "custom"), allow(unused_mut))]
+    let mut fixtures = vec![
+        ("text", TextComponent::plain("Hello, world!")),
+        (
+            "format",
+            "Styled"
+                .color(Color::Rgb(255, 0, 68))
+                .bold(true)
+                .italic(true)
+                .underlined(true)
+                .strikethrough(true)
+                .shadow_color(255, 0, 0, 0)
+                .font("minecraft:uniform")
+                .add_child(" plain".reset())
+                .add_child(" gold".color(Color::Gold)),
+        ),
+        (
+            "no_inherit",
+            TextComponent::new()
+                .color(Color::Red)
+                .add_child("a".color(Color::Red).no_inherit())
+                .add_child(TextComponent::new().add_children(vec![
+                    " b".bold(true).no_inherit(),
+                    " c".bold(true).no_inherit(),
+                ]))
+                .add_child(" d".color(Color::Red)),
+        ),
+        (
+            "translation",
+            TextComponent::translated_with(
+                "greeting",
+                [
+                    TextComponent::entity("@s", None),
+                    TextComponent::scoreboard("@s", "kills"),
+                ],
+            )
+            .color(Color::Green),
+        ),
+        (
+            "translation_positional",
+            TextComponent::translated_with("swapped", ["one", "two"]),
+        ),
+        (
+            "translation_fallback",
+            TranslatedMessage::new("missing", None).component_fallback("Fallback"),
+        ),
+        (
+            "translation_missing",
+            TextComponent::translated_key("missing"),
+        ),
+        (
+            "keybind",
+            TextComponent::from(Content::Keybind {
+                keybind: "key.jump".into(),
+            }),
+        ),
+        ("atlas", TextComponent::atlas("item/diamond", Atlas::ITEMS)),
+        (
+            "player_head",
+            TextComponent::player_head(ObjectPlayer::name("Steve"), false),
+        ),
+        (
+            "player_head_hat",
+            TextComponent::player_head(ObjectPlayer::name("Alex"), true),
+        ),
+        (
+            "player_head_properties",
+            TextComponent::player_head(
+                ObjectPlayer {
+                    name: Some("Notch".into()),
+                    id: Some([110_536_891, -1_145_291_004, -1_568_298_419, 1_187_539_025]),
+                    texture: None,
+                    properties: vec![PlayerProperties {
+                        name: "textures".into(),
+                        value: "ewogICJ0aW1lc3RhbXAiIDogMCB9".into(),
+                        signature: Some("c2lnbmF0dXJl".into()),
+                    }],
+                },
+                true,
+            ),
+        ),
+        ("score", TextComponent::scoreboard("@s", "kills")),
+        ("score_missing", TextComponent::scoreboard("@s", "deaths")),
+        ("entity", TextComponent::entity("@s", None)),
+        (
+            "entities",
+            TextComponent::entity_sep_str("@a", " & ").add_child(" joined"),
+        ),
+        ("entity_type", TextComponent::entity("@e[type=pig]", None)),
+        ("entity_missing", TextComponent::entity("@r", None)),
+        (
+            "nbt",
+            TextComponent::nbt("Health", NbtSource::entity("@s"), false, None),
+        ),
+        (
+            "nbt_storage",
+            TextComponent::nbt(
+                "lines[]",
+                NbtSource::storage("minecraft:messages"),
+                true,
+                Some(TextComponent::plain(" | ")),
+            ),
+        ),
+        (
+            "nbt_interpret",
+            TextComponent::nbt("CustomName", NbtSource::block(0, 64, 0), true, None),
+        ),
+        (
+            "nbt_nested",
+            TextComponent::nbt("Message", NbtSource::entity("@s"), true, None),
+        ),
+        (
+            "clicks",
+            TextComponent::new()
+                .add_child("url".click_event(ClickEvent::open_url("https://example.com")))
+                .add_child(" run".click_event(ClickEvent::run_command("/say hi")))
+                .add_child(" suggest".click_event(ClickEvent::suggest_command("/msg ")))
+                .add_child(" page".click_event(ClickEvent::change_page(2)))
+                .add_child(" copy".click_event(ClickEvent::copy_to_clipboard("copied")))
+                .add_child(
+                    " dialog".click_event(ClickEvent::show_dialog("minecraft:server_links")),
+                ),
+        ),
+        (
+            "hovers",
+            TextComponent::new()
+                .add_child("text".hover_event(HoverEvent::show_text("Hovered".bold(true))))
+                .add_child(" item".hover_event(HoverEvent::show_item(
+                    "minecraft:diamond_sword",
+                    Some(1),
+                    None::<&str>,
+                )))
+                .add_child(" entity".hover_event(HoverEvent::show_entity(
+                    "minecraft:pig",
+                    Uuid::from_u128(3),
+                    Some("Pig"),
+                )))
+                .insertion("inserted"),
+        ),
+    ];
+    #[cfg(feature = "custom")]
+    fixtures.extend([
+        (
+            "custom",
+            TextComponent::from(CustomData {
+                id: Cow::Borrowed("clock"),
+                payload: Payload::Empty,
+            }),
+        ),
+        (
+            "custom_nested",
+            TextComponent::from(CustomData {
+                id: Cow::Borrowed("kills"),
+                payload: Payload::Empty,
+            }),
+        ),
+        (
+            "custom_missing",
+            TextComponent::from(CustomData {
+                id: Cow::Borrowed("weather"),
+                payload: Payload::Empty,
+            }),
+        ),
+    ]);
+    fixtures
+}
+
+/// Compares the rendering with its golden file, writing it if it's missing or blessing.
+fn check(name: &str, extension: &str, rendered: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{name}.{extension}"));
+    if std::env::var_os("BLESS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, rendered).unwrap();
+        return;
+    }
+    let golden = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "{} is missing, run with BLESS=1 to write it",
+            path.display()
+        )
+    });
+    assert_eq!(
+        rendered,
+        golden,
+        "{} changed, rerun with BLESS=1 if it's expected",
+        path.display()
+    );
+}
+
+#[test]
+fn plain() {
+    let resolutor = resolutor();
+    for (name, component) in fixtures() {
+        check(name, "txt", &component.to_plain(&resolutor));
+    }
+}
+
+#[test]
+fn outline() {
+    for (name, component) in fixtures() {
+        check(name, "outline", &format!("{component:#?}"));
+    }
+}
+
+#[test]
+fn resolve_idempotent() {
+    let resolutor = resolutor();
+    for (name, component) in fixtures() {
+        let resolved = component.resolve(&resolutor);
+        assert_eq!(resolved.resolve(&resolutor), resolved, "{name}");
+    }
+}
+
+#[test]
+fn inheritance_transforms() {
+    #[cfg(feature = "pretty")]
+    colored::control::set_override(true);
+    let resolutor = resolutor();
+    for (name, component) in fixtures() {
+        let baked = component.bake_inheritance();
+        for transformed in [
+            baked.clone(),
+            baked.factor_common_format(),
+            component.factor_common_format(),
+        ] {
+            assert_eq!(
+                transformed.to_plain(&resolutor),
+                component.to_plain(&resolutor),
+                "{name}"
+            );
+            assert_eq!(
+                transformed.flatten_runs(&resolutor),
+                component.flatten_runs(&resolutor),
+                "{name}"
+            );
+            #[cfg(feature = "pretty")]
+            assert_eq!(
+                transformed.to_pretty(&resolutor).to_string(),
+                component.to_pretty(&resolutor).to_string(),
+                "{name}"
+            );
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json() {
+    let resolutor = resolutor();
+    for (name, component) in fixtures() {
+        check(name, "json", &component.to_json(&resolutor).unwrap());
+    }
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn snbt() {
+    use text_components::nbt::{NbtBuilder, ToSNBT};
+    let resolutor = resolutor();
+    for (name, component) in fixtures() {
+        check(
+            name,
+            "snbt",
+            &component.build(&resolutor, NbtBuilder::new()).to_snbt(),
+        );
+    }
+}
+
+/// Every component in the built tag has a content key, its children, arguments, hover
+/// texts and separators too.
+#[cfg(feature = "nbt")]
+#[test]
+fn nbt_contents() {
+    use simdnbt::owned::NbtTag;
+    use text_components::{nbt::NbtBuilder, resolving::BuildTarget};
+
+    fn check_component(tag: &NbtTag, name: &str) {
+        let compound = match tag {
+            NbtTag::Compound(compound) => compound,
+            NbtTag::String(text) => return assert!(!text.is_empty(), "{name}"),
+            NbtTag::List(list) => {
+                return list
+                    .as_nbt_tags()
+                    .iter()
+                    .for_each(|tag| check_component(tag, name));
+            }
+            tag => panic!("{name}: {tag:?}"),
+        };
+        assert!(
+            [
+                "text",
+                "translate",
+                "score",
+                "selector",
+                "keybind",
+                "nbt",
+                "object",
+                "player"
+            ]
+            .iter()
+            .any(|key| compound.get(key).is_some()),
+            "{name}: {compound:?}"
+        );
+        for key in ["extra", "with", "separator"] {
+            if let Some(tag) = compound.get(key) {
+                check_component(tag, name);
+            }
+        }
+        let event = |key| match compound.get(key) {
+            Some(NbtTag::Compound(event)) => Some(event),
+            _ => None,
+        };
+        if let Some(hover) = event("hover_event") {
+            for key in ["value", "name"] {
+                if let Some(tag) = hover.get(key) {
+                    check_component(tag, name);
+                }
+            }
+        }
+    }
+
+    let resolutor = resolutor();
+    for (name, component) in fixtures() {
+        assert_eq!(component.validate(), Ok(()), "{name}");
+        check_component(&component.build(&resolutor, NbtBuilder::new()), name);
+        // Left unresolved too, where custom contents have no vanilla form
+        check_component(
+            &NbtBuilder::new().build_component(&resolutor, &component),
+            name,
+        );
+    }
+}
+
+#[cfg(feature = "pretty")]
+#[test]
+fn pretty() {
+    colored::control::set_override(true);
+    let resolutor = resolutor();
+    for (name, component) in fixtures() {
+        let pretty = component.to_pretty(&resolutor).to_string();
+        check(name, "ansi", &without_hyperlinks(&pretty));
+    }
+}
+
+#[cfg(feature = "pretty")]
+#[test]
+fn pretty_footnotes() {
+    use text_components::fmt::{PrettyOptions, PrettyTextBuilder};
+    colored::control::set_override(true);
+    let component = TextComponent::new()
+        .add_child("Spawn".click_event(ClickEvent::run_command("/spawn")))
+        .add_child(" or ")
+        .add_child(
+            "msg"
+                .color(Color::Gold)
+                .click_event(ClickEvent::suggest_command("/msg Steve "))
+                .hover_event(HoverEvent::show_text(
+                    "Message ".add_child("Steve".bold(true)),
+                )),
+        );
+    let pretty = component
+        .build(&resolutor(), PrettyTextBuilder(PrettyOptions::footnotes()))
+        .to_string();
+    check("footnotes", "ansi", &pretty);
+    assert!(
+        pretty.contains(
+            "\n[1] run_command: /spawn\n[2] suggest_command: /msg Steve \n[3] show_text: "
+        ),
+        "{pretty}"
+    );
+    // Without footnotes nothing is added
+    assert!(
+        !component
+            .to_pretty(&resolutor())
+            .to_string()
+            .contains("[1]")
+    );
+}
+
+/// Links are only written when the terminal supports them.
+#[cfg(feature = "pretty")]
+fn without_hyperlinks(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("\x1b]8;;") {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest.find("\x1b\\").map_or(rest.len(), |end| end + 2);
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
@@ -0,0 +1,200 @@
+use text_components::{
+    Modifier, TextComponent,
+    format::{Color, Format},
+    resolving::NoResolutor,
+};
+
+#[test]
+fn bake() {
+    let component = "Hi "
+        .color(Color::Red)
+        .add_child("there".bold(true).add_child("!".color(Color::Gold)))
+        .add_child(TextComponent::translated_with("key", ["arg"]).italic(true));
+    let red = Format::new().color(Color::Red);
+    assert_eq!(
+        component.bake_inheritance(),
+        "Hi "
+            .color(Color::Red)
+            .add_child(
+                "there"
+                    .color(Color::Red)
+                    .bold(true)
+                    .add_child("!".color(Color::Gold).bold(true))
+            )
+            .add_child(
+                TextComponent::translated_with("key", ["arg".color(Color::Red).italic(true)])
+                    .color(Color::Red)
+                    .italic(true)
+            )
+    );
+    assert_eq!(component.bake_inheritance().format, red);
+}
+
+#[test]
+fn factor() {
+    let component = TextComponent::new().add_children(vec![
+        "Hi ".color(Color::Red).bold(false),
+        "there".color(Color::Red).bold(true),
+    ]);
+    assert_eq!(
+        component.factor_common_format(),
+        TextComponent::new()
+            .color(Color::Red)
+            .add_children(vec!["Hi ".bold(false), "there".bold(true)])
+    );
+    // A parent with its own text would change if it took the color
+    let component = "Hi ".add_child("there".color(Color::Red));
+    assert_eq!(component.factor_common_format(), component);
+    // Values the children would inherit anyway are left out
+    let component = "Hi ".color(Color::Red).add_child("there".color(Color::Red));
+    assert_eq!(
+        component.factor_common_format(),
+        "Hi ".color(Color::Red).add_child("there")
+    );
+    assert_eq!(
+        component.bake_inheritance().factor_common_format(),
+        component.factor_common_format()
+    );
+}
+
+#[test]
+fn no_inherit_takes_nothing_from_the_parent() {
+    let parent = Format::new().color(Color::Red).bold(true).font("uniform");
+    assert_eq!(Format::reset_marker().mix(&parent), Format::reset_marker());
+    let own = Format::reset_marker().italic(true);
+    assert_eq!(own.mix(&parent), own);
+    let mut format = own.clone();
+    format.mix_in_place(&parent);
+    assert_eq!(format, own);
+    // The values are defaults for the children too, not the ones of the grandparent
+    assert_eq!(Format::new().mix(&own.mix(&parent)).bold, None);
+    // Styles keep the values already set
+    assert_eq!(
+        "a".bold(true).style(Format::reset_marker()).format,
+        Format::new().bold(true).no_inherit()
+    );
+}
+
+#[test]
+fn reset_sets_every_value() {
+    let component = "a".reset();
+    assert_eq!(component.format.color, Some(Color::White));
+    assert_eq!(component.format.bold, Some(false));
+    assert_eq!(component.format.shadow_color, None);
+    assert!(!component.format.no_inherit);
+    // Explicit values win in a mix, the shadow color is still inherited
+    let parent = Format::new().color(Color::Red).shadow_color(255, 1, 2, 3);
+    let mixed = component.format.mix(&parent);
+    assert_eq!(mixed.color, Some(Color::White));
+    assert_eq!(mixed.shadow_color, parent.shadow_color);
+}
+
+#[test]
+fn expand_writes_only_the_hidden_values() {
+    let component = TextComponent::new()
+        .color(Color::Red)
+        .bold(true)
+        .add_child("a".no_inherit())
+        .add_child("b".no_inherit().color(Color::Gold).add_child("c"));
+    assert_eq!(
+        component.expand_no_inherit(),
+        TextComponent::new()
+            .color(Color::Red)
+            .bold(true)
+            .add_child("a".color(Color::White).bold(false))
+            .add_child("b".color(Color::Gold).bold(false).add_child("c"))
+    );
+    assert_eq!(
+        component.resolve(&NoResolutor),
+        component.expand_no_inherit()
+    );
+    // Nothing to hide at the root
+    assert_eq!(
+        "a".no_inherit().italic(true).expand_no_inherit(),
+        "a".italic(true)
+    );
+    // Defaults set by the parent need nothing either
+    let component = "a".color(Color::White).add_child("b".no_inherit());
+    assert_eq!(
+        component.expand_no_inherit(),
+        "a".color(Color::White).add_child("b")
+    );
+}
+
+#[test]
+fn factor_keeps_no_inherit() {
+    let component = TextComponent::new()
+        .color(Color::Red)
+        .add_child("a".color(Color::Red).no_inherit())
+        .add_child(TextComponent::new().add_children(vec![
+            "b".bold(true).no_inherit(),
+            "c".bold(true).no_inherit(),
+        ]))
+        .add_child("d".color(Color::Red).bold(true));
+    let factored = component.factor_common_format();
+    assert_eq!(
+        factored,
+        TextComponent::new()
+            .color(Color::Red)
+            .add_child("a".color(Color::Red).no_inherit())
+            .add_child(TextComponent::new().add_children(vec![
+                "b".bold(true).no_inherit(),
+                "c".bold(true).no_inherit(),
+            ]))
+            .add_child("d".bold(true))
+    );
+    assert_eq!(
+        factored.resolve(&NoResolutor).flatten_runs(&NoResolutor),
+        component.resolve(&NoResolutor).flatten_runs(&NoResolutor)
+    );
+}
+
+#[test]
+fn expand_default_shadow() {
+    let white = Format::default_shadow_color(Color::White);
+    assert_eq!(white, Format::parse_shadow_color(0xFF, 0x3F, 0x3F, 0x3F));
+    let component = "a".shadow_color(255, 200, 0, 0).add_child(
+        "b".no_inherit()
+            .add_child("c")
+            .add_child("d".color(Color::Gold)),
+    );
+    let expanded = component.expand_no_inherit();
+    let b = &expanded.children[0];
+    assert_eq!(b.format.shadow_color, Some(white));
+    assert_eq!(b.children[0].format.shadow_color, None);
+    // The shadow of a default color follows the color
+    assert_eq!(
+        b.children[1].format.shadow_color,
+        Some(Format::parse_shadow_color(0xFF, 0x3F, 0x2A, 0x00))
+    );
+    // Without a shadow to hide nothing is written
+    let component = "a".add_child("b".no_inherit().add_child("c".color(Color::Gold)));
+    assert_eq!(
+        component.expand_no_inherit(),
+        "a".add_child("b".add_child("c".color(Color::Gold)))
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn no_inherit_is_smaller_than_reset() {
+    let reset = "Error: "
+        .color(Color::Red)
+        .add_child("details".reset())
+        .to_json(&NoResolutor)
+        .unwrap();
+    let no_inherit = "Error: "
+        .color(Color::Red)
+        .add_child("details".no_inherit())
+        .to_json(&NoResolutor)
+        .unwrap();
+    assert_eq!(
+        no_inherit,
+        r#"{"text":"Error: ","extra":[{"text":"details","color":"white"}],"color":"red"}"#
+    );
+    assert_eq!(no_inherit.len(), 77);
+    assert_eq!(reset.len(), 192);
+    // Only what the parent sets is written
+    let plain = "details".no_inherit().to_json(&NoResolutor).unwrap();
+    assert_eq!(plain, r#"{"text":"details"}"#);
+}
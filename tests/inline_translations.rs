@@ -0,0 +1,117 @@
+use std::{borrow::Cow, collections::HashMap};
+use text_components::{
+    Modifier, TextComponent,
+    content::{Content, Resolvable},
+    resolving::TextResolutor,
+    translation::TranslatedMessage,
+};
+
+/// Only knows the plugin's farewell and the vanilla chat format.
+struct Manager;
+impl TextResolutor for Manager {
+    fn resolve_content(&self, _resolvable: &Resolvable) -> TextComponent {
+        TextComponent::new()
+    }
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, _data: &text_components::custom::CustomData) -> Option<TextComponent> {
+        None
+    }
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>> {
+        match key {
+            "plugin.farewell" => Some(Cow::Borrowed("Bye %s")),
+            "plugin.greeting" => Some(Cow::Borrowed("Hi %s")),
+            _ => None,
+        }
+    }
+}
+
+fn message(key: &'static str) -> TextComponent {
+    TranslatedMessage::new(key, Some(Box::new(["Steve".into()]))).component()
+}
+
+fn inline() -> HashMap<Cow<'static, str>, Cow<'static, str>> {
+    HashMap::from([
+        (
+            Cow::Borrowed("plugin.welcome"),
+            Cow::Borrowed("Welcome %s!"),
+        ),
+        (Cow::Borrowed("plugin.greeting"), Cow::Borrowed("Hello %s!")),
+    ])
+}
+
+#[test]
+fn only_inline() {
+    let component = message("plugin.welcome").with_inline_translations(inline());
+    assert_eq!(component.to_plain(&Manager), "Welcome Steve!");
+    assert_eq!(
+        message("plugin.welcome").to_plain(&Manager),
+        "[Translation: plugin.welcome]"
+    );
+}
+
+#[test]
+fn only_in_manager() {
+    let component = message("plugin.farewell").with_inline_translations(inline());
+    assert_eq!(component.to_plain(&Manager), "Bye Steve");
+}
+
+#[test]
+fn inline_wins() {
+    let component = message("plugin.greeting").with_inline_translations(inline());
+    assert_eq!(component.to_plain(&Manager), "Hello Steve!");
+    assert_eq!(message("plugin.greeting").to_plain(&Manager), "Hi Steve");
+}
+
+#[test]
+fn nested_keys_use_the_root_table() {
+    let component = TextComponent::new()
+        .add_children::<TextComponent>(vec![
+            message("plugin.welcome"),
+            " ".into(),
+            message("plugin.farewell").with_inline_translations(HashMap::from([(
+                Cow::Borrowed("plugin.farewell"),
+                Cow::Borrowed("Ignored %s"),
+            )])),
+        ])
+        .with_inline_translations(inline());
+    assert_eq!(component.to_plain(&Manager), "Welcome Steve! Bye Steve");
+}
+
+#[test]
+fn extending_the_table() {
+    let component = message("plugin.welcome")
+        .with_inline_translations(inline())
+        .with_inline_translations(HashMap::from([(
+            Cow::Borrowed("plugin.welcome"),
+            Cow::Borrowed("Hey %s"),
+        )]));
+    let table = component.inline_translations.as_ref().unwrap();
+    assert_eq!(table.len(), 2);
+    assert_eq!(component.to_plain(&Manager), "Hey Steve");
+}
+
+#[test]
+fn resolve_writes_fallbacks() {
+    let component = TextComponent::plain("> ")
+        .add_child(message("plugin.welcome"))
+        .with_inline_translations(inline());
+    let resolved = component.resolve(&Manager);
+    let Content::Translate(message) = &resolved.children[0].content else {
+        panic!("{resolved:?}");
+    };
+    assert_eq!(message.fallback.as_deref(), Some("Welcome %s!"));
+    assert_eq!(resolved.inline_translations, component.inline_translations);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn not_serialized() {
+    let component = message("plugin.welcome").with_inline_translations(inline());
+    let json = serde_json::to_value(&component).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({"translate": "plugin.welcome", "with": [{"text": "Steve"}]})
+    );
+    let json = serde_json::to_value(component.resolve(&Manager)).unwrap();
+    assert_eq!(json["fallback"], "Welcome %s!");
+}
@@ -0,0 +1,239 @@
+use text_components::{
+    Modifier, TextComponent,
+    content::NbtSource,
+    format::Color,
+    interactivity::{ClickEvent, HoverEvent, UrlError},
+};
+use uuid::Uuid;
+
+fn url(click: ClickEvent) -> String {
+    match click {
+        ClickEvent::OpenUrl { url } => url.into_owned(),
+        click => panic!("{click:?} isn't an open_url event"),
+    }
+}
+
+#[test]
+fn try_open_url() {
+    for (input, expected) in [
+        ("https://example.com", "https://example.com"),
+        ("HTTP://example.com/a?b=c#d", "http://example.com/a?b=c#d"),
+        (" https://example.com/ ", "https://example.com/"),
+        ("https://example.com/a page", "https://example.com/a%20page"),
+        (
+            "https://example.com/\"<x>\"",
+            "https://example.com/%22%3Cx%3E%22",
+        ),
+        ("https://example.com/é", "https://example.com/%C3%A9"),
+        ("https://example.com/a%20b", "https://example.com/a%20b"),
+    ] {
+        assert_eq!(url(ClickEvent::try_open_url(input).unwrap()), expected);
+    }
+    for (input, error) in [
+        (
+            "javascript:alert(1)",
+            UrlError::UnsupportedScheme("javascript".into()),
+        ),
+        (
+            "file:///etc/passwd",
+            UrlError::UnsupportedScheme("file".into()),
+        ),
+        ("example.com", UrlError::UnsupportedScheme("".into())),
+        ("https://", UrlError::MissingHost),
+        ("https:///path", UrlError::MissingHost),
+        ("https://example.com/\na", UrlError::ControlCharacter('\n')),
+        (
+            "https://example.com/\u{7f}",
+            UrlError::ControlCharacter('\u{7f}'),
+        ),
+    ] {
+        assert_eq!(ClickEvent::try_open_url(input), Err(error), "{input}");
+    }
+}
+
+#[test]
+fn validate() {
+    assert_eq!(
+        ClickEvent::open_url("https://example.com").validate(),
+        Ok(())
+    );
+    assert_eq!(
+        ClickEvent::open_url("https://example.com/a page").validate(),
+        Err(UrlError::IllegalCharacter(' '))
+    );
+    assert_eq!(
+        ClickEvent::open_url("ftp://example.com").validate(),
+        Err(UrlError::UnsupportedScheme("ftp".into()))
+    );
+    assert_eq!(ClickEvent::run_command("/help").validate(), Ok(()));
+}
+
+#[test]
+fn open_url_display() {
+    assert_eq!(
+        ClickEvent::open_url_display("https://minecraft.net", "the website"),
+        "the website"
+            .color(Color::Blue)
+            .underlined(true)
+            .hover_event(HoverEvent::show_text("https://minecraft.net"))
+            .click_event(ClickEvent::open_url("https://minecraft.net"))
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn every_event_has_a_constructor() {
+    use text_components::interactivity::{ClickAction, HoverAction};
+    use uuid::Uuid;
+    let clicks = [
+        (
+            ClickEvent::open_url("https://example.com"),
+            ClickAction::OpenUrl,
+            r#"{"action":"open_url","url":"https://example.com"}"#,
+        ),
+        (
+            ClickEvent::run_command("/spawn"),
+            ClickAction::RunCommand,
+            r#"{"action":"run_command","command":"/spawn"}"#,
+        ),
+        (
+            ClickEvent::suggest_command("/msg "),
+            ClickAction::SuggestCommand,
+            r#"{"action":"suggest_command","command":"/msg "}"#,
+        ),
+        (
+            ClickEvent::change_page(2),
+            ClickAction::ChangePage,
+            r#"{"action":"change_page","page":2}"#,
+        ),
+        (
+            ClickEvent::copy_to_clipboard("1234"),
+            ClickAction::CopyToClipboard,
+            r#"{"action":"copy_to_clipboard","value":"1234"}"#,
+        ),
+        (
+            ClickEvent::show_dialog("minecraft:server_links"),
+            ClickAction::ShowDialog,
+            r#"{"action":"show_dialog","dialog":"minecraft:server_links"}"#,
+        ),
+        #[cfg(feature = "custom")]
+        (
+            ClickEvent::custom("shop:buy", text_components::custom::Payload::Empty),
+            ClickAction::Custom,
+            r#"{"action":"custom","id":"shop:buy"}"#,
+        ),
+    ];
+    for (click, action, json) in clicks {
+        assert_eq!(click.action(), action);
+        assert_eq!(serde_json::to_string(&click).unwrap(), json);
+    }
+
+    let uuid = Uuid::from_u128(1);
+    let hovers = [
+        (
+            HoverEvent::show_text("Hi"),
+            HoverAction::ShowText,
+            r#"{"action":"show_text","value":{"text":"Hi"}}"#,
+        ),
+        (
+            HoverEvent::show_item("minecraft:stone", Some(2), None::<&str>),
+            HoverAction::ShowItem,
+            r#"{"action":"show_item","id":"minecraft:stone","count":2}"#,
+        ),
+        (
+            HoverEvent::show_entity("minecraft:pig", uuid, None::<&str>),
+            HoverAction::ShowEntity,
+            r#"{"action":"show_entity","id":"minecraft:pig","uuid":"00000000-0000-0000-0000-000000000001"}"#,
+        ),
+        (
+            HoverEvent::show_entity_named("minecraft:pig", uuid, "Babe".color(Color::Gold)),
+            HoverAction::ShowEntity,
+            r#"{"action":"show_entity","name":{"text":"Babe","color":"gold"},"id":"minecraft:pig","uuid":"00000000-0000-0000-0000-000000000001"}"#,
+        ),
+    ];
+    for (hover, action, json) in hovers {
+        assert_eq!(hover.action(), action);
+        assert_eq!(serde_json::to_string(&hover).unwrap(), json);
+    }
+}
+
+/// A component with a click and a hover event in every place one can be.
+fn hidden_events() -> TextComponent {
+    let run = |text: &'static str, command: &'static str| {
+        text.click_event(ClickEvent::run_command(command))
+            .hover_event(HoverEvent::show_text(text))
+    };
+    let separator = run(", ", "/separator");
+    run("root", "/root")
+        .add_child(run("child", "/child").add_child(run("grandchild", "/grandchild")))
+        .add_child(TextComponent::translated_with(
+            "key",
+            [run("arg", "/arg").click_event(ClickEvent::suggest_command("/arg"))],
+        ))
+        .add_child(TextComponent::entity("@a", Some(separator.clone())))
+        .add_child(TextComponent::nbt(
+            "Health",
+            NbtSource::entity("@p"),
+            false,
+            Some(separator),
+        ))
+        .add_child("hover".hover_event(HoverEvent::show_text(
+            run("text", "/text").add_child(run("text child", "/text_child")),
+        )))
+        .add_child(
+            "entity"
+                .click_event(ClickEvent::open_url("https://example.com"))
+                .hover_event(HoverEvent::show_entity_named(
+                    "minecraft:pig",
+                    Uuid::nil(),
+                    run("name", "/name"),
+                )),
+        )
+}
+
+#[test]
+fn map_events_everywhere() {
+    let mut component = hidden_events();
+    let mut clicks = 0;
+    component.map_click_events(|_| clicks += 1);
+    assert_eq!(clicks, 10);
+    let mut hovers = 0;
+    component.map_hover_events(|_| hovers += 1);
+    assert_eq!(hovers, 11);
+
+    // The texts of hovers replaced by f are visited
+    component.map_hover_events(|hover| {
+        if let HoverEvent::ShowEntity { name: Some(_), .. } = hover {
+            *hover = HoverEvent::show_text("pig".hover_event(HoverEvent::show_text("!")));
+        }
+    });
+    let mut hovers = 0;
+    component.map_hover_events(|_| hovers += 1);
+    assert_eq!(hovers, 11);
+}
+
+#[test]
+fn rewrite_commands() {
+    let mut component = hidden_events();
+    let mut rewritten = vec![];
+    component.rewrite_commands(|command| {
+        rewritten.push(command.to_string());
+        format!("/server lobby:{}", &command[1..])
+    });
+    assert_eq!(rewritten.len(), 9);
+    assert!(rewritten.contains(&String::from("/text_child")));
+    let mut commands = vec![];
+    component.map_click_events(|click| match click {
+        ClickEvent::RunCommand { command } | ClickEvent::SuggestCommand { command } => {
+            commands.push(command.to_string())
+        }
+        click => assert_eq!(*click, ClickEvent::open_url("https://example.com")),
+    });
+    assert_eq!(commands.len(), 9);
+    assert!(
+        commands
+            .iter()
+            .all(|command| command.starts_with("/server lobby:"))
+    );
+    assert!(commands.contains(&String::from("/server lobby:separator")));
+}
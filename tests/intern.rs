@@ -0,0 +1,58 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicIsize, Ordering},
+};
+use text_components::{
+    TextComponent,
+    intern::{ComponentInterner, SharedStr},
+};
+
+/// Keeps track of the bytes allocated and not freed yet.
+struct Counting;
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        LIVE.fetch_add(layout.size() as isize, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE.fetch_sub(layout.size() as isize, Ordering::Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+const SNBT: &str = r#"{translate:"multiplayer.player.joined.with.a.long.custom.key",font:"example:fonts/announcements",insertion:"/msg Steve hello there",with:[{text:"Steve",font:"example:fonts/announcements",insertion:"/msg Steve hello there"}]}"#;
+
+fn parse_many(parse: impl Fn(&str) -> TextComponent) -> (Vec<TextComponent>, isize) {
+    let before = LIVE.load(Ordering::Relaxed);
+    let components = (0..10_000).map(|_| parse(SNBT)).collect::<Vec<_>>();
+    (components, LIVE.load(Ordering::Relaxed) - before)
+}
+
+// A single test so no other one allocates while measuring
+#[test]
+fn interning() {
+    let (plain, plain_bytes) = parse_many(|snbt| TextComponent::from_snbt(snbt).unwrap());
+    let interner = ComponentInterner::new();
+    let (interned, interned_bytes) =
+        parse_many(|snbt| TextComponent::from_snbt_interned(snbt, &interner).unwrap());
+
+    assert_eq!(plain, interned);
+    assert_eq!(interner.len(), 3);
+    let font = interned[0].format.font.as_ref().unwrap();
+    assert!(font.is_shared());
+    assert_eq!(font, "example:fonts/announcements");
+    assert!(!plain[0].format.font.as_ref().unwrap().is_shared());
+
+    // About 7.9MB without interning and 6MB with it, the rest is the components themselves
+    println!("{plain_bytes} bytes without interning, {interned_bytes} with it");
+    assert!(interned_bytes < plain_bytes * 4 / 5);
+
+    let shared = interner.intern("example:fonts/announcements");
+    assert_eq!(&shared, font);
+    assert_eq!(SharedStr::from("example:fonts/announcements"), shared);
+}
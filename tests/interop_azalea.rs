@@ -0,0 +1,137 @@
+use azalea_chat::{FormattedText, click_event::ClickEvent as AzaleaClick};
+use serde_json::Value;
+use text_components::{
+    Modifier, TextComponent,
+    content::Atlas,
+    format::Color,
+    interactivity::{ClickEvent, HoverEvent},
+    interop::{InteropError, azalea},
+    resolving::NoResolutor,
+    translation::TranslatedMessage,
+};
+
+/// Components azalea can hold without losing anything.
+fn common() -> Vec<TextComponent> {
+    vec![
+        "Hello".color(Color::Red).bold(true),
+        TextComponent::plain("Parent")
+            .underlined(true)
+            .add_children(vec!["first".bold(false), "second".strikethrough(true)]),
+        TranslatedMessage::new(
+            "death.attack.player",
+            Some(Box::new(["Steve".color(Color::Gold)])),
+        )
+        .component()
+        .obfuscated(false),
+        "Click"
+            .click_event(ClickEvent::run_command("/help"))
+            .hover_event(HoverEvent::show_text("Runs /help".color(Color::Gray)))
+            .insertion("/help"),
+        "Font"
+            .font("minecraft:uniform")
+            .shadow_color(0xFF, 0x10, 0x20, 0x30),
+        "Url".click_event(ClickEvent::open_url("https://www.minecraft.net/")),
+    ]
+}
+
+#[test]
+fn json_matches_for_the_common_subset() {
+    for component in common() {
+        let azalea = FormattedText::try_from(&component).unwrap();
+        let mut ours = serde_json::to_value(&component).unwrap();
+        let mut theirs = serde_json::to_value(&azalea).unwrap();
+        // Azalea writes hex colors in uppercase
+        lowercase_colors(&mut ours);
+        lowercase_colors(&mut theirs);
+        assert_eq!(theirs, ours, "{component:?}");
+    }
+}
+
+#[test]
+fn round_trips_the_common_subset() {
+    let hex = "Hex".color(Color::Rgb(0xAB, 0xCD, 0xEF)).italic(false);
+    for component in common().into_iter().chain([hex]) {
+        let azalea = FormattedText::try_from(&component).unwrap();
+        assert_eq!(TextComponent::try_from(azalea), Ok(component));
+    }
+}
+
+#[test]
+fn unsupported_values_are_errors() {
+    let score = "Score: ".add_child(TextComponent::scoreboard("@s", "kills"));
+    assert_eq!(
+        FormattedText::try_from(&score),
+        Err(InteropError::Content("score"))
+    );
+    let item = "Item".hover_event(HoverEvent::show_item(
+        "minecraft:diamond",
+        Some(2),
+        None::<&str>,
+    ));
+    assert_eq!(
+        FormattedText::try_from(&item),
+        Err(InteropError::HoverEvent("show_item"))
+    );
+    let nested = "Hover".hover_event(HoverEvent::show_text("Dialog".click_event(
+        ClickEvent::ShowDialog {
+            dialog: "minecraft:server_links".into(),
+        },
+    )));
+    assert_eq!(
+        FormattedText::try_from(&nested),
+        Err(InteropError::ClickEvent("show_dialog"))
+    );
+
+    let mut file = azalea::to_lossy(&"File".into());
+    file.get_base_mut().style.click_event = Some(AzaleaClick::OpenFile {
+        path: "logs/latest.log".to_string(),
+    });
+    assert_eq!(
+        TextComponent::try_from(&file),
+        Err(InteropError::ClickEvent("open_file"))
+    );
+}
+
+#[test]
+fn lossy_contents_fall_back_to_placeholders() {
+    let score = TextComponent::scoreboard("@s", "kills");
+    let azalea = azalea::to_lossy(&"Score: ".add_child(score.clone()));
+    assert_eq!(
+        serde_json::to_value(&azalea).unwrap(),
+        serde_json::json!({"text": "Score: ", "extra": [score.to_plain(&NoResolutor)]})
+    );
+
+    let sprite = TextComponent::atlas("item/diamond", Atlas::ITEMS);
+    let back = azalea::from_lossy(&azalea::to_lossy(&sprite.clone().color(Color::Aqua)));
+    assert_eq!(back, sprite.to_plain(&NoResolutor).color(Color::Aqua));
+}
+
+#[test]
+fn lossy_events_are_dropped() {
+    let component = "Item"
+        .hover_event(HoverEvent::show_item(
+            "minecraft:diamond",
+            Some(2),
+            None::<&str>,
+        ))
+        .click_event(ClickEvent::ShowDialog {
+            dialog: "minecraft:server_links".into(),
+        });
+    let back = azalea::from_lossy(&azalea::to_lossy(&component));
+    assert_eq!(back, TextComponent::plain("Item"));
+}
+
+fn lowercase_colors(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match value {
+                    Value::String(color) if key == "color" => *color = color.to_lowercase(),
+                    value => lowercase_colors(value),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(lowercase_colors),
+        _ => {}
+    }
+}
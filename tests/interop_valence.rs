@@ -0,0 +1,148 @@
+use serde_json::Value;
+use text_components::{
+    Modifier, TextComponent,
+    content::{Atlas, Content, NbtSource},
+    format::Color,
+    interactivity::{ClickEvent, HoverEvent},
+    interop::{InteropError, valence},
+    resolving::NoResolutor,
+    translation::TranslatedMessage,
+};
+use valence_text::{ClickEvent as ValenceClick, Color as ValenceColor, Text};
+
+/// Components valence can hold without losing anything, and whose JSON doesn't
+/// depend on the 1.20.1 event and key names it writes.\
+/// Valence doesn't write plain children as bare strings, so every child is styled.
+fn common() -> Vec<TextComponent> {
+    vec![
+        "Hello".color(Color::Red).bold(true),
+        TextComponent::plain("Parent")
+            .underlined(true)
+            .add_children(vec!["first".bold(false), "second".strikethrough(true)]),
+        TranslatedMessage::new(
+            "death.attack.player",
+            Some(Box::new(["Steve".color(Color::Gold)])),
+        )
+        .component()
+        .obfuscated(false),
+        TextComponent {
+            content: Content::Keybind {
+                keybind: "key.jump".into(),
+            },
+            ..TextComponent::new()
+        },
+        TextComponent::scoreboard("@s", "kills"),
+        TextComponent::entity("@e[type=pig]", Some(" | ".into())),
+        TextComponent::nbt("Health", NbtSource::entity("@p"), false, None),
+        TextComponent::nbt("Items", NbtSource::block(1, 64, -3), true, None),
+        TextComponent::nbt("quests", NbtSource::storage("minecraft:data"), false, None),
+        "Font".font("minecraft:alt"),
+    ]
+}
+
+#[test]
+fn json_matches_for_the_common_subset() {
+    for component in common() {
+        let valence = Text::try_from(&component).unwrap();
+        let ours = serde_json::to_value(&component).unwrap();
+        let theirs = serde_json::to_value(&valence).unwrap();
+        assert_eq!(theirs, ours, "{component:?}");
+    }
+}
+
+#[test]
+fn round_trips() {
+    let events = vec![
+        "Click"
+            .click_event(ClickEvent::run_command("/help"))
+            .hover_event(HoverEvent::show_text("Runs /help".color(Color::Gray)))
+            .insertion("/help"),
+        "Item".hover_event(HoverEvent::show_item(
+            "minecraft:diamond",
+            Some(2),
+            Some("{Damage:3}"),
+        )),
+        "Entity".hover_event(HoverEvent::show_entity(
+            "minecraft:pig",
+            uuid::Uuid::nil(),
+            Some("Pig"),
+        )),
+    ];
+    let hex = "Hex".color(Color::Rgb(0xAB, 0xCD, 0xEF)).italic(false);
+    for component in common().into_iter().chain(events).chain([hex]) {
+        let valence = Text::try_from(&component).unwrap();
+        assert_eq!(TextComponent::try_from(valence), Ok(component));
+    }
+}
+
+#[test]
+fn unsupported_values_are_errors() {
+    let sprite = "Sprite: ".add_child(TextComponent::atlas("item/diamond", Atlas::ITEMS));
+    assert_eq!(
+        Text::try_from(&sprite),
+        Err(InteropError::Content("object"))
+    );
+    let storage = TextComponent::nbt("x", NbtSource::storage("Not An Id"), false, None);
+    assert_eq!(
+        Text::try_from(&storage),
+        Err(InteropError::Value("NBT storage"))
+    );
+    let shadow = "Shadow".shadow_color(0xFF, 0, 0, 0);
+    assert_eq!(
+        Text::try_from(&shadow),
+        Err(InteropError::Value("shadow color"))
+    );
+    let dialog = "Dialog".click_event(ClickEvent::ShowDialog {
+        dialog: "minecraft:server_links".into(),
+    });
+    assert_eq!(
+        Text::try_from(&dialog),
+        Err(InteropError::ClickEvent("show_dialog"))
+    );
+
+    let mut reset = Text::text("Reset");
+    reset.color = Some(ValenceColor::Reset);
+    assert_eq!(
+        TextComponent::try_from(&reset),
+        Err(InteropError::Value("reset color"))
+    );
+    let mut file = Text::text("File");
+    file.click_event = Some(ValenceClick::OpenFile("logs/latest.log".into()));
+    let mut parent = Text::text("Parent");
+    parent.extra.push(file);
+    assert_eq!(
+        TextComponent::try_from(parent),
+        Err(InteropError::ClickEvent("open_file"))
+    );
+}
+
+#[test]
+fn lossy_contents_fall_back_to_placeholders() {
+    let sprite = TextComponent::atlas("item/diamond", Atlas::ITEMS);
+    let valence = valence::to_lossy(&sprite.clone().color(Color::Aqua));
+    assert_eq!(
+        serde_json::to_value(&valence).unwrap(),
+        serde_json::json!({"text": sprite.to_plain(&NoResolutor), "color": "aqua"})
+    );
+
+    let storage = TextComponent::nbt("x", NbtSource::storage("Not An Id"), false, None);
+    let back = valence::from_lossy(&valence::to_lossy(&storage));
+    assert_eq!(back, TextComponent::plain(storage.to_plain(&NoResolutor)));
+}
+
+#[test]
+fn lossy_style_is_dropped() {
+    let component = "Shadow"
+        .font("example:runes")
+        .shadow_color(0xFF, 0, 0, 0)
+        .click_event(ClickEvent::ShowDialog {
+            dialog: "minecraft:server_links".into(),
+        });
+    let valence = valence::to_lossy(&component);
+    assert_eq!(
+        valence::from_lossy(&valence),
+        TextComponent::plain("Shadow")
+    );
+    let text: Value = serde_json::to_value(&valence).unwrap();
+    assert_eq!(text, serde_json::json!({"text": "Shadow"}));
+}
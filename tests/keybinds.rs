@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+use text_components::{
+    Modifier, TextComponent,
+    content::{Content, Resolvable},
+    resolving::{NoResolutor, TextResolutor},
+};
+
+/// Knows the binding of `key.jump` and the names of `key.jump` and `key.sneak`.
+struct Keys;
+impl TextResolutor for Keys {
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        NoResolutor.resolve_content(resolvable)
+    }
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, _data: &text_components::custom::CustomData) -> Option<TextComponent> {
+        None
+    }
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>> {
+        match key {
+            "key.jump" => Some(Cow::Borrowed("Jump")),
+            "key.sneak" => Some(Cow::Borrowed("Sneak %s 100%")),
+            _ => None,
+        }
+    }
+    fn keybind(&self, keybind: &str) -> Option<Cow<'_, str>> {
+        (keybind == "key.jump").then_some(Cow::Borrowed("Space"))
+    }
+}
+
+fn keybind(keybind: &'static str) -> TextComponent {
+    TextComponent::from(Content::Keybind {
+        keybind: keybind.into(),
+    })
+}
+
+#[test]
+fn bound_key_then_translation_then_placeholder() {
+    let line = TextComponent::plain("").add_children::<TextComponent>(vec![
+        keybind("key.jump"),
+        " ".into(),
+        keybind("key.sneak"),
+        " ".into(),
+        keybind("key.chat"),
+    ]);
+    assert_eq!(
+        line.to_plain(&Keys),
+        "Space Sneak %s 100% [Keybind: key.chat]"
+    );
+    assert_eq!(
+        line.to_plain(&NoResolutor),
+        "[Keybind: key.jump] [Keybind: key.sneak] [Keybind: key.chat]"
+    );
+}
+
+#[cfg(feature = "pretty")]
+#[test]
+fn pretty() {
+    colored::control::set_override(false);
+    assert_eq!(
+        keybind("key.sneak").to_pretty(&Keys).to_string(),
+        "Sneak %s 100%"
+    );
+    assert_eq!(
+        keybind("key.chat").to_pretty(&Keys).to_string(),
+        "[Keybind: key.chat]"
+    );
+}
@@ -0,0 +1,116 @@
+use text_components::{Modifier, TextComponent, content::Content, translation::TranslatedMessage};
+
+fn message() -> TextComponent {
+    TextComponent::translated(TranslatedMessage::new(
+        "chat.type.text",
+        Some(Box::new([
+            TextComponent::plain("Steve"),
+            "Hi".bold(true),
+            TextComponent::plain(""),
+        ])),
+    ))
+}
+
+fn keybind(keybind: &'static str) -> TextComponent {
+    TextComponent {
+        content: Content::Keybind {
+            keybind: keybind.into(),
+        },
+        ..TextComponent::new()
+    }
+}
+
+#[test]
+fn snbt_forms() {
+    for snbt in [
+        r#"{translate:"chat.type.text",with:["Steve",{text:"Hi",bold:true},""]}"#,
+        r#"{translate:"chat.type.text",with:['Steve',{text:"Hi",bold:1b},{text:""}]}"#,
+    ] {
+        assert_eq!(TextComponent::from_snbt(snbt).unwrap(), message(), "{snbt}");
+    }
+    for snbt in [r#"{keybind:"jump"}"#, r#"{keybind:"key.jump"}"#] {
+        assert_eq!(
+            TextComponent::from_snbt(snbt).unwrap(),
+            keybind("key.jump"),
+            "{snbt}"
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_forms() {
+    use text_components::serde::borrowed::TextComponentDe;
+    let parse = |json: &str| {
+        let owned = serde_json::from_str::<TextComponent>(json).unwrap();
+        let borrowed = serde_json::from_str::<TextComponentDe>(json).unwrap();
+        assert_eq!(owned, borrowed.into_owned(), "{json}");
+        owned
+    };
+    for json in [
+        r#"{"translate":"chat.type.text","with":["Steve",{"text":"Hi","bold":true},""]}"#,
+        r#"{"translate":"chat.type.text","with":[{"text":"Steve"},{"text":"Hi","bold":true},{"text":""}]}"#,
+    ] {
+        assert_eq!(parse(json), message(), "{json}");
+    }
+    // The string arguments are written back as components
+    assert_eq!(
+        serde_json::to_string(&message()).unwrap(),
+        r#"{"translate":"chat.type.text","with":[{"text":"Steve"},{"text":"Hi","bold":true},{"text":""}]}"#
+    );
+    // Modded keybinds already have a namespace
+    for (json, expected) in [
+        (r#"{"keybind":"jump"}"#, "key.jump"),
+        (r#"{"keybind":"key.jump"}"#, "key.jump"),
+        (r#"{"keybind":"key.mod.dash"}"#, "key.mod.dash"),
+    ] {
+        assert_eq!(parse(json), keybind(expected), "{json}");
+    }
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn nbt_forms() {
+    use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+    let translate = |with: NbtList| {
+        NbtTag::Compound(NbtCompound::from_values(vec![
+            ("translate".into(), NbtTag::String("chat.type.text".into())),
+            ("with".into(), NbtTag::List(with)),
+        ]))
+    };
+    let plain =
+        |text: &str| NbtCompound::from_values(vec![("".into(), NbtTag::String(text.into()))]);
+    let bold = NbtCompound::from_values(vec![
+        ("text".into(), NbtTag::String("Hi".into())),
+        ("bold".into(), NbtTag::Byte(1)),
+    ]);
+    // Lists with strings and compounds wrap the strings in a compound with an empty key
+    assert_eq!(
+        TextComponent::from_nbt(&translate(NbtList::Compound(vec![
+            plain("Steve"),
+            bold,
+            plain(""),
+        ]))),
+        Some(message())
+    );
+    assert_eq!(
+        TextComponent::from_nbt(&translate(NbtList::String(
+            vec!["Steve".into(), "".into(),]
+        ))),
+        Some(TextComponent::translated(TranslatedMessage::new(
+            "chat.type.text",
+            Some(Box::new([
+                TextComponent::plain("Steve"),
+                TextComponent::plain("")
+            ])),
+        )))
+    );
+    let keybind_tag = NbtTag::Compound(NbtCompound::from_values(vec![(
+        "keybind".into(),
+        NbtTag::String("jump".into()),
+    )]));
+    assert_eq!(
+        TextComponent::from_nbt(&keybind_tag),
+        Some(keybind("key.jump"))
+    );
+}
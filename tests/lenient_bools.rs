@@ -0,0 +1,74 @@
+use text_components::{
+    Modifier, TextComponent,
+    content::{NbtSource, ObjectPlayer},
+    serde::borrowed::TextComponentDe,
+};
+
+fn parse(json: &str) -> TextComponent {
+    let component = TextComponent::from_json(json).unwrap();
+    let borrowed = serde_json::from_str::<TextComponentDe>(json).unwrap();
+    assert_eq!(borrowed.into_owned(), component, "{json}");
+    component
+}
+
+/// Booleans as other tools leave them after converting NBT to JSON.
+#[test]
+fn real_world_snippets() {
+    for (json, expected) in [
+        (r#"{"text":"a","bold":1}"#, "a".bold(true)),
+        (r#"{"text":"a","bold":0}"#, "a".bold(false)),
+        (r#"{"text":"a","italic":"true"}"#, "a".italic(true)),
+        (r#"{"text":"a","italic":"false"}"#, "a".italic(false)),
+        (r#"{"text":"a","underlined":"1b"}"#, "a".underlined(true)),
+        (
+            r#"{"text":"a","strikethrough":"0b"}"#,
+            "a".strikethrough(false),
+        ),
+        (r#"{"text":"a","obfuscated":1.0}"#, "a".obfuscated(true)),
+        (r#"{"text":"a","bold":" true "}"#, "a".bold(true)),
+        (r#"{"text":"a","bold":null}"#, TextComponent::plain("a")),
+        (
+            r#"{"text":"Hi","bold":1,"italic":0,"extra":[{"text":"!","underlined":"true"}]}"#,
+            "Hi".bold(true)
+                .italic(false)
+                .add_child("!".underlined(true)),
+        ),
+        (
+            r#"{"object":"player","player":{"name":"Jeb_"},"hat":0}"#,
+            TextComponent::player_head(ObjectPlayer::name("Jeb_"), false),
+        ),
+        (
+            r#"{"object":"player","player":{"name":"Jeb_"},"hat":"0b"}"#,
+            TextComponent::player_head(ObjectPlayer::name("Jeb_"), false),
+        ),
+        (
+            r#"{"object":"player","player":{"name":"Jeb_"},"hat":1}"#,
+            TextComponent::player_head(ObjectPlayer::name("Jeb_"), true),
+        ),
+        (
+            r#"{"nbt":"Health","entity":"@p","interpret":1}"#,
+            TextComponent::nbt("Health", NbtSource::entity("@p"), true, None),
+        ),
+    ] {
+        assert_eq!(parse(json), expected, "{json}");
+    }
+}
+
+#[test]
+fn rejected_shapes() {
+    for json in [
+        r#"{"text":"a","bold":"yes"}"#,
+        r#"{"text":"a","bold":"2"}"#,
+        r#"{"text":"a","bold":""}"#,
+        r#"{"text":"a","bold":[]}"#,
+        r#"{"text":"a","bold":{}}"#,
+        r#"{"object":"player","player":{"name":"Jeb_"},"hat":"maybe"}"#,
+        r#"{"nbt":"Health","entity":"@p","interpret":[1]}"#,
+    ] {
+        assert!(TextComponent::from_json(json).is_err(), "{json}");
+        assert!(
+            serde_json::from_str::<TextComponentDe>(json).is_err(),
+            "{json}"
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use text_components::{
+    Modifier, TextComponent,
+    content::NbtSource,
+    dialog::Dialog,
+    format::Color,
+    interactivity::{ClickEvent, HoverEvent},
+    translation::TranslatedMessage,
+};
+use uuid::Uuid;
+
+#[test]
+fn minimal_tags() {
+    let component = "Hello "
+        .color(Color::Red)
+        .add_child("World".bold(true))
+        .add_child(" again".color(Color::Red));
+    assert_eq!(
+        component.to_minimessage(),
+        "<red>Hello <bold>World</bold> again</red>"
+    );
+}
+
+#[test]
+fn nesting_order() {
+    let component = "a"
+        .color(Color::Rgb(0x12, 0xAB, 0xEF))
+        .italic(true)
+        .add_child("b".italic(false).add_child("c".underlined(true)))
+        .add_child("d".font("minecraft:uniform"));
+    assert_eq!(
+        component.to_minimessage(),
+        "<#12abef><italic>a<!italic>b<underlined>c</underlined></!italic><font:minecraft:uniform>d</font></italic></#12abef>"
+    );
+}
+
+#[test]
+fn lowercase_hex() {
+    let component = "x"
+        .color(Color::Rgb(0xAB, 0xCD, 0xEF))
+        .shadow_color(0xCC, 0xAB, 0xCD, 0xEF);
+    assert_eq!(
+        component.to_minimessage(),
+        "<#abcdef><shadow:#abcdefcc>x</shadow></#abcdef>"
+    );
+}
+
+#[test]
+fn escapes() {
+    assert_eq!(
+        TextComponent::plain(r"<red> is not a tag, \ either").to_minimessage(),
+        r"\<red> is not a tag, \\ either"
+    );
+}
+
+#[test]
+fn events() {
+    let component = "Click"
+        .click_event(ClickEvent::run_command("/say hi"))
+        .hover_event(HoverEvent::show_text("It's <safe>".color(Color::Gold)))
+        .insertion("hello");
+    assert_eq!(
+        component.to_minimessage(),
+        r"<insert:hello><click:run_command:'/say hi'><hover:show_text:'<gold>It\'s \<safe></gold>'>Click</hover></click></insert>"
+    );
+    let entity = "Notch".hover_event(HoverEvent::show_entity(
+        "minecraft:player",
+        Uuid::nil(),
+        None::<TextComponent>,
+    ));
+    assert_eq!(
+        entity.to_minimessage(),
+        "<hover:show_entity:'minecraft:player':00000000-0000-0000-0000-000000000000>Notch</hover>"
+    );
+    // Children inherit the events, so they aren't opened again
+    let inherited = "a"
+        .click_event(ClickEvent::change_page(2))
+        .add_child("b".click_event(ClickEvent::change_page(2)));
+    assert_eq!(
+        inherited.to_minimessage(),
+        "<click:change_page:2>ab</click>"
+    );
+}
+
+#[test]
+fn dialogs() {
+    let id = "Rules".click_event(ClickEvent::show_dialog("server:rules"));
+    assert_eq!(
+        id.to_minimessage(),
+        "<click:show_dialog:'server:rules'>Rules</click>"
+    );
+    // MiniMessage can't hold inline dialogs, the tag is left out
+    let inline = "Rules"
+        .color(Color::Gold)
+        .click_event(ClickEvent::show_dialog(Dialog::notice("Rules")));
+    assert_eq!(inline.to_minimessage(), "<gold>Rules</gold>");
+}
+
+#[test]
+fn translations() {
+    let message = TranslatedMessage::new(
+        "chat.type.text",
+        Some(Box::new(["Steve".color(Color::Yellow), "Hi!".into()])),
+    );
+    assert_eq!(
+        message
+            .clone()
+            .component()
+            .color(Color::Yellow)
+            .to_minimessage(),
+        "<yellow><lang:chat.type.text:Steve:Hi!></yellow>"
+    );
+    assert_eq!(
+        message.component_fallback("<Steve> Hi!").to_minimessage(),
+        "<lang_or:chat.type.text:'<Steve> Hi!':'<yellow>Steve</yellow>':Hi!>"
+    );
+}
+
+#[test]
+fn resolvables() {
+    assert_eq!(
+        TextComponent::scoreboard("@s", "kills").to_minimessage(),
+        "<score:@s:kills>"
+    );
+    assert_eq!(
+        TextComponent::entity("@a", None).to_minimessage(),
+        "<selector:@a>"
+    );
+    assert_eq!(
+        TextComponent::nbt("Health", NbtSource::Entity("@s".into()), true, None).to_minimessage(),
+        "<nbt:entity:@s:Health:', ':interpret>"
+    );
+}
+
+#[test]
+fn placeholders() {
+    assert_eq!(
+        TextComponent::sprite("item/diamond").to_minimessage(),
+        "[Object: item/diamond]"
+    );
+}
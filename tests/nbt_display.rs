@@ -0,0 +1,122 @@
+use simdnbt::owned::{BaseNbt, Nbt, NbtCompound, NbtList, NbtTag};
+use text_components::{Modifier, TextComponent, format::Color, nbt::NbtDisplayStyle};
+
+fn compound(values: Vec<(&str, NbtTag)>) -> NbtCompound {
+    NbtCompound::from_values(
+        values
+            .into_iter()
+            .map(|(name, tag)| (name.into(), tag))
+            .collect(),
+    )
+}
+
+fn string(text: &'static str) -> TextComponent {
+    "\"".add_children(vec![text.color(Color::Green), "\"".into()])
+}
+
+#[test]
+fn scalars() {
+    assert_eq!(
+        TextComponent::nbt_tag_display(&NbtTag::Byte(3)),
+        "3".color(Color::Gold).add_child("b".color(Color::Red))
+    );
+    assert_eq!(
+        TextComponent::nbt_tag_display(&NbtTag::Int(-7)),
+        "-7".color(Color::Gold)
+    );
+    assert_eq!(
+        TextComponent::nbt_tag_display(&NbtTag::Double(1.5)),
+        "1.5".color(Color::Gold).add_child("d".color(Color::Red))
+    );
+    assert_eq!(
+        TextComponent::nbt_tag_display(&NbtTag::String("say \"hi\"".into())),
+        string("say \\\"hi\\\"")
+    );
+}
+
+#[test]
+fn compounds() {
+    let nbt = Nbt::Some(BaseNbt::new(
+        "",
+        compound(vec![
+            ("id", NbtTag::String("minecraft:pig".into())),
+            ("Health", NbtTag::Float(10.0)),
+        ]),
+    ));
+    let entry = |key: &'static str, value: TextComponent| {
+        TextComponent::new().add_children(vec![key.color(Color::Aqua), ": ".into(), value])
+    };
+    assert_eq!(
+        TextComponent::nbt_display(&nbt),
+        "{".color(Color::White).add_children(vec![
+            entry("id", string("minecraft:pig")),
+            ", ".into(),
+            entry(
+                "Health",
+                "10.0".color(Color::Gold).add_child("f".color(Color::Red))
+            ),
+            "}".into(),
+        ])
+    );
+    assert_eq!(TextComponent::nbt_display(&Nbt::None), TextComponent::new());
+}
+
+#[test]
+fn multiline() {
+    let style = NbtDisplayStyle {
+        inline_threshold: Some(1),
+        ..Default::default()
+    };
+    let tag = NbtTag::List(NbtList::Int(vec![1, 2]));
+    assert_eq!(
+        TextComponent::nbt_tag_display_with(&tag, &style),
+        "[".color(Color::White).add_children(vec![
+            "\n  ".into(),
+            "1".color(Color::Gold),
+            ",".into(),
+            "\n  ".into(),
+            "2".color(Color::Gold),
+            "\n".into(),
+            "]".into(),
+        ])
+    );
+}
+
+#[test]
+fn truncation() {
+    let style = NbtDisplayStyle {
+        max_depth: Some(1),
+        max_length: Some(2),
+        ..Default::default()
+    };
+    let tag = NbtTag::List(NbtList::Compound(vec![
+        compound(vec![("a", NbtTag::Int(1))]),
+        compound(vec![("b", NbtTag::Int(2))]),
+        compound(vec![("c", NbtTag::Int(3))]),
+    ]));
+    let nested = || "{...}".color(Color::White);
+    assert_eq!(
+        TextComponent::nbt_tag_display_with(&tag, &style),
+        "[".color(Color::White).add_children(vec![
+            nested(),
+            ", ".into(),
+            nested(),
+            ", ".into(),
+            "...".into(),
+            "]".into(),
+        ])
+    );
+    assert_eq!(
+        TextComponent::nbt_tag_display_with(&NbtTag::IntArray(vec![1, 2, 3]), &style),
+        "[".color(Color::White).add_children(vec![
+            "I".color(Color::Red),
+            "; ".into(),
+            "1".color(Color::Gold),
+            ", ".into(),
+            "2".color(Color::Gold),
+            ", ".into(),
+            "...".into(),
+            "]".into(),
+        ])
+    );
+}
@@ -0,0 +1,377 @@
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use text_components::{
+    Modifier, TextComponent,
+    content::ObjectPlayer,
+    format::{Color, Format},
+    interactivity::{ClickEvent, HoverEvent},
+    parse::nbt::NbtParseError,
+};
+
+fn compound(values: Vec<(&str, NbtTag)>) -> NbtTag {
+    NbtTag::Compound(NbtCompound::from_values(
+        values
+            .into_iter()
+            .map(|(name, tag)| (name.into(), tag))
+            .collect(),
+    ))
+}
+
+fn string(text: &str) -> NbtTag {
+    NbtTag::String(text.into())
+}
+
+fn at(path: &str, error: NbtParseError) -> NbtParseError {
+    NbtParseError::At(path.to_string(), Box::new(error))
+}
+
+#[test]
+fn not_components() {
+    assert_eq!(
+        TextComponent::try_from_nbt(&string("")),
+        Err(NbtParseError::MissingContent)
+    );
+    assert_eq!(
+        TextComponent::try_from_nbt(&compound(vec![("bold", NbtTag::Byte(1))])),
+        Err(NbtParseError::MissingContent)
+    );
+    assert_eq!(
+        TextComponent::try_from_nbt(&NbtTag::Int(3)),
+        Err(NbtParseError::WrongType("string, list or compound", "int"))
+    );
+    assert_eq!(TextComponent::from_nbt(&NbtTag::Int(3)), None);
+}
+
+#[test]
+fn paths() {
+    let child = |color: &str| {
+        NbtCompound::from_values(vec![
+            ("text".into(), string("b")),
+            ("color".into(), string(color)),
+        ])
+    };
+    let tag = |color: &str| {
+        compound(vec![
+            ("text", string("Hi")),
+            (
+                "extra",
+                NbtTag::List(NbtList::Compound(vec![
+                    NbtCompound::from_values(vec![("".into(), string("a"))]),
+                    child(color),
+                ])),
+            ),
+        ])
+    };
+    assert_eq!(
+        TextComponent::try_from_nbt(&tag("blue")),
+        Ok("Hi".add_children(vec!["a".into(), "b".color(Color::Blue)]))
+    );
+    let error = TextComponent::try_from_nbt(&tag("bleu")).unwrap_err();
+    assert_eq!(
+        error,
+        at("extra[1].color", NbtParseError::InvalidColor("bleu".into()))
+    );
+    assert_eq!(
+        error.to_string(),
+        "The color \"bleu\" can't be parsed. (At extra[1].color)"
+    );
+}
+
+#[test]
+fn wrong_types() {
+    let text = |key: &str, tag: NbtTag| compound(vec![("text", string("a")), (key, tag)]);
+    assert_eq!(
+        TextComponent::try_from_nbt(&text("bold", string("yes"))),
+        Err(at("bold", NbtParseError::WrongType("byte", "string")))
+    );
+    assert_eq!(
+        TextComponent::try_from_nbt(&text("extra", string("b"))),
+        Err(at("extra", NbtParseError::WrongType("list", "string")))
+    );
+    assert_eq!(
+        TextComponent::try_from_nbt(&compound(vec![("text", NbtTag::Byte(1))])),
+        Err(at("text", NbtParseError::WrongType("string", "byte")))
+    );
+    // Booleans may still be strings
+    assert_eq!(
+        TextComponent::try_from_nbt(&text("bold", string("true"))),
+        Ok("a".bold(true))
+    );
+}
+
+#[test]
+fn required_keys() {
+    let score = compound(vec![("score", compound(vec![("name", string("@s"))]))]);
+    assert_eq!(
+        TextComponent::try_from_nbt(&score),
+        Err(at(
+            "score",
+            NbtParseError::Required("Scoreboards".into(), "objective".into())
+        ))
+    );
+    let click = compound(vec![
+        ("text", string("a")),
+        (
+            "click_event",
+            compound(vec![("action", string("run_command"))]),
+        ),
+    ]);
+    assert_eq!(
+        TextComponent::try_from_nbt(&click),
+        Err(at(
+            "click_event",
+            NbtParseError::Required("\"run_command\"".into(), "command".into())
+        ))
+    );
+}
+
+#[test]
+fn events() {
+    let hover = |uuid: NbtTag| {
+        compound(vec![
+            ("text", string("a")),
+            (
+                "hover_event",
+                compound(vec![
+                    ("action", string("show_entity")),
+                    ("id", string("minecraft:pig")),
+                    ("uuid", uuid),
+                ]),
+            ),
+        ])
+    };
+    assert_eq!(
+        TextComponent::try_from_nbt(&hover(NbtTag::IntArray(vec![1, 2, 3]))),
+        Err(at(
+            "hover_event.uuid",
+            NbtParseError::InvalidUuid("of 3 ints".into())
+        ))
+    );
+    assert_eq!(
+        TextComponent::try_from_nbt(&hover(string("pig"))),
+        Err(at(
+            "hover_event.uuid",
+            NbtParseError::InvalidUuid("pig".into())
+        ))
+    );
+    assert!(TextComponent::try_from_nbt(&hover(NbtTag::IntArray(vec![1, 2, 3, 4]))).is_ok());
+
+    let click = compound(vec![
+        ("text", string("a")),
+        (
+            "click_event",
+            compound(vec![
+                ("action", string("open_file")),
+                ("path", string("a.txt")),
+            ]),
+        ),
+    ]);
+    assert_eq!(
+        TextComponent::try_from_nbt(&click),
+        Err(at(
+            "click_event.action",
+            NbtParseError::UnknownValue("open_file".into())
+        ))
+    );
+
+    let item = compound(vec![
+        ("text", string("a")),
+        (
+            "hover_event",
+            compound(vec![
+                ("action", string("show_item")),
+                ("id", string("minecraft:diamond")),
+                (
+                    "components",
+                    compound(vec![("scale", NbtTag::Float(f32::NAN))]),
+                ),
+            ]),
+        ),
+    ]);
+    assert_eq!(
+        TextComponent::try_from_nbt(&item),
+        Err(at(
+            "hover_event.components",
+            NbtParseError::NonFiniteNumber("The float NaN can't be written as SNBT.".into())
+        ))
+    );
+    assert_eq!(
+        TextComponent::from_nbt(&item),
+        Some("a".hover_event(HoverEvent::show_item(
+            "minecraft:diamond",
+            None,
+            None::<&str>
+        )))
+    );
+}
+
+#[test]
+fn lenient_forms() {
+    // Empty children are left out and empty arguments kept as empty texts
+    let tag = compound(vec![
+        ("translate", string("chat.type.text")),
+        ("with", NbtTag::List(NbtList::String(vec!["".into()]))),
+        (
+            "extra",
+            NbtTag::List(NbtList::String(vec!["".into(), "!".into()])),
+        ),
+        ("color", string("red")),
+    ]);
+    assert_eq!(
+        TextComponent::try_from_nbt(&tag),
+        Ok(TextComponent::translated_with("chat.type.text", [""])
+            .add_child("!")
+            .color(Color::Red))
+    );
+}
+
+#[test]
+fn from_nbt_skips() {
+    // What try_from_nbt reports, from_nbt leaves out like it always did
+    let text = |key: &str, tag: NbtTag| compound(vec![("text", string("a")), (key, tag)]);
+    for tag in [
+        text("bold", string("yes")),
+        text("extra", string("b")),
+        text("color", string("bleu")),
+        text("shadow_color", string("black")),
+        text("insertion", NbtTag::Int(1)),
+        text("click_event", string("/spawn")),
+        text(
+            "click_event",
+            compound(vec![
+                ("action", string("open_file")),
+                ("path", string("a.txt")),
+            ]),
+        ),
+        text(
+            "hover_event",
+            compound(vec![
+                ("action", string("show_entity")),
+                ("id", string("minecraft:pig")),
+                ("uuid", string("pig")),
+            ]),
+        ),
+        text("extra", NbtTag::List(NbtList::Int(vec![1]))),
+    ] {
+        assert!(TextComponent::try_from_nbt(&tag).is_err(), "{tag:?}");
+        assert_eq!(
+            TextComponent::from_nbt(&tag),
+            Some(TextComponent::plain("a")),
+            "{tag:?}"
+        );
+    }
+    let translation = compound(vec![
+        ("translate", string("chat.type.text")),
+        ("with", NbtTag::List(NbtList::Int(vec![1, 2]))),
+        ("fallback", NbtTag::Byte(0)),
+    ]);
+    assert_eq!(
+        TextComponent::from_nbt(&translation),
+        Some(TextComponent::translated_with(
+            "chat.type.text",
+            [TextComponent::new(), TextComponent::new()]
+        ))
+    );
+    // Without any content it still isn't a component
+    assert_eq!(
+        TextComponent::from_nbt(&compound(vec![("text", NbtTag::Byte(1))])),
+        None
+    );
+}
+
+/// The number as each numeric tag, floats with a fraction truncated away.
+fn numbers(n: i8) -> [NbtTag; 6] {
+    let float = n as f32 + 0.5 * n.signum() as f32;
+    [
+        NbtTag::Byte(n),
+        NbtTag::Short(n as i16),
+        NbtTag::Int(n as i32),
+        NbtTag::Long(n as i64),
+        NbtTag::Float(float),
+        NbtTag::Double(float as f64),
+    ]
+}
+
+#[test]
+fn numeric_types() {
+    let event = |key: &str, values: Vec<(&str, NbtTag)>| {
+        compound(vec![("text", string("a")), (key, compound(values))])
+    };
+    for number in numbers(2) {
+        let item = event(
+            "hover_event",
+            vec![
+                ("action", string("show_item")),
+                ("id", string("minecraft:stone")),
+                ("count", number.clone()),
+            ],
+        );
+        assert_eq!(
+            TextComponent::try_from_nbt(&item),
+            Ok("a".hover_event(HoverEvent::show_item(
+                "minecraft:stone",
+                Some(2),
+                None::<&str>
+            )))
+        );
+        let page = event(
+            "click_event",
+            vec![("action", string("change_page")), ("page", number)],
+        );
+        assert_eq!(
+            TextComponent::try_from_nbt(&page),
+            Ok("a".click_event(ClickEvent::ChangePage { page: 2 }))
+        );
+    }
+    for number in numbers(0) {
+        let head = compound(vec![("player", string("Jeb_")), ("hat", number.clone())]);
+        assert_eq!(
+            TextComponent::try_from_nbt(&head),
+            Ok(TextComponent::player_head(
+                ObjectPlayer::name("Jeb_"),
+                false
+            ))
+        );
+        let bold = compound(vec![("text", string("a")), ("bold", number)]);
+        assert_eq!(TextComponent::try_from_nbt(&bold), Ok("a".bold(false)));
+    }
+    for number in numbers(-1) {
+        let shadow = compound(vec![("text", string("a")), ("shadow_color", number)]);
+        let mut expected = TextComponent::plain("a");
+        expected.format.shadow_color = Some(Format::packed_shadow_color(-1));
+        assert_eq!(TextComponent::try_from_nbt(&shadow), Ok(expected));
+    }
+    for id in [
+        NbtTag::IntArray(vec![1, 2, 3, 4]),
+        NbtTag::List(NbtList::Byte(vec![1, 2, 3, 4])),
+        NbtTag::List(NbtList::Short(vec![1, 2, 3, 4])),
+        NbtTag::List(NbtList::Int(vec![1, 2, 3, 4])),
+        NbtTag::List(NbtList::Long(vec![1, 2, 3, 4])),
+        NbtTag::List(NbtList::Float(vec![1.5, 2., 3., 4.])),
+        NbtTag::List(NbtList::Double(vec![1.5, 2., 3., 4.])),
+    ] {
+        let head = compound(vec![("player", compound(vec![("id", id)]))]);
+        let player = ObjectPlayer {
+            id: Some([1, 2, 3, 4]),
+            ..ObjectPlayer::empty()
+        };
+        assert_eq!(
+            TextComponent::try_from_nbt(&head),
+            Ok(TextComponent::player_head(player, true))
+        );
+    }
+    // Numbers that don't fit still aren't ints
+    let page = event(
+        "click_event",
+        vec![
+            ("action", string("change_page")),
+            ("page", NbtTag::Long(1 << 40)),
+        ],
+    );
+    assert_eq!(
+        TextComponent::try_from_nbt(&page),
+        Err(at(
+            "click_event.page",
+            NbtParseError::WrongType("int", "long")
+        ))
+    );
+}
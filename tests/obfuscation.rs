@@ -0,0 +1,57 @@
+use text_components::fmt::obfuscation::{Charset, obfuscate_with};
+
+const SAMPLES: [&str; 4] = [
+    "Hello World!",
+    "  leading and trailing  ",
+    "tabs\tand\nnew lines",
+    "ünïcödé 文字 and emoji 🎉",
+];
+
+fn assert_same_shape(text: &str, obfuscated: &str) {
+    assert_eq!(text.chars().count(), obfuscated.chars().count());
+    for (original, replaced) in text.chars().zip(obfuscated.chars()) {
+        assert_eq!(original.is_whitespace(), replaced.is_whitespace());
+        if original.is_whitespace() {
+            assert_eq!(original, replaced);
+        }
+    }
+}
+
+#[test]
+fn keeps_length_and_whitespace() {
+    let mut rng = rand::rng();
+    for text in SAMPLES {
+        for charset in [Charset::Full, Charset::Ascii] {
+            assert_same_shape(text, &obfuscate_with(text, charset, &mut rng));
+        }
+    }
+}
+
+#[test]
+fn ascii_only() {
+    let mut rng = rand::rng();
+    for text in SAMPLES {
+        let obfuscated = obfuscate_with(text, Charset::Ascii, &mut rng);
+        assert!(obfuscated.is_ascii(), "{obfuscated:?} isn't ascii");
+    }
+}
+
+#[test]
+fn pretty_builder_charset() {
+    use text_components::{
+        Modifier,
+        fmt::{PrettyOptions, PrettyTextBuilder},
+        resolving::NoResolutor,
+    };
+    let options = PrettyOptions {
+        charset: Charset::Ascii,
+        ..Default::default()
+    };
+    let secret = "ünïcödé 文字".obfuscated(true);
+    for _ in 0..16 {
+        let built = secret
+            .build(&NoResolutor, PrettyTextBuilder(options))
+            .to_string();
+        assert!(built.is_ascii(), "{built:?} isn't ascii");
+    }
+}
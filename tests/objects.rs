@@ -0,0 +1,267 @@
+#![allow(unused_imports)]
+use text_components::{
+    TextComponent,
+    content::{Atlas, Content, Object, ObjectPlayer, PlayerProperties},
+};
+
+fn objects() -> [TextComponent; 3] {
+    [
+        TextComponent::sprite("item/diamond"),
+        TextComponent::atlas("item/diamond", Atlas::ITEMS),
+        TextComponent::player_head(ObjectPlayer::name("Jeb_"), false),
+    ]
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_discriminator() {
+    let json = objects().map(|object| serde_json::to_string(&object).unwrap());
+    assert_eq!(
+        json,
+        [
+            r#"{"object":"atlas","sprite":"item/diamond"}"#,
+            r#"{"object":"atlas","atlas":"minecraft:items","sprite":"item/diamond"}"#,
+            r#"{"object":"player","player":{"name":"Jeb_"},"hat":false}"#,
+        ]
+    );
+    for (json, object) in json.iter().zip(objects()) {
+        assert_eq!(TextComponent::from_json(json).unwrap(), object, "{json}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_without_discriminator() {
+    use text_components::serde::borrowed::TextComponentDe;
+    for (json, object) in [
+        r#"{"sprite":"item/diamond"}"#,
+        r#"{"atlas":"minecraft:items","sprite":"item/diamond"}"#,
+        r#"{"player":{"name":"Jeb_"},"hat":false}"#,
+    ]
+    .into_iter()
+    .zip(objects())
+    {
+        assert_eq!(TextComponent::from_json(json).unwrap(), object, "{json}");
+        let borrowed = serde_json::from_str::<TextComponentDe>(json).unwrap();
+        assert_eq!(borrowed.into_owned(), object, "{json}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_hat_and_profile() {
+    use text_components::{content::PlayerProperties, serde::borrowed::TextComponentDe};
+    let head = TextComponent::player_head(ObjectPlayer::name("Jeb_"), true);
+    assert_eq!(
+        serde_json::to_string(&head).unwrap(),
+        r#"{"object":"player","player":{"name":"Jeb_"}}"#
+    );
+    // A missing hat is shown and the profile may be only the name of the player
+    for json in [
+        r#"{"object":"player","player":{"name":"Jeb_"}}"#,
+        r#"{"player":"Jeb_"}"#,
+        r#"{"player":"Jeb_","hat":true}"#,
+    ] {
+        assert_eq!(TextComponent::from_json(json).unwrap(), head, "{json}");
+        let borrowed = serde_json::from_str::<TextComponentDe>(json).unwrap();
+        assert_eq!(borrowed.into_owned(), head, "{json}");
+    }
+
+    let profile = TextComponent::player_head(
+        ObjectPlayer {
+            name: None,
+            id: Some([1, -2, 3, -4]),
+            texture: None,
+            properties: vec![PlayerProperties {
+                name: "textures".into(),
+                value: "e30=".into(),
+                signature: None,
+            }],
+        },
+        false,
+    );
+    let json = serde_json::to_string(&profile).unwrap();
+    assert_eq!(
+        json,
+        r#"{"object":"player","player":{"id":[1,-2,3,-4],"properties":[{"name":"textures","value":"e30="}]},"hat":false}"#
+    );
+    assert_eq!(TextComponent::from_json(&json).unwrap(), profile);
+}
+
+#[test]
+fn snbt_player_name() {
+    assert_eq!(
+        TextComponent::from_snbt(r#"{player:"Jeb_",hat:false}"#).unwrap(),
+        objects()[2]
+    );
+}
+
+#[test]
+fn snbt_without_discriminator() {
+    for (snbt, object) in [
+        r#"{sprite:"item/diamond"}"#,
+        r#"{atlas:"minecraft:items",sprite:"item/diamond"}"#,
+        r#"{player:{name:"Jeb_"},hat:false}"#,
+    ]
+    .into_iter()
+    .zip(objects())
+    {
+        assert_eq!(TextComponent::from_snbt(snbt).unwrap(), object, "{snbt}");
+    }
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn nbt_round_trip() {
+    use text_components::{
+        nbt::{NbtBuilder, ToSNBT},
+        resolving::NoResolutor,
+    };
+    for object in objects() {
+        let nbt = object.build(&NoResolutor, NbtBuilder::new());
+        let snbt = nbt.to_snbt();
+        assert!(snbt.contains("object:"), "{snbt}");
+        assert_eq!(
+            TextComponent::from_nbt(&nbt),
+            Some(object.clone()),
+            "{snbt}"
+        );
+        assert_eq!(TextComponent::from_snbt(&snbt).unwrap(), object, "{snbt}");
+    }
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn nbt_without_discriminator() {
+    use simdnbt::owned::{NbtCompound, NbtTag};
+    let head = NbtTag::Compound(NbtCompound::from_values(vec![
+        (
+            "player".into(),
+            NbtTag::Compound(NbtCompound::from_values(vec![(
+                "name".into(),
+                NbtTag::String("Jeb_".into()),
+            )])),
+        ),
+        ("hat".into(), NbtTag::Byte(0)),
+    ]));
+    assert_eq!(TextComponent::from_nbt(&head), Some(objects()[2].clone()));
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn nbt_discriminator() {
+    use simdnbt::owned::{NbtCompound, NbtTag};
+    let string = |value: &str| NbtTag::String(value.into());
+    let compound = |values: Vec<(&str, NbtTag)>| {
+        NbtTag::Compound(NbtCompound::from_values(
+            values
+                .into_iter()
+                .map(|(key, value)| (key.into(), value))
+                .collect(),
+        ))
+    };
+    // As the game writes it
+    let sprite = compound(vec![
+        ("type", string("object")),
+        ("object", string("atlas")),
+        ("atlas", string("minecraft:items")),
+        ("sprite", string("item/diamond")),
+    ]);
+    assert_eq!(TextComponent::from_nbt(&sprite), Some(objects()[1].clone()));
+    let head = compound(vec![
+        ("type", string("object")),
+        ("object", string("player")),
+        ("player", compound(vec![("name", string("Jeb_"))])),
+        ("hat", NbtTag::Byte(0)),
+    ]);
+    assert_eq!(TextComponent::from_nbt(&head), Some(objects()[2].clone()));
+
+    for mismatched in [
+        compound(vec![
+            ("object", string("player")),
+            ("sprite", string("item/diamond")),
+        ]),
+        compound(vec![
+            ("object", string("atlas")),
+            ("player", string("Jeb_")),
+        ]),
+    ] {
+        assert_eq!(TextComponent::from_nbt(&mismatched), None);
+    }
+}
+
+#[test]
+fn default_players() {
+    assert_eq!(ObjectPlayer::default(), ObjectPlayer::empty());
+    assert!(ObjectPlayer::empty().is_empty());
+    let player = ObjectPlayer {
+        texture: Some("entity/player/wide/steve".into()),
+        ..ObjectPlayer::empty()
+    };
+    assert_eq!(player, ObjectPlayer::texture("entity/player/wide/steve"));
+    assert!(!player.is_empty());
+
+    let skin = ObjectPlayer {
+        properties: vec![PlayerProperties {
+            value: "e30=".into(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    assert_eq!(skin, ObjectPlayer::property("e30=", None::<&str>));
+}
+
+#[test]
+fn head_placeholders() {
+    use text_components::resolving::NoResolutor;
+    let plain =
+        |player: ObjectPlayer| TextComponent::player_head(player, true).to_plain(&NoResolutor);
+    assert_eq!(plain(ObjectPlayer::name("Notch")), "[Head: Notch]");
+    assert_eq!(
+        plain(ObjectPlayer::id([1, -2, 3, 4])),
+        "[Head: [1, -2, 3, 4]]"
+    );
+    assert_eq!(
+        plain(ObjectPlayer::texture(
+            "minecraft:entity/player/wide/steve.png"
+        )),
+        "[Head: texture entity/player/wide/steve]"
+    );
+    assert_eq!(
+        plain(ObjectPlayer::texture("item/steve")),
+        "[Head: texture item/steve]"
+    );
+    let skin = ObjectPlayer::property("e30=", None::<&str>);
+    assert_eq!(plain(skin.clone()), "[Head: prop 2e52…]");
+    let mut properties = ObjectPlayer::empty();
+    properties.properties.push(PlayerProperties {
+        name: "cape".into(),
+        value: "Y2FwZQ==".into(),
+        signature: None,
+    });
+    properties.properties.extend(skin.properties);
+    assert_eq!(plain(properties), "[Head: prop 2e52…]");
+    assert_eq!(plain(ObjectPlayer::empty()), "[Head]");
+    // The name comes first
+    let mut named = ObjectPlayer::texture("item/steve");
+    named.name = Some("Steve".into());
+    assert_eq!(plain(named), "[Head: Steve]");
+}
+
+#[test]
+fn invalid_sprites() {
+    // Only try_atlas checks the sprite, atlas behaves the same in every build
+    let component = TextComponent::atlas("Item/Diamond Sword", Atlas::ITEMS);
+    assert!(matches!(
+        component.content,
+        Content::Object(Object::Atlas { ref sprite, .. }) if sprite == "Item/Diamond Sword"
+    ));
+    assert_eq!(
+        TextComponent::try_atlas("Item/Diamond Sword", Atlas::ITEMS),
+        None
+    );
+    assert_eq!(
+        TextComponent::try_atlas("item/diamond_sword", Atlas::ITEMS),
+        Some(TextComponent::atlas("item/diamond_sword", Atlas::ITEMS))
+    );
+}
@@ -0,0 +1,140 @@
+use text_components::{
+    Modifier, TextComponent,
+    content::Content,
+    interactivity::{ClickEvent, HoverEvent, UrlError},
+    path::{ComponentPath, PathSegment},
+    translation::TranslatedMessage,
+    validate::{MAX_STRING_LENGTH, ValidationErrorKind},
+};
+
+fn text(component: Option<&TextComponent>) -> Option<&str> {
+    match &component?.content {
+        Content::Text { text } => Some(text),
+        _ => None,
+    }
+}
+
+/// "root" > ["first", translation("arg" with hover "deep"), entity with separator "sep"]
+fn fixture() -> TextComponent {
+    let arg = "arg".hover_event(HoverEvent::show_text("deep"));
+    TextComponent::plain("root").add_children::<TextComponent>(vec![
+        "first".into(),
+        TranslatedMessage::new("chat.type.text", Some(Box::new([arg]))).component(),
+        TextComponent::entity("@e", Some("sep".into())),
+    ])
+}
+
+#[test]
+fn resolves_paths() {
+    let component = fixture();
+    assert_eq!(
+        text(component.get_path(&ComponentPath::new())),
+        Some("root")
+    );
+    assert_eq!(
+        text(component.get_path(&ComponentPath::new().child(0))),
+        Some("first")
+    );
+    assert_eq!(
+        text(component.get_path(&ComponentPath::new().child(1).arg(0))),
+        Some("arg")
+    );
+    assert_eq!(
+        text(component.get_path(&ComponentPath::new().child(1).arg(0).hover_value())),
+        Some("deep")
+    );
+    assert_eq!(
+        text(component.get_path(&ComponentPath::new().child(2).separator())),
+        Some("sep")
+    );
+}
+
+#[test]
+fn out_of_bounds() {
+    let component = fixture();
+    for path in [
+        ComponentPath::new().child(3),
+        ComponentPath::new().child(1).arg(1),
+        ComponentPath::new().child(0).arg(0),
+        ComponentPath::new().separator(),
+        ComponentPath::new().hover_value(),
+        ComponentPath::new().child(0).hover_name(),
+        ComponentPath::new().child(1).arg(0).hover_value().child(0),
+    ] {
+        assert!(component.get_path(&path).is_none(), "{path}");
+    }
+}
+
+#[test]
+fn mutate_through_path() {
+    let mut component = fixture();
+    let path = ComponentPath::from(vec![
+        PathSegment::Child(1),
+        PathSegment::TranslateArg(0),
+        PathSegment::HoverValue,
+    ]);
+    *component.get_path_mut(&path).unwrap() = "changed".into();
+    assert_eq!(text(component.get_path(&path)), Some("changed"));
+    assert!(
+        component
+            .get_path_mut(&ComponentPath::new().child(9))
+            .is_none()
+    );
+}
+
+#[test]
+fn display() {
+    assert_eq!(ComponentPath::new().to_string(), "");
+    assert_eq!(
+        ComponentPath::new()
+            .child(2)
+            .arg(0)
+            .hover_value()
+            .to_string(),
+        "extra[2].with[0].hover_event.value"
+    );
+    assert_eq!(
+        ComponentPath::new().separator().hover_name().to_string(),
+        "separator.hover_event.name"
+    );
+}
+
+#[test]
+fn validate_reports_path() {
+    assert!(fixture().validate().is_ok());
+
+    let long = "a".repeat(MAX_STRING_LENGTH + 1);
+    let mut component = fixture();
+    let deep = ComponentPath::new().child(1).arg(0).hover_value();
+    *component.get_path_mut(&deep).unwrap() = long.clone().into();
+    let error = component.validate().unwrap_err();
+    assert_eq!(error.path, deep);
+    assert_eq!(
+        error.kind,
+        ValidationErrorKind::StringTooLong {
+            field: "text",
+            length: MAX_STRING_LENGTH + 1
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "The text has 32768 characters, the maximum is 32767 at extra[1].with[0].hover_event.value"
+    );
+
+    let error = TextComponent::plain(long).validate().unwrap_err();
+    assert!(error.path.is_root());
+    assert!(!error.to_string().contains(" at "));
+}
+
+#[test]
+fn validate_urls() {
+    let component = TextComponent::plain("root")
+        .add_child("ok")
+        .add_child("bad".click_event(ClickEvent::open_url("ftp://example.com")));
+    let error = component.validate().unwrap_err();
+    assert_eq!(error.path, ComponentPath::new().child(1));
+    assert_eq!(
+        error.kind,
+        ValidationErrorKind::Url(UrlError::UnsupportedScheme("ftp".into()))
+    );
+}
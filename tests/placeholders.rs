@@ -0,0 +1,156 @@
+use std::borrow::Cow;
+use text_components::{
+    Modifier, TextComponent,
+    content::{NbtSource, ObjectPlayer, Resolvable},
+    fmt::{ObfuscatedText, set_display_resolutor},
+    resolving::{NoResolutor, PlaceholderConfig, StaticScores, TextResolutor},
+    translation::TranslatedMessage,
+};
+
+/// Only translates `gui.done`, showing the rest with its placeholders.
+struct StaticTranslations(PlaceholderConfig);
+impl TextResolutor for StaticTranslations {
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        self.0.resolve_content(resolvable)
+    }
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, _data: &text_components::custom::CustomData) -> Option<TextComponent> {
+        None
+    }
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>> {
+        (key == "gui.done").then_some(Cow::Borrowed("Done"))
+    }
+    fn placeholders(&self) -> &PlaceholderConfig {
+        &self.0
+    }
+}
+
+fn line() -> TextComponent {
+    TextComponent::scoreboard("Steve", "kills").add_children::<TextComponent>(vec![
+        " ".into(),
+        TextComponent::scoreboard("Alex", "kills"),
+        " ".into(),
+        TextComponent::entity("@p", None),
+        " ".into(),
+        TextComponent::nbt("Health", NbtSource::entity("@p"), false, None),
+    ])
+}
+
+#[test]
+fn resolvables() {
+    assert_eq!(
+        line().to_plain(&NoResolutor),
+        "[Score kills@Steve] [Score kills@Alex] [Entity: @p] [Nbt: Health]"
+    );
+
+    let scores = StaticScores::new()
+        .score("Steve", "kills", 12)
+        .score("Steve", "deaths", -3);
+    assert_eq!(
+        line().to_plain(&scores),
+        "12 [Score kills@Alex] [Entity: @p] [Nbt: Health]"
+    );
+    assert_eq!(
+        TextComponent::scoreboard("Steve", "deaths").to_plain(&scores),
+        "-3"
+    );
+
+    let placeholders = PlaceholderConfig {
+        score: Cow::Borrowed("<{selector}'s {objective}>"),
+        nbt: Cow::Borrowed("?"),
+        ..PlaceholderConfig::new()
+    };
+    assert_eq!(
+        line().to_plain(&placeholders),
+        "<Steve's kills> <Alex's kills> [Entity: @p] ?"
+    );
+    // Only for this build
+    assert_eq!(
+        line().to_plain(&NoResolutor),
+        "[Score kills@Steve] [Score kills@Alex] [Entity: @p] [Nbt: Health]"
+    );
+}
+
+#[test]
+fn heads() {
+    let placeholders = PlaceholderConfig {
+        head: Cow::Borrowed("{player}'s head"),
+        unknown_head: Cow::Borrowed("a head"),
+        ..PlaceholderConfig::new()
+    };
+    let head = |player| TextComponent::player_head(player, true).to_plain(&placeholders);
+    assert_eq!(head(ObjectPlayer::name("Notch")), "Notch's head");
+    assert_eq!(head(ObjectPlayer::empty()), "a head");
+}
+
+#[test]
+fn translations() {
+    let translated = |fallback: Option<&'static str>| {
+        let mut message = TranslatedMessage::new("gui.done", None);
+        message.fallback = fallback.map(Cow::Borrowed);
+        TextComponent::translated(message)
+    };
+    assert_eq!(
+        translated(None).to_plain(&NoResolutor),
+        "[Translation: gui.done]"
+    );
+    let placeholders = PlaceholderConfig {
+        translation: Cow::Borrowed("{key}"),
+        fallback_first: true,
+        ..PlaceholderConfig::new()
+    };
+    let done = StaticTranslations(placeholders.clone());
+    assert_eq!(translated(None).to_plain(&placeholders), "gui.done");
+    assert_eq!(translated(None).to_plain(&done), "Done");
+    assert_eq!(translated(Some("Ok")).to_plain(&done), "Ok");
+    assert_eq!(
+        translated(Some("Ok"))
+            .flatten_runs(&done)
+            .iter()
+            .map(|run| run.text.as_str())
+            .collect::<String>(),
+        "Ok"
+    );
+
+    let done = StaticTranslations(PlaceholderConfig::default());
+    assert_eq!(translated(Some("Ok")).to_plain(&done), "Done");
+}
+
+fn quiz() -> TextComponent {
+    "Answer: "
+        .add_child(
+            "blue whale"
+                .obfuscated(true)
+                .add_child("!".obfuscated(false)),
+        )
+        .add_child(" (".add_child(TextComponent::translated_key("gui.done").obfuscated(true)))
+        .add_child(")")
+}
+
+#[test]
+fn obfuscated() {
+    let quiz = quiz();
+    let done = StaticTranslations(PlaceholderConfig::new());
+    assert_eq!(quiz.to_plain(&done), "Answer: blue whale! (Done)");
+    for (obfuscated, plain) in [
+        (ObfuscatedText::Reveal, "Answer: blue whale! (Done)"),
+        (ObfuscatedText::Mask('*'), "Answer: **** *****! (****)"),
+        (ObfuscatedText::Skip, "Answer: ! ()"),
+    ] {
+        let done = StaticTranslations(PlaceholderConfig {
+            obfuscated,
+            ..PlaceholderConfig::new()
+        });
+        assert_eq!(quiz.to_plain(&done), plain);
+    }
+}
+
+#[test]
+fn display_uses_the_placeholders_of_its_resolutor() {
+    let skip = PlaceholderConfig {
+        obfuscated: ObfuscatedText::Skip,
+        ..PlaceholderConfig::new()
+    };
+    set_display_resolutor(Box::leak(Box::new(skip)));
+    assert_eq!(quiz().to_string(), "Answer: ! ()");
+}
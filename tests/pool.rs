@@ -0,0 +1,68 @@
+use text_components::{
+    Modifier, TextComponent, format::Color, interactivity::HoverEvent, pool::ComponentPool,
+    resolving::NoResolutor,
+};
+
+#[test]
+fn clear_to_text() {
+    let mut component = "Hello"
+        .color(Color::Red)
+        .hover_event(HoverEvent::show_text("Hi"))
+        .add_children::<TextComponent>(vec!["a".into(), "b".into(), "c".into()]);
+    let capacity = component.children.capacity();
+    component.clear_to_text("Bye");
+    assert_eq!(component, TextComponent::plain("Bye"));
+    assert_eq!(component.children.capacity(), capacity);
+}
+
+#[test]
+fn children_vec_mut() {
+    let mut component = TextComponent::plain("Bar").add_children(vec!["a", "b", "c"]);
+    let capacity = component.children.capacity();
+    component.clear_to_text("Bar");
+    component.children_vec_mut().push("d".into());
+    assert_eq!(component, TextComponent::plain("Bar").add_child("d"));
+    assert_eq!(component.children.capacity(), capacity);
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn write_into_reused_buffer() {
+    use text_components::nbt::NbtBuilder;
+    let component = "Health: ".add_child(TextComponent::scoreboard("@s", "health"));
+    let mut buffer = vec![];
+    NbtBuilder::new().write_into(&NoResolutor, &component, &mut buffer);
+    let tag = simdnbt::owned::read_tag(&mut std::io::Cursor::new(&buffer[..])).unwrap();
+    assert_eq!(tag, component.build(&NoResolutor, NbtBuilder::new()));
+
+    let capacity = buffer.capacity();
+    buffer.clear();
+    NbtBuilder::new().write_into(&NoResolutor, &component, &mut buffer);
+    assert_eq!(buffer.capacity(), capacity);
+}
+
+#[test]
+fn recycles_boxes_and_children() {
+    let mut pool = ComponentPool::new();
+    let hover = pool.boxed("Hover".into());
+    let hover_address = &*hover as *const TextComponent;
+    let mut children = pool.children();
+    children.extend(["a".into(), TextComponent::entity_sep_str("@a", " & ")]);
+    let children_address = children.as_ptr();
+    let component = TextComponent {
+        children,
+        ..TextComponent::plain("Text").hover_event(HoverEvent::ShowText { value: hover })
+    };
+    assert_eq!(component.to_plain(&NoResolutor), "Texta[Entity: @a]");
+    pool.recycle(component);
+
+    // The separator box is given first, then the hover one
+    let separator = pool.boxed("x".into());
+    let reused = pool.boxed("Reused".into());
+    assert_eq!(&*reused as *const TextComponent, hover_address);
+    assert_eq!(*reused, TextComponent::plain("Reused"));
+    assert_ne!(&*separator as *const TextComponent, hover_address);
+    let children = pool.children();
+    assert!(children.is_empty());
+    assert_eq!(children.as_ptr(), children_address);
+}
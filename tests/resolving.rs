@@ -0,0 +1,517 @@
+use std::borrow::Cow;
+use text_components::{
+    Modifier, TextComponent,
+    content::{NbtSource, Resolvable},
+    format::Color,
+    interactivity::HoverEvent,
+    path::ComponentPath,
+    resolving::{NoResolutor, TextResolutor, TranslationSegment, resolve_nbt_values},
+    translation::TranslatedMessage,
+};
+
+/// Finds the same values for every NBT path.
+struct Values(Vec<&'static str>);
+impl TextResolutor for Values {
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        let values = self.0.iter().map(|value| value.to_string()).collect();
+        resolve_nbt_values(values, resolvable)
+    }
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, _data: &text_components::custom::CustomData) -> Option<TextComponent> {
+        None
+    }
+    fn translate(&self, _key: &str) -> Option<Cow<'_, str>> {
+        None
+    }
+}
+
+/// Gives the raw SNBT back without interpreting it.
+struct Raw(&'static str);
+impl TextResolutor for Raw {
+    fn resolve_content(&self, _resolvable: &Resolvable) -> TextComponent {
+        TextComponent::plain(self.0)
+    }
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, _data: &text_components::custom::CustomData) -> Option<TextComponent> {
+        None
+    }
+    fn translate(&self, _key: &str) -> Option<Cow<'_, str>> {
+        None
+    }
+}
+
+fn nbt(interpret: bool, separator: Option<TextComponent>) -> TextComponent {
+    TextComponent::nbt(
+        "Item.tag.display.Name",
+        NbtSource::Entity("@s".into()),
+        interpret,
+        separator,
+    )
+}
+
+#[test]
+fn single_values() {
+    let value = r#"{text:"Sword",color:"aqua"}"#;
+    assert_eq!(
+        nbt(true, None).resolve(&Values(vec![value])),
+        "Sword".color(Color::Aqua)
+    );
+    assert_eq!(
+        nbt(false, None).resolve(&Values(vec![value])),
+        TextComponent::plain(value)
+    );
+    assert_eq!(
+        nbt(true, None).resolve(&Values(vec![])),
+        TextComponent::new()
+    );
+}
+
+#[test]
+fn invalid_snbt_stays_raw() {
+    assert_eq!(
+        nbt(true, None).resolve(&Values(vec!["{text:"])),
+        TextComponent::plain("{text:")
+    );
+    assert_eq!(
+        nbt(true, None).resolve(&Raw("not snbt")),
+        TextComponent::plain("not snbt")
+    );
+}
+
+#[test]
+fn raw_text_is_interpreted() {
+    assert_eq!(
+        nbt(true, None).resolve(&Raw(r#"{text:"Hi",bold:1b}"#)),
+        "Hi".bold(true)
+    );
+    assert_eq!(
+        nbt(false, None).resolve(&Raw(r#""Hi""#)),
+        TextComponent::plain(r#""Hi""#)
+    );
+}
+
+#[test]
+fn multiple_values() {
+    let values = Values(vec![r#""a""#, r#"{text:"b",color:"red"}"#, "c"]);
+    assert_eq!(
+        nbt(true, Some(" | ".into())).resolve(&values),
+        TextComponent::new().add_children::<TextComponent>(vec![
+            "a".into(),
+            " | ".into(),
+            "b".color(Color::Red),
+            " | ".into(),
+            "c".into(),
+        ])
+    );
+    assert_eq!(
+        nbt(false, None).resolve(&values),
+        TextComponent::new().add_children::<TextComponent>(vec![
+            r#""a""#.into(),
+            ", ".into(),
+            r#"{text:"b",color:"red"}"#.into(),
+            ", ".into(),
+            "c".into(),
+        ])
+    );
+    assert_eq!(
+        nbt(false, None).resolve(&NoResolutor),
+        TextComponent::plain("[Nbt: Item.tag.display.Name]")
+    );
+}
+
+#[test]
+fn join_resolved() {
+    let entity = Resolvable::Entity {
+        selector: "@a".into(),
+        separator: Resolvable::entity_separator(),
+    };
+    assert_eq!(entity.join_resolved(vec![]), TextComponent::new());
+    assert_eq!(
+        entity.join_resolved(vec!["Steve".into()]),
+        TextComponent::plain("Steve")
+    );
+    assert_eq!(
+        entity.join_resolved(vec!["Steve".into(), "Alex".into(), "Notch".into()]),
+        TextComponent::new().add_children::<TextComponent>(vec![
+            "Steve".into(),
+            ", ".color(Color::Gray),
+            "Alex".into(),
+            ", ".color(Color::Gray),
+            "Notch".into(),
+        ])
+    );
+    let score = Resolvable::Scoreboard {
+        selector: "@a".into(),
+        objective: "kills".into(),
+    };
+    assert_eq!(
+        score.join_resolved(vec!["1".into(), "2".into()]),
+        TextComponent::new().add_children::<TextComponent>(vec![
+            "1".into(),
+            ", ".color(Color::Gray),
+            "2".into(),
+        ])
+    );
+}
+
+/// Names every entity "Steve" and "Alex", recording the separators it was given.
+struct Players(std::cell::RefCell<Vec<TextComponent>>);
+impl TextResolutor for Players {
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        self.0
+            .borrow_mut()
+            .push(resolvable.separator().into_owned());
+        resolvable.join_resolved(vec!["Steve".into(), "Alex".into()])
+    }
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, _data: &text_components::custom::CustomData) -> Option<TextComponent> {
+        None
+    }
+    fn translate(&self, _key: &str) -> Option<Cow<'_, str>> {
+        None
+    }
+}
+
+#[test]
+fn separators_are_resolved_first() {
+    let players = Players(Default::default());
+    let separator = TextComponent::entity("@r", Some(" & ".into()));
+    let component = TextComponent::entity("@a", Some(separator));
+    // The separator holds another selector, resolved before the outer one
+    let and = || " & ".into();
+    let names = || {
+        TextComponent::new().add_children::<TextComponent>(vec![
+            "Steve".into(),
+            and(),
+            "Alex".into(),
+        ])
+    };
+    assert_eq!(
+        component.resolve(&players),
+        TextComponent::new().add_children(vec!["Steve".into(), names(), "Alex".into()])
+    );
+    assert_eq!(players.0.borrow().as_slice(), &[" & ".into(), names()]);
+}
+
+#[test]
+fn translation_segments() {
+    use TranslationSegment::{Argument, Literal};
+    let literal = |text: &'static str| Literal(Cow::Borrowed(text));
+    assert_eq!(
+        NoResolutor.translation_segments("<%s> %s"),
+        vec![literal("<"), Argument(0), literal("> "), Argument(1)]
+    );
+    assert_eq!(
+        NoResolutor.translation_segments("Gave %2$s to %1$s"),
+        vec![literal("Gave "), Argument(1), literal(" to "), Argument(0)]
+    );
+    // No empty literal between adjacent placeholders
+    assert_eq!(
+        NoResolutor.translation_segments("%s%s%12$s"),
+        vec![Argument(0), Argument(1), Argument(11)]
+    );
+    assert_eq!(
+        NoResolutor.translation_segments("100% done %$s %0$s"),
+        vec![literal("100% done %$s %0$s")]
+    );
+    assert!(NoResolutor.translation_segments("").is_empty());
+}
+
+#[test]
+#[allow(deprecated)]
+fn split_translation_adapter() {
+    assert_eq!(
+        NoResolutor.split_translation(String::from("<%s> %s")),
+        vec![
+            (String::from("<"), 1),
+            (String::from("> "), 2),
+            (String::new(), 0)
+        ]
+    );
+    assert_eq!(
+        NoResolutor.split_translation(String::from("%2$s%1$s")),
+        vec![(String::new(), 2), (String::new(), 1), (String::new(), 0)]
+    );
+}
+
+/// Only knows the `greeting` key.
+struct Greeting;
+impl TextResolutor for Greeting {
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        NoResolutor.resolve_content(resolvable)
+    }
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, _data: &text_components::custom::CustomData) -> Option<TextComponent> {
+        None
+    }
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>> {
+        (key == "greeting").then_some(Cow::Borrowed("Hello %s"))
+    }
+}
+
+fn message(key: &'static str, fallback: Option<&'static str>) -> TextComponent {
+    let mut message = TranslatedMessage::new(key, Some(Box::new(["Alex".into()])));
+    message.fallback = fallback.map(Cow::Borrowed);
+    TextComponent::translated(message)
+}
+
+#[test]
+fn translation_precedence() {
+    let plain_and_runs = |component: &TextComponent, resolutor: &dyn TextResolutor| {
+        let runs = component.flatten_runs(resolutor);
+        let runs = runs.iter().map(|run| run.text.as_str()).collect::<String>();
+        let plain = component.to_plain(resolutor);
+        assert_eq!(plain, runs);
+        plain
+    };
+    // Key found, the fallback is ignored
+    assert_eq!(
+        plain_and_runs(&message("greeting", Some("Hi!")), &Greeting),
+        "Hello Alex"
+    );
+    // Key missing with a fallback
+    assert_eq!(
+        plain_and_runs(&message("farewell", Some("Bye!")), &Greeting),
+        "Bye!"
+    );
+    // Key missing without a fallback
+    assert_eq!(
+        plain_and_runs(&message("farewell", None), &Greeting),
+        "[Translation: farewell]"
+    );
+    // No translations at all
+    assert_eq!(
+        plain_and_runs(&message("greeting", Some("Hi!")), &NoResolutor),
+        "Hi!"
+    );
+    assert_eq!(
+        plain_and_runs(&message("greeting", None), &NoResolutor),
+        "[Translation: greeting]"
+    );
+}
+
+/// Fails to find the `broken` objective and gives the NBT values of `Raw`.
+struct Flaky;
+impl TextResolutor for Flaky {
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        self.try_resolve_content(resolvable)
+            .unwrap_or_else(|_| NoResolutor.resolve_content(resolvable))
+    }
+    fn try_resolve_content(
+        &self,
+        resolvable: &Resolvable,
+    ) -> Result<TextComponent, Box<dyn std::error::Error + Send + Sync>> {
+        match resolvable {
+            Resolvable::Scoreboard { objective, .. } if objective == "broken" => {
+                Err("Objective not found".into())
+            }
+            Resolvable::Scoreboard { .. } => Ok(TextComponent::plain("7")),
+            _ => Ok(
+                Raw(r#"{text:"Score: ",extra:[{score:{name:"@s",objective:"broken"}}]}"#)
+                    .resolve_content(resolvable),
+            ),
+        }
+    }
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, _data: &text_components::custom::CustomData) -> Option<TextComponent> {
+        None
+    }
+    fn translate(&self, _key: &str) -> Option<Cow<'_, str>> {
+        None
+    }
+}
+
+#[test]
+fn try_resolve() {
+    let score = |objective| TextComponent::scoreboard("@s", objective);
+    let working = "Kills: ".add_child(score("kills"));
+    assert_eq!(
+        working.try_resolve(&Flaky).unwrap(),
+        working.resolve(&Flaky)
+    );
+
+    let broken = "Kills: "
+        .add_child(score("kills"))
+        .add_child(TextComponent::translated_with(
+            "stats",
+            [score("kills"), score("broken")],
+        ));
+    let error = broken.try_resolve(&Flaky).unwrap_err();
+    assert_eq!(error.path, ComponentPath::new().child(1).arg(1));
+    assert_eq!(error.to_string(), "Objective not found at extra[1].with[1]");
+    // The infallible resolution keeps the placeholder
+    assert_eq!(
+        broken.resolve(&Flaky).to_plain(&Flaky),
+        "Kills: 7[Translation: stats]"
+    );
+
+    let error = TextComponent::entity_sep_str("@a", score("broken"))
+        .try_resolve(&Flaky)
+        .unwrap_err();
+    assert_eq!(error.path, ComponentPath::new().separator());
+
+    // Failures inside what the resolutor gave point to the resolvable that gave it
+    let error = "Stats: "
+        .add_child(nbt(true, None))
+        .try_resolve(&Flaky)
+        .unwrap_err();
+    assert_eq!(error.path, ComponentPath::new().child(0));
+    assert_eq!(
+        nbt(true, None).resolve(&Flaky).to_plain(&NoResolutor),
+        "Score: [Score broken@@s]"
+    );
+}
+
+#[test]
+fn hovers_are_resolved() {
+    let kills = || TextComponent::scoreboard("@s", "kills");
+    let text = "Kills".hover_event(HoverEvent::show_text("Kills: ".add_child(kills())));
+    let Some(HoverEvent::ShowText { value }) = text.resolve(&Flaky).interactions.hover else {
+        panic!("the hover is kept")
+    };
+    assert_eq!(*value, "Kills: ".add_child("7"));
+
+    let broken = "Kills".hover_event(HoverEvent::show_entity(
+        "minecraft:pig",
+        uuid::Uuid::nil(),
+        Some(TextComponent::scoreboard("@s", "broken")),
+    ));
+    let error = broken.try_resolve(&Flaky).unwrap_err();
+    assert_eq!(error.path, ComponentPath::new().hover_name());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn entity_names_are_resolved() {
+    let name =
+        TextComponent::translated_with("greeting", [TextComponent::scoreboard("@s", "kills")]);
+    let pig = "Pig".hover_event(HoverEvent::show_entity(
+        "minecraft:pig",
+        uuid::Uuid::nil(),
+        Some(name),
+    ));
+    let json = pig.to_json(&Flaky).unwrap();
+    let Some(HoverEvent::ShowEntity {
+        name: Some(name), ..
+    }) = TextComponent::from_json(&json).unwrap().interactions.hover
+    else {
+        panic!("{json} has no entity name")
+    };
+    assert_eq!(name.to_plain(&Greeting), "Hello 7");
+}
+
+/// Takes the resolutor by value, like the wrappers written around them.
+fn plain_with<R: TextResolutor>(component: &TextComponent, resolutor: R) -> String {
+    component.to_plain(&resolutor)
+}
+
+#[test]
+fn resolutor_pointers() {
+    use std::sync::Arc;
+    let component = "Hi ".add_child(TextComponent::entity("@p", None));
+    let shared: Arc<dyn TextResolutor> = Arc::new(Raw("Steve"));
+    assert_eq!(component.to_plain(&shared), "Hi Steve");
+    assert_eq!(plain_with(&component, shared.clone()), "Hi Steve");
+
+    let boxed = Box::new(Raw("Alex"));
+    assert_eq!(component.to_plain(&boxed), "Hi Alex");
+    assert_eq!(plain_with(&component, &boxed), "Hi Alex");
+    let dynamic: Box<dyn TextResolutor> = boxed;
+    assert_eq!(plain_with(&component, &*dynamic), "Hi Alex");
+    assert_eq!(plain_with(&component, dynamic), "Hi Alex");
+}
+
+#[test]
+fn traced_resolution() {
+    use text_components::resolving::{StaticScores, TracedKind};
+    let scores = StaticScores::new().score("@p", "kills", 5);
+    let component = TextComponent::plain("").add_children::<TextComponent>(vec![
+        TextComponent::scoreboard("@p", "kills"),
+        TextComponent::entity("@e", None),
+        TextComponent::nbt("Health", NbtSource::entity("@s"), false, None),
+        TextComponent::translated(TranslatedMessage::new("gui.done", None)),
+    ]);
+    #[cfg(feature = "custom")]
+    let component = component.add_child(text_components::content::Content::Custom(
+        text_components::custom::CustomData {
+            id: "clock".into(),
+            payload: Default::default(),
+        },
+    ));
+    let (resolved, trace) = component.resolve_traced(&scores);
+    assert_eq!(resolved, component.resolve(&scores));
+    assert_eq!(trace.nodes.len(), component.children.len());
+
+    let Some(TracedKind::Resolvable {
+        resolvable,
+        resolved,
+    }) = trace
+        .get(&ComponentPath::new().child(0))
+        .map(|node| &node.kind)
+    else {
+        panic!("{trace:?}")
+    };
+    assert_eq!(
+        resolvable,
+        &Resolvable::Scoreboard {
+            selector: "@p".into(),
+            objective: "kills".into(),
+        }
+    );
+    assert_eq!(**resolved, TextComponent::plain("5"));
+    let Some(TracedKind::Resolvable { resolved, .. }) = trace
+        .get(&ComponentPath::new().child(1))
+        .map(|node| &node.kind)
+    else {
+        panic!("{trace:?}")
+    };
+    assert_eq!(resolved.to_plain(&NoResolutor), "[Entity: @e]");
+    assert!(matches!(
+        trace
+            .get(&ComponentPath::new().child(2))
+            .map(|node| &node.kind),
+        Some(TracedKind::Resolvable {
+            resolvable: Resolvable::NBT { .. },
+            ..
+        })
+    ));
+    assert_eq!(
+        trace
+            .get(&ComponentPath::new().child(3))
+            .map(|node| &node.kind),
+        Some(&TracedKind::Translate {
+            key: "gui.done".into(),
+            translated: None,
+        })
+    );
+    #[cfg(feature = "custom")]
+    assert!(matches!(
+        trace.get(&ComponentPath::new().child(4)).map(|node| &node.kind),
+        Some(TracedKind::Custom { data, resolved: Some(_) }) if data.id == "clock"
+    ));
+    assert!(trace.get(&ComponentPath::new()).is_none());
+}
+
+#[test]
+fn traced_inline_translations() {
+    use std::collections::HashMap;
+    use text_components::resolving::TracedKind;
+    let component = TextComponent::plain("")
+        .add_child(TextComponent::translated_key("plugin.welcome"))
+        .add_child(TextComponent::translated_key("gui.done"))
+        .with_inline_translations(HashMap::from([(
+            "plugin.welcome".into(),
+            "Welcome!".into(),
+        )]));
+    let (resolved, trace) = component.resolve_traced(&NoResolutor);
+    assert_eq!(resolved, component.resolve(&NoResolutor));
+    let translated = |i| match trace
+        .get(&ComponentPath::new().child(i))
+        .map(|node| &node.kind)
+    {
+        Some(TracedKind::Translate { translated, .. }) => translated.clone(),
+        kind => panic!("{kind:?}"),
+    };
+    assert_eq!(translated(0), Some("Welcome!".to_string()));
+    assert_eq!(translated(1), None);
+}
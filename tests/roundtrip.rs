@@ -0,0 +1,155 @@
+#![allow(unused_imports)]
+use proptest::prelude::*;
+use text_components::{
+    TextComponent,
+    parse::escape_snbt,
+    resolving::NoResolutor,
+    testutil::{self, KeepResolutor},
+};
+
+proptest! {
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json(component in testutil::component()) {
+        let json = component.to_json(&KeepResolutor).unwrap();
+        let parsed = TextComponent::from_json(&json).unwrap();
+        testutil::assert_semantic_eq(&component, &parsed);
+    }
+
+    #[cfg(feature = "nbt")]
+    #[test]
+    fn nbt(component in testutil::resolved_component()) {
+        use text_components::nbt::NbtBuilder;
+        let nbt = component.build(&KeepResolutor, NbtBuilder::new());
+        let parsed = TextComponent::from_nbt(&nbt).unwrap();
+        testutil::assert_semantic_eq(&component, &parsed);
+    }
+
+    #[cfg(feature = "nbt")]
+    #[test]
+    fn snbt(component in testutil::resolved_component()) {
+        use text_components::nbt::{NbtBuilder, ToSNBT};
+        let snbt = component.build(&KeepResolutor, NbtBuilder::new()).to_snbt();
+        let parsed = TextComponent::from_snbt(&snbt).unwrap();
+        testutil::assert_semantic_eq(&component, &parsed);
+    }
+
+    #[cfg(feature = "binary-serde")]
+    #[test]
+    fn bincode(component in testutil::component()) {
+        prop_assert_eq!(through_bincode(&component), component);
+    }
+
+    #[cfg(feature = "binary-serde")]
+    #[test]
+    fn postcard(component in testutil::component()) {
+        prop_assert_eq!(through_postcard(&component), component);
+    }
+
+    #[test]
+    fn snbt_strings(string in any::<String>()) {
+        let parsed = TextComponent::from_snbt(&format!("\"{}\"", escape_snbt(&string))).unwrap();
+        prop_assert_eq!(parsed, TextComponent::plain(string));
+    }
+
+    #[test]
+    fn mix_in_place(format in testutil::format(), parent in testutil::format()) {
+        let mut mixed = format.clone();
+        mixed.mix_in_place(&parent);
+        prop_assert_eq!(mixed, format.mix(&parent));
+    }
+
+    #[test]
+    fn inheritance_transforms(component in testutil::component()) {
+        let runs = component.flatten_runs(&NoResolutor);
+        let baked = component.bake_inheritance();
+        prop_assert_eq!(&baked.flatten_runs(&NoResolutor), &runs);
+        prop_assert_eq!(&baked.factor_common_format().flatten_runs(&NoResolutor), &runs);
+        prop_assert_eq!(&component.factor_common_format().flatten_runs(&NoResolutor), &runs);
+    }
+
+    #[test]
+    fn resolve_idempotent(component in testutil::component()) {
+        let kept = component.resolve(&KeepResolutor);
+        prop_assert_eq!(kept.resolve(&KeepResolutor), kept);
+        let resolved = component.resolve(&NoResolutor);
+        prop_assert_eq!(resolved.resolve(&NoResolutor), resolved);
+    }
+}
+
+#[cfg(feature = "binary-serde")]
+fn through_bincode(component: &TextComponent) -> TextComponent {
+    use text_components::compact::TextComponentCompact;
+    let bytes = bincode::serialize(&TextComponentCompact::from(component)).unwrap();
+    bincode::deserialize::<TextComponentCompact>(&bytes)
+        .unwrap()
+        .into()
+}
+
+#[cfg(feature = "binary-serde")]
+fn through_postcard(component: &TextComponent) -> TextComponent {
+    use text_components::compact::TextComponentCompact;
+    let bytes = postcard::to_allocvec(&TextComponentCompact::from(component)).unwrap();
+    postcard::from_bytes::<TextComponentCompact>(&bytes)
+        .unwrap()
+        .into()
+}
+
+/// The parts of a component the vanilla shape doesn't have.
+#[cfg(feature = "binary-serde")]
+#[test]
+fn compact_keeps_everything() {
+    use std::{borrow::Cow, collections::HashMap};
+    use text_components::{
+        Modifier,
+        dialog::{AfterAction, Dialog, DialogAction, DialogButton},
+        format::Color,
+        interactivity::ClickEvent,
+    };
+
+    let dialog = Dialog::confirmation(
+        "Leave?",
+        DialogButton::new("Yes")
+            .tooltip("Back to the lobby".italic(true))
+            .action(DialogAction::RunCommand {
+                command: "/lobby".into(),
+            }),
+        DialogButton::new("No").width(80),
+    )
+    .body("You'll lose your progress.")
+    .pause(false)
+    .after_action(AfterAction::WaitForResponse);
+    #[allow(unused_mut)]
+    let mut components = vec![
+        "Hi "
+            .color(Color::Red)
+            .add_child("there".no_inherit())
+            .with_inline_translations(HashMap::from([(
+                Cow::Borrowed("greeting"),
+                Cow::Borrowed("Hello %s!"),
+            )])),
+        "Leave".click_event(ClickEvent::show_dialog(dialog)),
+    ];
+    #[cfg(feature = "unknown-fields")]
+    components.push(
+        text_components::unknown::preserve_unknown(|| {
+            TextComponent::from_json(
+                r#"{"text":"Hi","future":{"a":[1,2]},"extra":[{"text":"!","x":1}]}"#,
+            )
+        })
+        .unwrap(),
+    );
+    #[cfg(all(feature = "unknown-fields", feature = "nbt"))]
+    components.push({
+        use simdnbt::owned::{NbtCompound, NbtTag};
+        let tag = NbtTag::Compound(NbtCompound::from_values(vec![
+            ("text".into(), NbtTag::String("Hi".into())),
+            ("future".into(), NbtTag::IntArray(vec![1, 2, 3])),
+        ]));
+        text_components::unknown::preserve_unknown(|| TextComponent::from_nbt(&tag)).unwrap()
+    });
+    for component in components {
+        assert_eq!(through_bincode(&component), component);
+        assert_eq!(through_postcard(&component), component);
+    }
+}
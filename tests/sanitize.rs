@@ -0,0 +1,86 @@
+use text_components::{
+    Modifier, TextComponent,
+    content::NbtSource,
+    interactivity::{ClickAction, ClickEvent, HoverEvent},
+    resolving::NoResolutor,
+    sanitize::SanitizePolicy,
+    translation::TranslatedMessage,
+};
+
+fn run_command(component: &TextComponent) -> bool {
+    component.contains_click_action(|click| matches!(click, ClickEvent::RunCommand { .. }))
+}
+
+#[test]
+fn hover_of_a_hover() {
+    let inner = "gotcha".click_event(ClickEvent::run_command("/op @s"));
+    let outer = "hover me".hover_event(HoverEvent::show_text(inner));
+    let mut component = "Hello".hover_event(HoverEvent::show_text(outer));
+    assert!(run_command(&component));
+
+    component.sanitize(&SanitizePolicy::default());
+    assert!(!run_command(&component));
+    assert_eq!(component.stats().node_count, 3);
+}
+
+#[test]
+fn nbt_separator() {
+    let separator = ", ".click_event(ClickEvent::run_command("/op @s"));
+    let mut component = TextComponent::plain("Items: ").add_child(TextComponent::nbt(
+        "Inventory",
+        NbtSource::Entity("@s".into()),
+        false,
+        Some(separator),
+    ));
+    assert!(run_command(&component));
+
+    component.sanitize(&SanitizePolicy::default());
+    assert!(!run_command(&component));
+}
+
+#[test]
+fn translation_args_and_insertions() {
+    let arg = "Steve"
+        .insertion("/tp Steve")
+        .click_event(ClickEvent::open_url("https://example.com"));
+    let mut component = TranslatedMessage::new("chat.type.text", Some(Box::new([arg]))).component();
+
+    let mut strict = component.clone();
+    strict.sanitize(&SanitizePolicy::strict());
+    assert!(!strict.contains_click_action(|_| true));
+    assert_eq!(strict.stats().node_count, 2);
+
+    component.sanitize(&SanitizePolicy {
+        click_actions: vec![ClickAction::OpenUrl],
+        ..Default::default()
+    });
+    assert!(component.contains_click_action(|click| click.action() == ClickAction::OpenUrl));
+}
+
+#[test]
+fn max_depth() {
+    let mut component = TextComponent::plain("1")
+        .add_child("2".add_child("3".add_child("4")))
+        .hover_event(HoverEvent::show_text(
+            "2".hover_event(HoverEvent::show_text("3")),
+        ));
+    component.sanitize(&SanitizePolicy {
+        max_depth: Some(2),
+        ..Default::default()
+    });
+    assert_eq!(component.stats().max_depth, 2);
+    assert_eq!(component.stats().node_count, 3);
+}
+
+#[test]
+fn max_length() {
+    let mut component = TextComponent::plain("Hello ")
+        .add_child("wörld")
+        .add_child("!");
+    component.sanitize(&SanitizePolicy {
+        max_length: Some(8),
+        ..Default::default()
+    });
+    assert_eq!(component.to_plain(&NoResolutor), "Hello wö");
+    assert_eq!(component.children.len(), 1);
+}
@@ -0,0 +1,114 @@
+use jsonschema::Validator;
+use serde_json::{Value, json};
+use text_components::{
+    Modifier, TextComponent,
+    content::{NbtSource, ObjectPlayer},
+    dialog::{Dialog, DialogAction, DialogButton},
+    format::Color,
+    interactivity::{ClickEvent, HoverEvent},
+};
+use uuid::Uuid;
+
+fn validator() -> Validator {
+    let schema = serde_json::from_str(&TextComponent::json_schema_string()).unwrap();
+    jsonschema::validator_for(&schema).unwrap()
+}
+
+fn assert_valid(validator: &Validator, name: &str, json: &Value) {
+    let errors = validator
+        .iter_errors(json)
+        .map(|error| format!("{} at {}", error, error.instance_path()))
+        .collect::<Vec<_>>();
+    assert!(
+        errors.is_empty(),
+        "{name} doesn't match the schema: {errors:#?}"
+    );
+}
+
+#[test]
+fn fixtures_match() {
+    let validator = validator();
+    let mut fixtures = std::fs::read_dir("tests/golden")
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        })
+        .collect::<Vec<_>>();
+    fixtures.push("tests/convert/message.json".into());
+    assert!(fixtures.len() > 1);
+    for path in fixtures {
+        let json = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_valid(&validator, &path.display().to_string(), &json);
+    }
+}
+
+#[test]
+fn unresolved_components_match() {
+    let validator = validator();
+    let dialog = Dialog::multi_action("Rules".color(Color::Gold))
+        .body("Be nice to each other.")
+        .button(
+            DialogButton::new("Accept").action(DialogAction::RunCommand {
+                command: "/accept".into(),
+            }),
+        );
+    let components = [
+        TextComponent::scoreboard("@s", "kills"),
+        TextComponent::entity("@e[type=pig]", Some(" | ".into())),
+        TextComponent::nbt("Health", NbtSource::block(0, 64, 0), true, None),
+        TextComponent::sprite("item/diamond_sword"),
+        TextComponent::player_head(ObjectPlayer::name("Steve"), false),
+        TextComponent::translated_with("chat.type.text", ["Steve", "Hi"]),
+        "Rules"
+            .color(Color::Rgb(255, 0, 68))
+            .shadow_color(128, 16, 32, 48)
+            .click_event(ClickEvent::show_dialog(dialog))
+            .add_child("!"),
+        "Pig".hover_event(HoverEvent::show_entity(
+            "minecraft:pig",
+            Uuid::nil(),
+            Some("Babe"),
+        )),
+    ];
+    for component in components {
+        let json = serde_json::to_value(&component).unwrap();
+        assert_valid(&validator, &json.to_string(), &json);
+    }
+}
+
+#[test]
+fn shorthand_forms() {
+    let validator = validator();
+    let json = json!({
+        "text": "",
+        "extra": ["a", ["b", {"text": "c"}], {"translate": "gui.done", "with": ["d"]}],
+        "hover_event": {"action": "show_text", "value": "{\"text\":\"e\"}"},
+        "bold": "1b"
+    });
+    assert!(TextComponent::from_json_value(json.clone()).is_ok());
+    assert_valid(&validator, "shorthands", &json);
+}
+
+#[test]
+fn rejects_what_serde_rejects() {
+    let validator = validator();
+    let invalid = [
+        json!({"bold": true}),
+        json!({"text": "a", "color": "pink"}),
+        json!({"text": "a", "color": "#80ff0044"}),
+        json!({"text": "a", "click_event": {"action": "run_command"}}),
+        json!({"text": "a", "hover_event": {"action": "show_item"}}),
+        json!({"nbt": "Health"}),
+        json!({"score": {"name": "@s"}}),
+        json!({"text": "a", "extra": [1]}),
+    ];
+    for json in invalid {
+        assert!(
+            TextComponent::from_json_value(json.clone()).is_err(),
+            "{json} is read by serde"
+        );
+        assert!(!validator.is_valid(&json), "{json} matches the schema");
+    }
+}
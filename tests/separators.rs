@@ -0,0 +1,213 @@
+use text_components::{Modifier, TextComponent, content::NbtSource, format::Color};
+
+fn entity_forms() -> [(TextComponent, TextComponent); 3] {
+    [
+        // A string separator is a plain component
+        (
+            TextComponent::entity_sep_str("@a", " & "),
+            TextComponent::entity("@a", Some(TextComponent::plain(" & "))),
+        ),
+        (
+            TextComponent::entity_sep_str("@a", " & ".color(Color::Red)),
+            TextComponent::entity("@a", Some(" & ".color(Color::Red))),
+        ),
+        (
+            TextComponent::entity_sep_str("@a", ""),
+            TextComponent::entity("@a", Some(TextComponent::plain(""))),
+        ),
+    ]
+}
+
+#[test]
+fn constructors() {
+    for (short, long) in entity_forms() {
+        assert_eq!(short, long);
+    }
+    assert_eq!(
+        TextComponent::nbt_sep_str("Health", NbtSource::Entity("@p".into()), false, " | "),
+        TextComponent::nbt(
+            "Health",
+            NbtSource::Entity("@p".into()),
+            false,
+            Some(" | ".into())
+        )
+    );
+}
+
+#[test]
+fn snbt_forms() {
+    let expected = TextComponent::entity_sep_str("@a", " & ");
+    for snbt in [
+        r#"{selector:"@a",separator:" & "}"#,
+        r#"{selector:"@a",separator:' & '}"#,
+        r#"{selector:"@a",separator:{text:" & "}}"#,
+        r#"{selector:"@a",separator:[" & "]}"#,
+    ] {
+        assert_eq!(TextComponent::from_snbt(snbt).unwrap(), expected, "{snbt}");
+    }
+    assert_eq!(
+        TextComponent::from_snbt(r#"{selector:"@a",separator:[" & ",{text:"!",bold:true}]}"#)
+            .unwrap(),
+        TextComponent::entity_sep_str("@a", " & ".add_child("!".bold(true)))
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_forms() {
+    use text_components::serde::borrowed::TextComponentDe;
+    let parse = |json: &str| {
+        let owned = serde_json::from_str::<TextComponent>(json).unwrap();
+        let borrowed = serde_json::from_str::<TextComponentDe>(json).unwrap();
+        assert_eq!(owned, borrowed.into_owned(), "{json}");
+        owned
+    };
+    let expected = TextComponent::entity_sep_str("@a", " & ");
+    for json in [
+        r#"{"selector":"@a","separator":" & "}"#,
+        r#"{"selector":"@a","separator":{"text":" & "}}"#,
+        r#"{"selector":"@a","separator":[" & "]}"#,
+    ] {
+        assert_eq!(parse(json), expected, "{json}");
+    }
+    assert_eq!(
+        parse(r#"{"nbt":"Health","entity":"@p","separator":[" | ",{"text":"!","bold":true}]}"#),
+        TextComponent::nbt_sep_str(
+            "Health",
+            NbtSource::Entity("@p".into()),
+            false,
+            " | ".add_child("!".bold(true))
+        )
+    );
+    assert!(serde_json::from_str::<TextComponent>(r#"{"selector":"@a","separator":5}"#).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_resolvables() {
+    use text_components::{content::Resolvable, serde::borrowed::TextComponentDe};
+    // Each is read the same alone, as a component and borrowed
+    let parse = |json: &str| {
+        let resolvable = serde_json::from_str::<Resolvable>(json).unwrap();
+        let component = serde_json::from_str::<TextComponent>(json).unwrap();
+        let borrowed = serde_json::from_str::<TextComponentDe>(json).unwrap();
+        assert_eq!(component, borrowed.into_owned(), "{json}");
+        assert_eq!(component, TextComponent::from(resolvable.clone()), "{json}");
+        resolvable
+    };
+    let score = Resolvable::Scoreboard {
+        selector: "@p".into(),
+        objective: "kills".into(),
+    };
+    let entity = |separator: TextComponent| Resolvable::Entity {
+        selector: "@a".into(),
+        separator: Box::new(separator),
+    };
+    let nbt = Resolvable::NBT {
+        path: "Health".into(),
+        interpret: None,
+        separator: Resolvable::nbt_separator(),
+        source: NbtSource::Entity("@p".into()),
+    };
+    assert_eq!(
+        parse(r#"{"score":{"name":"@p","objective":"kills"}}"#),
+        score
+    );
+    assert_eq!(
+        parse(r#"{"selector":"@a"}"#),
+        entity(*Resolvable::entity_separator())
+    );
+    assert_eq!(
+        parse(r#"{"selector":"@a","separator":{"text":";","color":"red"}}"#),
+        entity(";".color(Color::Red))
+    );
+    assert_eq!(parse(r#"{"nbt":"Health","entity":"@p"}"#), nbt);
+    assert!(serde_json::from_str::<Resolvable>(r#"{"nbt":"Health"}"#).is_err());
+
+    // With the keys of more than one, the first the game tries wins
+    for (json, expected) in [
+        (
+            r#"{"selector":"@a","nbt":"Health","entity":"@p"}"#,
+            entity(*Resolvable::entity_separator()),
+        ),
+        (
+            r#"{"nbt":"Health","entity":"@p","selector":"@a","separator":";"}"#,
+            entity(";".into()),
+        ),
+        (
+            r#"{"score":{"name":"@p","objective":"kills"},"selector":"@a"}"#,
+            score.clone(),
+        ),
+        (
+            r#"{"score":{"name":"@p","objective":"kills"},"nbt":"Health","entity":"@p"}"#,
+            score,
+        ),
+    ] {
+        assert_eq!(parse(json), expected, "{json}");
+        // SNBT agrees when there's no type
+        assert_eq!(
+            TextComponent::from_snbt(json).unwrap(),
+            TextComponent::from(expected),
+            "{json}"
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_round_trip() {
+    for (component, _) in entity_forms() {
+        let json = serde_json::to_string(&component).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TextComponent>(&json).unwrap(),
+            component,
+            "{json}"
+        );
+    }
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn nbt_forms() {
+    use simdnbt::{
+        ToNbtTag,
+        owned::{NbtCompound, NbtList, NbtTag},
+    };
+    let compound = |separator: NbtTag| {
+        NbtTag::Compound(NbtCompound::from_values(vec![
+            ("selector".into(), NbtTag::String("@a".into())),
+            ("separator".into(), separator),
+        ]))
+    };
+    let text = |text: &str| {
+        NbtTag::Compound(NbtCompound::from_values(vec![(
+            "text".into(),
+            NbtTag::String(text.into()),
+        )]))
+    };
+    let expected = TextComponent::entity_sep_str("@a", " & ");
+    for separator in [
+        NbtTag::String(" & ".into()),
+        text(" & "),
+        NbtTag::List(NbtList::Compound(vec![NbtCompound::from_values(vec![(
+            "text".into(),
+            NbtTag::String(" & ".into()),
+        )])])),
+    ] {
+        assert_eq!(
+            TextComponent::from_nbt(&compound(separator)),
+            Some(expected.clone())
+        );
+    }
+    // An empty string used to fall back to the default separator
+    assert_eq!(
+        TextComponent::from_nbt(&compound(NbtTag::String("".into()))),
+        Some(TextComponent::entity_sep_str("@a", ""))
+    );
+    for (component, _) in entity_forms() {
+        assert_eq!(
+            TextComponent::from_nbt(&(&component).to_nbt_tag()),
+            Some(component)
+        );
+    }
+}
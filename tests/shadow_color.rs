@@ -0,0 +1,89 @@
+use text_components::TextComponent;
+#[cfg(any(feature = "serde", feature = "nbt"))]
+use text_components::format::ShadowColorFormat;
+
+#[test]
+fn both_forms_roundtrip() {
+    let floats =
+        TextComponent::from_snbt(r#"{text:"a",shadow_color:[1.0f,0.2f,0.0f,1.0f]}"#).unwrap();
+    let packed = TextComponent::from_snbt(r#"{text:"a",shadow_color:4294914816l}"#).unwrap();
+    assert_eq!(floats, packed);
+    // The game writes it as a signed int
+    let signed = TextComponent::from_snbt(r#"{text:"a",shadow_color:-52480}"#).unwrap();
+    assert_eq!(floats, signed);
+
+    #[cfg(any(feature = "serde", feature = "nbt"))]
+    for format in [ShadowColorFormat::Packed, ShadowColorFormat::Floats] {
+        #[cfg(feature = "serde")]
+        json(format, &floats);
+        #[cfg(feature = "nbt")]
+        nbt(format, &floats);
+    }
+}
+
+#[cfg(feature = "serde")]
+fn json(format: ShadowColorFormat, component: &TextComponent) {
+    use text_components::{
+        resolving::NoResolutor,
+        serde::{WithShadowColors, borrowed::TextComponentDe},
+    };
+    let authored = r#"{"text":"a","shadow_color":[1.0,0.2,0.0,1.0]}"#;
+    let expected = match format {
+        ShadowColorFormat::Packed => r#"{"text":"a","shadow_color":4294914816}"#,
+        ShadowColorFormat::Floats => authored,
+    };
+    for json in [
+        authored,
+        r#"{"text":"a","shadow_color":4294914816}"#,
+        r#"{"text":"a","shadow_color":-52480}"#,
+    ] {
+        let parsed = TextComponent::from_json(json).unwrap();
+        assert_eq!(&parsed, component, "{json}");
+        let resolved = parsed.resolve(&NoResolutor);
+        let serialized = serde_json::to_string(&WithShadowColors(format, &resolved)).unwrap();
+        assert_eq!(serialized, expected, "{json}");
+    }
+    // Outside of the wrapper it's packed again
+    let packed = r#"{"text":"a","shadow_color":4294914816}"#;
+    assert_eq!(component.to_json(&NoResolutor).unwrap(), packed);
+    let borrowed: TextComponentDe = serde_json::from_str(authored).unwrap();
+    assert_eq!(&borrowed.into_owned(), component);
+}
+
+#[cfg(feature = "nbt")]
+fn nbt(format: ShadowColorFormat, component: &TextComponent) {
+    use text_components::{
+        nbt::{NbtBuilder, ToSNBT},
+        resolving::NoResolutor,
+    };
+    let tag = component.build(&NoResolutor, NbtBuilder::new().shadow_colors(format));
+    assert_eq!(&TextComponent::from_nbt(&tag).unwrap(), component);
+    let snbt = tag.to_snbt();
+    let expected = match format {
+        ShadowColorFormat::Packed => r#"{text:"a",shadow_color:-52480}"#,
+        ShadowColorFormat::Floats => r#"{text:"a",shadow_color:[1.0f,0.2f,0.0f,1.0f]}"#,
+    };
+    assert_eq!(snbt, expected);
+    assert_eq!(&TextComponent::from_snbt(&snbt).unwrap(), component);
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn out_of_range_packed_colors_are_clamped() {
+    use simdnbt::owned::NbtTag;
+    use text_components::{format::Format, nbt::NbtBuilder, resolving::NoResolutor};
+    for (color, packed) in [(1 << 40, -1), (i64::MIN, i32::MIN), (-52480, -52480)] {
+        let component = TextComponent {
+            format: Format {
+                shadow_color: Some(color),
+                ..Format::new()
+            },
+            ..TextComponent::plain("a")
+        };
+        let tag = component.build(&NoResolutor, NbtBuilder::new());
+        let NbtTag::Compound(compound) = tag else {
+            panic!("expected a compound, got {tag:?}");
+        };
+        assert_eq!(compound.get("shadow_color"), Some(&NbtTag::Int(packed)));
+    }
+}
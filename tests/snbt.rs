@@ -0,0 +1,544 @@
+use text_components::{
+    Modifier, TextComponent,
+    content::{Atlas, NbtSource, ObjectPlayer},
+    format::{Color, Format},
+    interactivity::HoverEvent,
+    parse::{SnbtError, iter_snbt_components},
+    translation::Translation,
+};
+
+fn parse(snbt: &str) -> TextComponent {
+    TextComponent::from_snbt(snbt).unwrap_or_else(|err| panic!("{snbt}: {err}"))
+}
+
+#[test]
+fn entity_separator() {
+    let expected = TextComponent::entity("@a", Some("; ".color(Color::Gray)));
+    for snbt in [
+        r#"{selector:"@a",separator:{text:"; ",color:"gray"}}"#,
+        r#"{separator:{text:"; ",color:"gray"},selector:"@a"}"#,
+    ] {
+        assert_eq!(parse(snbt), expected, "{snbt}");
+    }
+}
+
+#[test]
+fn nbt_separator() {
+    let expected = TextComponent::nbt(
+        "Health",
+        NbtSource::Entity("@p".into()),
+        false,
+        Some(" | ".into()),
+    );
+    for snbt in [
+        r#"{nbt:"Health",entity:"@p",separator:" | "}"#,
+        r#"{separator:" | ",nbt:"Health",entity:"@p"}"#,
+        r#"{nbt:"Health",separator:" | ",entity:"@p"}"#,
+    ] {
+        assert_eq!(parse(snbt), expected, "{snbt}");
+    }
+}
+
+#[test]
+fn keys_after_separator() {
+    // The separator used to be read twice, swallowing whatever came next
+    assert_eq!(
+        parse(r#"{selector:"@a",separator:", ",color:"red",extra:["!"]}"#),
+        TextComponent::entity("@a", Some(", ".into()))
+            .color(Color::Red)
+            .add_child("!")
+    );
+}
+
+/// Every order of the given `key:value` pairs.
+fn permutations(keys: &[&str]) -> Vec<String> {
+    if keys.len() <= 1 {
+        return vec![keys.join(",")];
+    }
+    let mut orders = vec![];
+    for i in 0..keys.len() {
+        let mut rest = keys.to_vec();
+        let first = rest.remove(i);
+        for order in permutations(&rest) {
+            orders.push(format!("{first},{order}"));
+        }
+    }
+    orders
+}
+
+fn assert_any_order(keys: &[&str], expected: &TextComponent) {
+    for order in permutations(keys) {
+        let snbt = format!("{{{order}}}");
+        assert_eq!(&parse(&snbt), expected, "{snbt}");
+    }
+}
+
+#[test]
+fn translation_key_order() {
+    let expected = Translation::<1>("chat.greeting")
+        .message(["Steve"])
+        .component_fallback("Hi %s");
+    assert_any_order(
+        &[
+            r#"translate:"chat.greeting""#,
+            r#"fallback:"Hi %s""#,
+            r#"with:["Steve"]"#,
+        ],
+        &expected,
+    );
+}
+
+#[test]
+fn nbt_key_order() {
+    let expected = TextComponent::nbt(
+        "Inventory",
+        NbtSource::Entity("@s".into()),
+        true,
+        Some("-".into()),
+    );
+    assert_any_order(
+        &[
+            r#"nbt:"Inventory""#,
+            r#"source:"entity""#,
+            r#"entity:"@s""#,
+            "interpret:true",
+            r#"separator:"-""#,
+        ],
+        &expected,
+    );
+}
+
+#[test]
+fn nbt_source_is_validated() {
+    assert!(TextComponent::from_snbt(r#"{nbt:"a",source:"chunk",block:"~ ~ ~"}"#).is_err());
+    // The source key picks which of the given locations is used
+    assert_eq!(
+        parse(r#"{entity:"@s",source:"storage",storage:"a:b",nbt:"c"}"#),
+        TextComponent::nbt("c", NbtSource::Storage("a:b".into()), false, None)
+    );
+}
+
+#[test]
+fn player_object_key_order() {
+    let expected = TextComponent::player_head(ObjectPlayer::name("Jeb_"), false);
+    assert_any_order(
+        &[r#"object:"player""#, r#"player:{name:"Jeb_"}"#, "hat:false"],
+        &expected,
+    );
+    assert_any_order(&[r#"player:{name:"Jeb_"}"#, "hat:false"], &expected);
+}
+
+#[test]
+fn atlas_object_key_order() {
+    let expected = TextComponent::atlas("item/diamond", Atlas::ITEMS);
+    assert_any_order(
+        &[r#"atlas:"minecraft:items""#, r#"sprite:"item/diamond""#],
+        &expected,
+    );
+    assert_any_order(
+        &[
+            r#"sprite:"item/diamond""#,
+            r#"object:"atlas""#,
+            r#"atlas:"minecraft:items""#,
+        ],
+        &expected,
+    );
+}
+
+#[test]
+fn unknown_keys_are_skipped() {
+    let expected = "Hi".color(Color::Red).add_child("!");
+    for snbt in [
+        r#"{text:"Hi",future:1.5f,color:"red",extra:["!"]}"#,
+        r#"{future:'a "quoted", }value',text:"Hi",color:"red",extra:["!"]}"#,
+        r#"{text:"Hi",future:true,color:"red",extra:["!"]}"#,
+        r#"{text:"Hi",future:[I;1,2,3],color:"red",extra:["!"]}"#,
+        r#"{text:"Hi",future:{a:{b:[{c:"}]"},{d:[1b,2b]}],e:'{'},f:[]},color:"red",extra:["!"]}"#,
+        r#"{text:"Hi", future : { nested : [ { deeper : {} } ] } , color:"red",extra:["!"]}"#,
+    ] {
+        assert_eq!(parse(snbt), expected, "{snbt}");
+    }
+}
+
+#[test]
+fn strict_rejects_unknown_keys() {
+    let snbt = r#"{text:"Hi",future:{a:{b:[1,2]}},color:"red"}"#;
+    assert!(TextComponent::from_snbt(snbt).is_ok());
+    assert!(TextComponent::from_snbt_strict(snbt).is_err());
+    assert_eq!(
+        TextComponent::from_snbt_strict(r#"{text:"Hi",color:"red"}"#).unwrap(),
+        "Hi".color(Color::Red)
+    );
+}
+
+#[test]
+fn strict_keeps_to_its_input() {
+    // Embedded components are parsed on their own, the way from_embedded_str does
+    let snbt = r#"{text:"Hi",hover_event:{action:"show_text",value:'{text:"b",future:1}'}}"#;
+    assert_eq!(
+        TextComponent::from_snbt_strict(snbt).unwrap(),
+        "Hi".hover_event(HoverEvent::show_text("b"))
+    );
+    assert!(TextComponent::from_snbt_strict(r#"{text:"Hi",future:1}"#).is_err());
+    assert!(TextComponent::from_snbt(r#"{text:"Hi",future:1}"#).is_ok());
+}
+
+#[test]
+fn numbers() {
+    use text_components::parse::Num;
+    let cases: &[(&str, Option<Num>)] = &[
+        ("0", Some(Num::I32(0))),
+        ("42", Some(Num::I32(42))),
+        ("-42", Some(Num::I32(-42))),
+        ("+7", Some(Num::I32(7))),
+        ("12b", Some(Num::I8(12))),
+        ("12B", Some(Num::I8(12))),
+        ("-128b", Some(Num::I8(-128))),
+        ("300s", Some(Num::I16(300))),
+        ("300S", Some(Num::I16(300))),
+        ("5i", Some(Num::I32(5))),
+        ("5I", Some(Num::I32(5))),
+        ("9000000000l", Some(Num::I64(9000000000))),
+        ("9000000000L", Some(Num::I64(9000000000))),
+        ("1.5f", Some(Num::F32(1.5))),
+        ("1.5F", Some(Num::F32(1.5))),
+        ("1.5d", Some(Num::F64(1.5))),
+        ("1.5D", Some(Num::F64(1.5))),
+        ("2d", Some(Num::F64(2.0))),
+        ("1.5", Some(Num::F64(1.5))),
+        (".5", Some(Num::F64(0.5))),
+        ("-.5f", Some(Num::F32(-0.5))),
+        ("1.", Some(Num::F64(1.0))),
+        ("1.5e3", Some(Num::F64(1500.0))),
+        ("1E-2", Some(Num::F64(0.01))),
+        ("2e+2f", Some(Num::F32(200.0))),
+        ("255ub", Some(Num::I8(-1))),
+        ("255UB", Some(Num::I8(-1))),
+        ("-5sb", Some(Num::I8(-5))),
+        ("65535us", Some(Num::I16(-1))),
+        ("-5SS", Some(Num::I16(-5))),
+        ("4294967295ui", Some(Num::I32(-1))),
+        ("-5si", Some(Num::I32(-5))),
+        ("18446744073709551615ul", Some(Num::I64(-1))),
+        ("-5sl", Some(Num::I64(-5))),
+        ("128b", None),
+        ("256ub", None),
+        ("-1ub", None),
+        ("40000s", None),
+        ("2147483648", None),
+        ("99999999999999999999l", None),
+        ("1e400", None),
+        ("1e40f", None),
+        ("1-2", None),
+        ("--1", None),
+        ("1.2.3", None),
+        (".", None),
+        ("-", None),
+        ("e5", None),
+        ("1e", None),
+        ("1.5b", None),
+        ("1xb", None),
+        ("1bb", None),
+        ("", None),
+    ];
+    for (snbt, expected) in cases {
+        match expected {
+            Some(num) => assert_eq!(Num::from_snbt(snbt).ok(), Some(*num), "{snbt}"),
+            None => assert!(Num::from_snbt(snbt).is_err(), "{snbt} parsed"),
+        }
+    }
+    assert!(matches!(
+        Num::from_snbt("128b"),
+        Err(SnbtError::NumberOverflow(_, kind)) if kind == "byte"
+    ));
+    assert!(matches!(
+        Num::from_snbt("70000us"),
+        Err(SnbtError::NumberOverflow(_, kind)) if kind == "unsigned short"
+    ));
+}
+
+#[test]
+fn int_arrays() {
+    let expected = TextComponent::player_head(ObjectPlayer::id([1, -2, 3, -4]), true);
+    for snbt in [
+        r#"{player:{id:[I;1,-2,3,-4]}}"#,
+        r#"{player:{id:[1,-2,3,-4]}}"#,
+        r#"{player:{id:[ I ; 1 , -2 , 3 , -4 ]}}"#,
+        r#"{player:{id:[I;1,-2,3,-4i]}}"#,
+    ] {
+        assert_eq!(parse(snbt), expected, "{snbt}");
+    }
+    assert!(TextComponent::from_snbt(r#"{player:{id:[I;1,2,3]}}"#).is_err());
+    assert!(TextComponent::from_snbt(r#"{player:{id:[I;1,2b,3,4]}}"#).is_err());
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn player_id_roundtrip() {
+    use text_components::{
+        nbt::{NbtBuilder, ToSNBT},
+        resolving::NoResolutor,
+    };
+    let head = TextComponent::player_head(ObjectPlayer::id([1, -2, 3, -4]), false);
+    let snbt = head.build(&NoResolutor, NbtBuilder::new()).to_snbt();
+    assert_eq!(parse(&snbt), head, "{snbt}");
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn number_emission() {
+    use simdnbt::owned::NbtTag;
+    use text_components::{
+        nbt::{SnbtWriteError, ToSNBT},
+        parse::Num,
+    };
+    let cases = [
+        (NbtTag::Int(i32::MIN), "-2147483648", Num::I32(i32::MIN)),
+        (NbtTag::Int(i32::MAX), "2147483647", Num::I32(i32::MAX)),
+        (
+            NbtTag::Long(i64::MIN),
+            "-9223372036854775808l",
+            Num::I64(i64::MIN),
+        ),
+        (
+            NbtTag::Float(1e-45),
+            "0.000000000000000000000000000000000000000000001f",
+            Num::F32(1e-45),
+        ),
+        (
+            NbtTag::Float(f32::MIN_POSITIVE / 4.0),
+            "0.000000000000000000000000000000000000002938736f",
+            Num::F32(f32::MIN_POSITIVE / 4.0),
+        ),
+        (
+            NbtTag::Float(f32::MAX),
+            "340282350000000000000000000000000000000.0f",
+            Num::F32(f32::MAX),
+        ),
+        (NbtTag::Float(0.1), "0.1f", Num::F32(0.1)),
+        (NbtTag::Double(1e16), "10000000000000000.0d", Num::F64(1e16)),
+    ];
+    for (tag, expected, num) in cases {
+        let snbt = tag.try_to_snbt().unwrap();
+        assert_eq!(snbt, expected);
+        assert_eq!(Num::from_snbt(&snbt).unwrap(), num, "{snbt}");
+    }
+    assert!(matches!(
+        NbtTag::Float(f32::NAN).try_to_snbt(),
+        Err(SnbtWriteError::NonFiniteFloat(n)) if n.is_nan()
+    ));
+    assert_eq!(
+        NbtTag::Double(f64::INFINITY).try_to_snbt(),
+        Err(SnbtWriteError::NonFiniteDouble(f64::INFINITY))
+    );
+}
+
+#[test]
+fn shadow_color_lists() {
+    let shadow = |snbt: &str| parse(snbt).format.shadow_color;
+    assert_eq!(
+        shadow(r#"{text:"a",shadow_color:[1.0f,0.5f,0.0f,1.0f]}"#),
+        Some(0xFFFF8000)
+    );
+    assert_eq!(
+        shadow(r#"{text:"a",shadow_color:[ 0.2, 0.4, 0.6, 0.8 ]}"#),
+        Some(
+            Format::new()
+                .shadow_color(204, 51, 102, 153)
+                .shadow_color
+                .unwrap()
+        )
+    );
+    // Channels out of range are clamped
+    assert_eq!(
+        shadow(r#"{text:"a",shadow_color:[2,-1,0,1]}"#),
+        Some(0xFFFF0000)
+    );
+    for snbt in [
+        r#"{text:"a",shadow_color:[1.0,0.5,0.0]}"#,
+        r#"{text:"a",shadow_color:[1.0,0.5,0.0,1.0,1.0]}"#,
+        r#"{text:"a",shadow_color:[]}"#,
+    ] {
+        assert!(TextComponent::from_snbt(snbt).is_err(), "{snbt}");
+    }
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn shadow_color_lists_match_nbt() {
+    use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+    let channels = [0.2f32, 0.4, 0.6, 0.8];
+    let tag = NbtTag::Compound(NbtCompound::from_values(vec![
+        ("text".into(), NbtTag::String("a".into())),
+        (
+            "shadow_color".into(),
+            NbtTag::List(NbtList::Float(channels.to_vec())),
+        ),
+    ]));
+    assert_eq!(
+        TextComponent::from_nbt(&tag).unwrap(),
+        parse(r#"{text:"a",shadow_color:[0.2f,0.4f,0.6f,0.8f]}"#)
+    );
+}
+
+fn nested_hovers() -> TextComponent {
+    let inner = "c".hover_event(HoverEvent::ShowText {
+        value: Box::new("d".into()),
+    });
+    let middle = "b".hover_event(HoverEvent::ShowText {
+        value: Box::new(inner),
+    });
+    "a".hover_event(HoverEvent::ShowText {
+        value: Box::new(TextComponent::plain("x").add_child(middle)),
+    })
+}
+
+#[test]
+fn hover_text_values() {
+    let expected = "a".hover_event(HoverEvent::ShowText {
+        value: Box::new(TextComponent::plain("x").add_child("y".bold(true))),
+    });
+    assert_any_order(
+        &[
+            r#"text:"a""#,
+            r#"hover_event:{action:"show_text",value:['x',{text:'y',bold:1b}]}"#,
+        ],
+        &expected,
+    );
+    assert_eq!(
+        parse(r#"{text:"a",hover_event:{value:['x',{text:'y',bold:1b}],action:"show_text"}}"#),
+        expected
+    );
+    assert_eq!(
+        parse(
+            r#"{text:"a",hover_event:{value:["x",{text:"b",hover_event:{value:{text:"c",hover_event:{value:"d",action:"show_text"}},action:"show_text"}}],action:"show_text"}}"#
+        ),
+        nested_hovers()
+    );
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn hover_text_roundtrip() {
+    use text_components::{
+        nbt::{NbtBuilder, ToSNBT},
+        resolving::NoResolutor,
+    };
+    let component = nested_hovers();
+    let snbt = component.build(&NoResolutor, NbtBuilder::new()).to_snbt();
+    assert_eq!(parse(&snbt), component, "{snbt}");
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn unresolved_roundtrip() {
+    use simdnbt::ToNbtTag;
+    use text_components::nbt::ToSNBT;
+    let score = TextComponent::scoreboard("@s", "kills").color(Color::Red);
+    // Left unresolved the contents are written for the game to resolve them
+    assert_eq!(
+        score.to_nbt_tag().to_snbt(),
+        r#"{score:{name:"@s",objective:"kills"},color:"red"}"#
+    );
+    for component in [
+        TextComponent::scoreboard("@s", "kills"),
+        TextComponent::entity("@a", None),
+        TextComponent::entity("@e[type=cow]", Some(" & ".bold(true))),
+        TextComponent::nbt("Health", NbtSource::entity("@p"), false, None),
+        TextComponent::nbt(
+            "Items[0]",
+            NbtSource::block(1, -2, 3),
+            true,
+            Some(" | ".into()),
+        ),
+        TextComponent::nbt(
+            "quest.title",
+            NbtSource::Storage("mypack:data".into()),
+            true,
+            None,
+        ),
+        "Owner: ".add_child(
+            TextComponent::entity("@p", None).hover_event(HoverEvent::ShowText {
+                value: Box::new(TextComponent::scoreboard("@p", "level")),
+            }),
+        ),
+    ] {
+        let snbt = (&component).to_nbt_tag().to_snbt();
+        assert_eq!(parse(&snbt), component, "{snbt}");
+    }
+}
+
+#[test]
+fn prefix() {
+    let input = r#"  {text:"Hi",color:"red"} tellraw @a"#;
+    let (component, used) = TextComponent::from_snbt_prefix(input).unwrap();
+    assert_eq!(component, "Hi".color(Color::Red));
+    assert_eq!(&input[used..], " tellraw @a");
+
+    let (component, used) = TextComponent::from_snbt_prefix(r#"["é",'ü']!"#).unwrap();
+    assert_eq!(component, "é".add_child("ü"));
+    assert_eq!(used, 11);
+    assert!(TextComponent::from_snbt_prefix(r#"{text:"Hi""#).is_err());
+}
+
+#[test]
+fn back_to_back_components() {
+    let input = r#"{text:"a",bold:true}"b",
+        ['c',"d"]{text:"e"} junk"#;
+    let mut components = iter_snbt_components(input);
+    assert_eq!(components.next().unwrap().unwrap(), "a".bold(true));
+    assert_eq!(components.next().unwrap().unwrap(), "b".into());
+    assert_eq!(components.next().unwrap().unwrap(), "c".add_child("d"));
+    assert_eq!(components.next().unwrap().unwrap(), "e".into());
+    assert!(components.next().unwrap().is_err());
+    assert_eq!(components.rest(), "junk");
+    assert!(components.next().is_none());
+
+    let parsed = iter_snbt_components(r#" "x" , "y",,"z" "#)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(parsed, ["x".into(), "y".into(), "z".into()]);
+    assert_eq!(iter_snbt_components("  ,  ").count(), 0);
+}
+
+#[test]
+fn escapes() {
+    for (snbt, expected) in [
+        (r#""a\"b\'c\\d""#, "a\"b'c\\d"),
+        (r#"'a\"b\'c\\d'"#, "a\"b'c\\d"),
+        (r#""\b\f\n\r\s\t""#, "\u{8}\u{c}\n\r \t"),
+        (r#""\x41\u00e9\U0001F600""#, "Aé😀"),
+        (r#""\ud83d\ude00 \uD83D\uDE00""#, "😀 😀"),
+    ] {
+        assert_eq!(parse(snbt), TextComponent::plain(expected), "{snbt}");
+    }
+}
+
+#[test]
+fn invalid_escapes() {
+    for snbt in [r#""\q""#, r#""\x4""#, r#""\ud83d""#, r#""\U00110000""#] {
+        assert!(
+            matches!(
+                TextComponent::from_snbt(snbt),
+                Err(SnbtError::InvalidEscape(_))
+            ),
+            "{snbt}"
+        );
+    }
+    let (component, errors) = TextComponent::from_snbt_lossy(r#""a\qb""#);
+    assert_eq!(component, TextComponent::plain("a\\qb"));
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn truncated_input_is_an_error() {
+    let snbt = r#"{text:"a",score:{name:"@s",objective:"k"},player:{name:"Jeb_",id:[I;1,2,3,4]},hover_event:{action:"show_entity",id:"pig",uuid:[I;1,2,3,4],name:'b'},click_event:{action:"open_url",url:"https://a.b"},shadow_color:[1.0f,0.5f,0.0f,1.0f]}"#;
+    parse(snbt);
+    for (end, _) in snbt.char_indices().skip(1) {
+        assert!(
+            TextComponent::from_snbt(&snbt[..end]).is_err(),
+            "{} parsed",
+            &snbt[..end]
+        );
+    }
+}
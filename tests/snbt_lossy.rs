@@ -0,0 +1,112 @@
+use text_components::{
+    Modifier, TextComponent, content::Content, interactivity::HoverEvent, parse::SnbtError,
+};
+
+const EXAMPLE: &str = r#"["\"Howdy!\"", { text:"\nThis is a text component!\n", color:'blue', "bold":1b, italic:true }, {text:"Texto" , type: "translatable", fallback:"lol\n", translate:"lmao"}, {sprite:"items/iron_sword"}, "\n", {object:"player", player:{name:"MrMelther"}, hover_event:{action:"show_text",value:{text:"Send msg to MrMelther"}}, click_event:{action:"suggest_command", command:"/msg MrMelther "} }, {object:"player", player:{properties:[{name:"textures", value:"[Put your base64 texture here!]"}]}}]"#;
+
+fn example() -> TextComponent {
+    TextComponent::from_snbt(EXAMPLE).unwrap()
+}
+
+/// Parses the example lossily with `from` replaced by `to`.
+fn mangled(from: &str, to: &str) -> (TextComponent, Vec<SnbtError>) {
+    assert!(EXAMPLE.contains(from), "{from}");
+    TextComponent::from_snbt_lossy(&EXAMPLE.replacen(from, to, 1))
+}
+
+#[test]
+fn clean_input() {
+    let (component, errors) = TextComponent::from_snbt_lossy(EXAMPLE);
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(component, example());
+}
+
+#[test]
+fn unknown_keys_are_skipped() {
+    let (component, errors) = mangled("color:'blue'", "colour:'blue', color:'blue'");
+    assert_eq!(component, example());
+    assert!(
+        matches!(&errors[..], [SnbtError::At(_, error)] if matches!(&**error, SnbtError::UnknownKey(key) if key == "colour")),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn bad_values_are_dropped() {
+    let mut expected = example();
+    expected.children[0].format.color = None;
+    expected.children[0].format.bold = None;
+    let (component, errors) = mangled(r#"color:'blue', "bold":1b"#, r#"color:'bleu', "bold":[1b]"#);
+    assert_eq!(component, expected);
+    assert_eq!(errors.len(), 2, "{errors:?}");
+}
+
+#[test]
+fn broken_children_are_skipped() {
+    let mut expected = example();
+    expected.children.remove(2);
+    let (component, errors) = mangled(
+        r#"{sprite:"items/iron_sword"}"#,
+        r#"{sprite:"items/iron_sword" oops}"#,
+    );
+    assert_eq!(component, expected);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+
+    let mut expected = example();
+    expected.children.remove(0);
+    let (component, errors) = mangled(
+        r#"{ text:"\nThis is a text component!\n", color:'blue', "bold":1b, italic:true }"#,
+        r#"{ color:'blue', "bold":1b, italic:true }"#,
+    );
+    assert_eq!(component, expected);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+}
+
+#[test]
+fn cut_strings_take_the_rest() {
+    let end = EXAMPLE.find(r#" texture here!]"#).unwrap();
+    let (component, errors) = TextComponent::from_snbt_lossy(&EXAMPLE[..end]);
+    let mut expected = example();
+    expected.children.pop();
+    assert_eq!(component, expected);
+    assert_eq!(errors.len(), 2, "{errors:?}");
+
+    let end = EXAMPLE.find(r#"component!\n""#).unwrap();
+    let (component, errors) = TextComponent::from_snbt_lossy(&EXAMPLE[..end]);
+    assert_eq!(
+        component,
+        TextComponent::plain("\"Howdy!\"").add_child(TextComponent::plain("\nThis is a text "))
+    );
+    assert!(
+        matches!(&errors[..], [SnbtError::At(offset, error)] if *offset == end && matches!(**error, SnbtError::EndedAbruptely(_))),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn unrecoverable() {
+    let (component, errors) = TextComponent::from_snbt_lossy("[]");
+    assert_eq!(component.content, Content::Text { text: "".into() });
+    assert_eq!(errors.len(), 1, "{errors:?}");
+}
+
+#[test]
+fn errors_stay_with_their_input() {
+    // The embedded component can't be parsed, so it's kept as text without being reported
+    let snbt =
+        r#"{text:"Hi",hover_event:{action:"show_text",value:'{text:"b",color:"bleu"}'},future:1}"#;
+    let (component, errors) = TextComponent::from_snbt_lossy(snbt);
+    assert_eq!(
+        component,
+        "Hi".hover_event(HoverEvent::show_text(r#"{text:"b",color:"bleu"}"#))
+    );
+    assert!(
+        matches!(&errors[..], [SnbtError::At(_, error)] if matches!(**error, SnbtError::UnknownKey(_))),
+        "{errors:?}"
+    );
+    assert!(
+        TextComponent::from_snbt_lossy(r#"{text:"Hi"}"#)
+            .1
+            .is_empty()
+    );
+}
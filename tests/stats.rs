@@ -0,0 +1,70 @@
+use text_components::{
+    Modifier, TextComponent,
+    interactivity::{ClickEvent, HoverEvent},
+    stats::ComponentStats,
+    translation::TranslatedMessage,
+};
+use uuid::Uuid;
+
+fn fixture() -> TextComponent {
+    let hover = "hi".click_event(ClickEvent::copy_to_clipboard("hi"));
+    let separator = "; ".click_event(ClickEvent::run_command("/kill @a"));
+    TextComponent::plain("Hello")
+        .click_event(ClickEvent::run_command("/help"))
+        .hover_event(HoverEvent::show_text(hover))
+        .add_child(
+            TranslatedMessage::new("chat.type.text", Some(Box::new(["ab".into()]))).component(),
+        )
+        .add_child(TextComponent::entity("@a", Some(separator)))
+        .add_child(
+            TextComponent::scoreboard("@s", "kills").hover_event(HoverEvent::show_entity(
+                "minecraft:player",
+                Uuid::nil(),
+                Some("Bob"),
+            )),
+        )
+}
+
+#[test]
+fn counts() {
+    assert_eq!(
+        fixture().stats(),
+        ComponentStats {
+            node_count: 8,
+            max_depth: 3,
+            text_bytes: 14,
+            click_events: 3,
+            hover_events: 2,
+            translations: 1,
+            resolvables: 2,
+            customs: 0,
+        }
+    );
+}
+
+#[test]
+fn single_node() {
+    let stats = TextComponent::plain("Hello").stats();
+    assert_eq!(
+        (stats.node_count, stats.max_depth, stats.text_bytes),
+        (1, 1, 5)
+    );
+}
+
+#[test]
+fn click_actions() {
+    let component = fixture();
+    assert!(
+        component
+            .contains_click_action(|click| matches!(click, ClickEvent::CopyToClipboard { .. }))
+    );
+    assert!(!component.contains_click_action(|click| matches!(click, ClickEvent::OpenUrl { .. })));
+
+    let separator = TextComponent::entity(
+        "@a",
+        Some("; ".click_event(ClickEvent::run_command("/op @s"))),
+    );
+    assert!(
+        separator.contains_click_action(|click| matches!(click, ClickEvent::RunCommand { .. }))
+    );
+}
@@ -0,0 +1,233 @@
+use std::borrow::Cow;
+use text_components::{
+    Modifier, TextComponent,
+    content::{Content, Object, Resolvable},
+    fmt::{ComponentVisitor, TextBuilder, VisitorTarget},
+    format::Format,
+    interactivity::{ClickEvent, Interactivity},
+    resolving::{BuildTarget, NoResolutor, TextResolutor},
+    translation::Translation,
+};
+
+struct LogBuilder;
+impl BuildTarget for LogBuilder {
+    type Result = String;
+    fn build_component<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+        component: &TextComponent,
+    ) -> String {
+        TextBuilder::stringify_content(self, resolutor, component)
+            + &component
+                .children
+                .iter()
+                .map(|child| self.build_component(resolutor, child))
+                .collect::<String>()
+    }
+
+    fn decorate_interactions(&self, text: String, interactions: &Interactivity) -> String {
+        match &interactions.click {
+            Some(ClickEvent::OpenUrl { url }) => format!("{text} (link: {url})"),
+            _ => text,
+        }
+    }
+}
+
+fn component() -> TextComponent {
+    TextComponent::new().add_children::<TextComponent>(vec![
+        "See ".into(),
+        "the wiki"
+            .click_event(ClickEvent::open_url("https://minecraft.wiki"))
+            .insertion("wiki"),
+        " or ".into(),
+        "run /help".click_event(ClickEvent::suggest_command("/help")),
+    ])
+}
+
+#[test]
+fn decorated_interactions() {
+    assert_eq!(
+        component().build(&NoResolutor, LogBuilder),
+        "See the wiki (link: https://minecraft.wiki) or run /help"
+    );
+}
+
+#[test]
+fn interactions_dropped_by_default() {
+    assert_eq!(
+        component().to_plain(&NoResolutor),
+        "See the wiki or run /help"
+    );
+}
+
+/// The recursion [TextBuilder] used before going through [VisitorTarget].
+struct LegacyTextBuilder;
+impl BuildTarget for LegacyTextBuilder {
+    type Result = String;
+    fn build_component<R: TextResolutor + ?Sized>(
+        &self,
+        resolutor: &R,
+        component: &TextComponent,
+    ) -> String {
+        TextBuilder::stringify_content(self, resolutor, component)
+            + &component
+                .children
+                .iter()
+                .map(|child| self.build_component(resolutor, child))
+                .collect::<String>()
+    }
+}
+
+const CHAT: Translation<2> = Translation("chat.type.text");
+const GIVE: Translation<2> = Translation("commands.give.success");
+const MISSING: Translation<0> = Translation("missing.key");
+
+fn keybind(key: &'static str) -> TextComponent {
+    TextComponent::from(Content::Keybind {
+        keybind: key.into(),
+    })
+}
+
+struct English;
+impl TextResolutor for English {
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        NoResolutor.resolve_content(resolvable)
+    }
+    #[cfg(feature = "custom")]
+    fn resolve_custom(&self, data: &text_components::custom::CustomData) -> Option<TextComponent> {
+        NoResolutor.resolve_custom(data)
+    }
+    fn translate(&self, key: &str) -> Option<Cow<'_, str>> {
+        match key {
+            "chat.type.text" => Some(Cow::Borrowed("<%s> %s")),
+            "commands.give.success" => Some(Cow::Borrowed("Gave %2$s to %1$s")),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct Tags(String);
+impl ComponentVisitor for Tags {
+    type Output = String;
+    fn visit_text(&mut self, text: &str, format: &Format, interactions: &Interactivity) {
+        let bold = format.bold == Some(true);
+        if let Some(ClickEvent::OpenUrl { url }) = &interactions.click {
+            self.0.push_str(&format!("<a {url}>"));
+        }
+        match bold {
+            true => self.0.push_str(&format!("<b>{text}</b>")),
+            false => self.0.push_str(text),
+        }
+        if interactions.click.is_some() {
+            self.0.push_str("</a>");
+        }
+    }
+    fn visit_object(&mut self, _: &Object, _: &Format, _: &Interactivity) {
+        self.0.push_str("<img>");
+    }
+    fn visit_unresolved(&mut self, _: &Content, _: &Format, _: &Interactivity) {
+        self.0.push('?');
+    }
+    fn enter_children(&mut self) {
+        self.0.push('(');
+    }
+    fn exit_children(&mut self) {
+        self.0.push(')');
+    }
+    fn finish(self) -> String {
+        self.0
+    }
+}
+
+#[test]
+fn visitor() {
+    let component = CHAT
+        .message([
+            "Steve".bold(true),
+            "hi".add_child(TextComponent::sprite("item/apple")),
+        ])
+        .component()
+        .click_event(ClickEvent::open_url("https://example.com"))
+        .add_children::<TextComponent>(vec![" ".into(), "!".bold(true), keybind("key.jump")]);
+    assert_eq!(
+        component.build(&English, VisitorTarget(Tags::default())),
+        "<a https://example.com><</a><a https://example.com><b>Steve</b></a>\
+        <a https://example.com>> </a><a https://example.com>hi</a>(<img>)\
+        (<a https://example.com> </a>\
+        <a https://example.com><b>!</b></a>?)"
+    );
+}
+
+#[test]
+fn plain_text() {
+    let component = GIVE
+        .message(["Alex".into(), "Diamond".add_child(" x3")])
+        .component()
+        .add_children::<TextComponent>(vec![
+            MISSING.msg().component(),
+            MISSING.msg().component_fallback("fallback"),
+            keybind("key.jump"),
+            TextComponent::sprite("item/apple"),
+        ]);
+    assert_eq!(
+        component.to_plain(&English),
+        component.build(&English, LegacyTextBuilder)
+    );
+    assert_eq!(
+        component.to_plain(&English),
+        "Gave Diamond x3 to Alex[Translation: missing.key]fallback[Keybind: key.jump][Object: item/apple]"
+    );
+}
+
+#[cfg(feature = "test-util")]
+proptest::proptest! {
+    #[test]
+    fn plain_text_is_unchanged(component in text_components::testutil::component()) {
+        proptest::prop_assert_eq!(
+            component.to_plain(&English),
+            component.build(&English, LegacyTextBuilder)
+        );
+    }
+}
+
+#[test]
+fn flatten_runs() {
+    use text_components::{fmt::TextRun, format::Color};
+    let link = ClickEvent::open_url("https://example.com");
+    let component = "Hello "
+        .color(Color::Red)
+        .add_children::<TextComponent>(vec![
+            "big ".bold(true).add_child("world".color(Color::Blue)),
+            "".into(),
+            "!".into(),
+            GIVE.message(["you".italic(true), "cake".into()])
+                .component()
+                .click_event(link.clone()),
+        ]);
+    let run = |text: &str, format: Format, interactions: Interactivity| TextRun {
+        text: text.to_string(),
+        format,
+        interactions,
+    };
+    let red = Format::new().color(Color::Red);
+    let linked = Interactivity {
+        click: Some(link),
+        ..Interactivity::new()
+    };
+    assert_eq!(
+        component.flatten_runs(&English),
+        vec![
+            run("Hello ", red.clone(), Interactivity::new()),
+            run("big ", red.clone().bold(true), Interactivity::new()),
+            run(
+                "world",
+                Format::new().color(Color::Blue).bold(true),
+                Interactivity::new()
+            ),
+            run("!", red.clone(), Interactivity::new()),
+            run("Gave cake to ", red.clone(), linked.clone()),
+            run("you", red.italic(true), linked),
+        ]
+    );
+}
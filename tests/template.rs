@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use text_components::{
+    Modifier, TextComponent,
+    format::Color,
+    resolving::NoResolutor,
+    template::{ComponentTemplate, MissingPlaceholder},
+};
+
+fn values() -> HashMap<&'static str, TextComponent> {
+    HashMap::from([
+        ("prefix", "[+]".color(Color::Green)),
+        ("player", "Steve".into()),
+        ("count", "3".bold(true)),
+    ])
+}
+
+#[test]
+fn render() {
+    let template = ComponentTemplate::new("{prefix} {player} joined ({count} online)");
+    assert_eq!(
+        template.placeholders().collect::<Vec<_>>(),
+        ["prefix", "player", "count"]
+    );
+    let message = template.render(&values()).unwrap();
+    assert_eq!(
+        message,
+        TextComponent::new().add_children::<TextComponent>(vec![
+            "[+]".color(Color::Green),
+            " ".into(),
+            "Steve".into(),
+            " joined (".into(),
+            "3".bold(true),
+            " online)".into(),
+        ])
+    );
+    assert_eq!(
+        message.to_plain(&NoResolutor),
+        "[+] Steve joined (3 online)"
+    );
+}
+
+#[test]
+fn missing_values() {
+    let template = ComponentTemplate::new("{player} has {coins} coins");
+    assert_eq!(
+        template.render(&values()),
+        Err(MissingPlaceholder("coins".to_string()))
+    );
+    assert_eq!(
+        MissingPlaceholder("coins".to_string()).to_string(),
+        "No value given for the placeholder {coins}"
+    );
+}
+
+#[test]
+fn repeated_placeholders() {
+    let template = ComponentTemplate::new("{player}, {player} and {player}");
+    assert_eq!(template.placeholders().count(), 3);
+    assert_eq!(
+        template.render(&values()).unwrap().to_plain(&NoResolutor),
+        "Steve, Steve and Steve"
+    );
+}
+
+#[test]
+fn escapes() {
+    let template = ComponentTemplate::new("{{player}} {player} {} }} {{ {open");
+    assert_eq!(
+        template.render(&values()).unwrap().to_plain(&NoResolutor),
+        "{player} Steve {} } { {open"
+    );
+    let template = ComponentTemplate::new("{{literal}}");
+    assert_eq!(template.placeholders().count(), 0);
+    assert_eq!(
+        template.render(&HashMap::new()).unwrap(),
+        TextComponent::plain("{literal}")
+    );
+}
+
+#[test]
+fn formatting_boundaries() {
+    // Like "<gold>Welcome <bold>{player}</bold>{count}</gold>!"
+    let template = ComponentTemplate::from_component(
+        TextComponent::new().add_children::<TextComponent>(vec![
+            "Welcome "
+                .color(Color::Gold)
+                .add_children::<TextComponent>(vec!["{player}".bold(true), "{count}".into()]),
+            "!".into(),
+        ]),
+    );
+    let message = template.render(&values()).unwrap();
+    assert_eq!(
+        message,
+        TextComponent::new().add_children::<TextComponent>(vec![
+            "Welcome "
+                .color(Color::Gold)
+                .add_children::<TextComponent>(vec![
+                    "".bold(true).add_child("Steve"),
+                    "".add_child("3".bold(true)),
+                ]),
+            "!".into(),
+        ])
+    );
+    assert_eq!(message.to_plain(&NoResolutor), "Welcome Steve3!");
+}
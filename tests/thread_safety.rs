@@ -0,0 +1,91 @@
+use std::{sync::Arc, thread};
+#[cfg(feature = "custom")]
+use text_components::custom::{CustomData, Payload};
+#[cfg(feature = "pretty")]
+use text_components::fmt::PrettyTextBuilder;
+#[cfg(feature = "nbt")]
+use text_components::nbt::NbtBuilder;
+#[cfg(feature = "test-util")]
+use text_components::testutil::{KeepResolutor, TestResolutor};
+use text_components::{
+    Modifier, TextComponent,
+    containers::{Book, SignText},
+    content::{Content, NbtSource, Object, Resolvable},
+    dialog::{Dialog, DialogRef},
+    fmt::TextBuilder,
+    format::{Color, Format},
+    interactivity::{ClickEvent, HoverEvent, Interactivity},
+    path::ComponentPath,
+    resolving::{NoResolutor, PlaceholderConfig, ResolveError, StaticScores, TextResolutor},
+    translation::{TranslatedMessage, TranslationTable},
+};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn types_are_send_and_sync() {
+    assert_send_sync::<TextComponent>();
+    assert_send_sync::<Content>();
+    assert_send_sync::<Format>();
+    assert_send_sync::<Color>();
+    assert_send_sync::<Interactivity>();
+    assert_send_sync::<ClickEvent>();
+    assert_send_sync::<HoverEvent>();
+    assert_send_sync::<DialogRef>();
+    assert_send_sync::<Dialog>();
+    assert_send_sync::<Object>();
+    assert_send_sync::<Resolvable>();
+    assert_send_sync::<NbtSource>();
+    assert_send_sync::<TranslatedMessage>();
+    assert_send_sync::<TranslationTable>();
+    assert_send_sync::<ComponentPath>();
+    assert_send_sync::<Book>();
+    assert_send_sync::<SignText>();
+    assert_send_sync::<PlaceholderConfig>();
+    assert_send_sync::<ResolveError>();
+    #[cfg(feature = "custom")]
+    {
+        assert_send_sync::<CustomData>();
+        assert_send_sync::<Payload>();
+    }
+
+    assert_send_sync::<TextBuilder>();
+    #[cfg(feature = "pretty")]
+    assert_send_sync::<PrettyTextBuilder>();
+    #[cfg(feature = "nbt")]
+    assert_send_sync::<NbtBuilder>();
+    assert_send_sync::<NoResolutor>();
+    assert_send_sync::<StaticScores>();
+    #[cfg(feature = "test-util")]
+    {
+        assert_send_sync::<KeepResolutor>();
+        assert_send_sync::<TestResolutor>();
+    }
+}
+
+#[test]
+fn shared_between_threads() {
+    let component = Arc::new(
+        "Kills: "
+            .color(Color::Red)
+            .add_child(TextComponent::scoreboard("@s", "kills")),
+    );
+    let resolutors: Vec<Arc<dyn TextResolutor + Send + Sync>> = vec![
+        Arc::new(NoResolutor),
+        Arc::new(StaticScores::new().score("@s", "kills", 3)),
+    ];
+    let target = Arc::new(TextBuilder);
+    let built = resolutors
+        .into_iter()
+        .map(|resolutor| {
+            let component = component.clone();
+            let target = target.clone();
+            thread::spawn(move || component.build(&resolutor, target))
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(built, ["Kills: [Score kills@@s]", "Kills: 3"]);
+    assert_eq!(component.to_string(), "Kills: [Score kills@@s]");
+}
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+use text_components::{
+    Modifier, TextComponent,
+    fmt::TextBuilder,
+    format::Color,
+    resolving::{NoResolutor, PlaceholderConfig},
+    translation::TranslatedMessage,
+};
+
+#[test]
+fn translated_key() {
+    let key = format!("gui.{}", "done");
+    assert_eq!(
+        TextComponent::translated_key(key),
+        TextComponent::translated(TranslatedMessage::new("gui.done", None))
+    );
+    assert_eq!(
+        TextComponent::translated_key("gui.done"),
+        TextComponent::from(TranslatedMessage::from("gui.done"))
+    );
+}
+
+#[test]
+fn translated_with_keeps_the_order() {
+    let component = TextComponent::translated_with(
+        "death.attack.player",
+        ["Steve".color(Color::Red), "Alex".into(), "Sword".into()],
+    );
+    let args = component.as_translation().unwrap().args.as_deref().unwrap();
+    let args = args
+        .iter()
+        .map(|arg| arg.as_text().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(args, ["Steve", "Alex", "Sword"]);
+    assert_eq!(
+        component.as_translation().unwrap().args.as_deref().unwrap()[0]
+            .format
+            .color,
+        Some(Color::Red)
+    );
+    let ordered = TextComponent::translated_with("ordered", vec!["A", "B"])
+        .with_inline_translations(HashMap::from([("ordered".into(), "%s then %s".into())]));
+    assert_eq!(ordered.to_plain(&NoResolutor), "A then B");
+}
+
+#[test]
+fn translated_with_no_args() {
+    assert_eq!(
+        TextComponent::translated_with("gui.done", Vec::<TextComponent>::new()),
+        TextComponent::translated_key("gui.done")
+    );
+}
+
+#[test]
+fn conversions() {
+    let message = TranslatedMessage::from(String::from("gui.done"));
+    assert_eq!(message, TranslatedMessage::from("gui.done"));
+    assert_eq!(
+        TextComponent::from(&message),
+        TextComponent::from(message.clone())
+    );
+}
+
+#[test]
+fn fallback_takes_the_args() {
+    let fallback = |fallback: &'static str, args: Vec<&'static str>| {
+        TranslatedMessage::new(
+            "missing.key",
+            Some(args.into_iter().map(TextComponent::from).collect()),
+        )
+        .component_fallback(fallback)
+    };
+    // Both the visitors and the builders using stringify_content place them
+    let shown = |component: &TextComponent| {
+        let plain = component.to_plain(&NoResolutor);
+        assert_eq!(
+            TextBuilder::stringify_content(&TextBuilder, &NoResolutor, component),
+            plain
+        );
+        plain
+    };
+    assert_eq!(shown(&fallback("Hi %s!", vec!["Steve"])), "Hi Steve!");
+    assert_eq!(
+        shown(&fallback(
+            "%s gave %s to %s",
+            vec!["Steve", "a Sword", "Alex"]
+        )),
+        "Steve gave a Sword to Alex"
+    );
+    assert_eq!(
+        shown(&fallback("%2$s from %1$s", vec!["Steve", "a Sword"])),
+        "a Sword from Steve"
+    );
+    // Missing args show nothing, like in a translation
+    assert_eq!(shown(&fallback("%s and %s", vec!["A"])), "A and ");
+    assert_eq!(shown(&fallback("100%", vec![])), "100%");
+    // Without a fallback the placeholder is still shown as it is
+    let placeholders = PlaceholderConfig {
+        translation: "[%s]".into(),
+        ..PlaceholderConfig::new()
+    };
+    let missing = TextComponent::translated_with("missing.key", ["Steve"]);
+    assert_eq!(missing.to_plain(&placeholders), "[%s]");
+    assert_eq!(
+        TextBuilder::stringify_content(&TextBuilder, &placeholders, &missing),
+        "[%s]"
+    );
+}
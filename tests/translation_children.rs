@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use text_components::{
+    Modifier, TextComponent,
+    format::{Color, Format},
+    resolving::NoResolutor,
+};
+
+/// A red translation with a bold argument and two children, one of them italic.
+fn greeting(key: &'static str) -> TextComponent {
+    TextComponent::translated_with(key, ["Alex".bold(true)])
+        .color(Color::Red)
+        .add_child(" and".italic(true))
+        .add_child(" more")
+        .with_inline_translations(HashMap::from([("greet".into(), "Hello %s!".into())]))
+}
+
+fn red() -> Format {
+    Format::new().color(Color::Red)
+}
+
+#[test]
+fn plain() {
+    assert_eq!(
+        greeting("greet").to_plain(&NoResolutor),
+        "Hello Alex! and more"
+    );
+    assert_eq!(
+        greeting("missing").to_plain(&NoResolutor),
+        "[Translation: missing] and more"
+    );
+}
+
+#[test]
+fn children_inherit_the_format() {
+    let runs = greeting("greet")
+        .flatten_runs(&NoResolutor)
+        .into_iter()
+        .map(|run| (run.text, run.format))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        runs,
+        [
+            ("Hello ".to_string(), red()),
+            ("Alex".to_string(), red().bold(true)),
+            ("!".to_string(), red()),
+            (" and".to_string(), red().italic(true)),
+            (" more".to_string(), red()),
+        ]
+    );
+}
+
+#[cfg(feature = "pretty")]
+#[test]
+fn pretty() {
+    colored::control::set_override(true);
+    let red = |text: &str| format!("\u{1b}[91m{text}\u{1b}[0m");
+    assert_eq!(
+        greeting("greet").to_pretty(&NoResolutor).to_string(),
+        [
+            red("Hello "),
+            "\u{1b}[1;91mAlex\u{1b}[0m".to_string(),
+            red("!"),
+            "\u{1b}[3;91m and\u{1b}[0m".to_string(),
+            red(" more"),
+        ]
+        .concat()
+    );
+    // The placeholder and the fallback are colored like the translated text
+    assert_eq!(
+        greeting("missing").to_pretty(&NoResolutor).to_string(),
+        [
+            red("[Translation: missing]"),
+            "\u{1b}[3;91m and\u{1b}[0m".to_string(),
+            red(" more"),
+        ]
+        .concat()
+    );
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn nbt() {
+    use text_components::nbt::{NbtBuilder, ToSNBT};
+    let component = TextComponent::translated_with("greet", ["Alex".bold(true)])
+        .color(Color::Red)
+        .add_child(" and".italic(true));
+    let nbt = component.build(&NoResolutor, NbtBuilder::new());
+    assert_eq!(TextComponent::from_nbt(&nbt), Some(component));
+    assert_eq!(
+        nbt.to_snbt(),
+        r#"{translate:"greet",with:[{text:"Alex",bold:1b}],extra:[{text:" and",italic:1b}],color:"red"}"#
+    );
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn nbt_arguments_keep_their_events() {
+    use simdnbt::owned::{NbtList, NbtTag};
+    use text_components::{
+        interactivity::{ClickEvent, HoverEvent},
+        nbt::{NbtBuilder, ToSNBT},
+        resolving::BuildTarget,
+    };
+    let steve = "Steve"
+        .hover_event(HoverEvent::show_text("Click to message".add_child("!")))
+        .click_event(ClickEvent::suggest_command("/msg Steve "))
+        .add_child(
+            " (".add_child(TextComponent::entity("@s", None))
+                .add_child(")"),
+        );
+    let component =
+        TextComponent::translated_with("chat.type.text", [steve, "Hi".into()]).add_child(
+            TextComponent::entity("@a", Some(", ".hover_event(HoverEvent::show_text("and")))),
+        );
+    // Built without resolving, so the selectors and their separators are kept
+    let nbt = NbtBuilder::new().build_component(&NoResolutor, &component);
+    assert_eq!(
+        TextComponent::from_nbt(&nbt),
+        Some(component),
+        "{}",
+        nbt.to_snbt()
+    );
+
+    // Plain arguments and children are written as strings, like in JSON
+    let plain = TextComponent::translated_with("chat.type.text", ["Steve", "Hi"]).add_child("!");
+    let nbt = plain.build(&NoResolutor, NbtBuilder::new());
+    let NbtTag::Compound(compound) = &nbt else {
+        panic!("{nbt:?}")
+    };
+    assert!(matches!(
+        compound.get("with"),
+        Some(NbtTag::List(NbtList::String(_)))
+    ));
+    assert!(matches!(
+        compound.get("extra"),
+        Some(NbtTag::List(NbtList::String(_)))
+    ));
+    assert_eq!(
+        nbt.to_snbt(),
+        r#"{translate:"chat.type.text",with:["Steve","Hi"],extra:["!"]}"#
+    );
+    assert_eq!(TextComponent::from_nbt(&nbt), Some(plain));
+}
@@ -0,0 +1,5 @@
+use text_components::{format::Format, style};
+
+const TITLE: Format = style!(color: "#zz0044");
+
+fn main() {}
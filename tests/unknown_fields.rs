@@ -0,0 +1,63 @@
+use serde_json::{Value, json};
+use text_components::{
+    TextComponent,
+    resolving::NoResolutor,
+    unknown::{UnknownValue, preserve_unknown},
+};
+
+fn future() -> Value {
+    json!({
+        "text": "Hi",
+        "futureKey": {"a": 1},
+        "bold": true,
+        "extra": ["plain", {"text": "b", "futureChildKey": [1, "x"]}],
+    })
+}
+
+#[test]
+fn json_round_trip() {
+    let component = preserve_unknown(|| TextComponent::from_json_value(future())).unwrap();
+    let fields = component.unknown_fields.as_ref().unwrap();
+    assert_eq!(
+        fields.get("futureKey"),
+        Some(&UnknownValue::Json(json!({"a": 1})))
+    );
+    assert!(fields.get("bold").is_none());
+    assert!(component.children[1].unknown_fields.is_some());
+
+    assert_eq!(serde_json::to_value(&component).unwrap(), future());
+    assert_eq!(component.to_json_value(&NoResolutor).unwrap(), future());
+}
+
+#[test]
+fn dropped_by_default() {
+    let component = TextComponent::from_json_value(future()).unwrap();
+    assert!(component.unknown_fields.is_none());
+    assert_eq!(
+        serde_json::to_value(&component).unwrap(),
+        json!({"text": "Hi", "bold": true, "extra": ["plain", "b"]})
+    );
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn nbt_round_trip() {
+    use simdnbt::owned::{NbtCompound, NbtTag};
+    use text_components::nbt::NbtBuilder;
+    let future = NbtTag::Compound(NbtCompound::from_values(vec![
+        ("text".into(), NbtTag::String("Hi".into())),
+        (
+            "futureKey".into(),
+            NbtTag::Compound(NbtCompound::from_values(vec![("a".into(), NbtTag::Int(1))])),
+        ),
+    ]));
+    let component = preserve_unknown(|| TextComponent::from_nbt(&future)).unwrap();
+    assert!(component.unknown_fields.is_some());
+    assert_eq!(component.build(&NoResolutor, NbtBuilder::new()), future);
+    assert!(
+        TextComponent::from_nbt(&future)
+            .unwrap()
+            .unknown_fields
+            .is_none()
+    );
+}
@@ -0,0 +1,229 @@
+use text_components::{
+    Modifier, TextComponent,
+    content::{Atlas, NbtSource, ObjectPlayer, PlayerProperties, Resolvable},
+    format::Color,
+    resolving::NoResolutor,
+    serde::borrowed::TextComponentDe,
+};
+
+/// Components in the shape the game writes them, with the `type` key
+/// it adds, and the JSON this crate writes for them.
+fn golden() -> Vec<(&'static str, &'static str, TextComponent)> {
+    let mut textured = ObjectPlayer::name("Jeb_");
+    textured.id = Some([1, 2, 3, 4]);
+    textured.properties.push(PlayerProperties {
+        name: "textures".into(),
+        value: "e3RleHR1cmVzOnt9fQ==".into(),
+        signature: None,
+    });
+    vec![
+        (
+            r#"{"type":"object","object":"atlas","atlas":"minecraft:items","sprite":"item/porkchop"}"#,
+            r#"{"object":"atlas","atlas":"minecraft:items","sprite":"item/porkchop"}"#,
+            TextComponent::atlas("item/porkchop", Atlas::ITEMS),
+        ),
+        (
+            r#"{"type":"object","object":"atlas","sprite":"block/stone"}"#,
+            r#"{"object":"atlas","sprite":"block/stone"}"#,
+            TextComponent::sprite("block/stone"),
+        ),
+        (
+            r#"{"type":"object","object":"player","player":{"name":"Jeb_"},"hat":false}"#,
+            r#"{"object":"player","player":{"name":"Jeb_"},"hat":false}"#,
+            TextComponent::player_head(ObjectPlayer::name("Jeb_"), false),
+        ),
+        (
+            r#"{"type":"object","object":"player","player":{"name":"Jeb_","id":[1,2,3,4],"properties":[{"name":"textures","value":"e3RleHR1cmVzOnt9fQ=="}]},"hat":true}"#,
+            r#"{"object":"player","player":{"name":"Jeb_","id":[1,2,3,4],"properties":[{"name":"textures","value":"e3RleHR1cmVzOnt9fQ=="}]}}"#,
+            TextComponent::player_head(textured, true),
+        ),
+        (
+            r#"{"type":"score","score":{"name":"@s","objective":"kills"}}"#,
+            r#"{"score":{"name":"@s","objective":"kills"}}"#,
+            TextComponent::scoreboard("@s", "kills"),
+        ),
+        (
+            r#"{"type":"selector","selector":"@e[type=pig]","separator":{"text":", ","color":"gray"}}"#,
+            r#"{"selector":"@e[type=pig]","separator":{"text":", ","color":"gray"}}"#,
+            TextComponent::entity("@e[type=pig]", None),
+        ),
+        (
+            r#"{"type":"nbt","source":"entity","nbt":"Health","entity":"@p","interpret":true,"separator":{"text":", "}}"#,
+            r#"{"nbt":"Health","interpret":true,"separator":{"text":", "},"entity":"@p"}"#,
+            TextComponent::nbt("Health", NbtSource::entity("@p"), true, None),
+        ),
+        (
+            r#"{"type":"nbt","nbt":"Items[0]","block":"1 64 -3","separator":" | "}"#,
+            r#"{"nbt":"Items[0]","separator":{"text":" | "},"block":"1 64 -3"}"#,
+            TextComponent::nbt_sep_str("Items[0]", NbtSource::block(1, 64, -3), false, " | "),
+        ),
+        (
+            r#"{"type":"nbt","nbt":"quest.title","storage":"minecraft:quests"}"#,
+            r#"{"nbt":"quest.title","separator":{"text":", "},"storage":"minecraft:quests"}"#,
+            TextComponent::nbt(
+                "quest.title",
+                NbtSource::Storage("minecraft:quests".into()),
+                false,
+                None,
+            ),
+        ),
+    ]
+}
+
+#[test]
+fn reads_vanilla_json() {
+    for (vanilla, _, expected) in golden() {
+        assert_eq!(
+            TextComponent::from_json(vanilla).unwrap(),
+            expected,
+            "{vanilla}"
+        );
+        let borrowed = serde_json::from_str::<TextComponentDe>(vanilla).unwrap();
+        assert_eq!(borrowed.into_owned(), expected, "{vanilla}");
+    }
+}
+
+#[test]
+fn reads_vanilla_snbt() {
+    // JSON is valid SNBT, the game writes the same keys in both
+    for (vanilla, _, expected) in golden() {
+        assert_eq!(
+            TextComponent::from_snbt(vanilla).unwrap(),
+            expected,
+            "{vanilla}"
+        );
+    }
+}
+
+#[test]
+fn writes_vanilla_json() {
+    for (_, written, component) in golden() {
+        assert_eq!(serde_json::to_string(&component).unwrap(), written);
+        assert_eq!(
+            TextComponent::from_json(written).unwrap(),
+            component,
+            "{written}"
+        );
+    }
+}
+
+/// The wiki examples of each resolvable, with the JSON and SNBT they are written as
+/// on their own.
+fn resolvables() -> Vec<(Resolvable, &'static str, &'static str)> {
+    vec![
+        (
+            Resolvable::Scoreboard {
+                selector: "@p".into(),
+                objective: "kills".into(),
+            },
+            r#"{"score":{"name":"@p","objective":"kills"}}"#,
+            r#"{score:{name:"@p",objective:"kills"}}"#,
+        ),
+        (
+            Resolvable::Entity {
+                selector: "@e[type=pig]".into(),
+                separator: Resolvable::entity_separator(),
+            },
+            r#"{"selector":"@e[type=pig]","separator":{"text":", ","color":"gray"}}"#,
+            r#"{selector:"@e[type=pig]"}"#,
+        ),
+        (
+            Resolvable::Entity {
+                selector: "@a".into(),
+                separator: Box::new(" & ".into()),
+            },
+            r#"{"selector":"@a","separator":{"text":" & "}}"#,
+            r#"{selector:"@a",separator:" & "}"#,
+        ),
+        (
+            Resolvable::NBT {
+                path: "Health".into(),
+                interpret: Some(true),
+                separator: Resolvable::nbt_separator(),
+                source: NbtSource::entity("@p"),
+            },
+            r#"{"nbt":"Health","interpret":true,"separator":{"text":", "},"entity":"@p"}"#,
+            r#"{nbt:"Health",interpret:1b,entity:"@p",source:"entity"}"#,
+        ),
+        (
+            Resolvable::NBT {
+                path: "Items[0]".into(),
+                interpret: None,
+                separator: Box::new(" | ".into()),
+                source: NbtSource::block(1, 64, -3),
+            },
+            r#"{"nbt":"Items[0]","separator":{"text":" | "},"block":"1 64 -3"}"#,
+            r#"{nbt:"Items[0]",block:"1 64 -3",source:"block",separator:" | "}"#,
+        ),
+    ]
+}
+
+#[test]
+fn writes_resolvables() {
+    for (resolvable, json, _) in resolvables() {
+        assert_eq!(serde_json::to_string(&resolvable).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<Resolvable>(json).unwrap(),
+            resolvable,
+            "{json}"
+        );
+    }
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn writes_resolvables_nbt() {
+    use text_components::nbt::ToSNBT;
+    for (resolvable, _, snbt) in resolvables() {
+        assert_eq!(resolvable.to_nbt().to_snbt(), snbt);
+        assert_eq!(
+            TextComponent::from_snbt(snbt)
+                .unwrap()
+                .content
+                .as_resolvable(),
+            Some(&resolvable),
+            "{snbt}"
+        );
+    }
+}
+
+#[test]
+fn object_discriminator_is_checked() {
+    for json in [
+        r#"{"object":"atlas","player":{"name":"Jeb_"}}"#,
+        r#"{"object":"player","sprite":"block/stone"}"#,
+        r#"{"object":"banner","sprite":"block/stone"}"#,
+    ] {
+        assert!(TextComponent::from_json(json).is_err(), "{json}");
+        assert!(TextComponent::from_snbt(json).is_err(), "{json}");
+        assert!(
+            serde_json::from_str::<TextComponentDe>(json).is_err(),
+            "{json}"
+        );
+    }
+}
+
+#[test]
+fn plain_children_as_strings() {
+    let words = [
+        "The", " quick", " brown", " fox", " jumps", " over", " the", " lazy", " dog",
+    ];
+    let line = words
+        .iter()
+        .fold("<Steve> ".color(Color::Gray), |line, word| {
+            line.add_child(*word)
+        })
+        .add_child("!".bold(true));
+    let json = line.to_json(&NoResolutor).unwrap();
+    assert_eq!(
+        json,
+        r#"{"text":"<Steve> ","extra":["The"," quick"," brown"," fox"," jumps"," over"," the"," lazy"," dog",{"text":"!","bold":true}],"color":"gray"}"#
+    );
+    // Each plain child used to be written as {"text":"..."}
+    let objects = json.len() + words.len() * r#"{"text":}"#.len();
+    assert!(json.len() * 10 < objects * 7, "{} of {objects}", json.len());
+
+    assert_eq!(TextComponent::from_json(&json).unwrap(), line);
+    let borrowed = serde_json::from_str::<TextComponentDe>(&json).unwrap();
+    assert_eq!(borrowed.into_owned(), line);
+}